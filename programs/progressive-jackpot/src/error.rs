@@ -55,4 +55,388 @@ pub enum CasinoError {
     
     #[msg("Jackpot reset threshold not met")]
     ResetThresholdNotMet,
+    
+    #[msg("Batch exceeds maximum settlement count")]
+    BatchTooLarge,
+    
+    #[msg("Batch accounts malformed or mismatched")]
+    InvalidBatchAccounts,
+    
+    #[msg("Acknowledged sequence is out of range")]
+    InvalidNotificationAck,
+    
+    #[msg("Amount must be greater than zero")]
+    InvalidAmount,
+    
+    #[msg("Jackpot pool has not reached the minimum seed balance")]
+    PoolNotSeeded,
+    
+    #[msg("Bets are not accepted outside operating hours")]
+    OutsideOperatingHours,
+    
+    #[msg("Bet has no unclaimed winnings")]
+    NoWinToClaim,
+    
+    #[msg("Win exceeds the vesting threshold; use claim_vested_win")]
+    WinRequiresVesting,
+    
+    #[msg("Win is below the vesting threshold; use claim_win")]
+    WinNotVested,
+    
+    #[msg("No vested amount available yet")]
+    NothingVested,
+    
+    #[msg("Invalid experiment variants")]
+    InvalidExperiment,
+    
+    #[msg("Experiment variants exceed the base jackpot RTP")]
+    ExperimentRtpExceeded,
+    
+    #[msg("No accrued fees to claim")]
+    NoFeesToClaim,
+    
+    #[msg("Players cannot refer themselves")]
+    SelfReferral,
+    
+    #[msg("Referral accounts missing or mismatched")]
+    InvalidReferral,
+    
+    #[msg("Comp point redemption below the minimum")]
+    CompRedemptionTooSmall,
+    
+    #[msg("Not enough comp points")]
+    InsufficientCompPoints,
+    
+    #[msg("Invalid VIP tiers")]
+    InvalidVipTiers,
+    
+    #[msg("No rakeback to claim")]
+    NoRakeback,
+    
+    #[msg("Player is self-excluded")]
+    SelfExcluded,
+    
+    #[msg("Bet exceeds the player's daily wager or loss limit")]
+    DailyLimitExceeded,
+    
+    #[msg("Player is frozen pending review")]
+    PlayerFrozen,
+    
+    #[msg("Wallet is not on the allowlist")]
+    NotAMember,
+    
+    #[msg("Bet belongs to a different jackpot pool")]
+    PoolMismatch,
+    
+    #[msg("No open draw round for this bet")]
+    DrawClosed,
+    
+    #[msg("Draw round has no free entries")]
+    DrawFull,
+    
+    #[msg("Draw round has not ended")]
+    DrawNotEnded,
+    
+    #[msg("Draw round already settled")]
+    DrawAlreadySettled,
+    
+    #[msg("Winner does not match the drawn entry")]
+    InvalidDrawWinner,
+    
+    #[msg("Draw entry does not exist")]
+    InvalidDrawEntry,
+    
+    #[msg("Ticket already minted for this entry")]
+    TicketAlreadyMinted,
+    
+    #[msg("Ticket account does not prove ownership of the winning entry")]
+    InvalidDrawTicket,
+    
+    #[msg("No unpaid community share for this account")]
+    NoCommunityShare,
+    
+    #[msg("Invalid season parameters")]
+    InvalidSeason,
+    
+    #[msg("Season has not ended")]
+    SeasonNotEnded,
+    
+    #[msg("Season already finalized")]
+    SeasonAlreadyFinalized,
+    
+    #[msg("Winners do not match the season leaderboard")]
+    InvalidSeasonWinners,
+    
+    #[msg("No cashback to claim")]
+    NoCashback,
+    
+    #[msg("Bonus grant must be positive")]
+    InvalidBonusGrant,
+    
+    #[msg("Bonus balance too low for this bet")]
+    InsufficientBonusCredit,
+    
+    #[msg("Bonus-funded bet requires the player's BonusCredit account")]
+    MissingBonusCredit,
+    
+    #[msg("Bonus wagering requirement not yet met")]
+    BonusWageringIncomplete,
+    
+    #[msg("Promo claim does not match the campaign Merkle root")]
+    InvalidPromoProof,
+    
+    #[msg("Potential payout exceeds the exposure limit")]
+    ExposureLimitExceeded,
+    
+    #[msg("Withdrawal exceeds accumulated house fees")]
+    HouseFeesExceeded,
+    
+    #[msg("Destination is not an allowlisted treasury address")]
+    InvalidTreasuryDestination,
+    
+    #[msg("House sweeping is not configured")]
+    SweepDisabled,
+    
+    #[msg("House fees are below the sweep threshold")]
+    SweepBelowThreshold,
+    
+    #[msg("Keeper account does not belong to the signer")]
+    InvalidKeeper,
+    
+    #[msg("Bet is not pending past the VRF timeout")]
+    BetNotExpired,
+    
+    #[msg("VRF request was not created for this bet")]
+    VrfRequestBetMismatch,
+    
+    #[msg("VRF request belongs to a different player")]
+    VrfRequestPlayerMismatch,
+    
+    #[msg("Bet cannot be settled until min_settle_delay_slots have passed")]
+    SettlementTooEarly,
+    
+    #[msg("Bets must be placed directly or via an allowlisted integrator program")]
+    UnauthorizedCaller,
+    
+    #[msg("Pool has reached its bet limit for this slot")]
+    SlotBetLimitReached,
+    
+    #[msg("Player must wait for the bet cooldown to pass")]
+    CooldownActive,
+    
+    #[msg("Invariant violated: config percentages out of range")]
+    InvariantConfigPercentages,
+    
+    #[msg("Invariant violated: pool lamports below balance, pending payouts and rent")]
+    InvariantPoolUnderfunded,
+    
+    #[msg("Invariant violated: reserve pool lamports below its balance and rent")]
+    InvariantReserveUnderfunded,
+    
+    #[msg("Invariant violated: house vault lamports below rent and owed rakeback")]
+    InvariantHouseUnderfunded,
+    
+    #[msg("Invariant violated: pending earnings exceed lifetime earnings")]
+    InvariantEarningsOverclaimed,
+    
+    #[msg("Reserves were already published this epoch")]
+    ReservesAlreadyPublished,
+    
+    #[msg("Volume bucket is not for the current UTC day")]
+    InvalidVolumeBucketDay,
+    
+    #[msg("Win exceeds the manual review threshold; use claim_reviewed_win")]
+    WinRequiresReview,
+    
+    #[msg("Payout is awaiting approval or the dispute window")]
+    PayoutNotReleasable,
+    
+    #[msg("Bet is under dispute")]
+    BetDisputed,
+    
+    #[msg("Dispute is already resolved")]
+    DisputeNotOpen,
+    
+    #[msg("An emergency withdrawal is pending")]
+    EmergencyWithdrawPending,
+    
+    #[msg("No emergency withdrawal is pending")]
+    NoEmergencyWithdrawPending,
+    
+    #[msg("Emergency withdrawal timelock has not elapsed")]
+    EmergencyTimelockActive,
+    
+    #[msg("Casino is shut down; no new bets are accepted")]
+    CasinoShutdown,
+    
+    #[msg("Casino is not in sunset mode")]
+    NotShutDown,
+    
+    #[msg("Bets are still awaiting settlement")]
+    BetsStillOpen,
+    
+    #[msg("Decommission delay has not elapsed")]
+    DecommissionTooEarly,
+    
+    #[msg("Not enough stake shares")]
+    InsufficientShares,
+    
+    #[msg("LP mint already exists or shares were issued without it")]
+    LpMintUnavailable,
+    
+    #[msg("LP mint, token account and token program are required")]
+    MissingLpAccounts,
+    
+    #[msg("Rewards can only be compounded by their owner unless auto-compound is on")]
+    CompoundingDisabled,
+    
+    #[msg("Reward mint is already set")]
+    RewardMintAlreadySet,
+    
+    #[msg("No reward mint is set")]
+    RewardMintNotSet,
+    
+    #[msg("Reward mint, token account and token program are required to claim emissions")]
+    MissingRewardTokenAccounts,
+    
+    #[msg("Lending program is not configured or does not match")]
+    LendingNotConfigured,
+    
+    #[msg("Lending accounts do not match the open yield position")]
+    LendingReserveMismatch,
+    
+    #[msg("No yield strategy is set")]
+    StrategyNotSet,
+    
+    #[msg("Withdraw everything from the current strategy before switching")]
+    StrategyPositionOpen,
+    
+    #[msg("Strategy accounts do not match the configured strategy")]
+    InvalidStrategyAccounts,
+    
+    #[msg("Reward claim cooldown has not elapsed")]
+    ClaimCooldownActive,
+    
+    #[msg("Reward claim is below the minimum claim amount")]
+    ClaimBelowMinimum,
+    
+    #[msg("Payout must go to the player's designated beneficiary")]
+    BeneficiaryMismatch,
+    
+    #[msg("Payout split recipients or shares are invalid")]
+    InvalidPayoutSplit,
+    
+    #[msg("Split recipients missing or out of order")]
+    SplitRecipientMismatch,
+    
+    #[msg("Charity round-up cannot exceed 100% of the bet")]
+    InvalidCharityBps,
+    
+    #[msg("Charity round-ups need the charity vault")]
+    CharityNotConfigured,
+    
+    #[msg("Account is not the charity vault's configured charity")]
+    CharityMismatch,
+    
+    #[msg("Only a recently lost, wallet-funded bet can be doubled, and only once")]
+    DoubleOrNothingUnavailable,
+    
+    #[msg("Double-or-nothing bets settle through fulfill_double_or_nothing")]
+    DoubleOrNothingPending,
+    
+    #[msg("Bet is not a pending double-or-nothing")]
+    NotDoubleOrNothing,
+    
+    #[msg("Parlays need 2 to 5 legs, each paying more than its stake")]
+    InvalidParlay,
+    
+    #[msg("Parlays are disabled")]
+    ParlaysDisabled,
+    
+    #[msg("Parlay payout exceeds the configured maximum")]
+    ParlayPayoutTooLarge,
+    
+    #[msg("Parlay has neither won nor timed out")]
+    ParlayNotClaimable,
+    
+    #[msg("Side-bet rounds need 1 to 7 increasing bucket bounds and a future close time")]
+    InvalidSideBetRound,
+    
+    #[msg("Pool already has an open side-bet round")]
+    SideBetRoundOpen,
+    
+    #[msg("Side-bet round is no longer taking bets")]
+    SideBetClosed,
+    
+    #[msg("Side-bet bucket is out of range or already passed")]
+    InvalidSideBetBucket,
+    
+    #[msg("Pool's open side-bet round must be passed to settle a jackpot hit")]
+    SideBetPoolRequired,
+    
+    #[msg("Side-bet round has not settled")]
+    SideBetNotSettled,
+    
+    #[msg("Dice are disabled")]
+    DiceDisabled,
+    
+    #[msg("Dice win chance must be 1% to 95% and pay more than the wager")]
+    InvalidDiceTarget,
+    
+    #[msg("Paytable allows up to 16 outcomes with nonzero weights and jackpot shares of at most 100%")]
+    InvalidPaytable,
+    
+    #[msg("Paytable can't change while spins are open")]
+    PaytableInUse,
+    
+    #[msg("Slots have no paytable")]
+    SlotsDisabled,
+    
+    #[msg("Scratch batches need at least one card and a nonzero price")]
+    InvalidScratchBatch,
+    
+    #[msg("Every card in this scratch batch has been sold")]
+    ScratchBatchSoldOut,
+    
+    #[msg("Scratch card outcome does not match the batch's Merkle root")]
+    InvalidScratchProof,
+    
+    #[msg("Every sold scratch card must be revealed before the batch closes")]
+    ScratchCardsUnrevealed,
+    
+    #[msg("Lottery rounds need 1-6 picks from at most 64 numbers, a future draw and tier shares within the pot")]
+    InvalidLottoRound,
+    
+    #[msg("Lottery tickets must pick the round's count of distinct numbers in range")]
+    InvalidLottoPicks,
+    
+    #[msg("Lottery ticket sales have closed")]
+    LottoSalesClosed,
+    
+    #[msg("Lottery draw has not been reached or already ran")]
+    LottoNotDrawable,
+    
+    #[msg("Lottery round has not been drawn yet")]
+    LottoNotDrawn,
+    
+    #[msg("Bonus wheel allows up to 8 slices with nonzero weights")]
+    InvalidBonusWheel,
+    
+    #[msg("Bonus wheel has no prizes")]
+    BonusWheelDisabled,
+    
+    #[msg("No bonus wheel spins earned")]
+    NoBonusWheelSpins,
+    
+    #[msg("Revealed threshold and salt do not match the mystery jackpot commitment")]
+    InvalidMysteryReveal,
+    
+    #[msg("Bet did not lift the pool past the mystery threshold during the round")]
+    MysteryNotTriggered,
+    
+    #[msg("Invalid happy-hour window")]
+    InvalidHappyHour,
+    
+    #[msg("Jackpot bet record must be passed exactly when a jackpot lands")]
+    InvalidJackpotBet,
 }
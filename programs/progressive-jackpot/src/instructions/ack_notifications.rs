@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Keeper acknowledges delivered notifications up to and including `up_to_seq` (operator only)
+pub fn ack_notifications(
+    ctx: Context<AckNotifications>,
+    up_to_seq: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.operator.key() == ctx.accounts.config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let mut queue = ctx.accounts.notification_queue.load_mut()?;
+    
+    require!(
+        up_to_seq > queue.acked_seq && up_to_seq < queue.next_seq,
+        CasinoError::InvalidNotificationAck
+    );
+    
+    queue.acked_seq = up_to_seq;
+    
+    msg!("Notifications acknowledged up to seq {}", up_to_seq);
+    
+    emit!(NotificationsAcked {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        operator: ctx.accounts.operator.key(),
+        acked_seq: up_to_seq,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AckNotifications<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"notification_queue"], bump = notification_queue.load()?.bump)]
+    pub notification_queue: AccountLoader<'info, NotificationQueue>,
+    
+    pub operator: Signer<'info>,
+}
+
+#[event]
+pub struct NotificationsAcked {
+    pub sequence: u64,
+    pub operator: Pubkey,
+    pub acked_seq: u64,
+}
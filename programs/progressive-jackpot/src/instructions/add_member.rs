@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Approve a wallet to bet while the allowlist is enabled (authority only)
+pub fn add_member(
+    ctx: Context<AddMember>,
+    player: Pubkey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    
+    let membership = &mut ctx.accounts.membership;
+    membership.player = player;
+    membership.added_by = ctx.accounts.authority.key();
+    membership.added_at = now;
+    membership.bump = ctx.bumps.membership;
+    
+    msg!("Member added: {}", player);
+    
+    emit!(MemberAdded {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct AddMember<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Membership::INIT_SPACE,
+        seeds = [b"member", player.as_ref()],
+        bump
+    )]
+    pub membership: Account<'info, Membership>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MemberAdded {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Approve a held payout so the player can claim it before the dispute window ends (payout approver only)
+pub fn approve_payout(
+    ctx: Context<ApprovePayout>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pending_payout = &mut ctx.accounts.pending_payout;
+    
+    require!(
+        ctx.accounts.approver.key() == config.payout_approver,
+        CasinoError::Unauthorized
+    );
+    
+    pending_payout.approved = true;
+    
+    msg!("Payout of {} lamports approved for {}", pending_payout.amount, pending_payout.player);
+    
+    emit!(PayoutApproved {
+        sequence: config.next_event_sequence(),
+        approver: ctx.accounts.approver.key(),
+        player: pending_payout.player,
+        bet: pending_payout.bet,
+        amount: pending_payout.amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ApprovePayout<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"payout", pending_payout.bet.as_ref()], bump = pending_payout.bump)]
+    pub pending_payout: Account<'info, PendingPayout>,
+    
+    pub approver: Signer<'info>,
+}
+
+#[event]
+pub struct PayoutApproved {
+    pub sequence: u64,
+    pub approver: Pubkey,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+}
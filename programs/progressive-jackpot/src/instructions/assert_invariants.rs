@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Check cross-account accounting invariants, failing with a specific error on the first violation (permissionless)
+/// Read-only, so monitoring bots can simulate it as a canary
+pub fn assert_invariants(
+    ctx: Context<AssertInvariants>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let rent = Rent::get()?;
+    
+    let total_percentage = config.jackpot_percentage as u32
+        + config.house_percentage as u32
+        + config.defi_percentage as u32
+        + config.draw_bps as u32;
+    require!(
+        total_percentage <= 10000
+            && config.win_probability_bps > 0
+            && config.win_probability_bps <= 10000,
+        CasinoError::InvariantConfigPercentages
+    );
+    
+    // Pool lamports back the open jackpot, all unclaimed winnings, and rent
+    let pool = &ctx.accounts.pool;
+    let pool_info = pool.to_account_info();
+    let pool_required = pool.balance
+        .checked_add(pool.pending_payouts)
+        .and_then(|x| x.checked_add(rent.minimum_balance(pool_info.data_len())))
+        .ok_or(CasinoError::MathOverflow)?;
+    require!(
+        pool_info.lamports() >= pool_required,
+        CasinoError::InvariantPoolUnderfunded
+    );
+    
+    let reserve_info = ctx.accounts.reserve_pool.to_account_info();
+    let reserve_required = ctx.accounts.reserve_pool.balance
+        .checked_add(rent.minimum_balance(reserve_info.data_len()))
+        .ok_or(CasinoError::MathOverflow)?;
+    require!(
+        reserve_info.lamports() >= reserve_required,
+        CasinoError::InvariantReserveUnderfunded
+    );
+    
+    let house_info = ctx.accounts.house_vault.to_account_info();
+    require!(
+        house_info.lamports() >= ctx.accounts.house_vault.reserved_lamports(house_info.data_len())?,
+        CasinoError::InvariantHouseUnderfunded
+    );
+    
+    // Nothing can be owed beyond what was ever earned
+    if let Some(integrator) = &ctx.accounts.integrator {
+        require!(
+            integrator.pending_fees <= integrator.total_fees_accrued,
+            CasinoError::InvariantEarningsOverclaimed
+        );
+    }
+    
+    if let Some(earnings) = &ctx.accounts.referral_earnings {
+        require!(
+            earnings.pending <= earnings.total_earned,
+            CasinoError::InvariantEarningsOverclaimed
+        );
+    }
+    
+    msg!("Invariants hold for pool {}", pool.pool_id);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AssertInvariants<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(seeds = [b"reserve_pool"], bump = reserve_pool.bump)]
+    pub reserve_pool: Account<'info, ReservePool>,
+    
+    #[account(seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// Integrator to check, if any
+    #[account(seeds = [b"integrator", integrator.partner.as_ref()], bump = integrator.bump)]
+    pub integrator: Option<Account<'info, Integrator>>,
+    
+    /// Referrer earnings to check, if any
+    #[account(seeds = [b"referral_earnings", referral_earnings.referrer.as_ref()], bump = referral_earnings.bump)]
+    pub referral_earnings: Option<Account<'info, ReferralEarnings>>,
+}
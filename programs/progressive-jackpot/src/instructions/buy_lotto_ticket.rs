@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::{check_wager_allowed, record_wager};
+
+/// Buy a lottery ticket picking the round's count of distinct numbers; the price joins the round's pot
+pub fn buy_lotto_ticket(
+    ctx: Context<BuyLottoTicket>,
+    numbers: Vec<u8>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let round = &mut ctx.accounts.round;
+    
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        round.ticket_price,
+    )?;
+    record_wager(&mut ctx.accounts.player_profile, ctx.accounts.player.key(), ctx.bumps.player_profile, round.ticket_price)?;
+    
+    require!(
+        Clock::get()?.unix_timestamp < round.draw_ts,
+        CasinoError::LottoSalesClosed
+    );
+    
+    let mut picked = numbers;
+    picked.sort_unstable();
+    picked.dedup();
+    require!(
+        picked.len() == round.picks as usize
+            && picked.iter().all(|&n| n >= 1 && n <= round.max_number),
+        CasinoError::InvalidLottoPicks
+    );
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: round.to_account_info(),
+            },
+        ),
+        round.ticket_price,
+    )?;
+    
+    let index = round.ticket_count;
+    round.ticket_count += 1;
+    
+    let entry = &mut ctx.accounts.entry;
+    entry.round = round.key();
+    entry.index = index;
+    entry.player = ctx.accounts.player.key();
+    entry.numbers[..picked.len()].copy_from_slice(&picked);
+    entry.bump = ctx.bumps.entry;
+    
+    msg!("Lotto ticket {} in round {}: {:?}", index, round.round_id, picked);
+    
+    emit!(LottoTicketBought {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        round_id: round.round_id,
+        index,
+        player: entry.player,
+        numbers: entry.numbers,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BuyLottoTicket<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"lotto", round.round_id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, LottoRound>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + LottoEntry::INIT_SPACE,
+        seeds = [b"lotto_entry", round.key().as_ref(), round.ticket_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub entry: Account<'info, LottoEntry>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LottoTicketBought {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub index: u32,
+    pub player: Pubkey,
+    pub numbers: [u8; MAX_LOTTO_PICKS],
+}
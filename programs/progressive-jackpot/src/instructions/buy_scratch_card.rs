@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::{check_wager_allowed, record_wager};
+
+/// Buy the batch's next scratch card; the price goes to house fees
+pub fn buy_scratch_card(
+    ctx: Context<BuyScratchCard>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let batch = &mut ctx.accounts.batch;
+    
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        batch.card_price,
+    )?;
+    record_wager(&mut ctx.accounts.player_profile, ctx.accounts.player.key(), ctx.bumps.player_profile, batch.card_price)?;
+    
+    require!(
+        batch.cards_sold < batch.card_count,
+        CasinoError::ScratchBatchSoldOut
+    );
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.house_vault.to_account_info(),
+            },
+        ),
+        batch.card_price,
+    )?;
+    
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+        .checked_add(batch.card_price)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let index = batch.cards_sold;
+    batch.cards_sold += 1;
+    
+    let card = &mut ctx.accounts.card;
+    card.batch = batch.key();
+    card.index = index;
+    card.player = ctx.accounts.player.key();
+    card.bought_at = Clock::get()?.unix_timestamp;
+    card.bump = ctx.bumps.card;
+    
+    msg!("Scratch card {} of batch {} bought", index, batch.batch_id);
+    
+    emit!(ScratchCardBought {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        batch_id: batch.batch_id,
+        index,
+        player: card.player,
+        price: batch.card_price,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct BuyScratchCard<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"scratch_batch", batch.batch_id.to_le_bytes().as_ref()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, ScratchBatch>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + ScratchCard::INIT_SPACE,
+        seeds = [b"scratch_card", batch.key().as_ref(), batch.cards_sold.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub card: Account<'info, ScratchCard>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ScratchCardBought {
+    pub sequence: u64,
+    pub batch_id: u64,
+    pub index: u32,
+    pub player: Pubkey,
+    pub price: u64,
+}
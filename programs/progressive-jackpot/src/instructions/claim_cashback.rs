@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player claims cashback on net losses from closed weeks, paid by the house vault
+pub fn claim_cashback(
+    ctx: Context<ClaimCashback>,
+) -> Result<()> {
+    let player_profile = &mut ctx.accounts.player_profile;
+    
+    // Close last week if the player hasn't bet or settled since it ended
+    player_profile.roll_cashback_epoch(
+        cashback_epoch(Clock::get()?.unix_timestamp),
+        ctx.accounts.config.cashback_bps,
+    );
+    
+    let amount = player_profile.cashback_pending;
+    require!(
+        amount > 0,
+        CasinoError::NoCashback
+    );
+    
+    // Keep the vault rent-exempt and able to pay out owed rakeback
+    let vault_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(vault_info.data_len())?;
+    require!(
+        vault_info.lamports() >= amount.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += amount;
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    
+    player_profile.cashback_pending = 0;
+    
+    msg!("Cashback claimed: {} lamports by {}", amount, ctx.accounts.player.key());
+    
+    emit!(CashbackClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCashback<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"player", player.key().as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct CashbackClaimed {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,62 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Pay out community shares from the pool's last split (permissionless crank)
+/// Recipient wallets are passed as writable `remaining_accounts`
+pub fn claim_community_share<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimCommunityShare<'info>>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let pool_info = pool.to_account_info();
+    let share = pool.community_share;
+    
+    require!(
+        !ctx.remaining_accounts.is_empty() && ctx.remaining_accounts.len() <= RECENT_BETTORS_LEN,
+        CasinoError::InvalidBatchAccounts
+    );
+    
+    for recipient in ctx.remaining_accounts {
+        let index = pool.community_recipients
+            .iter()
+            .position(|key| key == recipient.key)
+            .filter(|&i| pool.community_unpaid & (1 << i) != 0)
+            .ok_or(CasinoError::NoCommunityShare)?;
+        
+        **pool_info.try_borrow_mut_lamports()? -= share;
+        **recipient.try_borrow_mut_lamports()? += share;
+        
+        pool.community_unpaid &= !(1 << index);
+        pool.pending_payouts = pool.pending_payouts
+            .checked_sub(share)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        emit!(CommunityShareClaimed {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            pool: pool.key(),
+            recipient: *recipient.key,
+            amount: share,
+        });
+    }
+    
+    msg!("Community shares paid: {} x {} lamports", ctx.remaining_accounts.len(), share);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimCommunityShare<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+}
+
+#[event]
+pub struct CommunityShareClaimed {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
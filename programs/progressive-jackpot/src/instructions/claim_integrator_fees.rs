@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Partner claims the house-fee share accrued on its bets
+pub fn claim_integrator_fees(
+    ctx: Context<ClaimIntegratorFees>,
+) -> Result<()> {
+    let amount = ctx.accounts.integrator.pending_fees;
+    
+    require!(
+        amount > 0,
+        CasinoError::NoFeesToClaim
+    );
+    
+    **ctx.accounts.partner.to_account_info().try_borrow_mut_lamports()? += amount;
+    **ctx.accounts.integrator.to_account_info().try_borrow_mut_lamports()? -= amount;
+    
+    let integrator = &mut ctx.accounts.integrator;
+    integrator.pending_fees = 0;
+    
+    msg!("Integrator {} claimed {} lamports", integrator.partner, amount);
+    
+    emit!(IntegratorFeesClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        partner: integrator.partner,
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimIntegratorFees<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"integrator", partner.key().as_ref()], bump = integrator.bump)]
+    pub integrator: Account<'info, Integrator>,
+    
+    #[account(mut)]
+    pub partner: Signer<'info>,
+}
+
+#[event]
+pub struct IntegratorFeesClaimed {
+    pub sequence: u64,
+    pub partner: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player collects a won parlay, or cashes out one stuck on a timed-out leg at its current stake
+/// Both the parlay and its VRF request are closed to the player
+pub fn claim_parlay(
+    ctx: Context<ClaimParlay>,
+) -> Result<()> {
+    let parlay = &ctx.accounts.parlay;
+    let vrf_request = &ctx.accounts.vrf_request;
+    
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    let timed_out = parlay.status == 0
+        && Clock::get()?.unix_timestamp - vrf_request.timestamp >= VRF_TIMEOUT;
+    
+    require!(
+        parlay.status == 1 || timed_out,
+        CasinoError::ParlayNotClaimable
+    );
+    
+    if timed_out {
+        // The house pays what the won legs earned so far and releases the rest of its liability
+        let top_up = parlay.current_stake - parlay.stake;
+        let house_info = ctx.accounts.house_vault.to_account_info();
+        let house_vault = &mut ctx.accounts.house_vault;
+        house_vault.game_liability = house_vault.game_liability
+            .saturating_sub(parlay.potential_payout - parlay.stake);
+        
+        let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+        require!(
+            house_info.lamports() >= top_up.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+            CasinoError::InsufficientFunds
+        );
+        **house_info.try_borrow_mut_lamports()? -= top_up;
+        **parlay.to_account_info().try_borrow_mut_lamports()? += top_up;
+        
+        let config = &mut ctx.accounts.config;
+        config.open_bets = config.open_bets.saturating_sub(1);
+    }
+    
+    let payout = parlay.current_stake;
+    
+    msg!("Parlay claimed: {} lamports by {}", payout, ctx.accounts.player.key());
+    
+    emit!(ParlayClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        parlay: parlay.key(),
+        payout,
+        timed_out,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimParlay<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"parlay", player.key().as_ref(), parlay.stake.to_le_bytes().as_ref()],
+        bump = parlay.bump
+    )]
+    pub parlay: Account<'info, Parlay>,
+    
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"vrf_request", parlay.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == parlay.key() @ CasinoError::VrfRequestBetMismatch
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct ParlayClaimed {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub parlay: Pubkey,
+    pub payout: u64,
+    pub timed_out: bool,
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player claims their promo reward with a Merkle proof against the campaign root
+pub fn claim_promo(
+    ctx: Context<ClaimPromo>,
+    amount: u64,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let player = ctx.accounts.player.key();
+    let campaign = &mut ctx.accounts.campaign;
+    
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    require!(
+        campaign.verify(&player, amount, &proof),
+        CasinoError::InvalidPromoProof
+    );
+    
+    // Campaign PDA must stay rent-exempt
+    let campaign_info = campaign.to_account_info();
+    let rent = Rent::get()?.minimum_balance(campaign_info.data_len());
+    require!(
+        campaign_info.lamports() >= amount.checked_add(rent).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    **campaign_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    campaign.claimed = campaign.claimed
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    let receipt = &mut ctx.accounts.promo_claim;
+    receipt.campaign = campaign.key();
+    receipt.player = player;
+    receipt.amount = amount;
+    receipt.claimed_at = now;
+    receipt.bump = ctx.bumps.promo_claim;
+    
+    msg!("Promo {} claimed: {} lamports by {}", campaign.campaign_id, amount, player);
+    
+    emit!(PromoClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        campaign_id: campaign.campaign_id,
+        player,
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimPromo<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"promo", campaign.campaign_id.to_le_bytes().as_ref()],
+        bump = campaign.bump
+    )]
+    pub campaign: Account<'info, PromoCampaign>,
+    
+    /// One claim per player per campaign
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PromoClaim::INIT_SPACE,
+        seeds = [b"promo_claim", campaign.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub promo_claim: Account<'info, PromoClaim>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PromoClaimed {
+    pub sequence: u64,
+    pub campaign_id: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player claims VIP rakeback from the house vault
+pub fn claim_rakeback(
+    ctx: Context<ClaimRakeback>,
+) -> Result<()> {
+    let amount = ctx.accounts.player_profile.rakeback_pending;
+    
+    require!(
+        amount > 0,
+        CasinoError::NoRakeback
+    );
+    
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += amount;
+    **ctx.accounts.house_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    
+    ctx.accounts.player_profile.rakeback_pending = 0;
+    
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.rakeback_owed = house_vault.rakeback_owed
+        .checked_sub(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Rakeback claimed: {} lamports by {}", amount, ctx.accounts.player.key());
+    
+    emit!(RakebackClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimRakeback<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"player", player.key().as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct RakebackClaimed {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Referrer claims commission accrued on referred players' bets
+pub fn claim_referral_earnings(
+    ctx: Context<ClaimReferralEarnings>,
+) -> Result<()> {
+    let amount = ctx.accounts.referral_earnings.pending;
+    
+    require!(
+        amount > 0,
+        CasinoError::NoFeesToClaim
+    );
+    
+    **ctx.accounts.referrer.to_account_info().try_borrow_mut_lamports()? += amount;
+    **ctx.accounts.referral_earnings.to_account_info().try_borrow_mut_lamports()? -= amount;
+    
+    let earnings = &mut ctx.accounts.referral_earnings;
+    earnings.pending = 0;
+    
+    msg!("Referrer {} claimed {} lamports", earnings.referrer, amount);
+    
+    emit!(ReferralEarningsClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        referrer: earnings.referrer,
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralEarnings<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"referral_earnings", referrer.key().as_ref()], bump = referral_earnings.bump)]
+    pub referral_earnings: Account<'info, ReferralEarnings>,
+    
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+}
+
+#[event]
+pub struct ReferralEarningsClaimed {
+    pub sequence: u64,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,186 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::claim_win::{pay_win, payout_wallet, win_recipient};
+
+/// Claim a win above the manual review threshold
+/// The first call holds it in a `PendingPayout`; later calls pay it once approved or the dispute window passes.
+/// A released win above the vesting threshold is paid through `claim_vested_win` instead
+pub fn claim_reviewed_win<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimReviewedWin<'info>>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    let bet = &mut ctx.accounts.bet;
+    let pending_payout = &mut ctx.accounts.pending_payout;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        bet.player == ctx.accounts.player.key(),
+        CasinoError::Unauthorized
+    );
+    
+    require_keys_eq!(bet.pool, pool.key(), CasinoError::PoolMismatch);
+    
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    require!(
+        !bet.disputed,
+        CasinoError::BetDisputed
+    );
+    
+    if bet.status == 1 {
+        require!(
+            config.requires_review(bet.win_amount),
+            CasinoError::NoWinToClaim
+        );
+        
+        // Hold the payout
+        pending_payout.player = bet.player;
+        pending_payout.bet = bet.key();
+        pending_payout.amount = bet.win_amount;
+        pending_payout.held_at = now;
+        pending_payout.approved = false;
+        pending_payout.bump = ctx.bumps.pending_payout;
+        
+        bet.status = 6; // held for review
+        
+        msg!("Payout of {} lamports held for review", pending_payout.amount);
+        
+        emit!(PayoutHeld {
+            sequence: config.next_event_sequence(),
+            player: bet.player,
+            bet: bet.key(),
+            amount: pending_payout.amount,
+            releases_at: now.saturating_add(config.payout_dispute_window_secs),
+        });
+        
+        return Ok(());
+    }
+    
+    require!(
+        bet.status == 6,
+        CasinoError::NoWinToClaim
+    );
+    
+    require!(
+        pending_payout.is_releasable(now, config.payout_dispute_window_secs),
+        CasinoError::PayoutNotReleasable
+    );
+    
+    let amount = pending_payout.amount;
+    
+    // Large wins are released in tranches instead
+    require!(
+        config.vesting_threshold == 0 || amount <= config.vesting_threshold,
+        CasinoError::WinRequiresVesting
+    );
+    
+    // The pool keeps its rent-exempt minimum
+    let pool_info = pool.to_account_info();
+    let pool_rent = Rent::get()?.minimum_balance(pool_info.data_len());
+    require!(
+        pool_info.lamports() >= amount.checked_add(pool_rent).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    // Transfer winnings to player
+    let wallet = payout_wallet(
+        ctx.accounts.player.to_account_info(),
+        ctx.accounts.player_profile.payout_beneficiary,
+        &ctx.accounts.beneficiary,
+    )?;
+    let recipient = win_recipient(bet, wallet, &mut ctx.accounts.bonus_credit, amount)?;
+    pay_win(
+        &pool.to_account_info(),
+        &recipient,
+        &ctx.accounts.payout_split,
+        ctx.remaining_accounts,
+        bet.bonus_funded,
+        amount,
+    )?;
+    
+    pool.pending_payouts = pool.pending_payouts
+        .checked_sub(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    bet.status = 4; // won and claimed
+    
+    msg!("Reviewed win claimed: {} lamports by {}", amount, bet.player);
+    
+    emit!(PayoutReleased {
+        sequence: config.next_event_sequence(),
+        player: bet.player,
+        bet: bet.key(),
+        amount,
+        approved: pending_payout.approved,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimReviewedWin<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut)]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PendingPayout::INIT_SPACE,
+        seeds = [b"payout", bet.key().as_ref()],
+        bump
+    )]
+    pub pending_payout: Account<'info, PendingPayout>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// Player's BonusCredit, required for bonus-funded bets
+    #[account(mut, seeds = [b"bonus", player.key().as_ref()], bump = bonus_credit.bump)]
+    pub bonus_credit: Option<Account<'info, BonusCredit>>,
+    
+    #[account(seeds = [b"player", bet.player.as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: must match `player_profile.payout_beneficiary`; required only when one is set
+    #[account(mut)]
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: player's PayoutSplit PDA; may be uninitialized
+    #[account(seeds = [b"payout_split", bet.player.as_ref()], bump)]
+    pub payout_split: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PayoutHeld {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+    pub releases_at: i64,
+}
+
+#[event]
+pub struct PayoutReleased {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+    pub approved: bool,
+}
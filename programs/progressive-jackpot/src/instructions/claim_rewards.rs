@@ -1,15 +1,32 @@
 use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
 use crate::state::*;
 use crate::error::CasinoError;
+use super::claim_win::payout_wallet;
 
-/// Claim DeFi rewards from staked pool
-/// Calculates rewards based on APY and time staked
+/// Claim DeFi rewards accrued on the caller's stake in the reward vault
+/// Pays both streams at once: realized SOL yield, and reward token emissions once a reward mint is set
 pub fn claim_rewards(
     ctx: Context<ClaimRewards>,
 ) -> Result<()> {
+    // Stakers who never bet have no profile, and so no beneficiary
+    let profile_info = &ctx.accounts.player_profile;
+    let designated = if profile_info.data_is_empty() {
+        None
+    } else {
+        PlayerProfile::try_deserialize(&mut &profile_info.try_borrow_data()?[..])?.payout_beneficiary
+    };
+    let wallet = payout_wallet(ctx.accounts.user.to_account_info(), designated, &ctx.accounts.beneficiary)?;
+    
     let reward_vault = &mut ctx.accounts.reward_vault;
     let reward_claim = &mut ctx.accounts.reward_claim;
-    let config = &ctx.accounts.config;
+    
+    // Only the user signs for a claim that doesn't exist yet, so a delegate can't create one
+    let claimer = ctx.accounts.claimer.key();
+    require!(
+        claimer == ctx.accounts.user.key() || reward_claim.claim_delegate == Some(claimer),
+        CasinoError::Unauthorized
+    );
     
     require!(
         reward_vault.staked_amount > 0,
@@ -27,46 +44,81 @@ pub fn claim_rewards(
         reward_claim.bump = ctx.bumps.reward_claim;
     }
     
-    // Calculate rewards based on APY
-    // Formula: rewards = staked_amount * (APY / 100) * (time_elapsed / year_seconds)
-    let year_seconds: i64 = 31536000; // 365 days
-    let time_elapsed = current_time
-        .checked_sub(reward_claim.last_claim)
-        .unwrap_or(0);
-    
-    if time_elapsed <= 0 {
-        return Err(CasinoError::ClaimPeriodNotStarted.into());
-    }
+    // Measured from the last claim, or from when the stake was opened
+    let cooldown = ctx.accounts.config.claim_cooldown_secs;
+    require!(
+        current_time >= reward_claim.last_claim.saturating_add(cooldown),
+        CasinoError::ClaimCooldownActive
+    );
     
-    // Calculate user's share of rewards (simplified: equal share for all contributors)
-    // In production, this would track individual contributions
-    let apy_decimal = (config.defi_percentage as u64)
-        .checked_mul(reward_vault.apy_bps as u64)
-        .and_then(|x| x.checked_div(10000))
-        .ok_or(CasinoError::MathOverflow)?;
+    reward_vault.accrue(current_time)?;
+    reward_claim.harvest(reward_vault)?;
     
-    let rewards = reward_vault.staked_amount
-        .checked_mul(apy_decimal)
-        .and_then(|x| x.checked_mul(time_elapsed as u64))
-        .and_then(|x| x.checked_div(10000))
-        .and_then(|x| x.checked_div(year_seconds as u64))
-        .ok_or(CasinoError::MathOverflow)?;
+    let rewards = reward_claim.pending_rewards;
+    let emissions = reward_claim.pending_emissions;
     
     require!(
-        rewards > 0,
+        rewards > 0 || emissions > 0,
         CasinoError::NoRewardsAvailable
     );
     
-    // Check if vault has enough funds
-    let vault_balance = ctx.accounts.reward_vault.to_account_info().lamports();
+    // Dust SOL stays accruing unless reward tokens are being claimed anyway
     require!(
-        vault_balance >= rewards,
-        CasinoError::InsufficientFunds
+        emissions > 0 || rewards >= ctx.accounts.config.min_claim_amount,
+        CasinoError::ClaimBelowMinimum
     );
     
-    // Transfer rewards to user
-    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += rewards;
-    **ctx.accounts.reward_vault.to_account_info().try_borrow_mut_lamports()? -= rewards;
+    if rewards > 0 {
+        // Check if vault has enough funds, keeping it rent-exempt and stakers' deposits untouched
+        let vault_info = reward_vault.to_account_info();
+        let reserved = Rent::get()?.minimum_balance(vault_info.data_len())
+            .checked_add(reward_vault.staker_assets)
+            .ok_or(CasinoError::MathOverflow)?;
+        require!(
+            vault_info.lamports() >= rewards.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+            CasinoError::InsufficientFunds
+        );
+        
+        // Transfer rewards to the user's payout wallet
+        **wallet.try_borrow_mut_lamports()? += rewards;
+        **vault_info.try_borrow_mut_lamports()? -= rewards;
+        
+        reward_claim.pending_rewards = 0;
+    }
+    
+    // Reward tokens are minted on claim, so emissions never draw on the vault's SOL
+    if emissions > 0 {
+        let (Some(reward_mint), Some(reward_token_account), Some(token_program)) = (
+            &ctx.accounts.reward_mint,
+            &ctx.accounts.reward_token_account,
+            &ctx.accounts.token_program,
+        ) else {
+            return err!(CasinoError::MissingRewardTokenAccounts);
+        };
+        require_keys_eq!(reward_token_account.owner, wallet.key(), CasinoError::MissingRewardTokenAccounts);
+        
+        let signer_seeds: &[&[&[u8]]] = &[&[b"reward_vault", &[reward_vault.bump]]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: reward_mint.to_account_info(),
+                    to: reward_token_account.to_account_info(),
+                    authority: reward_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            emissions,
+        )?;
+        
+        reward_claim.pending_emissions = 0;
+        reward_claim.total_emissions_claimed = reward_claim.total_emissions_claimed
+            .checked_add(emissions)
+            .ok_or(CasinoError::MathOverflow)?;
+        reward_vault.total_emissions_distributed = reward_vault.total_emissions_distributed
+            .checked_add(emissions)
+            .ok_or(CasinoError::MathOverflow)?;
+    }
     
     // Update claim state
     reward_claim.total_earned = reward_claim.total_earned
@@ -85,20 +137,27 @@ pub fn claim_rewards(
     
     reward_vault.last_distribution = current_time;
     
-    msg!("Rewards claimed: {} lamports by {}", rewards, ctx.accounts.user.key());
+    msg!(
+        "Rewards claimed: {} lamports and {} reward tokens by {}",
+        rewards, emissions, ctx.accounts.user.key()
+    );
     
-    emit!(RewardsClaimed {
+    emit_event!(ctx, RewardsClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
         user: ctx.accounts.user.key(),
-        amount: rewards,
+        sol_amount: rewards,
+        token_amount: emissions,
         total_claimed: reward_claim.total_claimed,
+        total_emissions_claimed: reward_claim.total_emissions_claimed,
     });
     
     Ok(())
 }
 
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct ClaimRewards<'info> {
-    #[account(seeds = [b"config"], bump = config.bump)]
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
     
     #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
@@ -106,22 +165,48 @@ pub struct ClaimRewards<'info> {
     
     #[account(
         init_if_needed,
-        payer = user,
-        space = 8 + std::mem::size_of::<RewardClaim>(),
+        payer = claimer,
+        space = 8 + RewardClaim::INIT_SPACE,
         seeds = [b"reward_claim", user.key().as_ref()],
         bump
     )]
     pub reward_claim: Account<'info, RewardClaim>,
     
+    /// Reward token mint; required to claim emissions once `set_reward_mint` has run
+    #[account(mut, address = reward_vault.reward_mint)]
+    pub reward_mint: Option<Account<'info, Mint>>,
+    
+    /// Reward mint token account owned by the user's payout wallet
+    #[account(mut, constraint = reward_token_account.mint == reward_vault.reward_mint @ CasinoError::MissingRewardTokenAccounts)]
+    pub reward_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    /// CHECK: owner of the reward claim (bound by its seeds); receives the payout unless a beneficiary is set
+    #[account(mut)]
+    pub user: UncheckedAccount<'info>,
+    
+    /// CHECK: user's PlayerProfile PDA; may be uninitialized
+    #[account(seeds = [b"player", user.key().as_ref()], bump)]
+    pub player_profile: UncheckedAccount<'info>,
+    
+    /// CHECK: must match the profile's `payout_beneficiary`; required only when one is set
+    #[account(mut)]
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    
+    /// The user, or their `claim_delegate`
     #[account(mut)]
-    pub user: Signer<'info>,
+    pub claimer: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
 #[event]
 pub struct RewardsClaimed {
+    pub sequence: u64,
     pub user: Pubkey,
-    pub amount: u64,
+    pub sol_amount: u64,
+    pub token_amount: u64,
     pub total_claimed: u64,
+    pub total_emissions_claimed: u64,
 }
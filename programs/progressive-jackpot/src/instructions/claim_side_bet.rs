@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Collect a player's share of a settled side-bet round and close their entry
+/// Backers of the winning bucket split the round pro rata; if nobody backed it, every stake is refunded
+pub fn claim_side_bet(
+    ctx: Context<ClaimSideBet>,
+) -> Result<()> {
+    let side_bet_pool = &ctx.accounts.side_bet_pool;
+    let entry = &ctx.accounts.side_bet_entry;
+    
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    require!(
+        side_bet_pool.settled,
+        CasinoError::SideBetNotSettled
+    );
+    
+    let winning_bucket = side_bet_pool.winning_bucket as usize;
+    let winning_total = side_bet_pool.bucket_totals[winning_bucket];
+    
+    let payout = if winning_total == 0 {
+        entry.stakes.iter().sum::<u64>()
+    } else {
+        let share = (entry.stakes[winning_bucket] as u128)
+            .checked_mul(side_bet_pool.distributable as u128)
+            .and_then(|v| v.checked_div(winning_total as u128))
+            .ok_or(CasinoError::MathOverflow)?;
+        u64::try_from(share).map_err(|_| CasinoError::MathOverflow)?
+    };
+    
+    if payout > 0 {
+        **side_bet_pool.to_account_info().try_borrow_mut_lamports()? -= payout;
+        **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += payout;
+    }
+    
+    msg!("Side bet claimed: {} lamports by {}", payout, ctx.accounts.player.key());
+    
+    emit!(SideBetClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        side_bet_pool: side_bet_pool.key(),
+        payout,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimSideBet<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"side_bet_pool", side_bet_pool.pool.as_ref(), side_bet_pool.round_id.to_le_bytes().as_ref()],
+        bump = side_bet_pool.bump
+    )]
+    pub side_bet_pool: Account<'info, SideBetPool>,
+    
+    #[account(
+        mut,
+        close = player,
+        has_one = side_bet_pool,
+        seeds = [b"side_bet", side_bet_pool.key().as_ref(), player.key().as_ref()],
+        bump = side_bet_entry.bump
+    )]
+    pub side_bet_entry: Account<'info, SideBetEntry>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct SideBetClaimed {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub side_bet_pool: Pubkey,
+    pub payout: u64,
+}
@@ -0,0 +1,211 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::claim_win::{pay_win, payout_wallet, win_recipient};
+
+/// Release the vested portion of a win above the vesting threshold
+/// The first call starts the schedule and pays the first tranche; a win held for review can start once it's releasable
+pub fn claim_vested_win<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimVestedWin<'info>>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    let bet = &mut ctx.accounts.bet;
+    let vesting = &mut ctx.accounts.vesting;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        bet.player == ctx.accounts.player.key(),
+        CasinoError::Unauthorized
+    );
+    
+    require_keys_eq!(bet.pool, pool.key(), CasinoError::PoolMismatch);
+    
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    require!(
+        !bet.disputed,
+        CasinoError::BetDisputed
+    );
+    
+    // A win held for review starts vesting only once `claim_reviewed_win` could release it
+    let reviewed = bet.status == 6;
+    if reviewed {
+        let pending_payout = ctx.accounts.pending_payout.as_ref().ok_or(CasinoError::PayoutNotReleasable)?;
+        require!(
+            pending_payout.is_releasable(now, config.payout_dispute_window_secs),
+            CasinoError::PayoutNotReleasable
+        );
+    }
+    
+    if bet.status == 1 || reviewed {
+        require!(
+            config.vesting_threshold > 0 && bet.win_amount > config.vesting_threshold,
+            CasinoError::WinNotVested
+        );
+        
+        require!(
+            reviewed || !config.requires_review(bet.win_amount),
+            CasinoError::WinRequiresReview
+        );
+        
+        // Start the schedule
+        vesting.player = bet.player;
+        vesting.bet = bet.key();
+        vesting.total_amount = bet.win_amount;
+        vesting.claimed_amount = 0;
+        vesting.start_timestamp = now;
+        vesting.bump = ctx.bumps.vesting;
+        
+        bet.status = 5; // vesting
+        
+        pool.vesting_outstanding = pool.vesting_outstanding
+            .checked_add(vesting.total_amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        emit!(VestingStarted {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            player: bet.player,
+            bet: bet.key(),
+            total_amount: vesting.total_amount,
+            tranches: VESTING_TRANCHES,
+        });
+    }
+    
+    require!(
+        bet.status == 5,
+        CasinoError::NoWinToClaim
+    );
+    
+    let amount = vesting.vested_amount(now)
+        .checked_sub(vesting.claimed_amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    require!(
+        amount > 0,
+        CasinoError::NothingVested
+    );
+    
+    // The pool keeps its rent-exempt minimum
+    let pool_info = pool.to_account_info();
+    let pool_rent = Rent::get()?.minimum_balance(pool_info.data_len());
+    require!(
+        pool_info.lamports() >= amount.checked_add(pool_rent).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    // Transfer tranche to player
+    let wallet = payout_wallet(
+        ctx.accounts.player.to_account_info(),
+        ctx.accounts.player_profile.payout_beneficiary,
+        &ctx.accounts.beneficiary,
+    )?;
+    let recipient = win_recipient(bet, wallet, &mut ctx.accounts.bonus_credit, amount)?;
+    pay_win(
+        &pool.to_account_info(),
+        &recipient,
+        &ctx.accounts.payout_split,
+        ctx.remaining_accounts,
+        bet.bonus_funded,
+        amount,
+    )?;
+    
+    pool.pending_payouts = pool.pending_payouts
+        .checked_sub(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Schedules started before the counter existed aren't included in it
+    pool.vesting_outstanding = pool.vesting_outstanding.saturating_sub(amount);
+    
+    vesting.claimed_amount = vesting.claimed_amount
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    if vesting.claimed_amount == vesting.total_amount {
+        bet.status = 4; // won and claimed
+    }
+    
+    msg!("Vested win claimed: {} of {} lamports", vesting.claimed_amount, vesting.total_amount);
+    
+    emit!(VestedWinClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: bet.player,
+        bet: bet.key(),
+        amount,
+        claimed_amount: vesting.claimed_amount,
+        total_amount: vesting.total_amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ClaimVestedWin<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut)]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + WinVesting::INIT_SPACE,
+        seeds = [b"vesting", bet.key().as_ref()],
+        bump
+    )]
+    pub vesting: Account<'info, WinVesting>,
+    
+    /// The bet's review hold; required to start vesting a win held for review
+    #[account(seeds = [b"payout", bet.key().as_ref()], bump = pending_payout.bump)]
+    pub pending_payout: Option<Account<'info, PendingPayout>>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// Player's BonusCredit, required for bonus-funded bets
+    #[account(mut, seeds = [b"bonus", player.key().as_ref()], bump = bonus_credit.bump)]
+    pub bonus_credit: Option<Account<'info, BonusCredit>>,
+    
+    #[account(seeds = [b"player", bet.player.as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: must match `player_profile.payout_beneficiary`; required only when one is set
+    #[account(mut)]
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: player's PayoutSplit PDA; may be uninitialized
+    #[account(seeds = [b"payout_split", bet.player.as_ref()], bump)]
+    pub payout_split: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct VestingStarted {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub total_amount: u64,
+    pub tranches: u64,
+}
+
+#[event]
+pub struct VestedWinClaimed {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+    pub claimed_amount: u64,
+    pub total_amount: u64,
+}
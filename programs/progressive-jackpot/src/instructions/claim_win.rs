@@ -0,0 +1,203 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Winner pulls a settled payout from the pool
+pub fn claim_win<'info>(
+    ctx: Context<'_, '_, 'info, 'info, ClaimWin<'info>>,
+) -> Result<()> {
+    let pool = &mut ctx.accounts.pool;
+    let bet = &mut ctx.accounts.bet;
+    
+    require!(
+        bet.player == ctx.accounts.player.key(),
+        CasinoError::Unauthorized
+    );
+    
+    require_keys_eq!(bet.pool, pool.key(), CasinoError::PoolMismatch);
+    
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    require!(
+        !bet.disputed,
+        CasinoError::BetDisputed
+    );
+    
+    require!(
+        bet.status == 1,
+        CasinoError::NoWinToClaim
+    );
+    
+    let amount = bet.win_amount;
+    
+    // Wins above the review threshold wait for the payout approver
+    require!(
+        !ctx.accounts.config.requires_review(amount),
+        CasinoError::WinRequiresReview
+    );
+    
+    // Large wins are released in tranches instead
+    let vesting_threshold = ctx.accounts.config.vesting_threshold;
+    require!(
+        vesting_threshold == 0 || amount <= vesting_threshold,
+        CasinoError::WinRequiresVesting
+    );
+    
+    // The pool keeps its rent-exempt minimum
+    let pool_info = pool.to_account_info();
+    let pool_rent = Rent::get()?.minimum_balance(pool_info.data_len());
+    require!(
+        pool_info.lamports() >= amount.checked_add(pool_rent).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    // Transfer winnings to player
+    let wallet = payout_wallet(
+        ctx.accounts.player.to_account_info(),
+        ctx.accounts.player_profile.payout_beneficiary,
+        &ctx.accounts.beneficiary,
+    )?;
+    let recipient = win_recipient(bet, wallet, &mut ctx.accounts.bonus_credit, amount)?;
+    pay_win(
+        &pool.to_account_info(),
+        &recipient,
+        &ctx.accounts.payout_split,
+        ctx.remaining_accounts,
+        bet.bonus_funded,
+        amount,
+    )?;
+    
+    pool.pending_payouts = pool.pending_payouts
+        .checked_sub(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    bet.status = 4; // won and claimed
+    
+    msg!("Win claimed: {} lamports by {}", amount, ctx.accounts.player.key());
+    
+    emit_event!(ctx, WinClaimed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        bet: bet.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+/// Where winnings go: the player's payout wallet, or their bonus balance for bonus-funded bets
+pub fn win_recipient<'info>(
+    bet: &Bet,
+    wallet: AccountInfo<'info>,
+    bonus_credit: &mut Option<Account<'info, BonusCredit>>,
+    amount: u64,
+) -> Result<AccountInfo<'info>> {
+    if !bet.bonus_funded {
+        return Ok(wallet);
+    }
+    
+    let bonus = bonus_credit.as_mut().ok_or(CasinoError::MissingBonusCredit)?;
+    bonus.balance = bonus.balance
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    Ok(bonus.to_account_info())
+}
+
+/// Move a claimed win out of the pool, first paying the shares of the player's `PayoutSplit`
+/// Split recipients are passed as writable `remaining_accounts` in the split's order; bonus-funded wins aren't split
+pub fn pay_win<'info>(
+    pool: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    payout_split: &UncheckedAccount<'info>,
+    split_recipients: &[AccountInfo<'info>],
+    bonus_funded: bool,
+    amount: u64,
+) -> Result<()> {
+    let split = if bonus_funded || payout_split.data_is_empty() {
+        PayoutSplit::default()
+    } else {
+        PayoutSplit::try_deserialize(&mut &payout_split.try_borrow_data()?[..])?
+    };
+    let shares = split.active_shares();
+    
+    require!(
+        split_recipients.len() == shares.len(),
+        CasinoError::SplitRecipientMismatch
+    );
+    
+    **pool.try_borrow_mut_lamports()? -= amount;
+    
+    let mut remainder = amount;
+    for (share, account) in shares.iter().zip(split_recipients) {
+        require_keys_eq!(account.key(), share.recipient, CasinoError::SplitRecipientMismatch);
+        
+        let cut = (amount as u128 * share.bps as u128 / 10000) as u64;
+        **account.try_borrow_mut_lamports()? += cut;
+        remainder -= cut;
+    }
+    
+    **recipient.try_borrow_mut_lamports()? += remainder;
+    
+    Ok(())
+}
+
+/// The player's `payout_beneficiary` if one is designated, otherwise the player's own wallet
+pub fn payout_wallet<'info>(
+    player: AccountInfo<'info>,
+    designated: Option<Pubkey>,
+    beneficiary: &Option<UncheckedAccount<'info>>,
+) -> Result<AccountInfo<'info>> {
+    let Some(designated) = designated else {
+        return Ok(player);
+    };
+    
+    let beneficiary = beneficiary.as_ref().ok_or(CasinoError::BeneficiaryMismatch)?;
+    require_keys_eq!(beneficiary.key(), designated, CasinoError::BeneficiaryMismatch);
+    Ok(beneficiary.to_account_info())
+}
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct ClaimWin<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut)]
+    pub bet: Account<'info, Bet>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// Player's BonusCredit, required for bonus-funded bets
+    #[account(mut, seeds = [b"bonus", player.key().as_ref()], bump = bonus_credit.bump)]
+    pub bonus_credit: Option<Account<'info, BonusCredit>>,
+    
+    #[account(seeds = [b"player", bet.player.as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: must match `player_profile.payout_beneficiary`; required only when one is set
+    #[account(mut)]
+    pub beneficiary: Option<UncheckedAccount<'info>>,
+    
+    /// CHECK: player's PayoutSplit PDA; may be uninitialized
+    #[account(seeds = [b"payout_split", bet.player.as_ref()], bump)]
+    pub payout_split: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct WinClaimed {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+}
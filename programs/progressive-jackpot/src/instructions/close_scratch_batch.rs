@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Retire a scratch batch once every sold card is revealed, releasing its unpaid prize lock (authority or operator)
+/// Unsold cards are forfeited; the batch's rent returns to whoever opened it
+pub fn close_scratch_batch(
+    ctx: Context<CloseScratchBatch>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    let batch = &ctx.accounts.batch;
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        batch.cards_revealed == batch.cards_sold,
+        CasinoError::ScratchCardsUnrevealed
+    );
+    
+    let released = batch.remaining_liability();
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.game_liability = house_vault.game_liability.saturating_sub(released);
+    
+    msg!("Scratch batch {} closed, {} lamports released", batch.batch_id, released);
+    
+    emit!(ScratchBatchClosed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        batch_id: batch.batch_id,
+        cards_sold: batch.cards_sold,
+        prizes_paid: batch.prizes_paid,
+        released,
+    });
+    
+    ctx.accounts.batch.close(ctx.accounts.opened_by.to_account_info())?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseScratchBatch<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        has_one = opened_by,
+        seeds = [b"scratch_batch", batch.batch_id.to_le_bytes().as_ref()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, ScratchBatch>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// CHECK: whoever opened the batch; receives its rent
+    #[account(mut)]
+    pub opened_by: UncheckedAccount<'info>,
+    
+    pub signer: Signer<'info>,
+}
+
+#[event]
+pub struct ScratchBatchClosed {
+    pub sequence: u64,
+    pub batch_id: u64,
+    pub cards_sold: u32,
+    pub prizes_paid: u64,
+    pub released: u64,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Start a mystery jackpot round on a pool by committing `hashv([threshold, salt])` (authority or operator)
+/// The bet that later lifts the pool past the secret threshold wins once it's revealed with `reveal_mystery`
+pub fn commit_mystery_jackpot(
+    ctx: Context<CommitMysteryJackpot>,
+    round_id: u64,
+    commitment: [u8; 32],
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        !config.shutdown,
+        CasinoError::CasinoShutdown
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let round = &mut ctx.accounts.round;
+    round.pool = ctx.accounts.pool.key();
+    round.round_id = round_id;
+    round.commitment = commitment;
+    round.started_at = now;
+    round.committed_by = signer;
+    round.bump = ctx.bumps.round;
+    
+    msg!("Mystery jackpot {} committed on pool {}", round_id, round.pool);
+    
+    emit!(MysteryJackpotCommitted {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        pool: round.pool,
+        round_id,
+        commitment,
+        started_at: now,
+        committed_by: signer,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct CommitMysteryJackpot<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + MysteryJackpot::INIT_SPACE,
+        seeds = [b"mystery", pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub round: Account<'info, MysteryJackpot>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MysteryJackpotCommitted {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub round_id: u64,
+    pub commitment: [u8; 32],
+    pub started_at: i64,
+    pub committed_by: Pubkey,
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Roll a user's accrued DeFi rewards into their stake instead of paying them out
+/// The user can always compound; anyone else can only if the user turned on `compound`
+pub fn compound_rewards(
+    ctx: Context<CompoundRewards>,
+) -> Result<()> {
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    
+    require!(
+        ctx.accounts.caller.key() == reward_claim.user || reward_claim.compound,
+        CasinoError::CompoundingDisabled
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    reward_vault.accrue(now)?;
+    reward_claim.harvest(reward_vault)?;
+    
+    let rewards = reward_claim.pending_rewards;
+    require!(
+        rewards > 0,
+        CasinoError::NoRewardsAvailable
+    );
+    
+    // The lamports stay in the vault, but they must exist just as they would for a claim
+    let vault_info = reward_vault.to_account_info();
+    let reserved = Rent::get()?.minimum_balance(vault_info.data_len())
+        .checked_add(reward_vault.staker_assets)
+        .ok_or(CasinoError::MathOverflow)?;
+    require!(
+        vault_info.lamports() >= rewards.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    reward_claim.pending_rewards = 0;
+    reward_claim.deposit(rewards, reward_vault)?;
+    reward_claim.total_earned = reward_claim.total_earned
+        .checked_add(rewards)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    reward_vault.staked_amount = reward_vault.staked_amount
+        .checked_add(rewards)
+        .ok_or(CasinoError::MathOverflow)?;
+    reward_vault.total_rewards_distributed = reward_vault.total_rewards_distributed
+        .checked_add(rewards)
+        .ok_or(CasinoError::MathOverflow)?;
+    reward_vault.last_distribution = now;
+    
+    msg!("Compounded {} lamports for {}", rewards, reward_claim.user);
+    
+    emit!(RewardsCompounded {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        user: reward_claim.user,
+        amount: rewards,
+        staked: reward_claim.staked,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CompoundRewards<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"reward_claim", reward_claim.user.as_ref()],
+        bump = reward_claim.bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    /// The user, or a keeper cranking an opted-in user
+    pub caller: Signer<'info>,
+}
+
+#[event]
+pub struct RewardsCompounded {
+    pub sequence: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub staked: u64,
+}
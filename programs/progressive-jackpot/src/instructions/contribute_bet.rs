@@ -1,32 +1,143 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use anchor_lang::system_program;
 use crate::state::*;
 use crate::error::CasinoError;
 
 /// Player contributes a bet to the jackpot pool
 /// Automatically distributes funds: jackpot, house, DeFi
 /// Triggers VRF request if win condition might be met
+/// `charity_bps` optionally adds a donation of that share of the bet, paid from the wallet to the charity vault
 pub fn contribute_bet(
     ctx: Context<ContributeBet>,
     amount: u64,
+    charity_bps: Option<u16>,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let pool = &mut ctx.accounts.pool;
     let reward_vault = &mut ctx.accounts.reward_vault;
     
+    // VIP tier comes from lifetime volume before this bet
+    let vip_tier = config.vip_tier(ctx.accounts.player_profile.total_wagered);
+    let vip = vip_tier.map(|index| config.vip_tiers[index as usize]);
+    let (min_bet, pool_max_bet) = pool.bet_limits(config);
+    let max_bet = match vip {
+        Some(tier) if tier.max_bet > 0 => tier.max_bet,
+        _ => pool_max_bet,
+    };
+    
     // Validate bet amount
     require!(
-        amount >= config.min_bet,
+        amount >= min_bet,
         CasinoError::BetTooSmall
     );
     
     require!(
-        amount <= config.max_bet,
+        amount <= max_bet,
         CasinoError::BetTooLarge
     );
     
+    let last_bet = ctx.accounts.player_profile.last_bet_timestamp;
+    require!(
+        config.bet_cooldown_secs == 0
+            || last_bet == 0
+            || Clock::get()?.unix_timestamp - last_bet >= config.bet_cooldown_secs,
+        CasinoError::CooldownActive
+    );
+    
+    require!(
+        pool.emergency_unlocks_at == 0,
+        CasinoError::EmergencyWithdrawPending
+    );
+    
+    require!(
+        pool.take_slot_capacity(Clock::get()?.slot, config.max_bets_per_slot),
+        CasinoError::SlotBetLimitReached
+    );
+    
+    // Program-owned accounts can't bet; they could sign via CPI with borrowed funds
+    require_keys_eq!(
+        *ctx.accounts.player.owner,
+        system_program::ID,
+        CasinoError::UnauthorizedCaller
+    );
+    
+    // Bets are top-level calls, or CPIs from the routing integrator's registered program
+    let caller = get_instruction_relative(0, &ctx.accounts.instructions_sysvar.to_account_info())?.program_id;
+    require!(
+        caller == crate::ID
+            || ctx.accounts.integrator.as_ref().is_some_and(|integrator| {
+                integrator.caller_program != Pubkey::default() && integrator.caller_program == caller
+            }),
+        CasinoError::UnauthorizedCaller
+    );
+    
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        amount,
+    )?;
+    
+    // Don't accept bets against an unseeded jackpot
+    require!(
+        pool.balance >= config.min_pool_seed,
+        CasinoError::PoolNotSeeded
+    );
+    
+    config.check_exposure(
+        pool.balance,
+        ctx.accounts.bankroll.as_ref().map_or(0, |bankroll| bankroll.balance),
+        amount,
+    )?;
+    
+    // Apply the player's experiment variant, if an experiment is running and they haven't opted out
+    let experiment_variant = if ctx.accounts.player_profile.experiment_opt_out {
+        None
+    } else {
+        config.experiment_variant(&ctx.accounts.player.key())
+    };
+    let (jackpot_percentage, win_probability_bps) = match experiment_variant {
+        Some(index) => {
+            let variant = config.experiment_variants[index as usize];
+            (variant.jackpot_percentage, variant.win_probability_bps)
+        }
+        None => (config.jackpot_percentage, pool.effective_win_probability_bps(config)),
+    };
+    
+    // Betting on consecutive UTC days earns the streak bonus from day `streak_days` on
+    let now = Clock::get()?.unix_timestamp;
+    let today = now.div_euclid(86400);
+    let first_bet_today = ctx.accounts.player_profile.streak_len == 0
+        || ctx.accounts.player_profile.streak_day != today;
+    let streak_len = ctx.accounts.player_profile.record_bet_day(today);
+    ctx.accounts.player_profile.roll_cashback_epoch(cashback_epoch(now), config.cashback_bps);
+    let streak_bonus = config.streak_days > 0 && streak_len >= config.streak_days;
+    let streak_bonus_bps = if streak_bonus { config.streak_odds_bonus_bps } else { 0 };
+    
+    // A happy-hour window boosts odds and the jackpot share; the other fee cuts stay whole
+    let happy_hour = config.active_happy_hour(now);
+    let (jackpot_percentage, win_probability_bps) = match happy_hour {
+        Some(window) => {
+            let jackpot_cap = 10000u16
+                .saturating_sub(config.house_percentage)
+                .saturating_sub(config.defi_percentage)
+                .saturating_sub(config.draw_bps)
+                .max(jackpot_percentage);
+            (
+                HappyHour::boost(jackpot_percentage, window.jackpot_multiplier_bps, jackpot_cap),
+                HappyHour::boost(win_probability_bps, window.win_probability_multiplier_bps, 10000),
+            )
+        }
+        None => (jackpot_percentage, win_probability_bps),
+    };
+    
     // Calculate distribution
     let jackpot_contribution = amount
-        .checked_mul(config.jackpot_percentage as u64)
+        .checked_mul(jackpot_percentage as u64)
         .and_then(|x| x.checked_div(10000))
         .ok_or(CasinoError::MathOverflow)?;
     
@@ -40,17 +151,223 @@ pub fn contribute_bet(
         .and_then(|x| x.checked_div(10000))
         .ok_or(CasinoError::MathOverflow)?;
     
-    // Transfer SOL to program
-    **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? += jackpot_contribution;
-    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? -= jackpot_contribution;
+    // A partner front-end takes its share out of the house fee
+    let integrator_fee = match &ctx.accounts.integrator {
+        Some(integrator) => house_fee
+            .checked_mul(integrator.fee_share_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?,
+        None => 0,
+    };
+    let house_fee = house_fee - integrator_fee;
+    
+    // A referred player's referrer takes a commission out of what's left; once the link exists it can't be left out
+    let referral_info = ctx.accounts.referral.to_account_info();
+    let referral_fee = if referral_info.data_is_empty() {
+        require!(
+            ctx.accounts.referral_earnings.is_none(),
+            CasinoError::InvalidReferral
+        );
+        0
+    } else {
+        let referral = Referral::try_deserialize(&mut &referral_info.try_borrow_data()?[..])?;
+        let earnings = ctx.accounts.referral_earnings.as_ref().ok_or(CasinoError::InvalidReferral)?;
+        require!(
+            earnings.referrer == referral.referrer,
+            CasinoError::InvalidReferral
+        );
+        
+        house_fee
+            .checked_mul(config.referral_fee_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?
+    };
+    let house_fee = house_fee - referral_fee;
+    
+    // An active season takes its prize share out of the house fee
+    let season_fee = match &ctx.accounts.season {
+        Some(season) => {
+            let season = season.load()?;
+            if season.is_active(now) {
+                house_fee
+                    .checked_mul(season.fee_bps as u64)
+                    .and_then(|x| x.checked_div(10000))
+                    .ok_or(CasinoError::MathOverflow)?
+            } else {
+                0
+            }
+        }
+        None => 0,
+    };
+    let house_fee = house_fee - season_fee;
+    
+    // VIP rakeback is credited from the house's share and stays in the house vault until claimed
+    let rakeback = match vip {
+        Some(tier) => house_fee
+            .checked_mul(tier.rakeback_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?,
+        None => 0,
+    };
+    
+    // A qualifying streak adds rakeback on top of the VIP rate, never more than the house fee
+    let streak_rakeback = if streak_bonus {
+        house_fee
+            .checked_mul(config.streak_rakeback_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?
+            .min(house_fee.saturating_sub(rakeback))
+    } else {
+        0
+    };
+    let rakeback = rakeback + streak_rakeback;
+    
+    // While draws are on, a bet enters the open round and funds its prize
+    // Between rounds, or once the round is full, the bet skips the draw and its share stays in the wager
+    let draw_contribution = match ctx.accounts.draw_round.as_ref().filter(|_| config.draw_bps > 0) {
+        Some(draw_round) => {
+            let mut round = draw_round.load_mut()?;
+            if round.is_open(now) && round.enter(ctx.accounts.player.key(), amount) {
+                let draw_contribution = amount
+                    .checked_mul(config.draw_bps as u64)
+                    .and_then(|x| x.checked_div(10000))
+                    .ok_or(CasinoError::MathOverflow)?;
+                round.prize = round.prize
+                    .checked_add(draw_contribution)
+                    .ok_or(CasinoError::MathOverflow)?;
+                draw_contribution
+            } else {
+                0
+            }
+        }
+        None => 0,
+    };
+    
+    // The rest of the wager is escrowed in the game vault
+    let game_contribution = amount
+        .checked_sub(jackpot_contribution)
+        .and_then(|x| x.checked_sub(house_fee))
+        .and_then(|x| x.checked_sub(defi_contribution))
+        .and_then(|x| x.checked_sub(integrator_fee))
+        .and_then(|x| x.checked_sub(referral_fee))
+        .and_then(|x| x.checked_sub(draw_contribution))
+        .and_then(|x| x.checked_sub(season_fee))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Bonus credit from comp points covers the escrowed leg, fronted by the house vault
+    let bonus_credit_used = ctx.accounts.player_profile.bonus_credit.min(game_contribution);
+    if bonus_credit_used > 0 {
+        let house_info = ctx.accounts.house_vault.to_account_info();
+        let reserved = ctx.accounts.house_vault.reserved_lamports(house_info.data_len())?;
+        require!(
+            house_info.lamports() >= bonus_credit_used.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+            CasinoError::InsufficientFunds
+        );
+        
+        **house_info.try_borrow_mut_lamports()? -= bonus_credit_used;
+        **ctx.accounts.game_vault.to_account_info().try_borrow_mut_lamports()? += bonus_credit_used;
+        
+        ctx.accounts.player_profile.bonus_credit -= bonus_credit_used;
+    }
     
-    **ctx.accounts.house_vault.to_account_info().try_borrow_mut_lamports()? += house_fee;
-    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? -= house_fee;
+    // A promotional bonus balance, if passed, pays the rest of the bet instead of the player
+    let bonus_funded = amount - bonus_credit_used;
+    let bonus_info = match ctx.accounts.bonus_credit.as_mut() {
+        Some(bonus) => {
+            require!(
+                bonus.balance >= bonus_funded,
+                CasinoError::InsufficientBonusCredit
+            );
+            
+            bonus.balance -= bonus_funded;
+            bonus.wagering_remaining = bonus.wagering_remaining.saturating_sub(amount);
+            Some(bonus.to_account_info())
+        }
+        None => None,
+    };
     
-    **ctx.accounts.reward_vault.to_account_info().try_borrow_mut_lamports()? += defi_contribution;
-    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? -= defi_contribution;
+    // Transfer the rest of the bet from the player (or their bonus balance)
+    let legs = [
+        (pool.to_account_info(), jackpot_contribution),
+        (ctx.accounts.house_vault.to_account_info(), house_fee),
+        (reward_vault.to_account_info(), defi_contribution),
+        (ctx.accounts.game_vault.to_account_info(), game_contribution - bonus_credit_used),
+    ];
+    let integrator_leg = ctx.accounts.integrator
+        .as_ref()
+        .map(|integrator| (integrator.to_account_info(), integrator_fee));
+    let referral_leg = ctx.accounts.referral_earnings
+        .as_ref()
+        .map(|earnings| (earnings.to_account_info(), referral_fee));
+    let draw_leg = ctx.accounts.draw_round
+        .as_ref()
+        .map(|draw_round| (draw_round.to_account_info(), draw_contribution));
+    let season_leg = ctx.accounts.season
+        .as_ref()
+        .map(|season| (season.to_account_info(), season_fee));
+    
+    for (to, lamports) in legs
+        .into_iter()
+        .chain(integrator_leg)
+        .chain(referral_leg)
+        .chain(draw_leg)
+        .chain(season_leg)
+    {
+        if lamports == 0 {
+            continue;
+        }
+        
+        if let Some(bonus_info) = &bonus_info {
+            **bonus_info.try_borrow_mut_lamports()? -= lamports;
+            **to.try_borrow_mut_lamports()? += lamports;
+            continue;
+        }
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to,
+                },
+            ),
+            lamports,
+        )?;
+    }
+    
+    // The charity round-up is on top of the wager and always comes from the player's own wallet
+    let charity_bps = charity_bps.unwrap_or(0);
+    require!(
+        charity_bps <= 10000,
+        CasinoError::InvalidCharityBps
+    );
+    
+    let charity_amount = amount
+        .checked_mul(charity_bps as u64)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    if charity_amount > 0 {
+        let charity_vault = ctx.accounts.charity_vault.as_mut().ok_or(CasinoError::CharityNotConfigured)?;
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to: charity_vault.to_account_info(),
+                },
+            ),
+            charity_amount,
+        )?;
+        
+        charity_vault.total_received = charity_vault.total_received
+            .checked_add(charity_amount)
+            .ok_or(CasinoError::MathOverflow)?;
+    }
     
     // Update state
+    let pool_balance_before = pool.balance;
     pool.balance = pool.balance
         .checked_add(jackpot_contribution)
         .ok_or(CasinoError::MathOverflow)?;
@@ -59,14 +376,149 @@ pub fn contribute_bet(
         .checked_add(1)
         .ok_or(CasinoError::MathOverflow)?;
     
+    pool.record_bettor(ctx.accounts.player.key());
+    
+    // Arm the must-hit-by jackpot once the ceiling is crossed
+    if pool.arm_must_hit() {
+        msg!("Must-hit-by ceiling reached: {}", pool.balance);
+        
+        emit!(MustHitArmed {
+            sequence: config.next_event_sequence(),
+            pool_balance: pool.balance,
+            must_hit_by: pool.must_hit_by,
+        });
+    }
+    
     config.total_bets = config.total_bets
         .checked_add(1)
         .ok_or(CasinoError::MathOverflow)?;
     
+    config.open_bets = config.open_bets
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // The DeFi cut is the player's stake in the reward vault; yield realized so far goes to the stake before it
+    reward_vault.accrue(Clock::get()?.unix_timestamp)?;
+    reward_vault.distribute_yield()?;
+    
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    if reward_claim.user == Pubkey::default() {
+        reward_claim.user = ctx.accounts.player.key();
+        reward_claim.last_claim = Clock::get()?.unix_timestamp;
+        reward_claim.bump = ctx.bumps.reward_claim;
+    }
+    reward_claim.deposit(defi_contribution, reward_vault)?;
+    
     reward_vault.staked_amount = reward_vault.staked_amount
         .checked_add(defi_contribution)
         .ok_or(CasinoError::MathOverflow)?;
     
+    ctx.accounts.house_vault.total_fees = ctx.accounts.house_vault.total_fees
+        .checked_add(house_fee)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    ctx.accounts.house_vault.rakeback_owed = ctx.accounts.house_vault.rakeback_owed
+        .checked_add(rakeback)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Only fees the house keeps are withdrawable; rent and other deposits are not
+    ctx.accounts.house_vault.accumulated_house_fees = ctx.accounts.house_vault.accumulated_house_fees
+        .checked_add(house_fee.saturating_sub(rakeback))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    ctx.accounts.game_vault.total_escrowed = ctx.accounts.game_vault.total_escrowed
+        .checked_add(game_contribution)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    if let Some(integrator) = ctx.accounts.integrator.as_mut() {
+        integrator.total_bets = integrator.total_bets
+            .checked_add(1)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        integrator.total_volume = integrator.total_volume
+            .checked_add(amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        integrator.total_fees_accrued = integrator.total_fees_accrued
+            .checked_add(integrator_fee)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        integrator.pending_fees = integrator.pending_fees
+            .checked_add(integrator_fee)
+            .ok_or(CasinoError::MathOverflow)?;
+    }
+    
+    if let Some(earnings) = ctx.accounts.referral_earnings.as_mut() {
+        earnings.total_earned = earnings.total_earned
+            .checked_add(referral_fee)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        earnings.pending = earnings.pending
+            .checked_add(referral_fee)
+            .ok_or(CasinoError::MathOverflow)?;
+    }
+    
+    // Update player profile (created on first bet)
+    let player_profile = &mut ctx.accounts.player_profile;
+    if player_profile.player == Pubkey::default() {
+        player_profile.player = ctx.accounts.player.key();
+        player_profile.bump = ctx.bumps.player_profile;
+    }
+    
+    player_profile.total_wagered = player_profile.total_wagered
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    player_profile.bets_placed = player_profile.bets_placed
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    player_profile.last_bet_timestamp = Clock::get()?.unix_timestamp;
+    
+    player_profile.total_charity = player_profile.total_charity
+        .checked_add(charity_amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    player_profile.rakeback_pending = player_profile.rakeback_pending
+        .checked_add(rakeback)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    player_profile.total_rakeback = player_profile.total_rakeback
+        .checked_add(rakeback)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Comp points accrue on the full wager
+    let comp_points_earned = (amount as u128)
+        .checked_mul(config.comp_points_per_sol as u128)
+        .map(|x| x / LAMPORTS_PER_SOL as u128)
+        .and_then(|x| u64::try_from(x).ok())
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    player_profile.comp_points = player_profile.comp_points
+        .checked_add(comp_points_earned)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Season standings use the player's wager total within the season
+    if let Some(season) = &ctx.accounts.season {
+        let mut season = season.load_mut()?;
+        if season.is_active(now) {
+            if player_profile.season_id != season.season_id {
+                player_profile.season_id = season.season_id;
+                player_profile.season_wagered = 0;
+            }
+            
+            player_profile.season_wagered = player_profile.season_wagered
+                .checked_add(amount)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            season.prize_pool = season.prize_pool
+                .checked_add(season_fee)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            season.record_wager(player_profile.player, player_profile.season_wagered);
+        }
+    }
+    
     // Check if we should trigger VRF (milestone or random chance)
     let should_trigger_vrf = if pool.milestone_bets > 0 {
         pool.bets_since_win >= pool.milestone_bets
@@ -102,7 +554,9 @@ pub fn contribute_bet(
     let bet = &mut ctx.accounts.bet;
     bet.player = ctx.accounts.player.key();
     bet.amount = amount;
+    bet.pool = pool.key();
     bet.timestamp = Clock::get()?.unix_timestamp;
+    bet.placed_slot = Clock::get()?.slot;
     bet.vrf_request_id = if should_trigger_vrf {
         Some(ctx.accounts.vrf_request.request_id)
     } else {
@@ -110,29 +564,202 @@ pub fn contribute_bet(
     };
     bet.status = 0; // pending
     bet.win_amount = 0;
+    bet.config_hash = config.params_hash();
+    bet.experiment_id = if experiment_variant.is_some() { config.experiment_id } else { 0 };
+    bet.experiment_variant = experiment_variant;
+    bet.win_probability_bps = win_probability_bps;
+    bet.streak_bonus_bps = streak_bonus_bps;
+    bet.fee_splits_bps = [jackpot_percentage, config.house_percentage, config.defi_percentage];
+    bet.odds_mode = config.odds_mode;
+    bet.odds_max_bet = max_bet;
+    bet.tier_multipliers_bps = TIER_MULTIPLIERS_BPS;
+    bet.bonus_funded = bonus_info.is_some();
+    bet.pool_balance_before = pool_balance_before;
+    bet.pool_balance_after = pool.balance;
+    bet.escrowed = game_contribution;
     bet.bump = ctx.bumps.bet;
     
+    if let Some(bucket) = &mut ctx.accounts.volume_bucket {
+        require!(bucket.day == today, CasinoError::InvalidVolumeBucketDay);
+        bucket.bets = bucket.bets.saturating_add(1);
+        bucket.volume = bucket.volume.saturating_add(amount);
+        if first_bet_today {
+            bucket.unique_bettors = bucket.unique_bettors.saturating_add(1);
+        }
+    }
+    
+    if let Some(stats) = &ctx.accounts.stats {
+        let mut stats = stats.load_mut()?;
+        let totals = stats.epoch_mut(Clock::get()?.epoch);
+        totals.bets = totals.bets.saturating_add(1);
+        totals.wagered = totals.wagered.saturating_add(amount);
+        totals.house_fees = totals.house_fees.saturating_add(house_fee);
+    }
+    
     msg!(
-        "Bet contributed: {} lamports, jackpot={}, house={}, defi={}",
-        amount, jackpot_contribution, house_fee, defi_contribution
+        "Bet contributed: {} lamports, jackpot={}, house={}, defi={}, game={}",
+        amount, jackpot_contribution, house_fee, defi_contribution, game_contribution
     );
     
-    emit!(BetContributed {
+    // Placement odds including the streak bonus; ramps and scaled odds apply at settlement
+    let effective_win_probability_bps = win_probability_bps
+        .saturating_add(streak_bonus_bps)
+        .min(10000);
+    
+    emit_event!(ctx, BetContributed {
+        sequence: config.next_event_sequence(),
         player: ctx.accounts.player.key(),
+        bet: ctx.accounts.bet.key(),
+        vrf_request: should_trigger_vrf.then(|| ctx.accounts.vrf_request.key()),
         amount,
+        win_probability_bps: effective_win_probability_bps,
         jackpot_contribution,
+        house_fee,
+        defi_contribution,
         pool_balance: pool.balance,
+        experiment_variant,
+        integrator: ctx.accounts.integrator.as_ref().map(|integrator| integrator.partner),
+        bonus_credit_used,
+        comp_points_earned,
+        vip_tier,
+        rakeback,
+        happy_hour,
     });
     
+    if charity_amount > 0 {
+        emit!(CharityDonated {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            player: ctx.accounts.player.key(),
+            bet: ctx.accounts.bet.key(),
+            amount: charity_amount,
+            charity_bps,
+            total_donated: ctx.accounts.player_profile.total_charity,
+        });
+    }
+    
+    if streak_bonus {
+        emit!(StreakBonusApplied {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            player: ctx.accounts.player.key(),
+            streak_len,
+            odds_bonus_bps: streak_bonus_bps,
+            rakeback_bonus: streak_rakeback,
+        });
+    }
+    
     Ok(())
 }
 
+/// Player checks shared by every wager entry point: shutdown, operating hours, freezes, the allowlist and gaming limits
+/// `amount` counts toward the player's daily caps as if it loses
+pub fn check_wager_allowed(
+    config: &Config,
+    player_profile: &PlayerProfile,
+    frozen_player: &AccountInfo,
+    membership: &AccountInfo,
+    gaming_limits: &AccountInfo,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        !config.shutdown,
+        CasinoError::CasinoShutdown
+    );
+    
+    require!(
+        config.is_within_operating_hours(Clock::get()?.unix_timestamp),
+        CasinoError::OutsideOperatingHours
+    );
+    
+    require!(
+        frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    require!(
+        !config.allowlist_enabled || !membership.data_is_empty(),
+        CasinoError::NotAMember
+    );
+    
+    enforce_gaming_limits(gaming_limits, player_profile, amount)
+}
+
+/// Count a game wager toward the player's lifetime volume, creating the profile on first use
+pub fn record_wager(
+    player_profile: &mut PlayerProfile,
+    player: Pubkey,
+    bump: u8,
+    amount: u64,
+) -> Result<()> {
+    if player_profile.player == Pubkey::default() {
+        player_profile.player = player;
+        player_profile.bump = bump;
+    }
+    
+    player_profile.total_wagered = player_profile.total_wagered
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    Ok(())
+}
+
+/// Enforce the player's responsible-gaming limits, if they have set any
+fn enforce_gaming_limits(
+    gaming_limits: &AccountInfo,
+    player_profile: &PlayerProfile,
+    amount: u64,
+) -> Result<()> {
+    // Limits are opt-in; players who never set them have no account
+    if gaming_limits.data_is_empty() {
+        return Ok(());
+    }
+    
+    require_keys_eq!(*gaming_limits.owner, crate::ID, CasinoError::Unauthorized);
+    let mut limits = GamingLimits::try_deserialize(&mut &gaming_limits.try_borrow_data()?[..])?;
+    let now = Clock::get()?.unix_timestamp;
+    
+    limits.apply_pending(now);
+    
+    require!(
+        now >= limits.self_excluded_until,
+        CasinoError::SelfExcluded
+    );
+    
+    // Roll the daily counters over at UTC midnight
+    let day = now.div_euclid(86400);
+    if day != limits.day {
+        limits.day = day;
+        limits.day_start_wagered = player_profile.total_wagered;
+        limits.day_start_won = player_profile.total_won;
+    }
+    
+    let wagered_today = player_profile.total_wagered
+        .checked_sub(limits.day_start_wagered)
+        .and_then(|x| x.checked_add(amount))
+        .ok_or(CasinoError::MathOverflow)?;
+    let won_today = player_profile.total_won.saturating_sub(limits.day_start_won);
+    
+    require!(
+        limits.daily_wager_cap == 0 || wagered_today <= limits.daily_wager_cap,
+        CasinoError::DailyLimitExceeded
+    );
+    
+    // Assume this bet loses
+    require!(
+        limits.daily_loss_cap == 0 || wagered_today.saturating_sub(won_today) <= limits.daily_loss_cap,
+        CasinoError::DailyLimitExceeded
+    );
+    
+    limits.try_serialize(&mut &mut gaming_limits.try_borrow_mut_data()?[..])
+}
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
+#[instruction(amount: u64)]
 pub struct ContributeBet<'info> {
     #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
     
-    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
     pub pool: Account<'info, JackpotPool>,
     
     #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
@@ -141,7 +768,7 @@ pub struct ContributeBet<'info> {
     #[account(
         init,
         payer = player,
-        space = 8 + std::mem::size_of::<Bet>(),
+        space = 8 + Bet::INIT_SPACE,
         seeds = [b"bet", player.key().as_ref(), amount.to_le_bytes().as_ref()],
         bump
     )]
@@ -150,15 +777,99 @@ pub struct ContributeBet<'info> {
     #[account(
         init,
         payer = player,
-        space = 8 + std::mem::size_of::<VrfRequest>(),
+        space = 8 + VrfRequest::INIT_SPACE,
         seeds = [b"vrf_request", bet.key().as_ref()],
         bump
     )]
     pub vrf_request: Account<'info, VrfRequest>,
     
-    /// CHECK: House vault for fees (can be any account)
-    #[account(mut)]
-    pub house_vault: AccountInfo<'info>,
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + RewardClaim::INIT_SPACE,
+        seeds = [b"reward_claim", player.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(mut, seeds = [b"game_vault"], bump = game_vault.bump)]
+    pub game_vault: Account<'info, GameVault>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    /// Partner front-end routing this bet, if any
+    #[account(mut, seeds = [b"integrator", integrator.partner.as_ref()], bump = integrator.bump)]
+    pub integrator: Option<Account<'info, Integrator>>,
+    
+    /// CHECK: player's Referral PDA; uninitialized unless they were referred
+    #[account(seeds = [b"referral", player.key().as_ref()], bump)]
+    pub referral: UncheckedAccount<'info>,
+    
+    /// Referrer's earnings account (required once `referral` is initialized)
+    #[account(mut, seeds = [b"referral_earnings", referral_earnings.referrer.as_ref()], bump = referral_earnings.bump)]
+    pub referral_earnings: Option<Account<'info, ReferralEarnings>>,
+    
+    /// Open draw round; the bet enters it while `Config::draw_bps` is set
+    #[account(
+        mut,
+        seeds = [b"draw", draw_round.load()?.round_id.to_le_bytes().as_ref()],
+        bump = draw_round.load()?.bump
+    )]
+    pub draw_round: Option<AccountLoader<'info, DrawRound>>,
+    
+    /// Running leaderboard season, if the bet should count toward it
+    #[account(
+        mut,
+        seeds = [b"season", season.load()?.season_id.to_le_bytes().as_ref()],
+        bump = season.load()?.bump
+    )]
+    pub season: Option<AccountLoader<'info, Season>>,
+    
+    /// Player's promotional bonus balance, to fund this bet from it
+    #[account(mut, seeds = [b"bonus", player.key().as_ref()], bump = bonus_credit.bump)]
+    pub bonus_credit: Option<Account<'info, BonusCredit>>,
+    
+    /// House bankroll counted toward the exposure limit, if funded
+    #[account(seeds = [b"bankroll"], bump = bankroll.bump)]
+    pub bankroll: Option<Account<'info, Bankroll>>,
+    
+    /// RTP stats, if tracked
+    #[account(mut, seeds = [b"stats"], bump = stats.load()?.bump)]
+    pub stats: Option<AccountLoader<'info, Stats>>,
+    
+    /// Charity vault receiving the round-up (required when `charity_bps` is set)
+    #[account(mut, seeds = [b"charity_vault"], bump = charity_vault.bump)]
+    pub charity_vault: Option<Account<'info, CharityVault>>,
+    
+    /// Today's volume bucket, if the dashboard tracks it
+    #[account(mut, seeds = [b"volume", volume_bucket.day.to_le_bytes().as_ref()], bump = volume_bucket.bump)]
+    pub volume_bucket: Option<Account<'info, VolumeBucket>>,
+    
+    /// CHECK: address is constrained to the instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
     
     #[account(mut)]
     pub player: Signer<'info>,
@@ -168,8 +879,47 @@ pub struct ContributeBet<'info> {
 
 #[event]
 pub struct BetContributed {
+    pub sequence: u64,
     pub player: Pubkey,
+    pub bet: Pubkey,
+    pub vrf_request: Option<Pubkey>,
     pub amount: u64,
+    pub win_probability_bps: u16,
     pub jackpot_contribution: u64,
+    pub house_fee: u64,
+    pub defi_contribution: u64,
+    pub pool_balance: u64,
+    pub experiment_variant: Option<u8>,
+    pub integrator: Option<Pubkey>,
+    pub bonus_credit_used: u64,
+    pub comp_points_earned: u64,
+    pub vip_tier: Option<u8>,
+    pub rakeback: u64,
+    pub happy_hour: Option<HappyHour>,
+}
+
+#[event]
+pub struct CharityDonated {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+    pub charity_bps: u16,
+    pub total_donated: u64,
+}
+
+#[event]
+pub struct StreakBonusApplied {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub streak_len: u16,
+    pub odds_bonus_bps: u16,
+    pub rakeback_bonus: u64,
+}
+
+#[event]
+pub struct MustHitArmed {
+    pub sequence: u64,
     pub pool_balance: u64,
+    pub must_hit_by: u64,
 }
@@ -0,0 +1,202 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::register_keeper::pay_keeper;
+
+/// Maximum number of lottery tickets cranked in a single transaction
+pub const MAX_LOTTO_CRANK: usize = 10;
+
+/// Accounts supplied per ticket in `remaining_accounts`: lotto entry, ticket player
+const ACCOUNTS_PER_LOTTO_TICKET: usize = 2;
+
+/// Settle a drawn lottery round's tickets (permissionless)
+/// Until every ticket is tallied, matches them against the draw; after that, pays each ticket its tier's share and closes it
+pub fn crank_lotto<'info>(
+    ctx: Context<'_, '_, 'info, 'info, CrankLotto<'info>>,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    
+    require!(
+        !remaining.is_empty() && remaining.len() % ACCOUNTS_PER_LOTTO_TICKET == 0,
+        CasinoError::InvalidBatchAccounts
+    );
+    
+    let cranked = remaining.len() / ACCOUNTS_PER_LOTTO_TICKET;
+    require!(
+        cranked <= MAX_LOTTO_CRANK,
+        CasinoError::BatchTooLarge
+    );
+    
+    let round = &mut ctx.accounts.round;
+    require!(
+        round.drawn,
+        CasinoError::LottoNotDrawn
+    );
+    
+    let round_key = round.key();
+    let round_info = round.to_account_info();
+    let paying = round.is_tallied();
+    
+    for accounts in remaining.chunks(ACCOUNTS_PER_LOTTO_TICKET) {
+        let mut entry: Account<'info, LottoEntry> = Account::try_from(&accounts[0])?;
+        let player = &accounts[1];
+        
+        require!(
+            entry.round == round_key && player.key() == entry.player,
+            CasinoError::InvalidBatchAccounts
+        );
+        
+        if !paying {
+            // Another crank may have tallied this ticket already
+            if entry.tallied {
+                continue;
+            }
+            
+            entry.matches = round.matches(&entry.numbers);
+            entry.tallied = true;
+            if entry.matches > 0 {
+                round.tier_winners[entry.matches as usize - 1] += 1;
+            }
+            round.tallied_count += 1;
+            
+            // Remaining accounts are not persisted by Anchor
+            entry.exit(&crate::ID)?;
+            continue;
+        }
+        
+        let payout = match entry.matches {
+            0 => 0,
+            matches => round.tier_payouts[matches as usize - 1],
+        };
+        
+        if payout > 0 {
+            **round_info.try_borrow_mut_lamports()? -= payout;
+            **player.try_borrow_mut_lamports()? += payout;
+        }
+        
+        round.paid_count += 1;
+        
+        emit!(LottoTicketPaid {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            round_id: round.round_id,
+            index: entry.index,
+            player: entry.player,
+            matches: entry.matches,
+            payout,
+        });
+        
+        entry.close(player.clone())?;
+    }
+    
+    if !paying && round.is_tallied() {
+        finalize_lotto_payouts(round, &mut ctx.accounts.house_vault)?;
+        
+        emit!(LottoTallied {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            round_id: round.round_id,
+            tier_winners: round.tier_winners,
+            tier_payouts: round.tier_payouts,
+        });
+    }
+    
+    pay_keeper(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.house_vault,
+        &mut ctx.accounts.keeper,
+        &ctx.accounts.keeper_authority,
+        cranked as u64,
+    )?;
+    
+    msg!("Lotto round {} cranked: {} tickets {}", round.round_id, cranked, if paying { "paid" } else { "tallied" });
+    
+    Ok(())
+}
+
+/// Fix each tier's per-ticket payout and send the rake, unwon tiers and rounding dust to house fees
+fn finalize_lotto_payouts<'info>(
+    round: &mut Account<'info, LottoRound>,
+    house_vault: &mut Account<'info, HouseVault>,
+) -> Result<()> {
+    let pot = (round.ticket_price as u128)
+        .checked_mul(round.ticket_count as u128)
+        .and_then(|v| u64::try_from(v).ok())
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let rake = pot
+        .checked_mul(round.rake_bps as u64)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let net_pot = pot - rake;
+    let mut paid_out: u64 = 0;
+    
+    for tier in 0..round.picks as usize {
+        let winners = round.tier_winners[tier] as u64;
+        if winners == 0 {
+            continue;
+        }
+        
+        let sub_pool = net_pot
+            .checked_mul(round.tier_shares_bps[tier] as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        round.tier_payouts[tier] = sub_pool / winners;
+        paid_out += round.tier_payouts[tier] * winners;
+    }
+    
+    let house_share = pot - paid_out;
+    if house_share > 0 {
+        **round.to_account_info().try_borrow_mut_lamports()? -= house_share;
+        **house_vault.to_account_info().try_borrow_mut_lamports()? += house_share;
+        
+        house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+            .checked_add(house_share)
+            .ok_or(CasinoError::MathOverflow)?;
+    }
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CrankLotto<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"lotto", round.round_id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, LottoRound>,
+    
+    /// Receives the rake and unwon tiers, and pays keeper fees
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// Registered keeper credited for the crank, if any
+    #[account(mut, seeds = [b"keeper", keeper.authority.as_ref()], bump = keeper.bump)]
+    pub keeper: Option<Account<'info, Keeper>>,
+    
+    /// Keeper wallet receiving the crank fee; required with `keeper`
+    #[account(mut)]
+    pub keeper_authority: Option<Signer<'info>>,
+}
+
+#[event]
+pub struct LottoTicketPaid {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub index: u32,
+    pub player: Pubkey,
+    pub matches: u8,
+    pub payout: u64,
+}
+
+#[event]
+pub struct LottoTallied {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub tier_winners: [u32; MAX_LOTTO_PICKS],
+    pub tier_payouts: [u64; MAX_LOTTO_PICKS],
+}
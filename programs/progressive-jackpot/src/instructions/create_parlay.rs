@@ -0,0 +1,207 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::{check_wager_allowed, record_wager};
+
+/// Place a parlay: up to five sequential legs where each won leg's payout is staked on the next
+/// `legs` holds each leg's win chance in basis points; the house backs the full potential payout up front
+pub fn create_parlay(
+    ctx: Context<CreateParlay>,
+    stake: u64,
+    legs: Vec<u16>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let now = Clock::get()?.unix_timestamp;
+    
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        stake,
+    )?;
+    record_wager(&mut ctx.accounts.player_profile, ctx.accounts.player.key(), ctx.bumps.player_profile, stake)?;
+    
+    require!(
+        config.max_parlay_payout > 0,
+        CasinoError::ParlaysDisabled
+    );
+    
+    require!(stake >= config.min_bet, CasinoError::BetTooSmall);
+    require!(stake <= config.max_bet, CasinoError::BetTooLarge);
+    
+    require!(
+        legs.len() >= 2 && legs.len() <= MAX_PARLAY_LEGS,
+        CasinoError::InvalidParlay
+    );
+    
+    // Every leg must pay out more than it stakes, or it's a guaranteed loss for the player
+    let mut potential_payout = stake;
+    for &chance_bps in &legs {
+        let next = parlay_leg_payout(potential_payout, chance_bps, config.house_percentage)?;
+        require!(next > potential_payout, CasinoError::InvalidParlay);
+        potential_payout = next;
+    }
+    
+    require!(
+        potential_payout <= config.max_parlay_payout,
+        CasinoError::ParlayPayoutTooLarge
+    );
+    
+    // Set aside the house's share of the potential payout so it can't be withdrawn mid-parlay
+    let liability = potential_payout - stake;
+    let house_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(house_info.data_len())?;
+    require!(
+        house_info.lamports() >= reserved.checked_add(liability).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.game_liability = house_vault.game_liability
+        .checked_add(liability)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // The parlay account holds the stake until it settles
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.parlay.to_account_info(),
+            },
+        ),
+        stake,
+    )?;
+    
+    let mut leg_chances_bps = [0u16; MAX_PARLAY_LEGS];
+    leg_chances_bps[..legs.len()].copy_from_slice(&legs);
+    
+    let parlay = &mut ctx.accounts.parlay;
+    parlay.player = ctx.accounts.player.key();
+    parlay.stake = stake;
+    parlay.leg_count = legs.len() as u8;
+    parlay.leg_chances_bps = leg_chances_bps;
+    parlay.leg_rolls = [0; MAX_PARLAY_LEGS];
+    parlay.current_leg = 0;
+    parlay.current_stake = stake;
+    parlay.potential_payout = potential_payout;
+    parlay.house_edge_bps = config.house_percentage;
+    parlay.status = 0; // pending
+    parlay.placed_slot = Clock::get()?.slot;
+    parlay.created_at = now;
+    parlay.bump = ctx.bumps.parlay;
+    
+    let mut request_id = [0u8; 32];
+    request_id[..8].copy_from_slice(&now.to_le_bytes());
+    
+    let vrf_request = &mut ctx.accounts.vrf_request;
+    vrf_request.bet = parlay.key();
+    vrf_request.player = parlay.player;
+    vrf_request.timestamp = now;
+    vrf_request.request_id = request_id;
+    vrf_request.status = 0; // pending
+    vrf_request.result = None;
+    vrf_request.bump = ctx.bumps.vrf_request;
+    
+    let config = &mut ctx.accounts.config;
+    config.open_bets = config.open_bets
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Parlay placed: {} lamports over {} legs, potential payout {}", stake, legs.len(), potential_payout);
+    
+    emit!(ParlayCreated {
+        sequence: config.next_event_sequence(),
+        player: parlay.player,
+        parlay: parlay.key(),
+        vrf_request: vrf_request.key(),
+        stake,
+        leg_chances_bps: legs,
+        potential_payout,
+    });
+    
+    Ok(())
+}
+
+/// Payout for winning a leg: the fair payout for its win chance, less the house edge
+pub fn parlay_leg_payout(stake: u64, chance_bps: u16, house_edge_bps: u16) -> Result<u64> {
+    require!(
+        chance_bps > 0 && chance_bps < 10000,
+        CasinoError::InvalidParlay
+    );
+    
+    let payout = (stake as u128)
+        .checked_mul(10000u128.saturating_sub(house_edge_bps as u128))
+        .and_then(|v| v.checked_div(chance_bps as u128))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    u64::try_from(payout).map_err(|_| CasinoError::MathOverflow.into())
+}
+
+#[derive(Accounts)]
+#[instruction(stake: u64)]
+pub struct CreateParlay<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Parlay::INIT_SPACE,
+        seeds = [b"parlay", player.key().as_ref(), stake.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub parlay: Account<'info, Parlay>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + VrfRequest::INIT_SPACE,
+        seeds = [b"vrf_request", parlay.key().as_ref()],
+        bump
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ParlayCreated {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub parlay: Pubkey,
+    pub vrf_request: Pubkey,
+    pub stake: u64,
+    pub leg_chances_bps: Vec<u16>,
+    pub potential_payout: u64,
+}
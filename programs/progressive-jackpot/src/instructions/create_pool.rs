@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Open an additional jackpot pool with its own limits and odds (authority only)
+pub fn create_pool(
+    ctx: Context<CreatePool>,
+    pool_id: u64,
+    params: PoolParams,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        params.win_probability_bps <= 10000,
+        CasinoError::InvalidConfig
+    );
+    
+    require!(
+        params.max_bet == 0 || params.max_bet >= params.min_bet,
+        CasinoError::InvalidConfig
+    );
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.pool_id = pool_id;
+    pool.balance = 0;
+    pool.last_winner = None;
+    pool.last_win_timestamp = None;
+    pool.reset_threshold = params.reset_threshold;
+    pool.bets_since_win = 0;
+    pool.milestone_bets = params.milestone_bets;
+    pool.pending_payouts = 0;
+    pool.must_hit_by = params.must_hit_by;
+    pool.must_hit_armed = false;
+    pool.min_bet = params.min_bet;
+    pool.max_bet = params.max_bet;
+    pool.win_probability_bps = params.win_probability_bps;
+    pool.bump = ctx.bumps.pool;
+    pool.version = STATE_VERSION;
+    
+    msg!("Pool {} created", pool_id);
+    
+    emit!(PoolCreated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        pool: pool.key(),
+        pool_id,
+        min_bet: params.min_bet,
+        max_bet: params.max_bet,
+        win_probability_bps: params.win_probability_bps,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(pool_id: u64)]
+pub struct CreatePool<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + JackpotPool::INIT_SPACE,
+        seeds = [b"pool", pool_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PoolCreated {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub pool_id: u64,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub win_probability_bps: u16,
+}
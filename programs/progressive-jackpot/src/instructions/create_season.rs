@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Schedule a leaderboard season funded by a share of house fees (authority only)
+pub fn create_season(
+    ctx: Context<CreateSeason>,
+    season_id: u64,
+    start_ts: i64,
+    end_ts: i64,
+    fee_bps: u16,
+    payout_bps: Vec<u16>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        season_id > 0 && end_ts > start_ts && fee_bps <= 10000,
+        CasinoError::InvalidSeason
+    );
+    
+    // Ranks share at most the whole prize; anything unallocated returns to the house
+    let payout_total: u32 = payout_bps.iter().map(|&bps| bps as u32).sum();
+    require!(
+        !payout_bps.is_empty() && payout_bps.len() <= SEASON_LEADERBOARD_LEN && payout_total <= 10000,
+        CasinoError::InvalidSeason
+    );
+    
+    let mut season = ctx.accounts.season.load_init()?;
+    season.season_id = season_id;
+    season.start_ts = start_ts;
+    season.end_ts = end_ts;
+    season.fee_bps = fee_bps;
+    season.payout_bps[..payout_bps.len()].copy_from_slice(&payout_bps);
+    season.payout_count = payout_bps.len() as u8;
+    season.bump = ctx.bumps.season;
+    
+    msg!("Season {} scheduled: {} to {}", season_id, start_ts, end_ts);
+    
+    emit!(SeasonCreated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        season_id,
+        start_ts,
+        end_ts,
+        fee_bps,
+        payout_bps,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct CreateSeason<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<Season>(),
+        seeds = [b"season", season_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub season: AccountLoader<'info, Season>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SeasonCreated {
+    pub sequence: u64,
+    pub season_id: u64,
+    pub start_ts: i64,
+    pub end_ts: i64,
+    pub fee_bps: u16,
+    pub payout_bps: Vec<u16>,
+}
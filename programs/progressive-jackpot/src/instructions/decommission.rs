@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Pay a pool's residual balance to the sunset beneficiary once every bet is settled (permissionless)
+/// Only after the casino has been shut down for the emergency delay; pending payouts stay claimable
+pub fn decommission(
+    ctx: Context<Decommission>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    
+    require!(
+        config.shutdown,
+        CasinoError::NotShutDown
+    );
+    
+    require!(
+        config.open_bets == 0,
+        CasinoError::BetsStillOpen
+    );
+    
+    let unlocks_at = config.shutdown_at
+        .checked_add(config.emergency_delay_secs.max(MIN_EMERGENCY_DELAY_SECS))
+        .ok_or(CasinoError::MathOverflow)?;
+    require!(
+        Clock::get()?.unix_timestamp >= unlocks_at,
+        CasinoError::DecommissionTooEarly
+    );
+    
+    let amount = pool.balance;
+    
+    **pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.beneficiary.try_borrow_mut_lamports()? += amount;
+    
+    pool.balance = 0;
+    
+    msg!("Pool {} decommissioned: {} lamports to {}", pool.pool_id, amount, ctx.accounts.beneficiary.key());
+    
+    emit!(PoolDecommissioned {
+        sequence: config.next_event_sequence(),
+        pool: pool.key(),
+        beneficiary: ctx.accounts.beneficiary.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Decommission<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    /// CHECK: must be `Config::sunset_beneficiary`
+    #[account(mut, address = config.sunset_beneficiary @ CasinoError::InvalidTreasuryDestination)]
+    pub beneficiary: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct PoolDecommissioned {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub beneficiary: Pubkey,
+    pub amount: u64,
+}
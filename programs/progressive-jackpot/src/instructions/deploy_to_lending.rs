@@ -0,0 +1,231 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{self, spl_token, Mint, SyncNative, Token, TokenAccount};
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// SPL token-lending `DepositReserveLiquidity` instruction tag (Solend and other token-lending forks)
+pub(crate) const DEPOSIT_RESERVE_LIQUIDITY: u8 = 4;
+
+/// SPL token-lending `RedeemReserveCollateral` instruction tag
+pub(crate) const REDEEM_RESERVE_COLLATERAL: u8 = 5;
+
+/// Build a token-lending deposit or redeem from `accounts` in the lending program's order
+/// The first five are writable and the eighth is the transfer authority, which signs
+pub(crate) fn token_lending_ix(
+    program_id: Pubkey,
+    tag: u8,
+    amount: u64,
+    accounts: &[AccountInfo],
+) -> Instruction {
+    let mut data = vec![tag];
+    data.extend_from_slice(&amount.to_le_bytes());
+    
+    Instruction {
+        program_id,
+        accounts: accounts
+            .iter()
+            .enumerate()
+            .map(|(i, account)| match i {
+                0..=4 => AccountMeta::new(account.key(), false),
+                7 => AccountMeta::new_readonly(account.key(), true),
+                _ => AccountMeta::new_readonly(account.key(), false),
+            })
+            .collect(),
+        data,
+    }
+}
+
+/// Supply idle house fees to the configured lending market (authority only)
+/// The SOL is wrapped and deposited; the reserve's collateral tokens are held by the `YieldPosition` PDA
+pub fn deploy_to_lending(
+    ctx: Context<DeployToLending>,
+    amount: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        config.lending_program != Pubkey::default()
+            && ctx.accounts.lending_program.key() == config.lending_program,
+        CasinoError::LendingNotConfigured
+    );
+    
+    require!(
+        amount > 0,
+        CasinoError::InvalidAmount
+    );
+    
+    require!(
+        amount <= ctx.accounts.house_vault.accumulated_house_fees,
+        CasinoError::HouseFeesExceeded
+    );
+    
+    let vault_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(vault_info.data_len())?;
+    require!(
+        vault_info.lamports() >= amount.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    // One reserve at a time; the position can move once it's fully recalled
+    let position = &mut ctx.accounts.yield_position;
+    if position.collateral_amount > 0 {
+        require!(
+            position.lending_program == ctx.accounts.lending_program.key()
+                && position.reserve == ctx.accounts.reserve.key(),
+            CasinoError::LendingReserveMismatch
+        );
+    }
+    position.lending_program = ctx.accounts.lending_program.key();
+    position.reserve = ctx.accounts.reserve.key();
+    position.collateral_mint = ctx.accounts.reserve_collateral_mint.key();
+    position.bump = ctx.bumps.yield_position;
+    
+    // Wrap the SOL
+    **ctx.accounts.wsol_account.to_account_info().try_borrow_mut_lamports()? += amount;
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    token::sync_native(CpiContext::new(
+        ctx.accounts.token_program.to_account_info(),
+        SyncNative {
+            account: ctx.accounts.wsol_account.to_account_info(),
+        },
+    ))?;
+    
+    let collateral_before = ctx.accounts.collateral_account.amount;
+    
+    let lending_accounts = [
+        ctx.accounts.wsol_account.to_account_info(),
+        ctx.accounts.collateral_account.to_account_info(),
+        ctx.accounts.reserve.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.reserve_collateral_mint.to_account_info(),
+        ctx.accounts.lending_market.to_account_info(),
+        ctx.accounts.lending_market_authority.to_account_info(),
+        ctx.accounts.yield_position.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    ];
+    let ix = token_lending_ix(
+        ctx.accounts.lending_program.key(),
+        DEPOSIT_RESERVE_LIQUIDITY,
+        amount,
+        &lending_accounts,
+    );
+    let signer_seeds: &[&[&[u8]]] = &[&[b"yield_position", &[ctx.accounts.yield_position.bump]]];
+    invoke_signed(
+        &ix,
+        &[&lending_accounts[..], &[ctx.accounts.lending_program.to_account_info()]].concat(),
+        signer_seeds,
+    )?;
+    
+    ctx.accounts.collateral_account.reload()?;
+    let minted = ctx.accounts.collateral_account.amount
+        .checked_sub(collateral_before)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let position = &mut ctx.accounts.yield_position;
+    position.collateral_amount = position.collateral_amount
+        .checked_add(minted)
+        .ok_or(CasinoError::MathOverflow)?;
+    position.deployed_lamports = position.deployed_lamports
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    position.last_deployed_at = Clock::get()?.unix_timestamp;
+    
+    ctx.accounts.house_vault.accumulated_house_fees -= amount;
+    
+    msg!("Deployed {} lamports to lending for {} collateral", amount, minted);
+    
+    emit!(LendingDeployed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        reserve: position.reserve,
+        amount,
+        collateral_minted: minted,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeployToLending<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + YieldPosition::INIT_SPACE,
+        seeds = [b"yield_position"],
+        bump
+    )]
+    pub yield_position: Account<'info, YieldPosition>,
+    
+    /// Wrapped SOL staging account, emptied by each deposit
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = native_mint,
+        token::authority = yield_position,
+        seeds = [b"yield_wsol"],
+        bump
+    )]
+    pub wsol_account: Account<'info, TokenAccount>,
+    
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+    
+    /// Holds the reserve's collateral tokens
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = reserve_collateral_mint,
+        token::authority = yield_position,
+        seeds = [b"yield_collateral", reserve_collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: validated by the lending program
+    #[account(mut)]
+    pub reserve: UncheckedAccount<'info>,
+    
+    /// CHECK: validated by the lending program
+    #[account(mut)]
+    pub reserve_liquidity_supply: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub reserve_collateral_mint: Account<'info, Mint>,
+    
+    /// CHECK: validated by the lending program
+    pub lending_market: UncheckedAccount<'info>,
+    
+    /// CHECK: validated by the lending program
+    pub lending_market_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: must be `Config::lending_program`, checked in the handler
+    #[account(executable)]
+    pub lending_program: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LendingDeployed {
+    pub sequence: u64,
+    pub reserve: Pubkey,
+    pub amount: u64,
+    pub collateral_minted: u64,
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Stake idle reward vault lamports with the configured yield strategy (authority only)
+/// `remaining_accounts` are the protocol's deposit accounts, with the SOL escrow and LST account in their slots
+pub fn deploy_to_strategy<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeployToStrategy<'info>>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let reward_vault = &ctx.accounts.reward_vault;
+    let call = reward_vault.strategy.deposit_call().ok_or(CasinoError::StrategyNotSet)?;
+    let protocol_accounts = ctx.remaining_accounts;
+    require!(
+        Some(ctx.accounts.strategy_program.key()) == reward_vault.strategy.program_id()
+            && protocol_accounts.len() == call.accounts
+            && protocol_accounts[call.escrow].key() == ctx.accounts.strategy_escrow.key()
+            && protocol_accounts[call.lst_account].key() == ctx.accounts.lst_account.key(),
+        CasinoError::InvalidStrategyAccounts
+    );
+    
+    require!(
+        amount > 0,
+        CasinoError::InvalidAmount
+    );
+    
+    // Stakers' deposits stay liquid for unstaking
+    let vault_info = reward_vault.to_account_info();
+    let reserved = Rent::get()?.minimum_balance(vault_info.data_len())
+        .checked_add(reward_vault.staker_assets)
+        .ok_or(CasinoError::MathOverflow)?;
+    require!(
+        vault_info.lamports() >= amount.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    // Protocols take deposits by system transfer, which needs a data-less payer
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.strategy_escrow.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    let lst_before = ctx.accounts.lst_account.amount;
+    let ix = call.instruction(ctx.accounts.strategy_program.key(), amount, protocol_accounts);
+    invoke_signed(
+        &ix,
+        &[protocol_accounts, &[ctx.accounts.strategy_program.to_account_info()]].concat(),
+        &[&[b"strategy_escrow", &[ctx.bumps.strategy_escrow]]],
+    )?;
+    
+    ctx.accounts.lst_account.reload()?;
+    let lst_received = ctx.accounts.lst_account.amount
+        .checked_sub(lst_before)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    reward_vault.strategy_deployed = reward_vault.strategy_deployed
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Deployed {} lamports to {:?} for {} LST", amount, reward_vault.strategy, lst_received);
+    
+    emit!(StrategyDeployed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        strategy: reward_vault.strategy,
+        amount,
+        lst_received,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeployToStrategy<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    /// Data-less PDA that pays deposits and receives withdrawals; empty between calls
+    #[account(mut, seeds = [b"strategy_escrow"], bump)]
+    pub strategy_escrow: SystemAccount<'info>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = lst_mint,
+        token::authority = reward_vault,
+        seeds = [b"strategy_lst", lst_mint.key().as_ref()],
+        bump
+    )]
+    pub lst_account: Account<'info, TokenAccount>,
+    
+    #[account(address = reward_vault.strategy_lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    /// CHECK: must be the strategy's program, checked in the handler
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct StrategyDeployed {
+    pub sequence: u64,
+    pub strategy: YieldStrategy,
+    pub amount: u64,
+    pub lst_received: u64,
+}
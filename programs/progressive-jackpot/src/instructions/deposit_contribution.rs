@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::get_instruction_relative;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::MustHitArmed;
+
+/// Route a cut of an external game's wager into the jackpot (built for CPI callers)
+/// Only pool accounting: no bet or VRF request is created
+pub fn deposit_contribution(
+    ctx: Context<DepositContribution>,
+    amount: u64,
+    player: Pubkey,
+) -> Result<()> {
+    require!(
+        amount > 0,
+        CasinoError::InvalidAmount
+    );
+    
+    // The top-level instruction belongs to the calling game program (or to us on a direct call)
+    let source_program = get_instruction_relative(0, &ctx.accounts.instructions_sysvar.to_account_info())?.program_id;
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.depositor.to_account_info(),
+                to: ctx.accounts.pool.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.balance = pool.balance
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    if pool.arm_must_hit() {
+        msg!("Must-hit-by ceiling reached: {}", pool.balance);
+        
+        emit!(MustHitArmed {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            pool_balance: pool.balance,
+            must_hit_by: pool.must_hit_by,
+        });
+    }
+    
+    msg!("Contribution received: {} lamports from program {}", amount, source_program);
+    
+    emit!(ContributionReceived {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        source_program,
+        depositor: ctx.accounts.depositor.key(),
+        player,
+        amount,
+        pool_balance: pool.balance,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DepositContribution<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    /// System account funding the contribution (player or a system-owned PDA of the caller)
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    
+    /// CHECK: address is constrained to the instructions sysvar
+    #[account(address = anchor_lang::solana_program::sysvar::instructions::ID)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ContributionReceived {
+    pub sequence: u64,
+    pub source_program: Pubkey,
+    pub depositor: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub pool_balance: u64,
+}
@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Pay everything in the charity vault above rent to the configured charity (permissionless crank)
+pub fn disburse_charity(
+    ctx: Context<DisburseCharity>,
+) -> Result<()> {
+    let vault_info = ctx.accounts.charity_vault.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(vault_info.data_len());
+    let amount = vault_info.lamports().saturating_sub(rent_reserve);
+    
+    require!(
+        amount > 0,
+        CasinoError::InsufficientFunds
+    );
+    
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.charity.try_borrow_mut_lamports()? += amount;
+    
+    let charity_vault = &mut ctx.accounts.charity_vault;
+    charity_vault.total_disbursed = charity_vault.total_disbursed
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Disbursed {} lamports to charity {}", amount, charity_vault.charity);
+    
+    emit!(CharityDisbursed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        charity: charity_vault.charity,
+        amount,
+        total_disbursed: charity_vault.total_disbursed,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DisburseCharity<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"charity_vault"], bump = charity_vault.bump, has_one = charity @ CasinoError::CharityMismatch)]
+    pub charity_vault: Account<'info, CharityVault>,
+    
+    /// CHECK: must be the vault's configured charity wallet
+    #[account(mut)]
+    pub charity: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct CharityDisbursed {
+    pub sequence: u64,
+    pub charity: Pubkey,
+    pub amount: u64,
+    pub total_disbursed: u64,
+}
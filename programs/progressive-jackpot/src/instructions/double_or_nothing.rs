@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::{check_wager_allowed, record_wager};
+
+/// Re-wager a just-lost bet's amount at 50/50 against the house, on the same Bet account
+/// The stake is escrowed in the game vault and the bet's VRF request is re-armed for `fulfill_double_or_nothing`
+pub fn double_or_nothing(
+    ctx: Context<DoubleOrNothing>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let bet = &mut ctx.accounts.bet;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        bet.player == ctx.accounts.player.key(),
+        CasinoError::Unauthorized
+    );
+    
+    let player_profile_bump = ctx.accounts.player_profile.bump;
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        bet.amount,
+    )?;
+    record_wager(&mut ctx.accounts.player_profile, ctx.accounts.player.key(), player_profile_bump, bet.amount)?;
+    
+    // Each loss can be doubled once, while the window is open
+    require!(
+        config.double_or_nothing_window_secs > 0
+            && bet.status == 2
+            && !bet.double_or_nothing
+            && !bet.bonus_funded
+            && now <= bet.settled_at.saturating_add(config.double_or_nothing_window_secs),
+        CasinoError::DoubleOrNothingUnavailable
+    );
+    
+    let amount = bet.amount;
+    
+    // The house funds half of a win, so it must be able to cover the stake now
+    let house_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(house_info.data_len())?;
+    require!(
+        house_info.lamports() >= amount.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: ctx.accounts.game_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    ctx.accounts.game_vault.total_escrowed = ctx.accounts.game_vault.total_escrowed
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // A fresh request on the bet's VRF account; timeouts are refunded by `sweep_expired_bets` as usual
+    let vrf_request = &mut ctx.accounts.vrf_request;
+    let mut request_id = [0u8; 32];
+    request_id[..8].copy_from_slice(&now.to_le_bytes());
+    request_id[8] = 1; // distinguishes the re-roll from the original request
+    vrf_request.timestamp = now;
+    vrf_request.request_id = request_id;
+    vrf_request.status = 0; // pending
+    vrf_request.result = None;
+    
+    bet.double_or_nothing = true;
+    bet.escrowed = amount;
+    bet.status = 0; // pending
+    bet.vrf_request_id = Some(request_id);
+    bet.placed_slot = Clock::get()?.slot;
+    bet.settled_at = 0;
+    
+    let config = &mut ctx.accounts.config;
+    config.open_bets = config.open_bets
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Double or nothing: {} lamports on bet {}", amount, bet.key());
+    
+    emit!(DoubleOrNothingPlaced {
+        sequence: config.next_event_sequence(),
+        player: bet.player,
+        bet: bet.key(),
+        vrf_request: vrf_request.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DoubleOrNothing<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"bet", bet.player.as_ref(), bet.amount.to_le_bytes().as_ref()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        mut,
+        seeds = [b"vrf_request", bet.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == bet.key() @ CasinoError::VrfRequestBetMismatch
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    #[account(seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(mut, seeds = [b"game_vault"], bump = game_vault.bump)]
+    pub game_vault: Account<'info, GameVault>,
+    
+    #[account(mut, seeds = [b"player", player.key().as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DoubleOrNothingPlaced {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub vrf_request: Pubkey,
+    pub amount: u64,
+}
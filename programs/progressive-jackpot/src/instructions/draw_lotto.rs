@@ -0,0 +1,82 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Draw a lottery round's winning numbers from VRF randomness once `draw_ts` has passed (authority or operator)
+/// Tickets are then matched and paid by `crank_lotto`
+pub fn draw_lotto(
+    ctx: Context<DrawLotto>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    let round = &mut ctx.accounts.round;
+    
+    // The VRF result comes from the caller, so only the house may supply it
+    let settler = ctx.accounts.settler.key();
+    require!(
+        settler == ctx.accounts.config.authority || settler == ctx.accounts.config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        !round.drawn && Clock::get()?.unix_timestamp >= round.draw_ts,
+        CasinoError::LottoNotDrawable
+    );
+    
+    // Partial Fisher-Yates over 1..=max_number, one 4-byte slice of the result per pick
+    let mut numbers = [0u8; MAX_LOTTO_NUMBER as usize];
+    for (i, n) in numbers.iter_mut().enumerate() {
+        *n = i as u8 + 1;
+    }
+    
+    let picks = round.picks as usize;
+    let max_number = round.max_number as usize;
+    for i in 0..picks {
+        let value = u32::from_le_bytes(vrf_result[i * 4..i * 4 + 4].try_into().unwrap()) as usize;
+        let j = i + value % (max_number - i);
+        numbers.swap(i, j);
+    }
+    
+    let mut winning_numbers = [0u8; MAX_LOTTO_PICKS];
+    winning_numbers[..picks].copy_from_slice(&numbers[..picks]);
+    winning_numbers[..picks].sort_unstable();
+    
+    round.winning_numbers = winning_numbers;
+    round.drawn = true;
+    
+    msg!("Lotto round {} drawn: {:?}", round.round_id, &winning_numbers[..picks]);
+    
+    emit!(LottoDrawn {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        round_id: round.round_id,
+        winning_numbers,
+        ticket_count: round.ticket_count,
+        vrf_result,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DrawLotto<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"lotto", round.round_id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, LottoRound>,
+    
+    /// Authority or operator supplying the VRF result
+    pub settler: Signer<'info>,
+}
+
+#[event]
+pub struct LottoDrawn {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub winning_numbers: [u8; MAX_LOTTO_PICKS],
+    pub ticket_count: u32,
+    pub vrf_result: [u8; 32],
+}
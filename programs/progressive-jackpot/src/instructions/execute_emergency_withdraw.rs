@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Move a queued emergency withdrawal out of the pool once its timelock has passed (authority only)
+/// Pending payouts stay in the pool so winners can still claim
+pub fn execute_emergency_withdraw(
+    ctx: Context<ExecuteEmergencyWithdraw>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        pool.emergency_unlocks_at != 0,
+        CasinoError::NoEmergencyWithdrawPending
+    );
+    
+    require!(
+        Clock::get()?.unix_timestamp >= pool.emergency_unlocks_at,
+        CasinoError::EmergencyTimelockActive
+    );
+    
+    require_keys_eq!(
+        ctx.accounts.destination.key(),
+        pool.emergency_destination,
+        CasinoError::InvalidTreasuryDestination
+    );
+    
+    // Wins settled during the delay may have drawn the balance down
+    let amount = pool.emergency_amount.min(pool.balance);
+    
+    **pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+    
+    pool.balance -= amount;
+    pool.emergency_amount = 0;
+    pool.emergency_destination = Pubkey::default();
+    pool.emergency_unlocks_at = 0;
+    
+    msg!(
+        "EMERGENCY WITHDRAWAL EXECUTED: {} lamports from pool {} to {}",
+        amount, pool.pool_id, ctx.accounts.destination.key()
+    );
+    
+    emit!(EmergencyWithdrawExecuted {
+        sequence: config.next_event_sequence(),
+        authority: ctx.accounts.authority.key(),
+        pool: pool.key(),
+        amount,
+        destination: ctx.accounts.destination.key(),
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ExecuteEmergencyWithdraw<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    /// CHECK: must match `JackpotPool::emergency_destination`
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct EmergencyWithdrawExecuted {
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+}
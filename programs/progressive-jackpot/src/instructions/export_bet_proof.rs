@@ -0,0 +1,57 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use super::fulfill_jackpot::derive_roll;
+
+/// Assemble a self-contained proof bundle for a single bet
+/// Returned via return data (Borsh) so auditors can verify a bet with one simulated call
+pub fn export_bet_proof(
+    ctx: Context<ExportBetProof>,
+) -> Result<BetProof> {
+    let bet = &ctx.accounts.bet;
+    let vrf_request = &ctx.accounts.vrf_request;
+    
+    let proof = BetProof {
+        bet: bet.key(),
+        player: bet.player,
+        amount: bet.amount,
+        timestamp: bet.timestamp,
+        status: bet.status,
+        win_amount: bet.win_amount,
+        config_hash: bet.config_hash,
+        vrf_request: vrf_request.key(),
+        request_id: vrf_request.request_id,
+        vrf_status: vrf_request.status,
+        vrf_result: vrf_request.result,
+        roll: vrf_request.result.as_ref().map(derive_roll),
+    };
+    
+    msg!("Bet proof exported for {}", proof.bet);
+    
+    Ok(proof)
+}
+
+#[derive(Accounts)]
+pub struct ExportBetProof<'info> {
+    pub bet: Account<'info, Bet>,
+    
+    #[account(seeds = [b"vrf_request", bet.key().as_ref()], bump = vrf_request.bump)]
+    pub vrf_request: Account<'info, VrfRequest>,
+}
+
+/// Canonical proof bundle for a single bet
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct BetProof {
+    pub bet: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub timestamp: i64,
+    pub status: u8,
+    pub win_amount: u64,
+    pub config_hash: [u8; 32],
+    pub vrf_request: Pubkey,
+    pub request_id: [u8; 32],
+    pub vrf_status: u8,
+    pub vrf_result: Option<[u8; 32]>,
+    /// Roll derived from the VRF result (0..10000), if fulfilled
+    pub roll: Option<u64>,
+}
@@ -0,0 +1,109 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Pay the season prize to the top of the leaderboard (permissionless once ended)
+/// Winners are passed as writable `remaining_accounts` in rank order; the unallocated rest returns to the house
+pub fn finalize_season<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FinalizeSeason<'info>>,
+) -> Result<()> {
+    let season_info = ctx.accounts.season.to_account_info();
+    let mut season = ctx.accounts.season.load_mut()?;
+    
+    require!(
+        season.finalized == 0,
+        CasinoError::SeasonAlreadyFinalized
+    );
+    
+    require!(
+        Clock::get()?.unix_timestamp >= season.end_ts,
+        CasinoError::SeasonNotEnded
+    );
+    
+    let paid_ranks = season.payout_count.min(season.entry_count) as usize;
+    require!(
+        ctx.remaining_accounts.len() == paid_ranks,
+        CasinoError::InvalidSeasonWinners
+    );
+    
+    let prize_pool = season.prize_pool;
+    let mut paid: u64 = 0;
+    
+    for (rank, winner) in ctx.remaining_accounts.iter().enumerate() {
+        require_keys_eq!(
+            *winner.key,
+            season.leaderboard[rank].player,
+            CasinoError::InvalidSeasonWinners
+        );
+        
+        let prize = prize_pool
+            .checked_mul(season.payout_bps[rank] as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        **season_info.try_borrow_mut_lamports()? -= prize;
+        **winner.try_borrow_mut_lamports()? += prize;
+        paid += prize;
+        
+        emit!(SeasonPrizePaid {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            season_id: season.season_id,
+            rank: rank as u8,
+            player: *winner.key,
+            amount: prize,
+        });
+    }
+    
+    // Unpaid ranks and rounding dust go back to the house
+    let remainder = prize_pool - paid;
+    **season_info.try_borrow_mut_lamports()? -= remainder;
+    **ctx.accounts.house_vault.to_account_info().try_borrow_mut_lamports()? += remainder;
+    
+    season.finalized = 1;
+    
+    msg!("Season {} finalized: {} paid, {} returned to house", season.season_id, paid, remainder);
+    
+    emit!(SeasonFinalized {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        season_id: season.season_id,
+        prize_pool,
+        paid,
+        returned: remainder,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FinalizeSeason<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"season", season.load()?.season_id.to_le_bytes().as_ref()],
+        bump = season.load()?.bump
+    )]
+    pub season: AccountLoader<'info, Season>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+}
+
+#[event]
+pub struct SeasonPrizePaid {
+    pub sequence: u64,
+    pub season_id: u64,
+    pub rank: u8,
+    pub player: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct SeasonFinalized {
+    pub sequence: u64,
+    pub season_id: u64,
+    pub prize_pool: u64,
+    pub paid: u64,
+    pub returned: u64,
+}
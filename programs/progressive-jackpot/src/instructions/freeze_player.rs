@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Block a player from betting and claiming pending review (authority or operator)
+pub fn freeze_player(
+    ctx: Context<FreezePlayer>,
+    player: Pubkey,
+    reason: u8,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    
+    let frozen = &mut ctx.accounts.frozen_player;
+    frozen.player = player;
+    frozen.frozen_by = signer;
+    frozen.frozen_at = now;
+    frozen.reason = reason;
+    frozen.bump = ctx.bumps.frozen_player;
+    
+    msg!("Player frozen: {} (reason {})", player, reason);
+    
+    emit!(PlayerFrozen {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player,
+        frozen_by: signer,
+        reason,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct FreezePlayer<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + FrozenPlayer::INIT_SPACE,
+        seeds = [b"frozen", player.as_ref()],
+        bump
+    )]
+    pub frozen_player: Account<'info, FrozenPlayer>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PlayerFrozen {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub frozen_by: Pubkey,
+    pub reason: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Settle a bonus wheel spin with VRF randomness, paying the landed prize from house fees
+/// The prize shrinks to what house fees can cover; once the VRF request has timed out, the spin is given back instead
+pub fn fulfill_bonus_wheel(
+    ctx: Context<FulfillBonusWheel>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    let vrf_request = &ctx.accounts.vrf_request;
+    let wheel_spin = &ctx.accounts.wheel_spin;
+    
+    require!(
+        vrf_request.status == 0,
+        CasinoError::VrfRequestNotFound
+    );
+    
+    let timed_out = Clock::get()?.unix_timestamp - vrf_request.timestamp >= VRF_TIMEOUT;
+    if !timed_out {
+        let earliest_slot = wheel_spin.placed_slot.saturating_add(ctx.accounts.config.min_settle_delay_slots.max(1));
+        require!(Clock::get()?.slot >= earliest_slot, CasinoError::SettlementTooEarly);
+    }
+    
+    // The VRF result comes from the caller, so only the house may supply it; anyone may refund once timed out
+    let settler = ctx.accounts.settler.key();
+    require!(
+        timed_out || settler == ctx.accounts.config.authority || settler == ctx.accounts.config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let bonus_wheel = &ctx.accounts.bonus_wheel;
+    let house_vault = &mut ctx.accounts.house_vault;
+    
+    let (slice, prize, paid) = if timed_out || bonus_wheel.prize_count == 0 {
+        let player_profile = &mut ctx.accounts.player_profile;
+        player_profile.bonus_wheel_spins = player_profile.bonus_wheel_spins.saturating_add(1);
+        (None, 0, 0)
+    } else {
+        let slice = bonus_wheel.landed(&vrf_result);
+        let prize = bonus_wheel.prizes[slice].amount;
+        
+        let house_info = house_vault.to_account_info();
+        let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+        let paid = prize
+            .min(house_vault.accumulated_house_fees)
+            .min(house_info.lamports().saturating_sub(reserved));
+        
+        if paid > 0 {
+            **house_info.try_borrow_mut_lamports()? -= paid;
+            **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += paid;
+            
+            house_vault.accumulated_house_fees -= paid;
+        }
+        
+        (Some(slice as u8), prize, paid)
+    };
+    
+    msg!("Bonus wheel settled: slice {:?}, prize {}, paid {}", slice, prize, paid);
+    
+    emit!(BonusWheelSpun {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: wheel_spin.player,
+        slice,
+        prize,
+        paid,
+    });
+    
+    let player = ctx.accounts.player.to_account_info();
+    ctx.accounts.vrf_request.close(player.clone())?;
+    ctx.accounts.wheel_spin.close(player)?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FulfillBonusWheel<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(seeds = [b"bonus_wheel"], bump = bonus_wheel.bump)]
+    pub bonus_wheel: Account<'info, BonusWheel>,
+    
+    /// Pays the prize out of house fees
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// Gets the spin back if the VRF request timed out
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        has_one = player,
+        seeds = [b"wheel_spin", wheel_spin.player.as_ref()],
+        bump = wheel_spin.bump
+    )]
+    pub wheel_spin: Account<'info, WheelSpin>,
+    
+    #[account(
+        mut,
+        seeds = [b"vrf_request", wheel_spin.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == wheel_spin.key() @ CasinoError::VrfRequestBetMismatch
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    /// CHECK: the spin's player; receives the prize and the closed accounts' rent
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+    
+    /// Authority or operator supplying the VRF result (anyone, once the request has timed out)
+    pub settler: Signer<'info>,
+}
+
+#[event]
+pub struct BonusWheelSpun {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub slice: Option<u8>,
+    pub prize: u64,
+    pub paid: u64,
+}
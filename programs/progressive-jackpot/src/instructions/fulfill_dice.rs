@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::fulfill_jackpot::derive_roll;
+
+/// Settle a dice roll with VRF randomness, paying the player directly and closing the roll
+/// Once the VRF request has timed out, the held stake is refunded instead
+pub fn fulfill_dice(
+    ctx: Context<FulfillDice>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let dice_roll = &ctx.accounts.dice_roll;
+    let vrf_request = &ctx.accounts.vrf_request;
+    
+    require!(
+        vrf_request.status == 0,
+        CasinoError::VrfRequestNotFound
+    );
+    
+    let timed_out = Clock::get()?.unix_timestamp - vrf_request.timestamp >= VRF_TIMEOUT;
+    if !timed_out {
+        let earliest_slot = dice_roll.placed_slot.saturating_add(config.min_settle_delay_slots.max(1));
+        require!(Clock::get()?.slot >= earliest_slot, CasinoError::SettlementTooEarly);
+    }
+    
+    // The VRF result comes from the caller, so only the house may supply it; anyone may refund once timed out
+    let settler = ctx.accounts.settler.key();
+    require!(
+        timed_out || settler == config.authority || settler == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let roll = derive_roll(&vrf_result);
+    let won = !timed_out && if dice_roll.roll_over {
+        roll >= dice_roll.target as u64
+    } else {
+        roll < dice_roll.target as u64
+    };
+    
+    let liability = dice_roll.payout - dice_roll.stake;
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.game_liability = house_vault.game_liability.saturating_sub(liability);
+    config.open_bets = config.open_bets.saturating_sub(1);
+    
+    let house_info = house_vault.to_account_info();
+    let dice_info = dice_roll.to_account_info();
+    
+    // Whatever the roll account holds when it's closed goes to the player
+    let paid = if timed_out {
+        dice_roll.stake
+    } else if won {
+        let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+        require!(
+            house_info.lamports() >= liability.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+            CasinoError::InsufficientFunds
+        );
+        **house_info.try_borrow_mut_lamports()? -= liability;
+        **dice_info.try_borrow_mut_lamports()? += liability;
+        dice_roll.payout
+    } else {
+        **dice_info.try_borrow_mut_lamports()? -= dice_roll.stake;
+        **house_info.try_borrow_mut_lamports()? += dice_roll.stake;
+        house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+            .checked_add(dice_roll.stake)
+            .ok_or(CasinoError::MathOverflow)?;
+        0
+    };
+    
+    msg!("Dice settled: roll {}, won {}, paid {}", roll, won, paid);
+    
+    emit!(DiceSettled {
+        sequence: config.next_event_sequence(),
+        player: dice_roll.player,
+        dice_roll: dice_roll.key(),
+        roll: if timed_out { None } else { Some(roll) },
+        target: dice_roll.target,
+        over_under: dice_roll.roll_over,
+        won,
+        paid,
+    });
+    
+    let player = ctx.accounts.player.to_account_info();
+    ctx.accounts.vrf_request.close(player.clone())?;
+    ctx.accounts.dice_roll.close(player)?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FulfillDice<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        mut,
+        has_one = player,
+        seeds = [b"dice", dice_roll.player.as_ref(), dice_roll.amount.to_le_bytes().as_ref()],
+        bump = dice_roll.bump
+    )]
+    pub dice_roll: Account<'info, DiceRoll>,
+    
+    #[account(
+        mut,
+        seeds = [b"vrf_request", dice_roll.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == dice_roll.key() @ CasinoError::VrfRequestBetMismatch
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    /// CHECK: the roll's player; receives the payout or refund and the closed accounts' rent
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+    
+    /// Authority or operator supplying the VRF result (anyone, once the request has timed out)
+    pub settler: Signer<'info>,
+}
+
+#[event]
+pub struct DiceSettled {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub dice_roll: Pubkey,
+    pub roll: Option<u64>,
+    pub target: u16,
+    pub over_under: bool,
+    pub won: bool,
+    pub paid: u64,
+}
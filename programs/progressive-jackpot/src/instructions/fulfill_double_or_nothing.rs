@@ -0,0 +1,151 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::fulfill_jackpot::derive_roll;
+
+/// Settle a double-or-nothing re-wager: a roll below 5000 wins twice the stake
+/// The escrowed stake and an equal amount from the house become claimable in the bet's pool (see `claim_win`)
+pub fn fulfill_double_or_nothing(
+    ctx: Context<FulfillDoubleOrNothing>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let bet = &mut ctx.accounts.bet;
+    let vrf_request = &mut ctx.accounts.vrf_request;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        bet.double_or_nothing && bet.status == 0,
+        CasinoError::NotDoubleOrNothing
+    );
+    
+    require!(
+        vrf_request.status == 0,
+        CasinoError::VrfRequestNotFound
+    );
+    
+    // Timed-out re-wagers are refunded by `sweep_expired_bets` instead
+    require!(
+        now - vrf_request.timestamp < VRF_TIMEOUT,
+        CasinoError::VrfTimeout
+    );
+    
+    let earliest_slot = bet.placed_slot.saturating_add(config.min_settle_delay_slots.max(1));
+    require!(Clock::get()?.slot >= earliest_slot, CasinoError::SettlementTooEarly);
+    
+    // The VRF result comes from the caller, so only the house may supply it
+    let settler = ctx.accounts.settler.key();
+    require!(
+        settler == config.authority || settler == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    vrf_request.status = 1; // fulfilled
+    vrf_request.result = Some(vrf_result);
+    config.open_bets = config.open_bets.saturating_sub(1);
+    
+    let roll = derive_roll(&vrf_result);
+    let won = roll < 5000;
+    let stake = bet.amount;
+    let payout = if won {
+        stake.checked_mul(2).ok_or(CasinoError::MathOverflow)?
+    } else {
+        0
+    };
+    
+    if won {
+        let house_info = ctx.accounts.house_vault.to_account_info();
+        let reserved = ctx.accounts.house_vault.reserved_lamports(house_info.data_len())?;
+        require!(
+            house_info.lamports() >= stake.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+            CasinoError::InsufficientFunds
+        );
+        
+        let game_vault_info = ctx.accounts.game_vault.to_account_info();
+        let pool_info = ctx.accounts.pool.to_account_info();
+        **house_info.try_borrow_mut_lamports()? -= stake;
+        **game_vault_info.try_borrow_mut_lamports()? -= stake;
+        **pool_info.try_borrow_mut_lamports()? += payout;
+        
+        ctx.accounts.game_vault.total_withdrawn = ctx.accounts.game_vault.total_withdrawn
+            .checked_add(stake)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        ctx.accounts.pool.pending_payouts = ctx.accounts.pool.pending_payouts
+            .checked_add(payout)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        let player_profile = &mut ctx.accounts.player_profile;
+        player_profile.total_won = player_profile.total_won
+            .checked_add(payout)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        bet.status = 1; // won, claimable
+        bet.win_amount = payout;
+    } else {
+        // The stake stays escrowed in the game vault
+        bet.status = 2; // lost
+    }
+    
+    bet.settled_at = now;
+    
+    msg!("Double or nothing settled: roll {}, payout {}", roll, payout);
+    
+    emit!(DoubleOrNothingSettled {
+        sequence: config.next_event_sequence(),
+        player: bet.player,
+        bet: bet.key(),
+        roll,
+        won,
+        payout,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FulfillDoubleOrNothing<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(mut, seeds = [b"game_vault"], bump = game_vault.bump)]
+    pub game_vault: Account<'info, GameVault>,
+    
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"bet", bet.player.as_ref(), bet.amount.to_le_bytes().as_ref()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        mut,
+        seeds = [b"vrf_request", bet.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == bet.key() @ CasinoError::VrfRequestBetMismatch
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    #[account(mut, seeds = [b"player", bet.player.as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// Authority or operator supplying the VRF result
+    pub settler: Signer<'info>,
+}
+
+#[event]
+pub struct DoubleOrNothingSettled {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub roll: u64,
+    pub won: bool,
+    pub payout: u64,
+}
@@ -1,17 +1,315 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::CasinoError;
+use super::register_keeper::pay_keeper;
 
-/// Fulfill jackpot win based on VRF result
-/// Determines if player wins, calculates payout, distributes funds
+/// Fulfill jackpot win based on VRF result (authority or operator)
+/// Determines if player wins and records a claimable payout (see `claim_win`); the outcome is also set as return data
 pub fn fulfill_jackpot(
     ctx: Context<FulfillJackpot>,
     vrf_result: [u8; 32],
+) -> Result<SettlementResult> {
+    // The VRF result comes from the caller, so only the house may supply it; timed-out bets are refunded by `sweep_expired_bets`
+    let settler = ctx.accounts.settler.key();
+    require!(
+        settler == ctx.accounts.config.authority || settler == ctx.accounts.config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    // Crank fees go to the settling operator, not to a co-signer
+    if let Some(keeper_authority) = &ctx.accounts.keeper_authority {
+        require_keys_eq!(keeper_authority.key(), settler, CasinoError::InvalidKeeper);
+    }
+    
+    let bets_since_win = ctx.accounts.pool.bets_since_win;
+    let bankroll_balance = ctx.accounts.bankroll.as_ref().map_or(0, |bankroll| bankroll.balance);
+    let outcome = settle_bet(
+        SettlementAccounts {
+            config: &mut ctx.accounts.config,
+            pool: &mut ctx.accounts.pool,
+            reserve_pool: &mut ctx.accounts.reserve_pool,
+            house_vault: &mut ctx.accounts.house_vault,
+            bet: &mut ctx.accounts.bet,
+            vrf_request: &mut ctx.accounts.vrf_request,
+            player_profile: &mut ctx.accounts.player_profile,
+        },
+        bankroll_balance,
+        vrf_result,
+    )?;
+    
+    // Emitted by the caller rather than `settle_bet` so it can go through `emit_cpi!`
+    let player = ctx.accounts.bet.player;
+    if outcome.is_win {
+        emit_event!(ctx, JackpotWon {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            player,
+            bet: ctx.accounts.bet.key(),
+            vrf_request: ctx.accounts.vrf_request.key(),
+            amount: outcome.win_amount,
+            pool_balance: ctx.accounts.pool.balance,
+            vrf_value: outcome.breakdown.roll,
+            breakdown: outcome.breakdown,
+        });
+    } else {
+        emit_event!(ctx, JackpotLoss {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            player,
+            bet: ctx.accounts.bet.key(),
+            vrf_request: ctx.accounts.vrf_request.key(),
+            vrf_value: outcome.breakdown.roll,
+            breakdown: outcome.breakdown,
+        });
+    }
+    
+    settle_side_bets(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.pool,
+        &mut ctx.accounts.house_vault,
+        &mut ctx.accounts.side_bet_pool,
+        bets_since_win,
+        outcome.is_jackpot_hit(),
+    )?;
+    
+    if outcome.is_win {
+        record_win(
+            &ctx.accounts.config,
+            &ctx.accounts.win_history,
+            &ctx.accounts.notification_queue,
+            ctx.accounts.bet.player,
+            ctx.accounts.player_profile.hide_from_history,
+            &outcome,
+        )?;
+    }
+    
+    record_house_shortfall(
+        &ctx.accounts.notification_queue,
+        ctx.accounts.house_vault.key(),
+        &outcome,
+    )?;
+    
+    record_settlement_stats(
+        &ctx.accounts.stats,
+        &mut ctx.accounts.volume_bucket,
+        ctx.accounts.bet.win_amount,
+        &outcome,
+    )?;
+    
+    pay_keeper(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.house_vault,
+        &mut ctx.accounts.keeper,
+        &ctx.accounts.keeper_authority,
+        1,
+    )?;
+    
+    Ok(SettlementResult {
+        is_win: outcome.is_win,
+        win_amount: outcome.win_amount,
+        new_pool_balance: ctx.accounts.pool.balance,
+    })
+}
+
+/// Add a settlement to the epoch RTP stats and today's volume bucket, where tracked
+/// `paid_out` is the bet's full award, covering consolations and reset payouts
+pub fn record_settlement_stats<'info>(
+    stats: &Option<AccountLoader<'info, Stats>>,
+    volume_bucket: &mut Option<Account<'info, VolumeBucket>>,
+    paid_out: u64,
+    outcome: &SettlementOutcome,
 ) -> Result<()> {
-    let config = &mut ctx.accounts.config;
-    let pool = &mut ctx.accounts.pool;
-    let bet = &mut ctx.accounts.bet;
-    let vrf_request = &mut ctx.accounts.vrf_request;
+    if let Some(stats) = stats {
+        let mut stats = stats.load_mut()?;
+        let totals = stats.epoch_mut(Clock::get()?.epoch);
+        totals.paid_out = totals.paid_out.saturating_add(paid_out);
+        if outcome.is_win {
+            let tier = outcome.tier as usize;
+            totals.wins_by_tier[tier] = totals.wins_by_tier[tier].saturating_add(1);
+        }
+    }
+    
+    if let Some(bucket) = volume_bucket {
+        require!(
+            bucket.day == Clock::get()?.unix_timestamp.div_euclid(86400),
+            CasinoError::InvalidVolumeBucketDay
+        );
+        if outcome.is_win {
+            bucket.wins = bucket.wins.saturating_add(1);
+            bucket.win_amount = bucket.win_amount.saturating_add(paid_out);
+        }
+    }
+    
+    Ok(())
+}
+
+/// Settle the pool's open side-bet round on a jackpot hit, paying the rake to the house
+/// `bets_since_win` is the pool's count before the hit reset it
+pub fn settle_side_bets<'info>(
+    config: &mut Config,
+    pool: &mut Account<'info, JackpotPool>,
+    house_vault: &mut Account<'info, HouseVault>,
+    side_bet_pool: &mut Option<Account<'info, SideBetPool>>,
+    bets_since_win: u64,
+    hit: bool,
+) -> Result<()> {
+    if !hit {
+        return Ok(());
+    }
+    
+    let Some(open_round) = pool.side_bet_pool else {
+        return Ok(());
+    };
+    
+    let round = side_bet_pool
+        .as_mut()
+        .filter(|round| round.key() == open_round)
+        .ok_or(CasinoError::SideBetPoolRequired)?;
+    
+    let winning_bucket = round.bucket_for(bets_since_win);
+    let winning_stake = round.bucket_totals[winning_bucket as usize];
+    
+    // With no winning backers every stake is refunded, so there's nothing to rake
+    let rake = if winning_stake == 0 {
+        0
+    } else {
+        round.total_staked
+            .checked_mul(round.rake_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?
+    };
+    
+    if rake > 0 {
+        move_pool_lamports(&round.to_account_info(), &house_vault.to_account_info(), rake)?;
+        house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+            .checked_add(rake)
+            .ok_or(CasinoError::MathOverflow)?;
+    }
+    
+    round.settled = true;
+    round.hit_at_bets = bets_since_win;
+    round.winning_bucket = winning_bucket;
+    round.distributable = round.total_staked - rake;
+    pool.side_bet_pool = None;
+    
+    msg!("Side-bet round {} settled: hit at {} bets, bucket {}", round.round_id, bets_since_win, winning_bucket);
+    
+    emit!(SideBetRoundSettled {
+        sequence: config.next_event_sequence(),
+        pool: pool.key(),
+        side_bet_pool: round.key(),
+        round_id: round.round_id,
+        hit_at_bets: bets_since_win,
+        winning_bucket,
+        winning_stake,
+        distributable: round.distributable,
+        rake,
+    });
+    
+    Ok(())
+}
+
+/// Append a win to the win history and queue a big-win notification above the threshold
+/// Players who opted out are recorded with the default pubkey
+pub fn record_win<'info>(
+    config: &Config,
+    win_history: &AccountLoader<'info, WinHistory>,
+    notification_queue: &AccountLoader<'info, NotificationQueue>,
+    player: Pubkey,
+    hide_player: bool,
+    outcome: &SettlementOutcome,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let player = if hide_player { Pubkey::default() } else { player };
+    
+    win_history
+        .load_mut()?
+        .record(player, outcome.win_amount, now, outcome.tier);
+    
+    if config.big_win_threshold > 0 && outcome.win_amount >= config.big_win_threshold {
+        notification_queue
+            .load_mut()?
+            .push(NOTIFICATION_BIG_WIN, player, outcome.win_amount, now);
+    }
+    
+    Ok(())
+}
+
+/// Queue a solvency warning when settlement found the house vault short of a house-paid amount
+pub fn record_house_shortfall<'info>(
+    notification_queue: &AccountLoader<'info, NotificationQueue>,
+    house_vault: Pubkey,
+    outcome: &SettlementOutcome,
+) -> Result<()> {
+    if outcome.house_shortfall > 0 {
+        notification_queue
+            .load_mut()?
+            .push(NOTIFICATION_SOLVENCY_WARNING, house_vault, outcome.house_shortfall, Clock::get()?.unix_timestamp);
+    }
+    
+    Ok(())
+}
+
+/// Derive the 0..10000 roll from a VRF result
+/// Reads the first 8 bytes as a little-endian u64, modulo 10000
+pub fn derive_roll(vrf_result: &[u8; 32]) -> u64 {
+    let vrf_value = u64::from_le_bytes([
+        vrf_result[0], vrf_result[1], vrf_result[2], vrf_result[3],
+        vrf_result[4], vrf_result[5], vrf_result[6], vrf_result[7],
+    ]);
+    
+    vrf_value % 10000
+}
+
+/// Result of settling a single bet
+pub struct SettlementOutcome {
+    pub is_win: bool,
+    pub win_amount: u64,
+    /// Payout tier: 0 = rare (100%), 1 = medium (50%), 2 = common (25%), 3 = bootstrap (house-paid)
+    pub tier: u8,
+    /// How the payout was derived, covering any reset payout
+    pub breakdown: SettlementBreakdown,
+    /// House-paid amount the house vault couldn't cover (0 if none)
+    pub house_shortfall: u64,
+}
+
+impl SettlementOutcome {
+    /// A win paid from the pot; bootstrap wins are paid by the house and leave it growing
+    pub fn is_jackpot_hit(&self) -> bool {
+        self.is_win && self.tier != 3
+    }
+}
+
+/// Accounts a single settlement reads and updates
+pub struct SettlementAccounts<'a, 'info> {
+    pub config: &'a mut Config,
+    pub pool: &'a mut Account<'info, JackpotPool>,
+    pub reserve_pool: &'a mut Account<'info, ReservePool>,
+    pub house_vault: &'a mut Account<'info, HouseVault>,
+    pub bet: &'a mut Account<'info, Bet>,
+    pub vrf_request: &'a mut Account<'info, VrfRequest>,
+    pub player_profile: &'a mut PlayerProfile,
+}
+
+/// Settle a single bet against a VRF result
+/// Shared by `fulfill_jackpot` and `fulfill_jackpots_batch`
+pub fn settle_bet(
+    accounts: SettlementAccounts,
+    bankroll_balance: u64,
+    vrf_result: [u8; 32],
+) -> Result<SettlementOutcome> {
+    let SettlementAccounts { config, pool, reserve_pool, house_vault, bet, vrf_request, player_profile } = accounts;
+    let player = bet.player;
+    
+    require_keys_eq!(bet.pool, pool.key(), CasinoError::PoolMismatch);
+    
+    // Wins count toward the cashback week they settle in
+    player_profile.roll_cashback_epoch(
+        cashback_epoch(Clock::get()?.unix_timestamp),
+        config.cashback_bps,
+    );
+    
+    // A bet settles exactly once
+    require!(bet.status == 0, CasinoError::VrfAlreadyFulfilled);
+    require!(!bet.double_or_nothing, CasinoError::DoubleOrNothingPending);
     
     // Verify VRF request exists and is pending
     require!(
@@ -24,43 +322,158 @@ pub fn fulfill_jackpot(
         CasinoError::InvalidVrfAuthority
     );
     
-    // Check timeout (e.g., 1 hour)
-    let timeout: i64 = 3600;
+    // Timed-out bets are refunded by `sweep_expired_bets` instead
     require!(
-        Clock::get()?.unix_timestamp - vrf_request.timestamp < timeout,
+        Clock::get()?.unix_timestamp - vrf_request.timestamp < VRF_TIMEOUT,
         CasinoError::VrfTimeout
     );
     
+    // Settle in a later slot so a bet and its reveal can't share a block
+    let earliest_slot = bet.placed_slot.saturating_add(config.min_settle_delay_slots.max(1));
+    require!(Clock::get()?.slot >= earliest_slot, CasinoError::SettlementTooEarly);
+    
+    config.check_exposure(pool.balance, bankroll_balance, bet.amount)?;
+    
     // Mark VRF as fulfilled
     vrf_request.status = 1; // fulfilled
     vrf_request.result = Some(vrf_result);
     
-    // Convert VRF result to u64 for probability calculation
-    let vrf_value = u64::from_le_bytes([
-        vrf_result[0], vrf_result[1], vrf_result[2], vrf_result[3],
-        vrf_result[4], vrf_result[5], vrf_result[6], vrf_result[7],
-    ]);
+    // Bets placed before the counter existed aren't included in it
+    config.open_bets = config.open_bets.saturating_sub(1);
     
     // Calculate win threshold: win if vrf_value % 10000 < win_probability_bps
-    let win_threshold = config.win_probability_bps as u64;
-    let vrf_mod = vrf_value % 10000;
-    let is_win = vrf_mod < win_threshold;
+    // Odds are frozen at placement so later config changes can't move them
+    let base_threshold = bet.win_probability_bps as u64;
+    
+    // Scaled odds: chance grows linearly with amount / max_bet
+    let win_threshold = if bet.odds_mode == ODDS_MODE_SCALED && bet.odds_max_bet > 0 {
+        let scaled = (base_threshold as u128)
+            .checked_mul(bet.amount as u128)
+            .and_then(|x| x.checked_div(bet.odds_max_bet as u128))
+            .ok_or(CasinoError::MathOverflow)?;
+        (scaled as u64).clamp(1, 10000)
+    } else {
+        base_threshold
+    };
+    
+    // Drought ramp: odds improve with every bet since the last win, up to the cap
+    let ramp_cap = if config.odds_ramp_max_bps > 0 {
+        config.odds_ramp_max_bps as u64
+    } else {
+        10000
+    };
+    let ramped_threshold = win_threshold
+        .saturating_add((config.odds_ramp_bps as u64).saturating_mul(pool.bets_since_win))
+        .min(ramp_cap.max(win_threshold));
+    let ramp_bps = ramped_threshold - win_threshold;
+    let win_threshold = ramped_threshold;
+    
+    // Daily streak bonus snapshotted at placement
+    let win_threshold = win_threshold
+        .saturating_add(bet.streak_bonus_bps as u64)
+        .min(10000);
+    
+    let vrf_mod = derive_roll(&vrf_result);
+    
+    // Must-hit-by: once armed, the next settled bet wins the full jackpot
+    let forced_win = pool.must_hit_armed;
+    let is_win = forced_win || vrf_mod < win_threshold;
+    
+    let mut outcome = SettlementOutcome {
+        is_win,
+        win_amount: 0,
+        tier: 0,
+        breakdown: SettlementBreakdown {
+            roll: vrf_mod,
+            threshold: win_threshold,
+            odds_mode: bet.odds_mode,
+            ramp_bps,
+            tier: None,
+            multiplier_bps: 0,
+            pool_balance_before: pool.balance,
+            capped_amount: 0,
+            reset_payout: 0,
+            consolation: 0,
+            community_share: 0,
+            forced_win,
+        },
+        house_shortfall: 0,
+    };
+    
+    // Bootstrap mode: while the pool is small, the house pays fixed-odds wins and the pot keeps growing
+    let mut bootstrap_payout = None;
+    if is_win
+        && !forced_win
+        && config.bootstrap_threshold > 0
+        && pool.balance < config.bootstrap_threshold
+    {
+        let win_amount = bet.amount
+            .checked_mul(config.bootstrap_payout_multiplier as u64)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        // House must stay rent-exempt and cover owed rakeback; if it can't, the pool pays the win instead
+        let house_info = house_vault.to_account_info();
+        let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+        if house_info.lamports() >= win_amount.checked_add(reserved).ok_or(CasinoError::MathOverflow)? {
+            bootstrap_payout = Some(win_amount);
+        } else {
+            outcome.house_shortfall = win_amount;
+        }
+    }
     
-    if is_win {
+    if let Some(win_amount) = bootstrap_payout {
+        let house_info = house_vault.to_account_info();
+        
+        // Move the payout into the pool's claimable balance
+        move_pool_lamports(&house_info, &pool.to_account_info(), win_amount)?;
+        
+        house_vault.total_bootstrap_payouts = house_vault.total_bootstrap_payouts
+            .checked_add(win_amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        pool.pending_payouts = pool.pending_payouts
+            .checked_add(win_amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        pool.last_winner = Some(player);
+        pool.last_win_timestamp = Some(Clock::get()?.unix_timestamp);
+        
+        bet.status = 1; // won, claimable
+        bet.win_amount = win_amount;
+        
+        outcome.win_amount = win_amount;
+        outcome.tier = 3;
+        
+        outcome.breakdown.tier = Some(3);
+        
+        player_profile.total_won = player_profile.total_won
+            .checked_add(win_amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        if win_amount > player_profile.biggest_win {
+            player_profile.biggest_win = win_amount;
+        }
+        
+        config.total_wins = config.total_wins
+            .checked_add(1)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        msg!("Bootstrap win paid by house! Player: {}, Amount: {}", player, win_amount);
+    } else if is_win {
         // Calculate win amount
         // Full jackpot for rare wins, partial for more common wins
-        let win_multiplier = if vrf_mod < (win_threshold / 10) {
-            // Rare win: 100% of pool
-            10000
+        let (win_multiplier, tier) = if forced_win || vrf_mod < (win_threshold / 10) {
+            // Rare win: 100% of pool by default
+            (bet.tier_multipliers_bps[0] as u64, 0)
         } else if vrf_mod < (win_threshold / 2) {
-            // Medium win: 50% of pool
-            5000
+            // Medium win: 50% of pool by default
+            (bet.tier_multipliers_bps[1] as u64, 1)
         } else {
-            // Common win: 25% of pool
-            2500
+            // Common win: 25% of pool by default
+            (bet.tier_multipliers_bps[2] as u64, 2)
         };
         
-        let win_amount = pool.balance
+        let mut win_amount = pool.balance
             .checked_mul(win_multiplier)
             .and_then(|x| x.checked_div(10000))
             .ok_or(CasinoError::MathOverflow)?;
@@ -70,34 +483,69 @@ pub fn fulfill_jackpot(
             CasinoError::InsufficientFunds
         );
         
-        // Transfer winnings to player
-        **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += win_amount;
-        **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? -= win_amount;
+        // Clip at max payout; the excess moves to the reserve pool
+        if config.max_payout > 0 && win_amount > config.max_payout {
+            let excess = win_amount - config.max_payout;
+            win_amount = config.max_payout;
+            
+            move_pool_lamports(&pool.to_account_info(), &reserve_pool.to_account_info(), excess)?;
+            
+            pool.balance = pool.balance
+                .checked_sub(excess)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            reserve_pool.balance = reserve_pool.balance
+                .checked_add(excess)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            outcome.breakdown.capped_amount = excess;
+        }
         
-        // Update state
+        // Reserve winnings in the pool until the player (and any community recipients) claim them
         pool.balance = pool.balance
             .checked_sub(win_amount)
             .ok_or(CasinoError::MathOverflow)?;
         
-        pool.last_winner = Some(ctx.accounts.player.key());
+        pool.pending_payouts = pool.pending_payouts
+            .checked_add(win_amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        let community_total = split_community_share(config, pool, player, win_amount)?;
+        let win_amount = win_amount - community_total;
+        outcome.breakdown.community_share = community_total;
+        
+        pool.last_winner = Some(player);
         pool.last_win_timestamp = Some(Clock::get()?.unix_timestamp);
         pool.bets_since_win = 0;
+        pool.must_hit_armed = false;
         
-        bet.status = 1; // won
+        bet.status = 1; // won, claimable
         bet.win_amount = win_amount;
         
+        outcome.win_amount = win_amount;
+        outcome.tier = tier;
+        
+        outcome.breakdown.tier = Some(tier);
+        outcome.breakdown.multiplier_bps = win_multiplier;
+        
+        player_profile.total_won = player_profile.total_won
+            .checked_add(win_amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        if win_amount > player_profile.biggest_win {
+            player_profile.biggest_win = win_amount;
+        }
+        
         config.total_wins = config.total_wins
             .checked_add(1)
             .ok_or(CasinoError::MathOverflow)?;
         
-        msg!("Jackpot won! Player: {}, Amount: {}", ctx.accounts.player.key(), win_amount);
+        msg!("Jackpot won! Player: {}, Amount: {}", player, win_amount);
         
-        emit!(JackpotWon {
-            player: ctx.accounts.player.key(),
-            amount: win_amount,
-            pool_balance: pool.balance,
-            vrf_value: vrf_mod,
-        });
+        // A grand win drains the pool; refill it from the reserve
+        if tier == 0 {
+            reseed_from_reserve(config, pool, reserve_pool)?;
+        }
     } else {
         // No win
         bet.status = 2; // lost
@@ -105,13 +553,66 @@ pub fn fulfill_jackpot(
         
         msg!("No win. VRF value: {}, threshold: {}", vrf_mod, win_threshold);
         
-        emit!(JackpotLoss {
-            player: ctx.accounts.player.key(),
-            vrf_value: vrf_mod,
-        });
+        // Enough losses in a day earn a free bonus wheel spin
+        let today = Clock::get()?.unix_timestamp.div_euclid(86400);
+        if player_profile.record_loss_day(today, config.bonus_wheel_losses) {
+            emit!(BonusWheelSpinEarned {
+                sequence: config.next_event_sequence(),
+                player,
+                day: today,
+                spins: player_profile.bonus_wheel_spins,
+            });
+        }
+        
+        // Near miss: the house pays a fixed consolation, claimable like a win
+        let near_miss = config.consolation_amount > 0
+            && vrf_mod < win_threshold.saturating_add(config.consolation_bps as u64);
+        
+        if near_miss {
+            let consolation = config.consolation_amount;
+            let house_info = house_vault.to_account_info();
+            let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+            
+            // An empty house skips the consolation rather than blocking settlement
+            if house_info.lamports() >= consolation.checked_add(reserved).ok_or(CasinoError::MathOverflow)? {
+                move_pool_lamports(&house_info, &pool.to_account_info(), consolation)?;
+                
+                house_vault.total_consolation_payouts = house_vault.total_consolation_payouts
+                    .checked_add(consolation)
+                    .ok_or(CasinoError::MathOverflow)?;
+                
+                pool.pending_payouts = pool.pending_payouts
+                    .checked_add(consolation)
+                    .ok_or(CasinoError::MathOverflow)?;
+                
+                bet.status = 1; // won, claimable
+                bet.win_amount = consolation;
+                
+                player_profile.total_won = player_profile.total_won
+                    .checked_add(consolation)
+                    .ok_or(CasinoError::MathOverflow)?;
+                
+                outcome.breakdown.consolation = consolation;
+                
+                msg!("Near miss! Consolation: {}", consolation);
+                
+                emit!(ConsolationPaid {
+                    sequence: config.next_event_sequence(),
+                    player,
+                    bet: bet.key(),
+                    amount: consolation,
+                    vrf_value: vrf_mod,
+                    threshold: win_threshold,
+                });
+            } else {
+                outcome.house_shortfall = consolation;
+                msg!("House vault cannot cover consolation; skipped");
+            }
+        }
     }
     
     // Check if pool should reset (reached threshold)
+    // The payout is credited to this bet, which can only settle once
     if pool.balance >= pool.reset_threshold && pool.reset_threshold > 0 {
         // Partial payout and reset
         let reset_payout = pool.reset_threshold
@@ -119,53 +620,325 @@ pub fn fulfill_jackpot(
             .ok_or(CasinoError::MathOverflow)?;
         
         if reset_payout > 0 {
-            **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += reset_payout;
-            **ctx.accounts.pool.to_account_info().try_borrow_mut_lamports()? -= reset_payout;
-            
             pool.balance = pool.balance
                 .checked_sub(reset_payout)
                 .ok_or(CasinoError::MathOverflow)?;
             
+            pool.pending_payouts = pool.pending_payouts
+                .checked_add(reset_payout)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            // Reset payout is claimed together with any win on this bet
+            bet.status = 1; // won, claimable
+            bet.win_amount = bet.win_amount
+                .checked_add(reset_payout)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            player_profile.total_won = player_profile.total_won
+                .checked_add(reset_payout)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            outcome.breakdown.reset_payout = reset_payout;
+            
             msg!("Pool reset threshold reached. Partial payout: {}", reset_payout);
         }
         
         pool.bets_since_win = 0;
     }
     
+    bet.settled_at = Clock::get()?.unix_timestamp;
+    
+    Ok(outcome)
+}
+
+/// Move lamports between two program-owned accounts
+fn move_pool_lamports<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    
     Ok(())
 }
 
+/// Set aside part of a jackpot win for the pool's recent bettors (excluding the winner)
+/// Returns the lamports set aside; skipped while an earlier split is still being paid out
+fn split_community_share<'info>(
+    config: &mut Config,
+    pool: &mut Account<'info, JackpotPool>,
+    winner: Pubkey,
+    win_amount: u64,
+) -> Result<u64> {
+    if config.community_share_bps == 0 || pool.community_unpaid != 0 {
+        return Ok(0);
+    }
+    
+    let mut recipients = [Pubkey::default(); RECENT_BETTORS_LEN];
+    let mut count = 0;
+    for bettor in pool.recent_bettors {
+        if bettor != Pubkey::default() && bettor != winner {
+            recipients[count] = bettor;
+            count += 1;
+        }
+    }
+    
+    if count == 0 {
+        return Ok(0);
+    }
+    
+    let share = win_amount
+        .checked_mul(config.community_share_bps as u64)
+        .and_then(|x| x.checked_div(10000))
+        .and_then(|x| x.checked_div(count as u64))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    if share == 0 {
+        return Ok(0);
+    }
+    
+    pool.community_recipients = recipients;
+    pool.community_share = share;
+    pool.community_unpaid = (1u16 << count) - 1;
+    
+    emit!(CommunitySplit {
+        sequence: config.next_event_sequence(),
+        pool: pool.key(),
+        winner,
+        recipients: count as u8,
+        share,
+    });
+    
+    Ok(share * count as u64)
+}
+
+/// Refill the jackpot from the reserve up to `min_pool_seed`, or with the whole reserve if unset
+fn reseed_from_reserve<'info>(
+    config: &mut Config,
+    pool: &mut Account<'info, JackpotPool>,
+    reserve_pool: &mut Account<'info, ReservePool>,
+) -> Result<()> {
+    let wanted = if config.min_pool_seed > 0 {
+        config.min_pool_seed.saturating_sub(pool.balance)
+    } else {
+        reserve_pool.balance
+    };
+    
+    let amount = wanted.min(reserve_pool.balance);
+    if amount == 0 {
+        return Ok(());
+    }
+    
+    move_pool_lamports(&reserve_pool.to_account_info(), &pool.to_account_info(), amount)?;
+    
+    reserve_pool.balance = reserve_pool.balance
+        .checked_sub(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    reserve_pool.total_reseeded = reserve_pool.total_reseeded
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    pool.balance = pool.balance
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Jackpot reseeded from reserve: {}", amount);
+    
+    emit!(JackpotReseeded {
+        sequence: config.next_event_sequence(),
+        amount,
+        pool_balance: pool.balance,
+        reserve_balance: reserve_pool.balance,
+    });
+    
+    Ok(())
+}
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
 #[derive(Accounts)]
 pub struct FulfillJackpot<'info> {
     #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
     
-    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
     pub pool: Account<'info, JackpotPool>,
     
-    #[account(mut)]
+    #[account(mut, seeds = [b"reserve_pool"], bump = reserve_pool.bump)]
+    pub reserve_pool: Account<'info, ReservePool>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"bet", bet.player.as_ref(), bet.amount.to_le_bytes().as_ref()],
+        bump = bet.bump
+    )]
     pub bet: Account<'info, Bet>,
     
-    #[account(mut)]
+    #[account(
+        mut,
+        seeds = [b"vrf_request", bet.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == bet.key() @ CasinoError::VrfRequestBetMismatch,
+        constraint = vrf_request.player == bet.player @ CasinoError::VrfRequestPlayerMismatch
+    )]
     pub vrf_request: Account<'info, VrfRequest>,
     
-    /// CHECK: Player account (verified via bet.player)
+    #[account(
+        mut,
+        seeds = [b"player", bet.player.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut, seeds = [b"win_history"], bump = win_history.load()?.bump)]
+    pub win_history: AccountLoader<'info, WinHistory>,
+    
+    #[account(mut, seeds = [b"notification_queue"], bump = notification_queue.load()?.bump)]
+    pub notification_queue: AccountLoader<'info, NotificationQueue>,
+    
+    /// House bankroll counted toward the exposure limit, if funded
+    #[account(seeds = [b"bankroll"], bump = bankroll.bump)]
+    pub bankroll: Option<Account<'info, Bankroll>>,
+    
+    /// RTP stats, if tracked
+    #[account(mut, seeds = [b"stats"], bump = stats.load()?.bump)]
+    pub stats: Option<AccountLoader<'info, Stats>>,
+    
+    /// Today's volume bucket, if the dashboard tracks it
+    #[account(mut, seeds = [b"volume", volume_bucket.day.to_le_bytes().as_ref()], bump = volume_bucket.bump)]
+    pub volume_bucket: Option<Account<'info, VolumeBucket>>,
+    
+    /// Pool's open side-bet round; required to settle a jackpot hit while one is open
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"side_bet_pool", pool.key().as_ref(), side_bet_pool.round_id.to_le_bytes().as_ref()],
+        bump = side_bet_pool.bump
+    )]
+    pub side_bet_pool: Option<Account<'info, SideBetPool>>,
+    
+    /// Registered keeper credited for the crank, if any
+    #[account(mut, seeds = [b"keeper", keeper.authority.as_ref()], bump = keeper.bump)]
+    pub keeper: Option<Account<'info, Keeper>>,
+    
+    /// Keeper wallet receiving the crank fee; required with `keeper` and must be the settler
     #[account(mut)]
-    pub player: AccountInfo<'info>,
+    pub keeper_authority: Option<Signer<'info>>,
+    
+    /// Authority or operator supplying the VRF result
+    pub settler: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+/// Outcome returned by `fulfill_jackpot` for programs settling via CPI
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct SettlementResult {
+    pub is_win: bool,
+    pub win_amount: u64,
+    pub new_pool_balance: u64,
+}
+
+/// Explains how a settlement arrived at its payout
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug)]
+pub struct SettlementBreakdown {
+    /// Roll in 0..10000 derived from the VRF result
+    pub roll: u64,
+    /// Effective win probability (basis points) the roll was compared against
+    pub threshold: u64,
+    /// Odds mode used to derive the threshold (see `Config::odds_mode`)
+    pub odds_mode: u8,
+    /// Threshold boost from the drought ramp (see `Config::odds_ramp_bps`)
+    pub ramp_bps: u64,
+    /// Payout tier: 0 = rare, 1 = medium, 2 = common, 3 = bootstrap (None on a loss)
+    pub tier: Option<u8>,
+    /// Share of the pool paid out, in basis points
+    pub multiplier_bps: u64,
+    /// Pool balance before settlement
+    pub pool_balance_before: u64,
+    /// Amount clipped by `max_payout` and sent to the reserve pool
+    pub capped_amount: u64,
+    /// Extra payout from the pool reaching its reset threshold
+    pub reset_payout: u64,
+    /// Near-miss consolation paid by the house vault
+    pub consolation: u64,
+    /// Part of the jackpot win set aside for recent bettors (see `Config::community_share_bps`)
+    pub community_share: u64,
+    /// Win was forced by the must-hit-by ceiling rather than the roll
+    pub forced_win: bool,
+}
+
 #[event]
 pub struct JackpotWon {
+    pub sequence: u64,
     pub player: Pubkey,
+    pub bet: Pubkey,
+    pub vrf_request: Pubkey,
     pub amount: u64,
     pub pool_balance: u64,
     pub vrf_value: u64,
+    pub breakdown: SettlementBreakdown,
 }
 
 #[event]
 pub struct JackpotLoss {
+    pub sequence: u64,
     pub player: Pubkey,
+    pub bet: Pubkey,
+    pub vrf_request: Pubkey,
     pub vrf_value: u64,
+    pub breakdown: SettlementBreakdown,
+}
+
+#[event]
+pub struct ConsolationPaid {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+    pub vrf_value: u64,
+    pub threshold: u64,
+}
+
+#[event]
+pub struct BonusWheelSpinEarned {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub day: i64,
+    pub spins: u8,
+}
+
+#[event]
+pub struct CommunitySplit {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub winner: Pubkey,
+    pub recipients: u8,
+    pub share: u64,
+}
+
+#[event]
+pub struct JackpotReseeded {
+    pub sequence: u64,
+    pub amount: u64,
+    pub pool_balance: u64,
+    pub reserve_balance: u64,
+}
+
+#[event]
+pub struct SideBetRoundSettled {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub side_bet_pool: Pubkey,
+    pub round_id: u64,
+    pub hit_at_bets: u64,
+    pub winning_bucket: u8,
+    pub winning_stake: u64,
+    pub distributable: u64,
+    pub rake: u64,
 }
@@ -0,0 +1,223 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::fulfill_jackpot::{record_house_shortfall, record_settlement_stats, record_win, settle_bet, settle_side_bets, SettlementAccounts, JackpotLoss, JackpotWon};
+use super::register_keeper::pay_keeper;
+
+/// Maximum number of bets settled in a single batch
+pub const MAX_BATCH_SETTLEMENTS: usize = 10;
+
+/// Accounts supplied per bet in `remaining_accounts`: bet, vrf_request, player_profile
+const ACCOUNTS_PER_SETTLEMENT: usize = 3;
+
+/// Settle several pending bets in one transaction (authority or operator)
+/// Each bet rolls against hash(vrf_result || bet) so bets sharing a batch get independent outcomes
+pub fn fulfill_jackpots_batch<'info>(
+    ctx: Context<'_, '_, 'info, 'info, FulfillJackpotsBatch<'info>>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    // The VRF result comes from the caller, so only the house may supply it; timed-out bets are refunded by `sweep_expired_bets`
+    let settler = ctx.accounts.settler.key();
+    require!(
+        settler == ctx.accounts.config.authority || settler == ctx.accounts.config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    // Crank fees go to the settling operator, not to a co-signer
+    if let Some(keeper_authority) = &ctx.accounts.keeper_authority {
+        require_keys_eq!(keeper_authority.key(), settler, CasinoError::InvalidKeeper);
+    }
+    
+    let remaining = ctx.remaining_accounts;
+    
+    require!(
+        !remaining.is_empty() && remaining.len() % ACCOUNTS_PER_SETTLEMENT == 0,
+        CasinoError::InvalidBatchAccounts
+    );
+    
+    let settlement_count = remaining.len() / ACCOUNTS_PER_SETTLEMENT;
+    require!(
+        settlement_count <= MAX_BATCH_SETTLEMENTS,
+        CasinoError::BatchTooLarge
+    );
+    
+    let mut wins: u64 = 0;
+    
+    for accounts in remaining.chunks(ACCOUNTS_PER_SETTLEMENT) {
+        let mut bet: Account<'info, Bet> = Account::try_from(&accounts[0])?;
+        let mut vrf_request: Account<'info, VrfRequest> = Account::try_from(&accounts[1])?;
+        let mut player_profile: Account<'info, PlayerProfile> = Account::try_from(&accounts[2])?;
+        
+        require!(
+            player_profile.player == bet.player,
+            CasinoError::InvalidBatchAccounts
+        );
+        
+        // Derive a per-bet result from the shared VRF output
+        let bet_vrf_result = hashv(&[&vrf_result, bet.key().as_ref()]).to_bytes();
+        
+        let bets_since_win = ctx.accounts.pool.bets_since_win;
+        let bankroll_balance = ctx.accounts.bankroll.as_ref().map_or(0, |bankroll| bankroll.balance);
+        let outcome = settle_bet(
+            SettlementAccounts {
+                config: &mut ctx.accounts.config,
+                pool: &mut ctx.accounts.pool,
+                reserve_pool: &mut ctx.accounts.reserve_pool,
+                house_vault: &mut ctx.accounts.house_vault,
+                bet: &mut bet,
+                vrf_request: &mut vrf_request,
+                player_profile: &mut player_profile,
+            },
+            bankroll_balance,
+            bet_vrf_result,
+        )?;
+        
+        if outcome.is_win {
+            emit_event!(ctx, JackpotWon {
+                sequence: ctx.accounts.config.next_event_sequence(),
+                player: bet.player,
+                bet: bet.key(),
+                vrf_request: vrf_request.key(),
+                amount: outcome.win_amount,
+                pool_balance: ctx.accounts.pool.balance,
+                vrf_value: outcome.breakdown.roll,
+                breakdown: outcome.breakdown,
+            });
+        } else {
+            emit_event!(ctx, JackpotLoss {
+                sequence: ctx.accounts.config.next_event_sequence(),
+                player: bet.player,
+                bet: bet.key(),
+                vrf_request: vrf_request.key(),
+                vrf_value: outcome.breakdown.roll,
+                breakdown: outcome.breakdown,
+            });
+        }
+        
+        settle_side_bets(
+            &mut ctx.accounts.config,
+            &mut ctx.accounts.pool,
+            &mut ctx.accounts.house_vault,
+            &mut ctx.accounts.side_bet_pool,
+            bets_since_win,
+            outcome.is_jackpot_hit(),
+        )?;
+        
+        if outcome.is_win {
+            wins = wins
+                .checked_add(1)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            record_win(
+                &ctx.accounts.config,
+                &ctx.accounts.win_history,
+                &ctx.accounts.notification_queue,
+                bet.player,
+                player_profile.hide_from_history,
+                &outcome,
+            )?;
+        }
+        
+        record_house_shortfall(
+            &ctx.accounts.notification_queue,
+            ctx.accounts.house_vault.key(),
+            &outcome,
+        )?;
+        
+        record_settlement_stats(
+            &ctx.accounts.stats,
+            &mut ctx.accounts.volume_bucket,
+            bet.win_amount,
+            &outcome,
+        )?;
+        
+        // Remaining accounts are not persisted by Anchor
+        bet.exit(&crate::ID)?;
+        vrf_request.exit(&crate::ID)?;
+        player_profile.exit(&crate::ID)?;
+    }
+    
+    pay_keeper(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.house_vault,
+        &mut ctx.accounts.keeper,
+        &ctx.accounts.keeper_authority,
+        settlement_count as u64,
+    )?;
+    
+    msg!("Batch settled: {} bets, {} wins", settlement_count, wins);
+    
+    emit_event!(ctx, BatchSettled {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        settled: settlement_count as u64,
+        wins,
+        pool_balance: ctx.accounts.pool.balance,
+    });
+    
+    Ok(())
+}
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct FulfillJackpotsBatch<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut, seeds = [b"reserve_pool"], bump = reserve_pool.bump)]
+    pub reserve_pool: Account<'info, ReservePool>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(mut, seeds = [b"win_history"], bump = win_history.load()?.bump)]
+    pub win_history: AccountLoader<'info, WinHistory>,
+    
+    #[account(mut, seeds = [b"notification_queue"], bump = notification_queue.load()?.bump)]
+    pub notification_queue: AccountLoader<'info, NotificationQueue>,
+    
+    /// House bankroll counted toward the exposure limit, if funded
+    #[account(seeds = [b"bankroll"], bump = bankroll.bump)]
+    pub bankroll: Option<Account<'info, Bankroll>>,
+    
+    /// RTP stats, if tracked
+    #[account(mut, seeds = [b"stats"], bump = stats.load()?.bump)]
+    pub stats: Option<AccountLoader<'info, Stats>>,
+    
+    /// Today's volume bucket, if the dashboard tracks it
+    #[account(mut, seeds = [b"volume", volume_bucket.day.to_le_bytes().as_ref()], bump = volume_bucket.bump)]
+    pub volume_bucket: Option<Account<'info, VolumeBucket>>,
+    
+    /// Pool's open side-bet round; required to settle a jackpot hit while one is open
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"side_bet_pool", pool.key().as_ref(), side_bet_pool.round_id.to_le_bytes().as_ref()],
+        bump = side_bet_pool.bump
+    )]
+    pub side_bet_pool: Option<Account<'info, SideBetPool>>,
+    
+    /// Registered keeper credited for the crank, if any
+    #[account(mut, seeds = [b"keeper", keeper.authority.as_ref()], bump = keeper.bump)]
+    pub keeper: Option<Account<'info, Keeper>>,
+    
+    /// Keeper wallet receiving the crank fee; required with `keeper` and must be the settler
+    #[account(mut)]
+    pub keeper_authority: Option<Signer<'info>>,
+    
+    /// Authority or operator supplying the VRF result
+    pub settler: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BatchSettled {
+    pub sequence: u64,
+    pub settled: u64,
+    pub wins: u64,
+    pub pool_balance: u64,
+}
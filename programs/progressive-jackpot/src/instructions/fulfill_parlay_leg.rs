@@ -0,0 +1,158 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::create_parlay::parlay_leg_payout;
+use super::fulfill_jackpot::derive_roll;
+
+/// Settle a parlay's current leg with VRF randomness
+/// A won leg re-arms the VRF request for the next one; a loss pays the stake to the house and closes the parlay
+pub fn fulfill_parlay_leg(
+    ctx: Context<FulfillParlayLeg>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let parlay = &mut ctx.accounts.parlay;
+    let vrf_request = &mut ctx.accounts.vrf_request;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(parlay.status == 0, CasinoError::VrfAlreadyFulfilled);
+    
+    require!(
+        vrf_request.status == 0,
+        CasinoError::VrfRequestNotFound
+    );
+    
+    // A leg that timed out is cashed out through `claim_parlay` instead
+    require!(
+        now - vrf_request.timestamp < VRF_TIMEOUT,
+        CasinoError::VrfTimeout
+    );
+    
+    let earliest_slot = parlay.placed_slot.saturating_add(config.min_settle_delay_slots.max(1));
+    require!(Clock::get()?.slot >= earliest_slot, CasinoError::SettlementTooEarly);
+    
+    // The VRF result comes from the caller, so only the house may supply it
+    let settler = ctx.accounts.settler.key();
+    require!(
+        settler == config.authority || settler == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let leg = parlay.current_leg as usize;
+    let roll = derive_roll(&vrf_result);
+    let won = roll < parlay.leg_chances_bps[leg] as u64;
+    parlay.leg_rolls[leg] = roll;
+    
+    let liability = parlay.potential_payout - parlay.stake;
+    let house_info = ctx.accounts.house_vault.to_account_info();
+    let parlay_info = parlay.to_account_info();
+    
+    if won {
+        parlay.current_stake = parlay_leg_payout(parlay.current_stake, parlay.leg_chances_bps[leg], parlay.house_edge_bps)?;
+        parlay.current_leg += 1;
+    }
+    
+    let completed = !won || parlay.current_leg == parlay.leg_count;
+    
+    if !completed {
+        // Re-arm the request for the next leg
+        let mut request_id = [0u8; 32];
+        request_id[..8].copy_from_slice(&now.to_le_bytes());
+        request_id[8] = parlay.current_leg;
+        vrf_request.timestamp = now;
+        vrf_request.request_id = request_id;
+        vrf_request.result = None;
+        parlay.placed_slot = Clock::get()?.slot;
+    } else {
+        vrf_request.status = 1; // fulfilled
+        vrf_request.result = Some(vrf_result);
+        config.open_bets = config.open_bets.saturating_sub(1);
+        
+        let house_vault = &mut ctx.accounts.house_vault;
+        house_vault.game_liability = house_vault.game_liability.saturating_sub(liability);
+        
+        if won {
+            // The house tops the held stake up to the full payout, claimable via `claim_parlay`
+            let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+            require!(
+                house_info.lamports() >= liability.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+                CasinoError::InsufficientFunds
+            );
+            **house_info.try_borrow_mut_lamports()? -= liability;
+            **parlay_info.try_borrow_mut_lamports()? += liability;
+            parlay.status = 1; // won, claimable
+        } else {
+            **parlay_info.try_borrow_mut_lamports()? -= parlay.stake;
+            **house_info.try_borrow_mut_lamports()? += parlay.stake;
+            house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+                .checked_add(parlay.stake)
+                .ok_or(CasinoError::MathOverflow)?;
+        }
+    }
+    
+    msg!("Parlay leg {} settled: roll {}, won {}", leg, roll, won);
+    
+    emit!(ParlayLegSettled {
+        sequence: config.next_event_sequence(),
+        player: parlay.player,
+        parlay: parlay.key(),
+        leg: leg as u8,
+        roll,
+        won,
+        current_stake: if won { parlay.current_stake } else { 0 },
+        completed,
+    });
+    
+    // A lost parlay's accounts are closed, returning their rent to the player
+    if completed && !won {
+        let player = ctx.accounts.player.to_account_info();
+        ctx.accounts.vrf_request.close(player.clone())?;
+        ctx.accounts.parlay.close(player)?;
+    }
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FulfillParlayLeg<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        mut,
+        has_one = player,
+        seeds = [b"parlay", parlay.player.as_ref(), parlay.stake.to_le_bytes().as_ref()],
+        bump = parlay.bump
+    )]
+    pub parlay: Account<'info, Parlay>,
+    
+    #[account(
+        mut,
+        seeds = [b"vrf_request", parlay.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == parlay.key() @ CasinoError::VrfRequestBetMismatch
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    /// CHECK: the parlay's player; receives the rent back if the parlay loses
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+    
+    /// Authority or operator supplying the VRF result
+    pub settler: Signer<'info>,
+}
+
+#[event]
+pub struct ParlayLegSettled {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub parlay: Pubkey,
+    pub leg: u8,
+    pub roll: u64,
+    pub won: bool,
+    pub current_stake: u64,
+    pub completed: bool,
+}
@@ -0,0 +1,236 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::fulfill_jackpot::settle_side_bets;
+
+/// Settle a slots spin with VRF randomness, paying fixed outcomes directly and closing the spin
+/// A jackpot is held in the pool as a claimable `jackpot_bet` for `claim_win`; once the VRF request has timed out, the held stake is refunded instead
+pub fn fulfill_slots(
+    ctx: Context<FulfillSlots>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    let vrf_request = &ctx.accounts.vrf_request;
+    let slot_spin = &ctx.accounts.slot_spin;
+    
+    require!(
+        vrf_request.status == 0,
+        CasinoError::VrfRequestNotFound
+    );
+    
+    let timed_out = Clock::get()?.unix_timestamp - vrf_request.timestamp >= VRF_TIMEOUT;
+    if !timed_out {
+        let earliest_slot = slot_spin.placed_slot.saturating_add(ctx.accounts.config.min_settle_delay_slots.max(1));
+        require!(Clock::get()?.slot >= earliest_slot, CasinoError::SettlementTooEarly);
+    }
+    
+    // The VRF result comes from the caller, so only the house may supply it; anyone may refund once timed out
+    let settler = ctx.accounts.settler.key();
+    require!(
+        timed_out || settler == ctx.accounts.config.authority || settler == ctx.accounts.config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.game_liability = house_vault.game_liability.saturating_sub(slot_spin.liability);
+    
+    let paytable = &mut ctx.accounts.paytable;
+    paytable.open_spins = paytable.open_spins.saturating_sub(1);
+    
+    let config = &mut ctx.accounts.config;
+    config.open_bets = config.open_bets.saturating_sub(1);
+    
+    let pool = &mut ctx.accounts.pool;
+    let house_info = house_vault.to_account_info();
+    let spin_info = slot_spin.to_account_info();
+    
+    let outcome = (!timed_out).then(|| paytable.outcome(&vrf_result));
+    let entry = outcome.map(|index| paytable.entries[index]);
+    let jackpot = entry.is_some_and(|entry| entry.jackpot);
+    let bets_since_win = pool.bets_since_win;
+    
+    require!(
+        ctx.accounts.jackpot_bet.is_some() == jackpot,
+        CasinoError::InvalidJackpotBet
+    );
+    
+    // Whatever the spin account holds when it's closed goes to the player
+    let paid = match entry {
+        None => slot_spin.stake,
+        Some(entry) if entry.jackpot => {
+            let mut win_amount = pool.balance
+                .checked_mul(entry.multiplier_bps as u64)
+                .and_then(|x| x.checked_div(10000))
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            if config.max_payout > 0 {
+                win_amount = win_amount.min(config.max_payout);
+            }
+            
+            // Reserve the win in the pool; `claim_win` and its siblings apply review, vesting and disputes
+            pool.balance = pool.balance
+                .checked_sub(win_amount)
+                .ok_or(CasinoError::InsufficientFunds)?;
+            
+            pool.pending_payouts = pool.pending_payouts
+                .checked_add(win_amount)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            let jackpot_bet = ctx.accounts.jackpot_bet.as_mut().ok_or(CasinoError::InvalidJackpotBet)?;
+            jackpot_bet.player = slot_spin.player;
+            jackpot_bet.pool = pool.key();
+            jackpot_bet.amount = slot_spin.amount;
+            jackpot_bet.timestamp = slot_spin.created_at;
+            jackpot_bet.status = 1; // won, claimable
+            jackpot_bet.win_amount = win_amount;
+            jackpot_bet.placed_slot = slot_spin.placed_slot;
+            jackpot_bet.settled_at = Clock::get()?.unix_timestamp;
+            jackpot_bet.bump = ctx.bumps.jackpot_bet.ok_or(CasinoError::InvalidJackpotBet)?;
+            
+            // The staked part of the wager is the house's take on a jackpot spin
+            **spin_info.try_borrow_mut_lamports()? -= slot_spin.stake;
+            **house_info.try_borrow_mut_lamports()? += slot_spin.stake;
+            house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+                .checked_add(slot_spin.stake)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            pool.last_winner = Some(slot_spin.player);
+            pool.last_win_timestamp = Some(Clock::get()?.unix_timestamp);
+            pool.bets_since_win = 0;
+            pool.must_hit_armed = false;
+            
+            config.total_wins = config.total_wins
+                .checked_add(1)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            win_amount
+        }
+        Some(entry) => {
+            let payout = (slot_spin.amount as u128)
+                .checked_mul(entry.multiplier_bps as u128)
+                .map(|v| v / 10000)
+                .and_then(|v| u64::try_from(v).ok())
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            if payout > slot_spin.stake {
+                let top_up = payout - slot_spin.stake;
+                let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+                require!(
+                    house_info.lamports() >= top_up.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+                    CasinoError::InsufficientFunds
+                );
+                **house_info.try_borrow_mut_lamports()? -= top_up;
+                **spin_info.try_borrow_mut_lamports()? += top_up;
+            } else {
+                let kept = slot_spin.stake - payout;
+                **spin_info.try_borrow_mut_lamports()? -= kept;
+                **house_info.try_borrow_mut_lamports()? += kept;
+                house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+                    .checked_add(kept)
+                    .ok_or(CasinoError::MathOverflow)?;
+            }
+            
+            payout
+        }
+    };
+    
+    settle_side_bets(
+        config,
+        pool,
+        house_vault,
+        &mut ctx.accounts.side_bet_pool,
+        bets_since_win,
+        jackpot,
+    )?;
+    
+    msg!("Slots settled: outcome {:?}, jackpot {}, paid {}", outcome, jackpot, paid);
+    
+    emit!(SlotsSpun {
+        sequence: config.next_event_sequence(),
+        player: slot_spin.player,
+        slot_spin: slot_spin.key(),
+        outcome: outcome.map(|index| index as u8),
+        symbols: entry.map(|entry| entry.symbols),
+        jackpot,
+        jackpot_bet: ctx.accounts.jackpot_bet.as_ref().map(|bet| bet.key()),
+        paid,
+    });
+    
+    let player = ctx.accounts.player.to_account_info();
+    ctx.accounts.vrf_request.close(player.clone())?;
+    ctx.accounts.slot_spin.close(player)?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FulfillSlots<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut, seeds = [b"paytable"], bump = paytable.bump)]
+    pub paytable: Account<'info, Paytable>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        mut,
+        has_one = player,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"slots", slot_spin.player.as_ref(), slot_spin.amount.to_le_bytes().as_ref()],
+        bump = slot_spin.bump
+    )]
+    pub slot_spin: Account<'info, SlotSpin>,
+    
+    #[account(
+        mut,
+        seeds = [b"vrf_request", slot_spin.key().as_ref()],
+        bump = vrf_request.bump,
+        constraint = vrf_request.bet == slot_spin.key() @ CasinoError::VrfRequestBetMismatch
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    /// Pool's open side-bet round; required to settle a jackpot spin while one is open
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"side_bet_pool", pool.key().as_ref(), side_bet_pool.round_id.to_le_bytes().as_ref()],
+        bump = side_bet_pool.bump
+    )]
+    pub side_bet_pool: Option<Account<'info, SideBetPool>>,
+    
+    /// CHECK: the spin's player; receives the payout or refund and the closed accounts' rent
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+    
+    /// Claimable record of a jackpot spin; must be passed exactly when the outcome is a jackpot
+    #[account(
+        init,
+        payer = settler,
+        space = 8 + Bet::INIT_SPACE,
+        seeds = [b"slots_win", slot_spin.key().as_ref(), slot_spin.created_at.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub jackpot_bet: Option<Account<'info, Bet>>,
+    
+    /// Authority or operator supplying the VRF result (anyone, once the request has timed out); pays for `jackpot_bet`
+    #[account(mut)]
+    pub settler: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SlotsSpun {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub slot_spin: Pubkey,
+    pub outcome: Option<u8>,
+    pub symbols: Option<[u8; SLOT_REELS]>,
+    pub jackpot: bool,
+    pub jackpot_bet: Option<Pubkey>,
+    pub paid: u64,
+}
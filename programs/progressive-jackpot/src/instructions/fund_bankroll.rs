@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Deposit risk capital into the house bankroll (authority or treasurer)
+pub fn fund_bankroll(
+    ctx: Context<FundBankroll>,
+    amount: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.treasurer,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        amount > 0,
+        CasinoError::InvalidAmount
+    );
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.signer.to_account_info(),
+                to: ctx.accounts.bankroll.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let bankroll = &mut ctx.accounts.bankroll;
+    bankroll.bump = ctx.bumps.bankroll;
+    bankroll.balance = bankroll.balance
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    bankroll.total_deposited = bankroll.total_deposited
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Bankroll funded: {} lamports, balance {}", amount, bankroll.balance);
+    
+    emit!(BankrollFunded {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        signer,
+        amount,
+        balance: bankroll.balance,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FundBankroll<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + Bankroll::INIT_SPACE,
+        seeds = [b"bankroll"],
+        bump
+    )]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BankrollFunded {
+    pub sequence: u64,
+    pub signer: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Snapshot a pool's balance and effective limits
+/// Returned via return data (Borsh) so composing programs can read it through CPI or simulation
+pub fn get_pool_state(
+    ctx: Context<GetPoolState>,
+) -> Result<PoolState> {
+    let config = &ctx.accounts.config;
+    let pool = &ctx.accounts.pool;
+    let (min_bet, max_bet) = pool.bet_limits(config);
+    
+    Ok(PoolState {
+        pool_id: pool.pool_id,
+        balance: pool.balance,
+        pending_payouts: pool.pending_payouts,
+        win_probability_bps: pool.effective_win_probability_bps(config),
+        min_bet,
+        max_bet,
+        bets_since_win: pool.bets_since_win,
+        must_hit_armed: pool.must_hit_armed,
+        last_winner: pool.last_winner,
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetPoolState<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+}
+
+/// Pool snapshot returned by `get_pool_state`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct PoolState {
+    pub pool_id: u64,
+    pub balance: u64,
+    pub pending_payouts: u64,
+    /// Base win probability before scaling, ramps, and streak bonuses (basis points)
+    pub win_probability_bps: u16,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub bets_since_win: u64,
+    pub must_hit_armed: bool,
+    pub last_winner: Option<Pubkey>,
+}
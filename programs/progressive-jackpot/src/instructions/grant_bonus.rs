@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Top up a player's bonus balance from a promotion (authority or operator)
+/// The signer funds the grant; bonus must be wagered `wagering_requirement` lamports before withdrawal
+pub fn grant_bonus(
+    ctx: Context<GrantBonus>,
+    player: Pubkey,
+    amount: u64,
+    wagering_requirement: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        amount > 0,
+        CasinoError::InvalidBonusGrant
+    );
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.signer.to_account_info(),
+                to: ctx.accounts.bonus_credit.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let bonus = &mut ctx.accounts.bonus_credit;
+    if bonus.player == Pubkey::default() {
+        bonus.player = player;
+        bonus.bump = ctx.bumps.bonus_credit;
+    }
+    
+    bonus.balance = bonus.balance
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    bonus.wagering_remaining = bonus.wagering_remaining
+        .checked_add(wagering_requirement)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    bonus.total_granted = bonus.total_granted
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Granted {} lamports of bonus to {}", amount, player);
+    
+    emit!(BonusGranted {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player,
+        granted_by: signer,
+        amount,
+        wagering_requirement,
+        balance: bonus.balance,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(player: Pubkey)]
+pub struct GrantBonus<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + BonusCredit::INIT_SPACE,
+        seeds = [b"bonus", player.as_ref()],
+        bump
+    )]
+    pub bonus_credit: Account<'info, BonusCredit>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BonusGranted {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub granted_by: Pubkey,
+    pub amount: u64,
+    pub wagering_requirement: u64,
+    pub balance: u64,
+}
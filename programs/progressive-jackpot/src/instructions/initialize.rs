@@ -3,7 +3,7 @@ use crate::state::*;
 use crate::error::CasinoError;
 
 /// Initialize the casino jackpot system
-/// Creates config, jackpot pool, and DeFi reward vault PDAs
+/// Creates config, jackpot pool, DeFi reward vault, and house vault PDAs
 pub fn initialize(
     ctx: Context<Initialize>,
     jackpot_percentage: u16,
@@ -17,11 +17,11 @@ pub fn initialize(
     switchboard_queue: Option<Pubkey>,
     reset_threshold: u64,
     milestone_bets: u64,
-    apy_bps: u16,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let pool = &mut ctx.accounts.pool;
     let reward_vault = &mut ctx.accounts.reward_vault;
+    let house_vault = &mut ctx.accounts.house_vault;
     
     // Validate percentages sum to reasonable amount (not more than 100%)
     let total_percentage = jackpot_percentage
@@ -51,6 +51,10 @@ pub fn initialize(
     
     // Initialize config
     config.authority = ctx.accounts.authority.key();
+    config.treasurer = ctx.accounts.authority.key();
+    config.operator = ctx.accounts.authority.key();
+    config.risk_manager = ctx.accounts.authority.key();
+    config.payout_approver = ctx.accounts.authority.key();
     config.jackpot_percentage = jackpot_percentage;
     config.house_percentage = house_percentage;
     config.defi_percentage = defi_percentage;
@@ -62,10 +66,13 @@ pub fn initialize(
     config.switchboard_queue = switchboard_queue;
     config.defi_vault_bump = ctx.bumps.reward_vault;
     config.total_bets = 0;
+    config.event_sequence = 0;
     config.total_wins = 0;
     config.bump = ctx.bumps.config;
+    config.version = STATE_VERSION;
     
     // Initialize pool
+    pool.pool_id = DEFAULT_POOL_ID;
     pool.balance = 0;
     pool.last_winner = None;
     pool.last_win_timestamp = None;
@@ -73,14 +80,26 @@ pub fn initialize(
     pool.bets_since_win = 0;
     pool.milestone_bets = milestone_bets;
     pool.bump = ctx.bumps.pool;
+    pool.version = STATE_VERSION;
     
     // Initialize reward vault
     reward_vault.staked_amount = 0;
     reward_vault.total_rewards_distributed = 0;
     reward_vault.last_distribution = Clock::get()?.unix_timestamp;
     reward_vault.distribution_period = 86400; // 1 day default
-    reward_vault.apy_bps = apy_bps;
     reward_vault.bump = ctx.bumps.reward_vault;
+    reward_vault.version = STATE_VERSION;
+    reward_vault.acc_reward_per_share = 0;
+    reward_vault.last_accrual = Clock::get()?.unix_timestamp;
+    
+    // Initialize house vault
+    house_vault.total_fees = 0;
+    house_vault.total_bootstrap_payouts = 0;
+    house_vault.total_consolation_payouts = 0;
+    house_vault.rakeback_owed = 0;
+    house_vault.accumulated_house_fees = 0;
+    house_vault.game_liability = 0;
+    house_vault.bump = ctx.bumps.house_vault;
     
     msg!("Casino initialized: jackpot={}%, house={}%, defi={}%", 
          jackpot_percentage, house_percentage, defi_percentage);
@@ -93,7 +112,7 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + std::mem::size_of::<Config>(),
+        space = 8 + Config::INIT_SPACE,
         seeds = [b"config"],
         bump
     )]
@@ -102,8 +121,8 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + std::mem::size_of::<JackpotPool>(),
-        seeds = [b"pool"],
+        space = 8 + JackpotPool::INIT_SPACE,
+        seeds = [b"pool", DEFAULT_POOL_ID.to_le_bytes().as_ref()],
         bump
     )]
     pub pool: Account<'info, JackpotPool>,
@@ -111,12 +130,21 @@ pub struct Initialize<'info> {
     #[account(
         init,
         payer = authority,
-        space = 8 + std::mem::size_of::<RewardVault>(),
+        space = 8 + RewardVault::INIT_SPACE,
         seeds = [b"reward_vault"],
         bump
     )]
     pub reward_vault: Account<'info, RewardVault>,
     
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + HouseVault::INIT_SPACE,
+        seeds = [b"house_vault"],
+        bump
+    )]
+    pub house_vault: Account<'info, HouseVault>,
+    
     #[account(mut)]
     pub authority: Signer<'info>,
     
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the vault that collects charity round-ups for `charity` (authority only)
+pub fn initialize_charity_vault(
+    ctx: Context<InitializeCharityVault>,
+    charity: Pubkey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        charity != Pubkey::default(),
+        CasinoError::InvalidConfig
+    );
+    
+    let charity_vault = &mut ctx.accounts.charity_vault;
+    charity_vault.charity = charity;
+    charity_vault.total_received = 0;
+    charity_vault.total_disbursed = 0;
+    charity_vault.bump = ctx.bumps.charity_vault;
+    
+    msg!("Charity vault initialized for {}", charity);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeCharityVault<'info> {
+    #[account(seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CharityVault::INIT_SPACE,
+        seeds = [b"charity_vault"],
+        bump
+    )]
+    pub charity_vault: Account<'info, CharityVault>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the game vault that escrows the remainder of each bet (authority only)
+/// Must exist before bets can be placed
+pub fn initialize_game_vault(
+    ctx: Context<InitializeGameVault>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let game_vault = &mut ctx.accounts.game_vault;
+    game_vault.total_escrowed = 0;
+    game_vault.total_withdrawn = 0;
+    game_vault.bump = ctx.bumps.game_vault;
+    
+    msg!("Game vault initialized");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeGameVault<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GameVault::INIT_SPACE,
+        seeds = [b"game_vault"],
+        bump
+    )]
+    pub game_vault: Account<'info, GameVault>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the reward vault's LP share mint (authority only)
+/// Only before the first `stake_defi`, so every share has a matching token
+pub fn initialize_lp_mint(
+    ctx: Context<InitializeLpMint>,
+) -> Result<()> {
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        reward_vault.lp_mint == Pubkey::default() && reward_vault.total_shares == 0,
+        CasinoError::LpMintUnavailable
+    );
+    
+    reward_vault.lp_mint = ctx.accounts.lp_mint.key();
+    
+    msg!("LP mint created: {}", reward_vault.lp_mint);
+    
+    emit!(LpMintInitialized {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        mint: reward_vault.lp_mint,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeLpMint<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(
+        init,
+        payer = authority,
+        mint::decimals = 9,
+        mint::authority = reward_vault,
+        seeds = [b"lp_mint"],
+        bump
+    )]
+    pub lp_mint: Account<'info, Mint>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LpMintInitialized {
+    pub sequence: u64,
+    pub mint: Pubkey,
+}
@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the keeper notification queue (authority only)
+/// Must exist before bets can be fulfilled
+pub fn initialize_notification_queue(
+    ctx: Context<InitializeNotificationQueue>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let mut queue = ctx.accounts.notification_queue.load_init()?;
+    queue.next_seq = 1;
+    queue.acked_seq = 0;
+    queue.dropped = 0;
+    queue.bump = ctx.bumps.notification_queue;
+    
+    msg!("Notification queue initialized: {} entries", NOTIFICATION_QUEUE_LEN);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeNotificationQueue<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<NotificationQueue>(),
+        seeds = [b"notification_queue"],
+        bump
+    )]
+    pub notification_queue: AccountLoader<'info, NotificationQueue>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
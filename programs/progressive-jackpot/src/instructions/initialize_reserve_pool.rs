@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the overflow reserve pool (authority only)
+/// Must exist before bets can be fulfilled
+pub fn initialize_reserve_pool(
+    ctx: Context<InitializeReservePool>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let reserve_pool = &mut ctx.accounts.reserve_pool;
+    reserve_pool.balance = 0;
+    reserve_pool.total_reseeded = 0;
+    reserve_pool.bump = ctx.bumps.reserve_pool;
+    
+    msg!("Reserve pool initialized");
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeReservePool<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ReservePool::INIT_SPACE,
+        seeds = [b"reserve_pool"],
+        bump
+    )]
+    pub reserve_pool: Account<'info, ReservePool>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the RTP stats ring buffer (authority only)
+/// Optional; once created, bets and settlements passing it are tallied per epoch
+pub fn initialize_stats(
+    ctx: Context<InitializeStats>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let mut stats = ctx.accounts.stats.load_init()?;
+    stats.bump = ctx.bumps.stats;
+    
+    msg!("Stats initialized: {} epochs", STATS_EPOCHS);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeStats<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<Stats>(),
+        seeds = [b"stats"],
+        bump
+    )]
+    pub stats: AccountLoader<'info, Stats>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the win history ring buffer (authority only)
+/// Must exist before bets can be fulfilled
+pub fn initialize_win_history(
+    ctx: Context<InitializeWinHistory>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let mut win_history = ctx.accounts.win_history.load_init()?;
+    win_history.head = 0;
+    win_history.total_recorded = 0;
+    win_history.bump = ctx.bumps.win_history;
+    
+    msg!("Win history initialized: {} entries", WIN_HISTORY_LEN);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeWinHistory<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + std::mem::size_of::<WinHistory>(),
+        seeds = [b"win_history"],
+        bump
+    )]
+    pub win_history: AccountLoader<'info, WinHistory>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
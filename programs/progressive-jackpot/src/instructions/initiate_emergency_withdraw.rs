@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Queue a withdrawal of pool funds after a critical bug (authority only)
+/// Betting on the pool stops immediately and a pause notification is queued; the funds move only after the emergency delay
+pub fn initiate_emergency_withdraw(
+    ctx: Context<InitiateEmergencyWithdraw>,
+    amount: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        pool.emergency_unlocks_at == 0,
+        CasinoError::EmergencyWithdrawPending
+    );
+    
+    require!(
+        amount > 0 && amount <= pool.balance,
+        CasinoError::InsufficientFunds
+    );
+    
+    // Pay the treasurer or one of the allowlisted treasury addresses
+    let destination = ctx.accounts.destination.key();
+    require!(
+        destination == config.treasurer || config.is_treasury_destination(&destination),
+        CasinoError::InvalidTreasuryDestination
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let unlocks_at = now
+        .checked_add(config.emergency_delay_secs.max(MIN_EMERGENCY_DELAY_SECS))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    pool.emergency_amount = amount;
+    pool.emergency_destination = destination;
+    pool.emergency_unlocks_at = unlocks_at;
+    
+    ctx.accounts.notification_queue
+        .load_mut()?
+        .push(NOTIFICATION_PAUSE, pool.key(), amount, now);
+    
+    msg!(
+        "EMERGENCY WITHDRAWAL QUEUED: {} lamports from pool {} to {}, executable at {}",
+        amount, pool.pool_id, destination, unlocks_at
+    );
+    
+    emit!(EmergencyWithdrawInitiated {
+        sequence: config.next_event_sequence(),
+        authority: ctx.accounts.authority.key(),
+        pool: pool.key(),
+        amount,
+        destination,
+        unlocks_at,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitiateEmergencyWithdraw<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut, seeds = [b"notification_queue"], bump = notification_queue.load()?.bump)]
+    pub notification_queue: AccountLoader<'info, NotificationQueue>,
+    
+    /// CHECK: the treasurer or an address in `Config::treasury_destinations`, checked in the handler
+    pub destination: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct EmergencyWithdrawInitiated {
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub pool: Pubkey,
+    pub amount: u64,
+    pub destination: Pubkey,
+    pub unlocks_at: i64,
+}
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_lang::Discriminator;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Grow `Config`, the reward and house vaults, and any pools or player profiles in `remaining_accounts`
+/// to the current layout (authority only)
+/// Stamps versioned accounts with `STATE_VERSION`; the authority pays any extra rent
+pub fn migrate<'info>(
+    ctx: Context<'_, '_, 'info, 'info, Migrate<'info>>,
+) -> Result<()> {
+    let authority = &ctx.accounts.authority;
+    let system_program = &ctx.accounts.system_program;
+    
+    let config_info = ctx.accounts.config.to_account_info();
+    grow_account(&config_info, 8 + Config::INIT_SPACE, authority, system_program)?;
+    let mut config = Config::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+    
+    require!(
+        authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let reward_vault_info = ctx.accounts.reward_vault.to_account_info();
+    grow_account(&reward_vault_info, 8 + RewardVault::INIT_SPACE, authority, system_program)?;
+    let mut reward_vault = RewardVault::try_deserialize(&mut &reward_vault_info.try_borrow_data()?[..])?;
+    reward_vault.version = STATE_VERSION;
+    reward_vault.try_serialize(&mut &mut reward_vault_info.try_borrow_mut_data()?[..])?;
+    
+    grow_account(&ctx.accounts.house_vault, 8 + HouseVault::INIT_SPACE, authority, system_program)?;
+    
+    let mut pools: u8 = 0;
+    let mut profiles: u8 = 0;
+    for info in ctx.remaining_accounts {
+        require_keys_eq!(*info.owner, crate::ID, CasinoError::InvalidBatchAccounts);
+        let discriminator: [u8; 8] = info.try_borrow_data()?
+            .get(..8)
+            .and_then(|bytes| bytes.try_into().ok())
+            .ok_or(CasinoError::InvalidBatchAccounts)?;
+        
+        if discriminator == JackpotPool::DISCRIMINATOR {
+            grow_account(info, 8 + JackpotPool::INIT_SPACE, authority, system_program)?;
+            
+            let mut pool = JackpotPool::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"pool", pool.pool_id.to_le_bytes().as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(info.key(), expected, CasinoError::InvalidBatchAccounts);
+            
+            pool.version = STATE_VERSION;
+            pool.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+            pools = pools.saturating_add(1);
+        } else if discriminator == PlayerProfile::DISCRIMINATOR {
+            grow_account(info, 8 + PlayerProfile::INIT_SPACE, authority, system_program)?;
+            
+            // Profile fields added since the account was created read as zero from the grown tail
+            let profile = PlayerProfile::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+            let (expected, _) = Pubkey::find_program_address(
+                &[b"player", profile.player.as_ref()],
+                &crate::ID,
+            );
+            require_keys_eq!(info.key(), expected, CasinoError::InvalidBatchAccounts);
+            profiles = profiles.saturating_add(1);
+        } else {
+            return err!(CasinoError::InvalidBatchAccounts);
+        }
+    }
+    
+    // 0 for a config created before versioning
+    let from_version = config.version;
+    config.version = STATE_VERSION;
+    
+    msg!("Migrated config, vaults, {} pools and {} player profiles from v{} to v{}", pools, profiles, from_version, STATE_VERSION);
+    
+    emit!(StateMigrated {
+        sequence: config.next_event_sequence(),
+        from_version,
+        version: STATE_VERSION,
+        pools,
+        profiles,
+    });
+    
+    config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+    
+    Ok(())
+}
+
+/// Realloc an account up to `len` bytes, with `payer` covering the extra rent
+/// The account's own balance is never used, since vaults and pools hold funds above their rent
+fn grow_account<'info>(
+    info: &AccountInfo<'info>,
+    len: usize,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    if info.data_len() >= len {
+        return Ok(());
+    }
+    
+    let rent = Rent::get()?;
+    let extra_rent = rent.minimum_balance(len).saturating_sub(rent.minimum_balance(info.data_len()));
+    if extra_rent > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            extra_rent,
+        )?;
+    }
+    
+    info.realloc(len, true)?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct Migrate<'info> {
+    /// CHECK: deserialized after it has been grown to the current layout
+    #[account(mut, seeds = [b"config"], bump)]
+    pub config: UncheckedAccount<'info>,
+    
+    /// CHECK: deserialized after it has been grown to the current layout
+    #[account(mut, seeds = [b"reward_vault"], bump)]
+    pub reward_vault: UncheckedAccount<'info>,
+    
+    /// CHECK: only grown; its fields are unchanged
+    #[account(mut, seeds = [b"house_vault"], bump)]
+    pub house_vault: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct StateMigrated {
+    pub sequence: u64,
+    /// Config's layout version before the migration
+    pub from_version: u8,
+    pub version: u8,
+    pub pools: u8,
+    pub profiles: u8,
+}
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Mint, MintTo, SetAuthority, Token, TokenAccount};
+use anchor_spl::token::spl_token::instruction::AuthorityType;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Mint a one-of-one SPL token ticket for the player's draw entry
+/// The entry is then owned by whoever holds the ticket when the round settles
+pub fn mint_draw_ticket(
+    ctx: Context<MintDrawTicket>,
+    entry_index: u32,
+) -> Result<()> {
+    let (round_id, bump) = {
+        let draw_round = ctx.accounts.draw_round.load()?;
+        
+        require!(
+            draw_round.settled == 0,
+            CasinoError::DrawAlreadySettled
+        );
+        
+        require!(
+            entry_index < draw_round.entry_count,
+            CasinoError::InvalidDrawEntry
+        );
+        
+        let entry = draw_round.entries[entry_index as usize];
+        require!(
+            entry.player == ctx.accounts.player.key(),
+            CasinoError::Unauthorized
+        );
+        
+        require!(
+            entry.ticket_mint == Pubkey::default(),
+            CasinoError::TicketAlreadyMinted
+        );
+        
+        (draw_round.round_id, draw_round.bump)
+    };
+    
+    // The draw round PDA is the mint authority
+    let round_id_bytes = round_id.to_le_bytes();
+    let signer_seeds: &[&[&[u8]]] = &[&[b"draw", round_id_bytes.as_ref(), &[bump]]];
+    
+    token::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.ticket_mint.to_account_info(),
+                to: ctx.accounts.ticket_account.to_account_info(),
+                authority: ctx.accounts.draw_round.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        1,
+    )?;
+    
+    // Revoke minting so supply stays at one
+    token::set_authority(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            SetAuthority {
+                current_authority: ctx.accounts.draw_round.to_account_info(),
+                account_or_mint: ctx.accounts.ticket_mint.to_account_info(),
+            },
+            signer_seeds,
+        ),
+        AuthorityType::MintTokens,
+        None,
+    )?;
+    
+    let mut draw_round = ctx.accounts.draw_round.load_mut()?;
+    draw_round.entries[entry_index as usize].ticket_mint = ctx.accounts.ticket_mint.key();
+    
+    msg!("Draw ticket minted: round {}, entry {}", round_id, entry_index);
+    
+    emit!(DrawTicketMinted {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        round_id,
+        entry_index,
+        player: ctx.accounts.player.key(),
+        mint: ctx.accounts.ticket_mint.key(),
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(entry_index: u32)]
+pub struct MintDrawTicket<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"draw", draw_round.load()?.round_id.to_le_bytes().as_ref()],
+        bump = draw_round.load()?.bump
+    )]
+    pub draw_round: AccountLoader<'info, DrawRound>,
+    
+    #[account(
+        init,
+        payer = player,
+        mint::decimals = 0,
+        mint::authority = draw_round,
+        seeds = [b"ticket", draw_round.key().as_ref(), entry_index.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub ticket_mint: Account<'info, Mint>,
+    
+    #[account(
+        init,
+        payer = player,
+        token::mint = ticket_mint,
+        token::authority = player,
+        seeds = [b"ticket_account", ticket_mint.key().as_ref()],
+        bump
+    )]
+    pub ticket_account: Account<'info, TokenAccount>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DrawTicketMinted {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub entry_index: u32,
+    pub player: Pubkey,
+    pub mint: Pubkey,
+}
@@ -1,13 +1,225 @@
 pub mod initialize;
+pub mod initialize_win_history;
+pub mod initialize_stats;
+pub mod initialize_notification_queue;
+pub mod initialize_reserve_pool;
+pub mod initialize_game_vault;
+pub mod initialize_charity_vault;
+pub mod create_pool;
 pub mod contribute_bet;
 pub mod fulfill_jackpot;
+pub mod fulfill_jackpots_batch;
+pub mod double_or_nothing;
+pub mod fulfill_double_or_nothing;
+pub mod create_parlay;
+pub mod fulfill_parlay_leg;
+pub mod claim_parlay;
+pub mod open_side_bet_round;
+pub mod place_side_bet;
+pub mod claim_side_bet;
+pub mod play_dice;
+pub mod fulfill_dice;
+pub mod set_paytable;
+pub mod play_slots;
+pub mod fulfill_slots;
+pub mod open_scratch_batch;
+pub mod buy_scratch_card;
+pub mod reveal_card;
+pub mod close_scratch_batch;
+pub mod open_lotto_round;
+pub mod buy_lotto_ticket;
+pub mod draw_lotto;
+pub mod crank_lotto;
+pub mod set_bonus_wheel;
+pub mod spin_bonus_wheel;
+pub mod fulfill_bonus_wheel;
+pub mod commit_mystery_jackpot;
+pub mod reveal_mystery;
+pub mod set_happy_hours;
+pub mod claim_win;
+pub mod claim_vested_win;
+pub mod claim_reviewed_win;
+pub mod approve_payout;
+pub mod open_dispute;
+pub mod resolve_dispute;
+pub mod claim_community_share;
 pub mod claim_rewards;
+pub mod set_auto_compound;
+pub mod set_claim_delegate;
+pub mod compound_rewards;
+pub mod set_reward_mint;
+pub mod set_emission_rate;
+pub mod set_yield_strategy;
+pub mod deploy_to_strategy;
+pub mod withdraw_from_strategy;
+pub mod update_realized_yield;
+pub mod stake_defi;
+pub mod unstake_defi;
+pub mod initialize_lp_mint;
 pub mod withdraw_house;
+pub mod deploy_to_lending;
+pub mod recall_from_lending;
+pub mod set_treasury_destinations;
+pub mod sweep_house;
+pub mod register_keeper;
+pub mod sweep_expired_bets;
+pub mod fund_bankroll;
+pub mod withdraw_bankroll;
+pub mod withdraw_game_vault;
+pub mod disburse_charity;
 pub mod update_config;
+pub mod set_roles;
+pub mod ack_notifications;
+pub mod export_bet_proof;
+pub mod get_pool_state;
+pub mod migrate;
+pub mod reconcile_pool;
+pub mod initiate_emergency_withdraw;
+pub mod execute_emergency_withdraw;
+pub mod set_shutdown;
+pub mod decommission;
+pub mod assert_invariants;
+pub mod publish_reserves;
+pub mod open_volume_bucket;
+pub mod seed_jackpot;
+pub mod deposit_contribution;
+pub mod register_integrator;
+pub mod claim_integrator_fees;
+pub mod register_referral;
+pub mod claim_referral_earnings;
+pub mod redeem_comp_points;
+pub mod set_vip_tiers;
+pub mod claim_rakeback;
+pub mod claim_cashback;
+pub mod grant_bonus;
+pub mod withdraw_bonus;
+pub mod set_promo_merkle_root;
+pub mod claim_promo;
+pub mod set_gaming_limits;
+pub mod freeze_player;
+pub mod unfreeze_player;
+pub mod add_member;
+pub mod open_draw_round;
+pub mod mint_draw_ticket;
+pub mod settle_draw;
+pub mod create_season;
+pub mod finalize_season;
+pub mod set_operating_hours;
+pub mod set_experiment;
+pub mod set_privacy;
+pub mod set_payout_beneficiary;
+pub mod set_payout_split;
+pub mod set_bootstrap;
 
 pub use initialize::*;
+pub use initialize_win_history::*;
+pub use initialize_stats::*;
+pub use initialize_notification_queue::*;
+pub use initialize_reserve_pool::*;
+pub use initialize_game_vault::*;
+pub use initialize_charity_vault::*;
+pub use create_pool::*;
 pub use contribute_bet::*;
 pub use fulfill_jackpot::*;
+pub use fulfill_jackpots_batch::*;
+pub use double_or_nothing::*;
+pub use fulfill_double_or_nothing::*;
+pub use create_parlay::*;
+pub use fulfill_parlay_leg::*;
+pub use claim_parlay::*;
+pub use open_side_bet_round::*;
+pub use place_side_bet::*;
+pub use claim_side_bet::*;
+pub use play_dice::*;
+pub use fulfill_dice::*;
+pub use set_paytable::*;
+pub use play_slots::*;
+pub use fulfill_slots::*;
+pub use open_scratch_batch::*;
+pub use buy_scratch_card::*;
+pub use reveal_card::*;
+pub use close_scratch_batch::*;
+pub use open_lotto_round::*;
+pub use buy_lotto_ticket::*;
+pub use draw_lotto::*;
+pub use crank_lotto::*;
+pub use set_bonus_wheel::*;
+pub use spin_bonus_wheel::*;
+pub use fulfill_bonus_wheel::*;
+pub use commit_mystery_jackpot::*;
+pub use reveal_mystery::*;
+pub use set_happy_hours::*;
+pub use claim_win::*;
+pub use claim_vested_win::*;
+pub use claim_reviewed_win::*;
+pub use approve_payout::*;
+pub use open_dispute::*;
+pub use resolve_dispute::*;
+pub use claim_community_share::*;
 pub use claim_rewards::*;
+pub use set_auto_compound::*;
+pub use set_claim_delegate::*;
+pub use compound_rewards::*;
+pub use set_reward_mint::*;
+pub use set_emission_rate::*;
+pub use set_yield_strategy::*;
+pub use deploy_to_strategy::*;
+pub use withdraw_from_strategy::*;
+pub use update_realized_yield::*;
+pub use stake_defi::*;
+pub use unstake_defi::*;
+pub use initialize_lp_mint::*;
 pub use withdraw_house::*;
+pub use deploy_to_lending::*;
+pub use recall_from_lending::*;
+pub use set_treasury_destinations::*;
+pub use sweep_house::*;
+pub use register_keeper::*;
+pub use sweep_expired_bets::*;
+pub use fund_bankroll::*;
+pub use withdraw_bankroll::*;
+pub use withdraw_game_vault::*;
+pub use disburse_charity::*;
 pub use update_config::*;
+pub use set_roles::*;
+pub use ack_notifications::*;
+pub use export_bet_proof::*;
+pub use get_pool_state::*;
+pub use migrate::*;
+pub use reconcile_pool::*;
+pub use initiate_emergency_withdraw::*;
+pub use execute_emergency_withdraw::*;
+pub use set_shutdown::*;
+pub use decommission::*;
+pub use assert_invariants::*;
+pub use publish_reserves::*;
+pub use open_volume_bucket::*;
+pub use seed_jackpot::*;
+pub use deposit_contribution::*;
+pub use register_integrator::*;
+pub use claim_integrator_fees::*;
+pub use register_referral::*;
+pub use claim_referral_earnings::*;
+pub use redeem_comp_points::*;
+pub use set_vip_tiers::*;
+pub use claim_rakeback::*;
+pub use claim_cashback::*;
+pub use grant_bonus::*;
+pub use withdraw_bonus::*;
+pub use set_promo_merkle_root::*;
+pub use claim_promo::*;
+pub use set_gaming_limits::*;
+pub use freeze_player::*;
+pub use unfreeze_player::*;
+pub use add_member::*;
+pub use open_draw_round::*;
+pub use mint_draw_ticket::*;
+pub use settle_draw::*;
+pub use create_season::*;
+pub use finalize_season::*;
+pub use set_operating_hours::*;
+pub use set_experiment::*;
+pub use set_privacy::*;
+pub use set_payout_beneficiary::*;
+pub use set_payout_split::*;
+pub use set_bootstrap::*;
@@ -0,0 +1,74 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Contest a bet; any payout on it is frozen until the authority resolves the dispute
+pub fn open_dispute(
+    ctx: Context<OpenDispute>,
+    reason: u8,
+) -> Result<()> {
+    let bet = &mut ctx.accounts.bet;
+    
+    require!(
+        bet.player == ctx.accounts.player.key(),
+        CasinoError::Unauthorized
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    
+    let dispute = &mut ctx.accounts.dispute;
+    dispute.bet = bet.key();
+    dispute.player = bet.player;
+    dispute.reason = reason;
+    dispute.opened_at = now;
+    dispute.status = DISPUTE_OPEN;
+    dispute.bump = ctx.bumps.dispute;
+    
+    bet.disputed = true;
+    
+    msg!("Dispute opened on bet {} (reason {})", bet.key(), reason);
+    
+    emit!(DisputeOpened {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: bet.player,
+        bet: bet.key(),
+        bet_status: bet.status,
+        reason,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct OpenDispute<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Dispute::INIT_SPACE,
+        seeds = [b"dispute", bet.key().as_ref()],
+        bump
+    )]
+    pub dispute: Account<'info, Dispute>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DisputeOpened {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub bet_status: u8,
+    pub reason: u8,
+    pub timestamp: i64,
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Open a raffle round accepting entries until `draw_end_ts` (authority or operator)
+pub fn open_draw_round(
+    ctx: Context<OpenDrawRound>,
+    round_id: u64,
+    draw_end_ts: i64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        draw_end_ts > now,
+        CasinoError::InvalidConfig
+    );
+    
+    let mut draw_round = ctx.accounts.draw_round.load_init()?;
+    draw_round.round_id = round_id;
+    draw_round.draw_start_ts = now;
+    draw_round.draw_end_ts = draw_end_ts;
+    draw_round.bump = ctx.bumps.draw_round;
+    
+    msg!("Draw round {} open until {}", round_id, draw_end_ts);
+    
+    emit!(DrawRoundOpened {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        round_id,
+        draw_start_ts: now,
+        draw_end_ts,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct OpenDrawRound<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + std::mem::size_of::<DrawRound>(),
+        seeds = [b"draw", round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub draw_round: AccountLoader<'info, DrawRound>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DrawRoundOpened {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub draw_start_ts: i64,
+    pub draw_end_ts: i64,
+}
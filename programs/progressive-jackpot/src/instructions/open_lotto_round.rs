@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Open a pick-N lottery round selling tickets until `draw_ts` (authority or operator)
+/// `params.tier_shares_bps[k - 1]`, one per pick, is the share of the pot after rake split among tickets matching `k` numbers
+pub fn open_lotto_round(
+    ctx: Context<OpenLottoRound>,
+    round_id: u64,
+    params: LottoRoundParams,
+) -> Result<()> {
+    let LottoRoundParams { picks, max_number, ticket_price, draw_ts, rake_bps, tier_shares_bps } = params;
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        !config.shutdown,
+        CasinoError::CasinoShutdown
+    );
+    
+    let shares: u32 = tier_shares_bps.iter().map(|&share| share as u32).sum();
+    require!(
+        (1..=MAX_LOTTO_PICKS as u8).contains(&picks)
+            && max_number > picks
+            && max_number <= MAX_LOTTO_NUMBER
+            && ticket_price > 0
+            && draw_ts > Clock::get()?.unix_timestamp
+            && rake_bps <= 10000
+            && tier_shares_bps.len() == picks as usize
+            && shares <= 10000,
+        CasinoError::InvalidLottoRound
+    );
+    
+    let round = &mut ctx.accounts.round;
+    round.round_id = round_id;
+    round.picks = picks;
+    round.max_number = max_number;
+    round.ticket_price = ticket_price;
+    round.draw_ts = draw_ts;
+    round.rake_bps = rake_bps;
+    round.tier_shares_bps[..tier_shares_bps.len()].copy_from_slice(&tier_shares_bps);
+    round.bump = ctx.bumps.round;
+    
+    msg!("Lotto round {} open: pick {} of {} until {}", round_id, picks, max_number, draw_ts);
+    
+    emit!(LottoRoundOpened {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        round_id,
+        picks,
+        max_number,
+        ticket_price,
+        draw_ts,
+        rake_bps,
+        tier_shares_bps: round.tier_shares_bps,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct OpenLottoRound<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + LottoRound::INIT_SPACE,
+        seeds = [b"lotto", round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub round: Account<'info, LottoRound>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LottoRoundOpened {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub picks: u8,
+    pub max_number: u8,
+    pub ticket_price: u64,
+    pub draw_ts: i64,
+    pub rake_bps: u16,
+    pub tier_shares_bps: [u16; MAX_LOTTO_PICKS],
+}
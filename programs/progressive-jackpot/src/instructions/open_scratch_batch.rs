@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Commit a batch of pre-generated scratch cards by the Merkle root of their outcomes (authority or operator)
+/// The batch's total prizes are locked against house withdrawals until it closes
+pub fn open_scratch_batch(
+    ctx: Context<OpenScratchBatch>,
+    batch_id: u64,
+    merkle_root: [u8; 32],
+    card_count: u32,
+    card_price: u64,
+    total_prizes: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        !config.shutdown,
+        CasinoError::CasinoShutdown
+    );
+    
+    require!(
+        card_count > 0 && card_price > 0,
+        CasinoError::InvalidScratchBatch
+    );
+    
+    let house_vault = &mut ctx.accounts.house_vault;
+    let house_info = house_vault.to_account_info();
+    let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+    require!(
+        house_info.lamports() >= reserved.checked_add(total_prizes).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    house_vault.game_liability = house_vault.game_liability
+        .checked_add(total_prizes)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let batch = &mut ctx.accounts.batch;
+    batch.batch_id = batch_id;
+    batch.merkle_root = merkle_root;
+    batch.card_count = card_count;
+    batch.card_price = card_price;
+    batch.total_prizes = total_prizes;
+    batch.opened_by = signer;
+    batch.bump = ctx.bumps.batch;
+    
+    msg!("Scratch batch {} opened: {} cards at {} lamports", batch_id, card_count, card_price);
+    
+    emit!(ScratchBatchOpened {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        batch_id,
+        merkle_root,
+        card_count,
+        card_price,
+        total_prizes,
+        opened_by: signer,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(batch_id: u64)]
+pub struct OpenScratchBatch<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + ScratchBatch::INIT_SPACE,
+        seeds = [b"scratch_batch", batch_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub batch: Account<'info, ScratchBatch>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ScratchBatchOpened {
+    pub sequence: u64,
+    pub batch_id: u64,
+    pub merkle_root: [u8; 32],
+    pub card_count: u32,
+    pub card_price: u64,
+    pub total_prizes: u64,
+    pub opened_by: Pubkey,
+}
@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Open a side-bet round on how many bets the pool's next jackpot hit takes (authority or operator)
+/// `bucket_bounds` are the increasing `bets_since_win` boundaries; the hit settles the round in `fulfill_jackpot`
+pub fn open_side_bet_round(
+    ctx: Context<OpenSideBetRound>,
+    round_id: u64,
+    bucket_bounds: Vec<u64>,
+    rake_bps: u16,
+    betting_closes_at: i64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let pool = &mut ctx.accounts.pool;
+    require!(
+        pool.side_bet_pool.is_none(),
+        CasinoError::SideBetRoundOpen
+    );
+    
+    require!(
+        !bucket_bounds.is_empty()
+            && bucket_bounds.len() < MAX_SIDE_BET_BUCKETS
+            && bucket_bounds[0] > 0
+            && bucket_bounds.windows(2).all(|pair| pair[0] < pair[1])
+            && rake_bps < 10000
+            && betting_closes_at > Clock::get()?.unix_timestamp,
+        CasinoError::InvalidSideBetRound
+    );
+    
+    let mut bounds = [0u64; MAX_SIDE_BET_BUCKETS];
+    bounds[..bucket_bounds.len()].copy_from_slice(&bucket_bounds);
+    
+    let side_bet_pool = &mut ctx.accounts.side_bet_pool;
+    side_bet_pool.pool = pool.key();
+    side_bet_pool.round_id = round_id;
+    side_bet_pool.bucket_count = bucket_bounds.len() as u8 + 1;
+    side_bet_pool.bucket_bounds = bounds;
+    side_bet_pool.bucket_totals = [0; MAX_SIDE_BET_BUCKETS];
+    side_bet_pool.total_staked = 0;
+    side_bet_pool.rake_bps = rake_bps;
+    side_bet_pool.betting_closes_at = betting_closes_at;
+    side_bet_pool.settled = false;
+    side_bet_pool.hit_at_bets = 0;
+    side_bet_pool.winning_bucket = 0;
+    side_bet_pool.distributable = 0;
+    side_bet_pool.bump = ctx.bumps.side_bet_pool;
+    
+    pool.side_bet_pool = Some(side_bet_pool.key());
+    
+    msg!("Side-bet round {} open on pool {} until {}", round_id, pool.pool_id, betting_closes_at);
+    
+    emit!(SideBetRoundOpened {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        pool: pool.key(),
+        side_bet_pool: side_bet_pool.key(),
+        round_id,
+        bucket_bounds,
+        rake_bps,
+        betting_closes_at,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(round_id: u64)]
+pub struct OpenSideBetRound<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(
+        init,
+        payer = signer,
+        space = 8 + SideBetPool::INIT_SPACE,
+        seeds = [b"side_bet_pool", pool.key().as_ref(), round_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub side_bet_pool: Account<'info, SideBetPool>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SideBetRoundOpened {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub side_bet_pool: Pubkey,
+    pub round_id: u64,
+    pub bucket_bounds: Vec<u64>,
+    pub rake_bps: u16,
+    pub betting_closes_at: i64,
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Create the volume bucket for UTC `day` (permissionless)
+/// Only today's or tomorrow's bucket can be opened, so dashboards can pre-open the next day
+pub fn open_volume_bucket(
+    ctx: Context<OpenVolumeBucket>,
+    day: i64,
+) -> Result<()> {
+    let today = Clock::get()?.unix_timestamp.div_euclid(86400);
+    require!(
+        day == today || day == today + 1,
+        CasinoError::InvalidVolumeBucketDay
+    );
+    
+    let bucket = &mut ctx.accounts.volume_bucket;
+    bucket.day = day;
+    bucket.bump = ctx.bumps.volume_bucket;
+    
+    msg!("Volume bucket opened for day {}", day);
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(day: i64)]
+pub struct OpenVolumeBucket<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VolumeBucket::INIT_SPACE,
+        seeds = [b"volume", day.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub volume_bucket: Account<'info, VolumeBucket>,
+    
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
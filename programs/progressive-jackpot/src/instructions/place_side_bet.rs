@@ -0,0 +1,143 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::{check_wager_allowed, record_wager};
+
+/// Stake on the `bets_since_win` bucket the pool's next jackpot hit will land in
+/// Buckets the pool has already counted past can't be backed
+pub fn place_side_bet(
+    ctx: Context<PlaceSideBet>,
+    bucket: u8,
+    amount: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let side_bet_pool = &mut ctx.accounts.side_bet_pool;
+    
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        amount,
+    )?;
+    record_wager(&mut ctx.accounts.player_profile, ctx.accounts.player.key(), ctx.bumps.player_profile, amount)?;
+    
+    require!(amount >= config.min_bet, CasinoError::BetTooSmall);
+    
+    require!(
+        !side_bet_pool.settled && Clock::get()?.unix_timestamp < side_bet_pool.betting_closes_at,
+        CasinoError::SideBetClosed
+    );
+    
+    require!(
+        bucket < side_bet_pool.bucket_count
+            && bucket >= side_bet_pool.bucket_for(ctx.accounts.pool.bets_since_win),
+        CasinoError::InvalidSideBetBucket
+    );
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.player.to_account_info(),
+                to: side_bet_pool.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let index = bucket as usize;
+    side_bet_pool.bucket_totals[index] = side_bet_pool.bucket_totals[index]
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    side_bet_pool.total_staked = side_bet_pool.total_staked
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let entry = &mut ctx.accounts.side_bet_entry;
+    if entry.player == Pubkey::default() {
+        entry.side_bet_pool = side_bet_pool.key();
+        entry.player = ctx.accounts.player.key();
+        entry.bump = ctx.bumps.side_bet_entry;
+    }
+    entry.stakes[index] = entry.stakes[index]
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Side bet: {} lamports on bucket {}", amount, bucket);
+    
+    emit!(SideBetPlaced {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        side_bet_pool: side_bet_pool.key(),
+        bucket,
+        amount,
+        bucket_total: side_bet_pool.bucket_totals[index],
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PlaceSideBet<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"side_bet_pool", pool.key().as_ref(), side_bet_pool.round_id.to_le_bytes().as_ref()],
+        bump = side_bet_pool.bump
+    )]
+    pub side_bet_pool: Account<'info, SideBetPool>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + SideBetEntry::INIT_SPACE,
+        seeds = [b"side_bet", side_bet_pool.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub side_bet_entry: Account<'info, SideBetEntry>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SideBetPlaced {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub side_bet_pool: Pubkey,
+    pub bucket: u8,
+    pub amount: u64,
+    pub bucket_total: u64,
+}
@@ -0,0 +1,259 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::{check_wager_allowed, record_wager};
+
+/// Roll dice at player-chosen odds: over `target` if `over_under`, under it otherwise
+/// The jackpot and DeFi legs are contributed like a normal bet; the rest is held against the house until `fulfill_dice`
+pub fn play_dice(
+    ctx: Context<PlayDice>,
+    amount: u64,
+    target: u16,
+    over_under: bool,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        config.dice_house_edge_bps > 0,
+        CasinoError::DiceDisabled
+    );
+    
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        amount,
+    )?;
+    record_wager(&mut ctx.accounts.player_profile, ctx.accounts.player.key(), ctx.bumps.player_profile, amount)?;
+    
+    require!(
+        pool.emergency_unlocks_at == 0,
+        CasinoError::EmergencyWithdrawPending
+    );
+    
+    let (min_bet, max_bet) = pool.bet_limits(config);
+    require!(amount >= min_bet, CasinoError::BetTooSmall);
+    require!(amount <= max_bet, CasinoError::BetTooLarge);
+    
+    // Rolls are 0..10000: over wins at or above the target, under wins below it
+    require!(target <= 10000, CasinoError::InvalidDiceTarget);
+    let win_chance_bps = if over_under { 10000 - target } else { target };
+    require!(
+        (MIN_DICE_CHANCE_BPS..=MAX_DICE_CHANCE_BPS).contains(&win_chance_bps),
+        CasinoError::InvalidDiceTarget
+    );
+    
+    let payout = (amount as u128)
+        .checked_mul(10000 - config.dice_house_edge_bps as u128)
+        .and_then(|v| v.checked_div(win_chance_bps as u128))
+        .ok_or(CasinoError::MathOverflow)?;
+    let payout = u64::try_from(payout).map_err(|_| CasinoError::MathOverflow)?;
+    require!(payout > amount, CasinoError::InvalidDiceTarget);
+    
+    let jackpot_contribution = amount
+        .checked_mul(config.jackpot_percentage as u64)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let defi_contribution = amount
+        .checked_mul(config.defi_percentage as u64)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let stake = amount - jackpot_contribution - defi_contribution;
+    
+    // Set aside what the house would add on a win so it can't be withdrawn while the roll is open
+    let liability = payout - stake;
+    let house_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(house_info.data_len())?;
+    require!(
+        house_info.lamports() >= reserved.checked_add(liability).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    ctx.accounts.house_vault.game_liability = ctx.accounts.house_vault.game_liability
+        .checked_add(liability)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let legs = [
+        (pool.to_account_info(), jackpot_contribution),
+        (reward_vault.to_account_info(), defi_contribution),
+        (ctx.accounts.dice_roll.to_account_info(), stake),
+    ];
+    
+    for (to, lamports) in legs {
+        if lamports == 0 {
+            continue;
+        }
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to,
+                },
+            ),
+            lamports,
+        )?;
+    }
+    
+    pool.balance = pool.balance
+        .checked_add(jackpot_contribution)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // The DeFi cut is the player's stake in the reward vault
+    reward_vault.accrue(now)?;
+    
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    if reward_claim.user == Pubkey::default() {
+        reward_claim.user = ctx.accounts.player.key();
+        reward_claim.last_claim = now;
+        reward_claim.bump = ctx.bumps.reward_claim;
+    }
+    reward_claim.deposit(defi_contribution, reward_vault)?;
+    
+    reward_vault.staked_amount = reward_vault.staked_amount
+        .checked_add(defi_contribution)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let dice_roll = &mut ctx.accounts.dice_roll;
+    dice_roll.player = ctx.accounts.player.key();
+    dice_roll.amount = amount;
+    dice_roll.stake = stake;
+    dice_roll.target = target;
+    dice_roll.roll_over = over_under;
+    dice_roll.payout = payout;
+    dice_roll.placed_slot = Clock::get()?.slot;
+    dice_roll.created_at = now;
+    dice_roll.bump = ctx.bumps.dice_roll;
+    
+    let mut request_id = [0u8; 32];
+    request_id[..8].copy_from_slice(&now.to_le_bytes());
+    
+    let vrf_request = &mut ctx.accounts.vrf_request;
+    vrf_request.bet = dice_roll.key();
+    vrf_request.player = dice_roll.player;
+    vrf_request.timestamp = now;
+    vrf_request.request_id = request_id;
+    vrf_request.status = 0; // pending
+    vrf_request.result = None;
+    vrf_request.bump = ctx.bumps.vrf_request;
+    
+    let config = &mut ctx.accounts.config;
+    config.total_bets = config.total_bets
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    config.open_bets = config.open_bets
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Dice: {} lamports, {}% to win {}", amount, win_chance_bps / 100, payout);
+    
+    emit!(DicePlaced {
+        sequence: config.next_event_sequence(),
+        player: dice_roll.player,
+        dice_roll: dice_roll.key(),
+        vrf_request: vrf_request.key(),
+        amount,
+        target,
+        over_under,
+        win_chance_bps,
+        payout,
+        jackpot_contribution,
+        defi_contribution,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct PlayDice<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + RewardClaim::INIT_SPACE,
+        seeds = [b"reward_claim", player.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + DiceRoll::INIT_SPACE,
+        seeds = [b"dice", player.key().as_ref(), amount.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub dice_roll: Account<'info, DiceRoll>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + VrfRequest::INIT_SPACE,
+        seeds = [b"vrf_request", dice_roll.key().as_ref()],
+        bump
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DicePlaced {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub dice_roll: Pubkey,
+    pub vrf_request: Pubkey,
+    pub amount: u64,
+    pub target: u16,
+    pub over_under: bool,
+    pub win_chance_bps: u16,
+    pub payout: u64,
+    pub jackpot_contribution: u64,
+    pub defi_contribution: u64,
+}
@@ -0,0 +1,260 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::contribute_bet::{check_wager_allowed, record_wager};
+
+/// Spin the slots against the paytable; a jackpot outcome wins the progressive pool
+/// The jackpot and DeFi legs are contributed like a normal bet; the rest is held against the house until `fulfill_slots`
+pub fn play_slots(
+    ctx: Context<PlaySlots>,
+    amount: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    let paytable = &mut ctx.accounts.paytable;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        paytable.entry_count > 0,
+        CasinoError::SlotsDisabled
+    );
+    
+    check_wager_allowed(
+        config,
+        &ctx.accounts.player_profile,
+        &ctx.accounts.frozen_player,
+        &ctx.accounts.membership,
+        &ctx.accounts.gaming_limits,
+        amount,
+    )?;
+    record_wager(&mut ctx.accounts.player_profile, ctx.accounts.player.key(), ctx.bumps.player_profile, amount)?;
+    
+    require!(
+        pool.emergency_unlocks_at == 0,
+        CasinoError::EmergencyWithdrawPending
+    );
+    
+    let (min_bet, max_bet) = pool.bet_limits(config);
+    require!(amount >= min_bet, CasinoError::BetTooSmall);
+    require!(amount <= max_bet, CasinoError::BetTooLarge);
+    
+    require!(
+        pool.balance >= config.min_pool_seed,
+        CasinoError::PoolNotSeeded
+    );
+    
+    let jackpot_contribution = amount
+        .checked_mul(config.jackpot_percentage as u64)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let defi_contribution = amount
+        .checked_mul(config.defi_percentage as u64)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let stake = amount - jackpot_contribution - defi_contribution;
+    
+    // Lock what the house would add on the best fixed outcome; jackpot outcomes are paid by the pool
+    let mut best_payout: u64 = 0;
+    for entry in paytable.entries[..paytable.entry_count as usize].iter().filter(|entry| !entry.jackpot) {
+        let payout = (amount as u128)
+            .checked_mul(entry.multiplier_bps as u128)
+            .map(|v| v / 10000)
+            .ok_or(CasinoError::MathOverflow)?;
+        best_payout = best_payout.max(u64::try_from(payout).map_err(|_| CasinoError::MathOverflow)?);
+    }
+    let liability = best_payout.saturating_sub(stake);
+    
+    let house_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(house_info.data_len())?;
+    require!(
+        house_info.lamports() >= reserved.checked_add(liability).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    ctx.accounts.house_vault.game_liability = ctx.accounts.house_vault.game_liability
+        .checked_add(liability)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let legs = [
+        (pool.to_account_info(), jackpot_contribution),
+        (reward_vault.to_account_info(), defi_contribution),
+        (ctx.accounts.slot_spin.to_account_info(), stake),
+    ];
+    
+    for (to, lamports) in legs {
+        if lamports == 0 {
+            continue;
+        }
+        
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.player.to_account_info(),
+                    to,
+                },
+            ),
+            lamports,
+        )?;
+    }
+    
+    pool.balance = pool.balance
+        .checked_add(jackpot_contribution)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Spins can hit the jackpot, so they count toward the drought like bets
+    pool.bets_since_win = pool.bets_since_win
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // The DeFi cut is the player's stake in the reward vault
+    reward_vault.accrue(now)?;
+    
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    if reward_claim.user == Pubkey::default() {
+        reward_claim.user = ctx.accounts.player.key();
+        reward_claim.last_claim = now;
+        reward_claim.bump = ctx.bumps.reward_claim;
+    }
+    reward_claim.deposit(defi_contribution, reward_vault)?;
+    
+    reward_vault.staked_amount = reward_vault.staked_amount
+        .checked_add(defi_contribution)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    paytable.open_spins = paytable.open_spins
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let slot_spin = &mut ctx.accounts.slot_spin;
+    slot_spin.player = ctx.accounts.player.key();
+    slot_spin.pool = pool.key();
+    slot_spin.amount = amount;
+    slot_spin.stake = stake;
+    slot_spin.liability = liability;
+    slot_spin.placed_slot = Clock::get()?.slot;
+    slot_spin.created_at = now;
+    slot_spin.bump = ctx.bumps.slot_spin;
+    
+    let mut request_id = [0u8; 32];
+    request_id[..8].copy_from_slice(&now.to_le_bytes());
+    
+    let vrf_request = &mut ctx.accounts.vrf_request;
+    vrf_request.bet = slot_spin.key();
+    vrf_request.player = slot_spin.player;
+    vrf_request.timestamp = now;
+    vrf_request.request_id = request_id;
+    vrf_request.status = 0; // pending
+    vrf_request.result = None;
+    vrf_request.bump = ctx.bumps.vrf_request;
+    
+    let config = &mut ctx.accounts.config;
+    config.total_bets = config.total_bets
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    config.open_bets = config.open_bets
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Slots: {} lamports spun", amount);
+    
+    emit!(SlotsPlaced {
+        sequence: config.next_event_sequence(),
+        player: slot_spin.player,
+        slot_spin: slot_spin.key(),
+        vrf_request: vrf_request.key(),
+        amount,
+        jackpot_contribution,
+        defi_contribution,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(amount: u64)]
+pub struct PlaySlots<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + RewardClaim::INIT_SPACE,
+        seeds = [b"reward_claim", player.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    #[account(mut, seeds = [b"paytable"], bump = paytable.bump)]
+    pub paytable: Account<'info, Paytable>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + SlotSpin::INIT_SPACE,
+        seeds = [b"slots", player.key().as_ref(), amount.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub slot_spin: Account<'info, SlotSpin>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + VrfRequest::INIT_SPACE,
+        seeds = [b"vrf_request", slot_spin.key().as_ref()],
+        bump
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// CHECK: player's GamingLimits PDA; may be uninitialized, validated in `enforce_gaming_limits`
+    #[account(mut, seeds = [b"limits", player.key().as_ref()], bump)]
+    pub gaming_limits: UncheckedAccount<'info>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    /// CHECK: player's Membership PDA; must exist while the allowlist is enabled
+    #[account(seeds = [b"member", player.key().as_ref()], bump)]
+    pub membership: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct SlotsPlaced {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub slot_spin: Pubkey,
+    pub vrf_request: Pubkey,
+    pub amount: u64,
+    pub jackpot_contribution: u64,
+    pub defi_contribution: u64,
+}
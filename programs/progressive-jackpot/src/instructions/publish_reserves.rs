@@ -0,0 +1,88 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Write the pool's `ReserveSnapshot` so solvency can be checked from one account (permissionless)
+/// Can be republished once per epoch
+pub fn publish_reserves(
+    ctx: Context<PublishReserves>,
+) -> Result<()> {
+    let clock = Clock::get()?;
+    let rent = Rent::get()?;
+    let snapshot = &mut ctx.accounts.snapshot;
+    
+    require!(
+        snapshot.timestamp == 0 || clock.epoch > snapshot.epoch,
+        CasinoError::ReservesAlreadyPublished
+    );
+    
+    let pool = &ctx.accounts.pool;
+    let pool_info = pool.to_account_info();
+    let house_info = ctx.accounts.house_vault.to_account_info();
+    
+    snapshot.pool = pool.key();
+    snapshot.pool_balance = pool.balance;
+    snapshot.pending_payouts = pool.pending_payouts;
+    snapshot.vesting_outstanding = pool.vesting_outstanding;
+    snapshot.pool_lamports = pool_info.lamports()
+        .saturating_sub(rent.minimum_balance(pool_info.data_len()));
+    snapshot.reserve_balance = ctx.accounts.reserve_pool.balance;
+    snapshot.house_vault_lamports = house_info.lamports()
+        .saturating_sub(rent.minimum_balance(house_info.data_len()));
+    snapshot.house_fees = ctx.accounts.house_vault.accumulated_house_fees;
+    snapshot.epoch = clock.epoch;
+    snapshot.timestamp = clock.unix_timestamp;
+    snapshot.bump = ctx.bumps.snapshot;
+    
+    msg!("Reserves published for pool {} in epoch {}", pool.pool_id, clock.epoch);
+    
+    emit!(ReservesPublished {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        pool: snapshot.pool,
+        epoch: snapshot.epoch,
+        pool_balance: snapshot.pool_balance,
+        pending_payouts: snapshot.pending_payouts,
+        pool_lamports: snapshot.pool_lamports,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct PublishReserves<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(seeds = [b"reserve_pool"], bump = reserve_pool.bump)]
+    pub reserve_pool: Account<'info, ReservePool>,
+    
+    #[account(seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = publisher,
+        space = 8 + ReserveSnapshot::INIT_SPACE,
+        seeds = [b"reserves", pool.key().as_ref()],
+        bump
+    )]
+    pub snapshot: Account<'info, ReserveSnapshot>,
+    
+    #[account(mut)]
+    pub publisher: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ReservesPublished {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub epoch: u64,
+    pub pool_balance: u64,
+    pub pending_payouts: u64,
+    pub pool_lamports: u64,
+}
@@ -0,0 +1,171 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token::{self, spl_token, CloseAccount, Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::CasinoError;
+use super::deploy_to_lending::{token_lending_ix, REDEEM_RESERVE_COLLATERAL};
+
+/// Redeem lending collateral back into the house vault as house fees (authority only)
+/// Anything redeemed above the principal those tokens cover is recorded as yield
+pub fn recall_from_lending(
+    ctx: Context<RecallFromLending>,
+    collateral_amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let position = &ctx.accounts.yield_position;
+    require!(
+        collateral_amount > 0 && collateral_amount <= position.collateral_amount,
+        CasinoError::InvalidAmount
+    );
+    
+    let liquidity_before = ctx.accounts.wsol_account.amount;
+    
+    // Redeem takes the reserve's collateral mint before its liquidity supply
+    let lending_accounts = [
+        ctx.accounts.collateral_account.to_account_info(),
+        ctx.accounts.wsol_account.to_account_info(),
+        ctx.accounts.reserve.to_account_info(),
+        ctx.accounts.reserve_collateral_mint.to_account_info(),
+        ctx.accounts.reserve_liquidity_supply.to_account_info(),
+        ctx.accounts.lending_market.to_account_info(),
+        ctx.accounts.lending_market_authority.to_account_info(),
+        ctx.accounts.yield_position.to_account_info(),
+        ctx.accounts.token_program.to_account_info(),
+    ];
+    let ix = token_lending_ix(
+        position.lending_program,
+        REDEEM_RESERVE_COLLATERAL,
+        collateral_amount,
+        &lending_accounts,
+    );
+    let signer_seeds: &[&[&[u8]]] = &[&[b"yield_position", &[position.bump]]];
+    invoke_signed(
+        &ix,
+        &[&lending_accounts[..], &[ctx.accounts.lending_program.to_account_info()]].concat(),
+        signer_seeds,
+    )?;
+    
+    ctx.accounts.wsol_account.reload()?;
+    let redeemed = ctx.accounts.wsol_account.amount
+        .checked_sub(liquidity_before)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Unwrap by closing the staging account into the house vault; its rent goes along with the SOL
+    token::close_account(CpiContext::new_with_signer(
+        ctx.accounts.token_program.to_account_info(),
+        CloseAccount {
+            account: ctx.accounts.wsol_account.to_account_info(),
+            destination: ctx.accounts.house_vault.to_account_info(),
+            authority: ctx.accounts.yield_position.to_account_info(),
+        },
+        signer_seeds,
+    ))?;
+    
+    let position = &mut ctx.accounts.yield_position;
+    let principal = ((position.deployed_lamports as u128) * (collateral_amount as u128)
+        / (position.collateral_amount as u128)) as u64;
+    let yield_earned = redeemed.saturating_sub(principal);
+    
+    position.collateral_amount -= collateral_amount;
+    position.deployed_lamports -= principal;
+    position.total_yield = position.total_yield
+        .checked_add(yield_earned)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.accumulated_house_fees = house_vault.accumulated_house_fees
+        .checked_add(redeemed)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Recalled {} lamports from lending ({} yield)", redeemed, yield_earned);
+    
+    emit!(LendingRecalled {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        reserve: position.reserve,
+        collateral_amount,
+        amount: redeemed,
+        yield_earned,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RecallFromLending<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"yield_position"],
+        bump = yield_position.bump,
+        has_one = reserve @ CasinoError::LendingReserveMismatch,
+        has_one = lending_program @ CasinoError::LendingReserveMismatch
+    )]
+    pub yield_position: Account<'info, YieldPosition>,
+    
+    /// Wrapped SOL staging account, closed into the house vault after each redeem
+    #[account(
+        init_if_needed,
+        payer = authority,
+        token::mint = native_mint,
+        token::authority = yield_position,
+        seeds = [b"yield_wsol"],
+        bump
+    )]
+    pub wsol_account: Account<'info, TokenAccount>,
+    
+    #[account(address = spl_token::native_mint::ID)]
+    pub native_mint: Account<'info, Mint>,
+    
+    #[account(
+        mut,
+        seeds = [b"yield_collateral", reserve_collateral_mint.key().as_ref()],
+        bump
+    )]
+    pub collateral_account: Account<'info, TokenAccount>,
+    
+    /// CHECK: must be the position's reserve; the rest is validated by the lending program
+    #[account(mut)]
+    pub reserve: UncheckedAccount<'info>,
+    
+    /// CHECK: validated by the lending program
+    #[account(mut)]
+    pub reserve_liquidity_supply: UncheckedAccount<'info>,
+    
+    #[account(mut, address = yield_position.collateral_mint)]
+    pub reserve_collateral_mint: Account<'info, Mint>,
+    
+    /// CHECK: validated by the lending program
+    pub lending_market: UncheckedAccount<'info>,
+    
+    /// CHECK: validated by the lending program
+    pub lending_market_authority: UncheckedAccount<'info>,
+    
+    /// CHECK: must be the position's lending program
+    #[account(executable)]
+    pub lending_program: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct LendingRecalled {
+    pub sequence: u64,
+    pub reserve: Pubkey,
+    pub collateral_amount: u64,
+    pub amount: u64,
+    pub yield_earned: u64,
+}
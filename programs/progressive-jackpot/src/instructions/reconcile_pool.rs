@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Compare a pool's recorded balance with its lamports net of rent and pending payouts (authority only)
+/// Emits `PoolDrift`; with `correct` set, the recorded balance is overwritten with the actual one
+pub fn reconcile_pool(
+    ctx: Context<ReconcilePool>,
+    correct: bool,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let pool_info = ctx.accounts.pool.to_account_info();
+    let rent = Rent::get()?.minimum_balance(pool_info.data_len());
+    let pool = &mut ctx.accounts.pool;
+    
+    let actual_balance = pool_info.lamports()
+        .saturating_sub(rent)
+        .saturating_sub(pool.pending_payouts);
+    let recorded_balance = pool.balance;
+    let drift = i64::try_from(actual_balance as i128 - recorded_balance as i128)
+        .map_err(|_| CasinoError::MathOverflow)?;
+    
+    let corrected = correct && drift != 0;
+    if corrected {
+        pool.balance = actual_balance;
+    }
+    
+    msg!("Pool {} drift: {} (corrected: {})", pool.pool_id, drift, corrected);
+    
+    emit!(PoolDrift {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        pool: ctx.accounts.pool.key(),
+        recorded_balance,
+        actual_balance,
+        drift,
+        corrected,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReconcilePool<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct PoolDrift {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    /// `pool.balance` before reconciliation
+    pub recorded_balance: u64,
+    /// Pool lamports minus rent and pending payouts
+    pub actual_balance: u64,
+    /// `actual_balance - recorded_balance`
+    pub drift: i64,
+    pub corrected: bool,
+}
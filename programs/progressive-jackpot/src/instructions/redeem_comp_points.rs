@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player converts comp points into bonus-bet credit spent on their next bets
+pub fn redeem_comp_points(
+    ctx: Context<RedeemCompPoints>,
+    points: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let player_profile = &mut ctx.accounts.player_profile;
+    
+    require!(
+        config.comp_point_value > 0,
+        CasinoError::InvalidConfig
+    );
+    
+    require!(
+        points > 0 && points >= config.min_comp_redemption,
+        CasinoError::CompRedemptionTooSmall
+    );
+    
+    require!(
+        points <= player_profile.comp_points,
+        CasinoError::InsufficientCompPoints
+    );
+    
+    let credit = points
+        .checked_mul(config.comp_point_value)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    player_profile.comp_points -= points;
+    player_profile.bonus_credit = player_profile.bonus_credit
+        .checked_add(credit)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Redeemed {} comp points for {} lamports of bonus credit", points, credit);
+    
+    emit!(CompPointsRedeemed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        points,
+        credit,
+        bonus_credit: player_profile.bonus_credit,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RedeemCompPoints<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"player", player.key().as_ref()], bump = player_profile.bump)]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct CompPointsRedeemed {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub points: u64,
+    pub credit: u64,
+    pub bonus_credit: u64,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Register a white-label partner and its share of the house fee (authority only)
+pub fn register_integrator(
+    ctx: Context<RegisterIntegrator>,
+    partner: Pubkey,
+    fee_share_bps: u16,
+    caller_program: Pubkey,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        fee_share_bps <= 10000,
+        CasinoError::InvalidConfig
+    );
+    
+    let integrator = &mut ctx.accounts.integrator;
+    integrator.partner = partner;
+    integrator.fee_share_bps = fee_share_bps;
+    integrator.total_bets = 0;
+    integrator.total_volume = 0;
+    integrator.total_fees_accrued = 0;
+    integrator.pending_fees = 0;
+    integrator.caller_program = caller_program;
+    integrator.bump = ctx.bumps.integrator;
+    
+    msg!("Integrator registered: {} ({} bps of house fee)", partner, fee_share_bps);
+    
+    emit!(IntegratorRegistered {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        partner,
+        fee_share_bps,
+        caller_program,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(partner: Pubkey)]
+pub struct RegisterIntegrator<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Integrator::INIT_SPACE,
+        seeds = [b"integrator", partner.as_ref()],
+        bump
+    )]
+    pub integrator: Account<'info, Integrator>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct IntegratorRegistered {
+    pub sequence: u64,
+    pub partner: Pubkey,
+    pub fee_share_bps: u16,
+    pub caller_program: Pubkey,
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Register the signer as a keeper eligible for crank fees
+pub fn register_keeper(
+    ctx: Context<RegisterKeeper>,
+) -> Result<()> {
+    let keeper = &mut ctx.accounts.keeper;
+    keeper.authority = ctx.accounts.authority.key();
+    keeper.cranks = 0;
+    keeper.total_earned = 0;
+    keeper.registered_at = Clock::get()?.unix_timestamp;
+    keeper.bump = ctx.bumps.keeper;
+    
+    msg!("Keeper registered: {}", keeper.authority);
+    
+    emit!(KeeperRegistered {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        keeper: keeper.authority,
+    });
+    
+    Ok(())
+}
+
+/// Pay a keeper `Config::keeper_fee` per crank out of accumulated house fees
+/// Shortfalls in the house vault reduce the fee rather than blocking the crank
+pub fn pay_keeper<'info>(
+    config: &mut Config,
+    house_vault: &mut Account<'info, HouseVault>,
+    keeper: &mut Option<Account<'info, Keeper>>,
+    keeper_authority: &Option<Signer<'info>>,
+    cranks: u64,
+) -> Result<()> {
+    let Some(keeper) = keeper.as_mut() else {
+        return Ok(());
+    };
+    
+    let authority = keeper_authority.as_ref().ok_or(CasinoError::InvalidKeeper)?;
+    require_keys_eq!(
+        keeper.authority,
+        authority.key(),
+        CasinoError::InvalidKeeper
+    );
+    
+    let house_info = house_vault.to_account_info();
+    let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+    let fee = config.keeper_fee
+        .saturating_mul(cranks)
+        .min(house_vault.accumulated_house_fees)
+        .min(house_info.lamports().saturating_sub(reserved));
+    
+    if fee > 0 {
+        **house_info.try_borrow_mut_lamports()? -= fee;
+        **authority.to_account_info().try_borrow_mut_lamports()? += fee;
+        
+        house_vault.accumulated_house_fees -= fee;
+    }
+    
+    keeper.cranks = keeper.cranks
+        .checked_add(cranks)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    keeper.total_earned = keeper.total_earned
+        .checked_add(fee)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    emit!(KeeperPaid {
+        sequence: config.next_event_sequence(),
+        keeper: keeper.authority,
+        cranks,
+        fee,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterKeeper<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Keeper::INIT_SPACE,
+        seeds = [b"keeper", authority.key().as_ref()],
+        bump
+    )]
+    pub keeper: Account<'info, Keeper>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct KeeperRegistered {
+    pub sequence: u64,
+    pub keeper: Pubkey,
+}
+
+#[event]
+pub struct KeeperPaid {
+    pub sequence: u64,
+    pub keeper: Pubkey,
+    pub cranks: u64,
+    pub fee: u64,
+}
@@ -0,0 +1,79 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player records the referrer credited on their future bets (once per player)
+pub fn register_referral(
+    ctx: Context<RegisterReferral>,
+    referrer: Pubkey,
+) -> Result<()> {
+    let player = ctx.accounts.player.key();
+    
+    require!(
+        referrer != player,
+        CasinoError::SelfReferral
+    );
+    
+    let referral = &mut ctx.accounts.referral;
+    referral.player = player;
+    referral.referrer = referrer;
+    referral.bump = ctx.bumps.referral;
+    
+    // Earnings account is shared by everyone the referrer brings in
+    let earnings = &mut ctx.accounts.referral_earnings;
+    if earnings.referrer == Pubkey::default() {
+        earnings.referrer = referrer;
+        earnings.bump = ctx.bumps.referral_earnings;
+    }
+    
+    earnings.referred_players = earnings.referred_players
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Referral registered: {} referred by {}", player, referrer);
+    
+    emit!(ReferralRegistered {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player,
+        referrer,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(referrer: Pubkey)]
+pub struct RegisterReferral<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + Referral::INIT_SPACE,
+        seeds = [b"referral", player.key().as_ref()],
+        bump
+    )]
+    pub referral: Account<'info, Referral>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + ReferralEarnings::INIT_SPACE,
+        seeds = [b"referral_earnings", referrer.as_ref()],
+        bump
+    )]
+    pub referral_earnings: Account<'info, ReferralEarnings>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ReferralRegistered {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub referrer: Pubkey,
+}
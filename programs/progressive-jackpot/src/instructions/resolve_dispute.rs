@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Close out a dispute and unfreeze the bet's payout (authority only)
+pub fn resolve_dispute(
+    ctx: Context<ResolveDispute>,
+    upheld: bool,
+    resolution_hash: [u8; 32],
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let dispute = &mut ctx.accounts.dispute;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        dispute.status == DISPUTE_OPEN,
+        CasinoError::DisputeNotOpen
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    
+    dispute.status = if upheld { DISPUTE_UPHELD } else { DISPUTE_REJECTED };
+    dispute.resolved_by = ctx.accounts.authority.key();
+    dispute.resolved_at = now;
+    dispute.resolution_hash = resolution_hash;
+    
+    ctx.accounts.bet.disputed = false;
+    
+    msg!("Dispute on bet {} resolved (upheld: {})", dispute.bet, upheld);
+    
+    emit!(DisputeResolved {
+        sequence: config.next_event_sequence(),
+        authority: ctx.accounts.authority.key(),
+        player: dispute.player,
+        bet: dispute.bet,
+        upheld,
+        resolution_hash,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut)]
+    pub bet: Account<'info, Bet>,
+    
+    #[account(mut, seeds = [b"dispute", bet.key().as_ref()], bump = dispute.bump)]
+    pub dispute: Account<'info, Dispute>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub upheld: bool,
+    pub resolution_hash: [u8; 32],
+    pub timestamp: i64,
+}
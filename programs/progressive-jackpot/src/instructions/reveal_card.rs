@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Reveal a bought scratch card's pre-committed outcome, paying any prize and closing the card
+/// Anyone holding the outcome and its proof may reveal, so the UI can show the card without waiting for VRF
+pub fn reveal_card(
+    ctx: Context<RevealCard>,
+    index: u32,
+    outcome: ScratchOutcome,
+    proof: Vec<[u8; 32]>,
+) -> Result<()> {
+    let batch = &mut ctx.accounts.batch;
+    
+    require!(
+        batch.verify(&batch.key(), index, &outcome, &proof),
+        CasinoError::InvalidScratchProof
+    );
+    
+    // Release the prize from the batch's lock before checking the house can pay it
+    let house_vault = &mut ctx.accounts.house_vault;
+    house_vault.game_liability = house_vault.game_liability
+        .saturating_sub(outcome.prize.min(batch.remaining_liability()));
+    
+    if outcome.prize > 0 {
+        let house_info = house_vault.to_account_info();
+        let reserved = house_vault.reserved_lamports(house_info.data_len())?;
+        require!(
+            house_info.lamports() >= outcome.prize.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+            CasinoError::InsufficientFunds
+        );
+        
+        **house_info.try_borrow_mut_lamports()? -= outcome.prize;
+        **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += outcome.prize;
+    }
+    
+    batch.cards_revealed += 1;
+    batch.prizes_paid = batch.prizes_paid
+        .checked_add(outcome.prize)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Scratch card {} of batch {} revealed: {} lamports", index, batch.batch_id, outcome.prize);
+    
+    emit!(ScratchCardRevealed {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        batch_id: batch.batch_id,
+        index,
+        player: ctx.accounts.card.player,
+        prize: outcome.prize,
+        salt: outcome.salt,
+    });
+    
+    ctx.accounts.card.close(ctx.accounts.player.to_account_info())?;
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(index: u32)]
+pub struct RevealCard<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        seeds = [b"scratch_batch", batch.batch_id.to_le_bytes().as_ref()],
+        bump = batch.bump
+    )]
+    pub batch: Account<'info, ScratchBatch>,
+    
+    #[account(
+        mut,
+        has_one = batch,
+        has_one = player,
+        seeds = [b"scratch_card", batch.key().as_ref(), index.to_le_bytes().as_ref()],
+        bump = card.bump
+    )]
+    pub card: Account<'info, ScratchCard>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// CHECK: the card's buyer; receives the prize and the card's rent
+    #[account(mut)]
+    pub player: UncheckedAccount<'info>,
+}
+
+#[event]
+pub struct ScratchCardRevealed {
+    pub sequence: u64,
+    pub batch_id: u64,
+    pub index: u32,
+    pub player: Pubkey,
+    pub prize: u64,
+    pub salt: [u8; 32],
+}
@@ -0,0 +1,162 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::fulfill_jackpot::settle_side_bets;
+
+/// Reveal a mystery jackpot's threshold and award the bet that lifted the pool past it (permissionless)
+/// The win is the pool's balance at the hit, capped at `max_payout`, held as a claimable `mystery_win` for `claim_win`;
+/// the round closes to whoever committed it
+pub fn reveal_mystery(
+    ctx: Context<RevealMystery>,
+    threshold: u64,
+    salt: [u8; 32],
+) -> Result<()> {
+    let round = &ctx.accounts.round;
+    let bet = &ctx.accounts.bet;
+    
+    require!(
+        round.verify(threshold, &salt),
+        CasinoError::InvalidMysteryReveal
+    );
+    
+    require!(
+        bet.timestamp >= round.started_at
+            && bet.pool_balance_before < threshold
+            && bet.pool_balance_after >= threshold,
+        CasinoError::MysteryNotTriggered
+    );
+    
+    let config = &mut ctx.accounts.config;
+    let pool = &mut ctx.accounts.pool;
+    
+    let mut win_amount = bet.pool_balance_after.min(pool.balance);
+    if config.max_payout > 0 {
+        win_amount = win_amount.min(config.max_payout);
+    }
+    
+    // Reserve the win in the pool; `claim_win` and its siblings apply review, vesting and disputes
+    pool.balance -= win_amount;
+    pool.pending_payouts = pool.pending_payouts
+        .checked_add(win_amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    let now = Clock::get()?.unix_timestamp;
+    let mystery_win = &mut ctx.accounts.mystery_win;
+    mystery_win.player = bet.player;
+    mystery_win.pool = pool.key();
+    mystery_win.amount = bet.amount;
+    mystery_win.timestamp = bet.timestamp;
+    mystery_win.status = 1; // won, claimable
+    mystery_win.win_amount = win_amount;
+    mystery_win.placed_slot = bet.placed_slot;
+    mystery_win.settled_at = now;
+    mystery_win.bump = ctx.bumps.mystery_win;
+    
+    let bets_since_win = pool.bets_since_win;
+    pool.last_winner = Some(bet.player);
+    pool.last_win_timestamp = Some(now);
+    pool.bets_since_win = 0;
+    pool.must_hit_armed = false;
+    
+    config.total_wins = config.total_wins
+        .checked_add(1)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    settle_side_bets(
+        config,
+        pool,
+        &mut ctx.accounts.house_vault,
+        &mut ctx.accounts.side_bet_pool,
+        bets_since_win,
+        true,
+    )?;
+    
+    msg!("Mystery jackpot {} hit at {}: {} lamports to {}", round.round_id, threshold, win_amount, bet.player);
+    
+    emit!(MysteryJackpotHit {
+        sequence: config.next_event_sequence(),
+        pool: pool.key(),
+        round_id: round.round_id,
+        threshold,
+        salt,
+        bet: bet.key(),
+        mystery_win: mystery_win.key(),
+        player: bet.player,
+        win_amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealMystery<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        has_one = committed_by,
+        close = committed_by,
+        seeds = [b"mystery", pool.key().as_ref(), round.round_id.to_le_bytes().as_ref()],
+        bump = round.bump
+    )]
+    pub round: Account<'info, MysteryJackpot>,
+    
+    /// The bet that lifted the pool past the threshold
+    #[account(
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"bet", bet.player.as_ref(), bet.amount.to_le_bytes().as_ref()],
+        bump = bet.bump
+    )]
+    pub bet: Account<'info, Bet>,
+    
+    /// Receives the rake on a side-bet round the hit settles
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// Pool's open side-bet round; required to reveal while one is open
+    #[account(
+        mut,
+        has_one = pool @ CasinoError::PoolMismatch,
+        seeds = [b"side_bet_pool", pool.key().as_ref(), side_bet_pool.round_id.to_le_bytes().as_ref()],
+        bump = side_bet_pool.bump
+    )]
+    pub side_bet_pool: Option<Account<'info, SideBetPool>>,
+    
+    /// Claimable record of the mystery win for the bet's player
+    #[account(
+        init,
+        payer = revealer,
+        space = 8 + Bet::INIT_SPACE,
+        seeds = [b"mystery_win", round.key().as_ref(), round.started_at.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mystery_win: Account<'info, Bet>,
+    
+    /// CHECK: whoever committed the round; receives its rent
+    #[account(mut)]
+    pub committed_by: UncheckedAccount<'info>,
+    
+    /// Anyone holding the preimage; pays for `mystery_win`
+    #[account(mut)]
+    pub revealer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct MysteryJackpotHit {
+    pub sequence: u64,
+    pub pool: Pubkey,
+    pub round_id: u64,
+    pub threshold: u64,
+    pub salt: [u8; 32],
+    pub bet: Pubkey,
+    pub mystery_win: Pubkey,
+    pub player: Pubkey,
+    pub win_amount: u64,
+}
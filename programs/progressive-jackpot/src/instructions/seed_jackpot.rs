@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Seed or donate SOL to the jackpot pool (permissionless)
+pub fn seed_jackpot(
+    ctx: Context<SeedJackpot>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        amount > 0,
+        CasinoError::InvalidAmount
+    );
+    
+    // Donor is a system account, so move funds via the system program
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.donor.to_account_info(),
+                to: ctx.accounts.pool.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    let pool = &mut ctx.accounts.pool;
+    pool.balance = pool.balance
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Jackpot seeded: {} lamports by {}", amount, ctx.accounts.donor.key());
+    
+    emit!(JackpotSeeded {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        donor: ctx.accounts.donor.key(),
+        amount,
+        pool_balance: pool.balance,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SeedJackpot<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
+    pub pool: Account<'info, JackpotPool>,
+    
+    #[account(mut)]
+    pub donor: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct JackpotSeeded {
+    pub sequence: u64,
+    pub donor: Pubkey,
+    pub amount: u64,
+    pub pool_balance: u64,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// User opts in or out of having their DeFi rewards compounded by anyone
+/// Creates the reward claim if the user has no stake yet
+pub fn set_auto_compound(
+    ctx: Context<SetAutoCompound>,
+    enabled: bool,
+) -> Result<()> {
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    if reward_claim.user == Pubkey::default() {
+        reward_claim.user = ctx.accounts.user.key();
+        reward_claim.last_claim = Clock::get()?.unix_timestamp;
+        reward_claim.bump = ctx.bumps.reward_claim;
+    }
+    
+    reward_claim.compound = enabled;
+    
+    msg!("Auto-compound {} for {}", enabled, ctx.accounts.user.key());
+    
+    emit!(AutoCompoundSet {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        user: ctx.accounts.user.key(),
+        enabled,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetAutoCompound<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + RewardClaim::INIT_SPACE,
+        seeds = [b"reward_claim", user.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct AutoCompoundSet {
+    pub sequence: u64,
+    pub user: Pubkey,
+    pub enabled: bool,
+}
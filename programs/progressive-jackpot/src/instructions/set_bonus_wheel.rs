@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Replace the bonus wheel's prize table; an empty list turns the wheel off (authority only)
+pub fn set_bonus_wheel(
+    ctx: Context<SetBonusWheel>,
+    prizes: Vec<WheelPrize>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let bonus_wheel = &mut ctx.accounts.bonus_wheel;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        prizes.len() <= MAX_WHEEL_PRIZES && prizes.iter().all(|prize| prize.weight > 0),
+        CasinoError::InvalidBonusWheel
+    );
+    
+    let total_weight: u32 = prizes.iter().map(|prize| prize.weight as u32).sum();
+    
+    bonus_wheel.prize_count = prizes.len() as u8;
+    bonus_wheel.prizes = [WheelPrize::default(); MAX_WHEEL_PRIZES];
+    bonus_wheel.prizes[..prizes.len()].copy_from_slice(&prizes);
+    bonus_wheel.total_weight = total_weight;
+    bonus_wheel.bump = ctx.bumps.bonus_wheel;
+    
+    msg!("Bonus wheel updated: {} slices, total weight {}", prizes.len(), total_weight);
+    
+    emit!(BonusWheelUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        prizes,
+        total_weight,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBonusWheel<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + BonusWheel::INIT_SPACE,
+        seeds = [b"bonus_wheel"],
+        bump
+    )]
+    pub bonus_wheel: Account<'info, BonusWheel>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BonusWheelUpdated {
+    pub sequence: u64,
+    pub prizes: Vec<WheelPrize>,
+    pub total_weight: u32,
+}
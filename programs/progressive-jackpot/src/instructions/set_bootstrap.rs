@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Configure cold-start bootstrap mode (authority only)
+/// While the pool is below `threshold`, wins pay `payout_multiplier` x the bet from the house vault
+pub fn set_bootstrap(
+    ctx: Context<SetBootstrap>,
+    threshold: u64,
+    payout_multiplier: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        threshold == 0 || payout_multiplier > 0,
+        CasinoError::InvalidConfig
+    );
+    
+    config.bootstrap_threshold = threshold;
+    config.bootstrap_payout_multiplier = payout_multiplier;
+    
+    msg!("Bootstrap mode updated: threshold={}, multiplier={}x", threshold, payout_multiplier);
+    
+    emit!(BootstrapUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        threshold,
+        payout_multiplier,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetBootstrap<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct BootstrapUpdated {
+    pub sequence: u64,
+    pub threshold: u64,
+    pub payout_multiplier: u16,
+}
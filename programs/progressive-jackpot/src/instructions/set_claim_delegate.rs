@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// User lets a bot or custodian call `claim_rewards` for them, or revokes it with `None`
+/// Payouts always go to the user, never to the delegate
+pub fn set_claim_delegate(
+    ctx: Context<SetClaimDelegate>,
+    delegate: Option<Pubkey>,
+) -> Result<()> {
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    if reward_claim.user == Pubkey::default() {
+        reward_claim.user = ctx.accounts.user.key();
+        reward_claim.last_claim = Clock::get()?.unix_timestamp;
+        reward_claim.bump = ctx.bumps.reward_claim;
+    }
+    
+    reward_claim.claim_delegate = delegate;
+    
+    msg!("Claim delegate for {} set to {:?}", ctx.accounts.user.key(), delegate);
+    
+    emit!(ClaimDelegateSet {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        user: ctx.accounts.user.key(),
+        delegate,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetClaimDelegate<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + RewardClaim::INIT_SPACE,
+        seeds = [b"reward_claim", user.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct ClaimDelegateSet {
+    pub sequence: u64,
+    pub user: Pubkey,
+    pub delegate: Option<Pubkey>,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Set the reward token emission schedule (authority only)
+/// Emissions up to now are accrued at the old rate first, so changes are never retroactive
+pub fn set_emission_rate(
+    ctx: Context<SetEmissionRate>,
+    rate_per_second: u64,
+    ends_at: i64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    require!(
+        reward_vault.reward_mint != Pubkey::default(),
+        CasinoError::RewardMintNotSet
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        ends_at == 0 || ends_at > now,
+        CasinoError::InvalidConfig
+    );
+    
+    reward_vault.accrue(now)?;
+    reward_vault.emission_rate = rate_per_second;
+    reward_vault.emission_ends_at = ends_at;
+    
+    msg!("Emission rate set: {} per second until {}", rate_per_second, ends_at);
+    
+    emit!(EmissionRateSet {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        rate_per_second,
+        ends_at,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetEmissionRate<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct EmissionRateSet {
+    pub sequence: u64,
+    pub rate_per_second: u64,
+    pub ends_at: i64,
+}
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Start, replace, or stop (experiment_id = 0) an odds experiment (authority only)
+/// Variants are snapshotted into config; bets record the bucket and odds they were placed under
+pub fn set_experiment(
+    ctx: Context<SetExperiment>,
+    experiment_id: u64,
+    variants: Vec<ExperimentVariant>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    if experiment_id == 0 {
+        config.experiment_id = 0;
+        config.experiment_variant_count = 0;
+        config.experiment_variants = [ExperimentVariant::default(); MAX_EXPERIMENT_VARIANTS];
+        
+        msg!("Experiment stopped");
+        
+        emit!(ExperimentUpdated {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            experiment_id,
+            variant_count: 0,
+            rtp_bps: 0,
+        });
+        
+        return Ok(());
+    }
+    
+    require!(
+        !variants.is_empty() && variants.len() <= MAX_EXPERIMENT_VARIANTS,
+        CasinoError::InvalidExperiment
+    );
+    
+    let mut total_weight: u64 = 0;
+    for variant in variants.iter() {
+        require!(
+            variant.win_probability_bps > 0 && variant.win_probability_bps <= 10000,
+            CasinoError::InvalidExperiment
+        );
+        
+        let total_percentage = variant.jackpot_percentage as u64
+            + config.house_percentage as u64
+            + config.defi_percentage as u64;
+        require!(
+            total_percentage <= 10000,
+            CasinoError::InvalidExperiment
+        );
+        
+        total_weight += variant.weight_bps as u64;
+    }
+    
+    require!(
+        total_weight == 10000,
+        CasinoError::InvalidExperiment
+    );
+    
+    config.experiment_id = experiment_id;
+    config.experiment_variant_count = variants.len() as u8;
+    config.experiment_variants = [ExperimentVariant::default(); MAX_EXPERIMENT_VARIANTS];
+    config.experiment_variants[..variants.len()].copy_from_slice(&variants);
+    
+    // Global RTP guard: the traffic-weighted mix may not exceed the base jackpot share
+    let rtp_bps = config.experiment_rtp_bps();
+    require!(
+        rtp_bps <= config.jackpot_percentage as u64,
+        CasinoError::ExperimentRtpExceeded
+    );
+    
+    msg!("Experiment {} started with {} variants", experiment_id, variants.len());
+    
+    emit!(ExperimentUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        experiment_id,
+        variant_count: variants.len() as u8,
+        rtp_bps,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetExperiment<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct ExperimentUpdated {
+    pub sequence: u64,
+    pub experiment_id: u64,
+    pub variant_count: u8,
+    pub rtp_bps: u64,
+}
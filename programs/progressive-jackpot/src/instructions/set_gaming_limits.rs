@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Player sets their daily wager cap, daily loss cap, and self-exclusion end
+/// Tighter limits apply immediately; any relaxation waits out a 24h cooldown
+pub fn set_gaming_limits(
+    ctx: Context<SetGamingLimits>,
+    daily_wager_cap: u64,
+    daily_loss_cap: u64,
+    self_excluded_until: i64,
+) -> Result<()> {
+    let now = Clock::get()?.unix_timestamp;
+    let limits = &mut ctx.accounts.gaming_limits;
+    
+    let is_new = limits.player == Pubkey::default();
+    if is_new {
+        limits.player = ctx.accounts.player.key();
+        limits.bump = ctx.bumps.gaming_limits;
+    }
+    
+    limits.apply_pending(now);
+    
+    // A cap of 0 means uncapped, so lifting a cap is a relaxation
+    let loosens_cap = |current: u64, requested: u64| {
+        current != 0 && (requested == 0 || requested > current)
+    };
+    let relaxes = !is_new
+        && (loosens_cap(limits.daily_wager_cap, daily_wager_cap)
+            || loosens_cap(limits.daily_loss_cap, daily_loss_cap)
+            || (limits.self_excluded_until > now && self_excluded_until < limits.self_excluded_until));
+    
+    if relaxes {
+        limits.pending_wager_cap = daily_wager_cap;
+        limits.pending_loss_cap = daily_loss_cap;
+        limits.pending_self_excluded_until = self_excluded_until;
+        limits.pending_effective_at = now + LIMIT_RELAX_COOLDOWN;
+        
+        msg!("Gaming limits relaxation queued until {}", limits.pending_effective_at);
+    } else {
+        limits.daily_wager_cap = daily_wager_cap;
+        limits.daily_loss_cap = daily_loss_cap;
+        limits.self_excluded_until = self_excluded_until;
+        limits.pending_effective_at = 0;
+        
+        msg!("Gaming limits updated");
+    }
+    
+    emit!(GamingLimitsUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: limits.player,
+        daily_wager_cap,
+        daily_loss_cap,
+        self_excluded_until,
+        effective_at: if relaxes { limits.pending_effective_at } else { now },
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetGamingLimits<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + GamingLimits::INIT_SPACE,
+        seeds = [b"limits", player.key().as_ref()],
+        bump
+    )]
+    pub gaming_limits: Account<'info, GamingLimits>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct GamingLimitsUpdated {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub daily_wager_cap: u64,
+    pub daily_loss_cap: u64,
+    pub self_excluded_until: i64,
+    /// When the new limits take effect (now, or after the cooldown for a relaxation)
+    pub effective_at: i64,
+}
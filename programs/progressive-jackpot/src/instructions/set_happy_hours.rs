@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Replace the weekly happy-hour windows (authority only; an empty list clears them)
+/// Bets placed inside a window have their odds and jackpot share scaled by its multipliers
+pub fn set_happy_hours(
+    ctx: Context<SetHappyHours>,
+    windows: Vec<HappyHour>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        windows.len() <= MAX_HAPPY_HOURS,
+        CasinoError::InvalidHappyHour
+    );
+    
+    let multiplier_range = 10000..=MAX_HAPPY_HOUR_MULTIPLIER_BPS;
+    for window in windows.iter() {
+        require!(
+            window.days != 0
+                && window.days <= 0x7f
+                && window.start_hour < window.end_hour
+                && window.end_hour <= 24,
+            CasinoError::InvalidHappyHour
+        );
+        
+        require!(
+            multiplier_range.contains(&window.win_probability_multiplier_bps)
+                && multiplier_range.contains(&window.jackpot_multiplier_bps),
+            CasinoError::InvalidHappyHour
+        );
+    }
+    
+    config.happy_hour_count = windows.len() as u8;
+    config.happy_hours = [HappyHour::default(); MAX_HAPPY_HOURS];
+    config.happy_hours[..windows.len()].copy_from_slice(&windows);
+    
+    msg!("Happy hours updated: {} windows", windows.len());
+    
+    emit!(HappyHoursUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        authority: ctx.accounts.authority.key(),
+        windows,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetHappyHours<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct HappyHoursUpdated {
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub windows: Vec<HappyHour>,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Minutes in a day
+const MINUTES_PER_DAY: u16 = 1440;
+
+/// Configure the UTC betting window (operator only)
+pub fn set_operating_hours(
+    ctx: Context<SetOperatingHours>,
+    enabled: bool,
+    days: u8,
+    start_minute: u16,
+    end_minute: u16,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.operator.key() == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        days <= 0x7f && start_minute < MINUTES_PER_DAY && end_minute < MINUTES_PER_DAY,
+        CasinoError::InvalidConfig
+    );
+    
+    config.operating_hours_enabled = enabled;
+    config.operating_days = days;
+    config.operating_start_minute = start_minute;
+    config.operating_end_minute = end_minute;
+    
+    msg!(
+        "Operating hours updated: enabled={}, days={:#09b}, {}-{}",
+        enabled, days, start_minute, end_minute
+    );
+    
+    emit!(OperatingHoursUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        operator: ctx.accounts.operator.key(),
+        enabled,
+        days,
+        start_minute,
+        end_minute,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetOperatingHours<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub operator: Signer<'info>,
+}
+
+#[event]
+pub struct OperatingHoursUpdated {
+    pub sequence: u64,
+    pub operator: Pubkey,
+    pub enabled: bool,
+    pub days: u8,
+    pub start_minute: u16,
+    pub end_minute: u16,
+}
@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Player routes their wins and DeFi reward claims to a cold wallet, or back to themselves with `None`
+/// Creates the player profile if the player has not bet yet
+pub fn set_payout_beneficiary(
+    ctx: Context<SetPayoutBeneficiary>,
+    beneficiary: Option<Pubkey>,
+) -> Result<()> {
+    let player_profile = &mut ctx.accounts.player_profile;
+    if player_profile.player == Pubkey::default() {
+        player_profile.player = ctx.accounts.player.key();
+        player_profile.bump = ctx.bumps.player_profile;
+    }
+    
+    player_profile.payout_beneficiary = beneficiary;
+    
+    msg!("Payout beneficiary for {} set to {:?}", ctx.accounts.player.key(), beneficiary);
+    
+    emit!(PayoutBeneficiarySet {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        beneficiary,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutBeneficiary<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PayoutBeneficiarySet {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub beneficiary: Option<Pubkey>,
+}
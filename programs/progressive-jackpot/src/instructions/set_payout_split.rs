@@ -0,0 +1,78 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player divides their future win claims among up to 5 recipients; an empty list removes the split
+/// Whatever the shares leave over goes to the player (or their payout beneficiary)
+pub fn set_payout_split(
+    ctx: Context<SetPayoutSplit>,
+    shares: Vec<SplitShare>,
+) -> Result<()> {
+    let payout_split = &mut ctx.accounts.payout_split;
+    
+    require!(
+        shares.len() <= MAX_SPLIT_RECIPIENTS,
+        CasinoError::InvalidPayoutSplit
+    );
+    
+    let mut total_bps: u16 = 0;
+    for (index, share) in shares.iter().enumerate() {
+        require!(
+            share.bps > 0 && share.recipient != Pubkey::default(),
+            CasinoError::InvalidPayoutSplit
+        );
+        
+        require!(
+            shares[..index].iter().all(|other| other.recipient != share.recipient),
+            CasinoError::InvalidPayoutSplit
+        );
+        
+        total_bps = total_bps
+            .checked_add(share.bps)
+            .filter(|&bps| bps <= 10000)
+            .ok_or(CasinoError::InvalidPayoutSplit)?;
+    }
+    
+    payout_split.player = ctx.accounts.player.key();
+    payout_split.bump = ctx.bumps.payout_split;
+    payout_split.share_count = shares.len() as u8;
+    payout_split.shares = [SplitShare::default(); MAX_SPLIT_RECIPIENTS];
+    payout_split.shares[..shares.len()].copy_from_slice(&shares);
+    
+    msg!("Payout split updated: {} recipients, {} bps", shares.len(), total_bps);
+    
+    emit!(PayoutSplitUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        shares,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPayoutSplit<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PayoutSplit::INIT_SPACE,
+        seeds = [b"payout_split", player.key().as_ref()],
+        bump
+    )]
+    pub payout_split: Account<'info, PayoutSplit>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PayoutSplitUpdated {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub shares: Vec<SplitShare>,
+}
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Replace the slots paytable; an empty list turns slots off (authority only)
+pub fn set_paytable(
+    ctx: Context<SetPaytable>,
+    entries: Vec<PaytableEntry>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let paytable = &mut ctx.accounts.paytable;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    // Open spins settle against the table they were placed under
+    require!(
+        paytable.open_spins == 0,
+        CasinoError::PaytableInUse
+    );
+    
+    require!(
+        entries.len() <= MAX_PAYTABLE_ENTRIES,
+        CasinoError::InvalidPaytable
+    );
+    
+    let mut total_weight: u32 = 0;
+    for entry in &entries {
+        require!(
+            entry.weight > 0 && (!entry.jackpot || entry.multiplier_bps <= 10000),
+            CasinoError::InvalidPaytable
+        );
+        total_weight += entry.weight as u32;
+    }
+    
+    paytable.entry_count = entries.len() as u8;
+    paytable.entries = [PaytableEntry::default(); MAX_PAYTABLE_ENTRIES];
+    paytable.entries[..entries.len()].copy_from_slice(&entries);
+    paytable.total_weight = total_weight;
+    paytable.bump = ctx.bumps.paytable;
+    
+    msg!("Paytable updated: {} outcomes, total weight {}", entries.len(), total_weight);
+    
+    emit!(PaytableUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        entries,
+        total_weight,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPaytable<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = authority,
+        space = 8 + Paytable::INIT_SPACE,
+        seeds = [b"paytable"],
+        bump
+    )]
+    pub paytable: Account<'info, Paytable>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PaytableUpdated {
+    pub sequence: u64,
+    pub entries: Vec<PaytableEntry>,
+    pub total_weight: u32,
+}
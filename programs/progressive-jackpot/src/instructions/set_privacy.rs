@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// Player sets their data-minimization preferences
+/// Creates the player profile if the player has not bet yet
+pub fn set_privacy(
+    ctx: Context<SetPrivacy>,
+    experiment_opt_out: bool,
+    hide_from_history: bool,
+) -> Result<()> {
+    let player_profile = &mut ctx.accounts.player_profile;
+    if player_profile.player == Pubkey::default() {
+        player_profile.player = ctx.accounts.player.key();
+        player_profile.bump = ctx.bumps.player_profile;
+    }
+    
+    player_profile.experiment_opt_out = experiment_opt_out;
+    player_profile.hide_from_history = hide_from_history;
+    
+    msg!(
+        "Privacy updated: experiment_opt_out={}, hide_from_history={}",
+        experiment_opt_out, hide_from_history
+    );
+    
+    emit!(PrivacyUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        experiment_opt_out,
+        hide_from_history,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetPrivacy<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PrivacyUpdated {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub experiment_opt_out: bool,
+    pub hide_from_history: bool,
+}
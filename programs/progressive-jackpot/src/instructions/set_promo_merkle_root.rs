@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Publish or replace a promo campaign's Merkle root and add funding (authority or operator)
+pub fn set_promo_merkle_root(
+    ctx: Context<SetPromoMerkleRoot>,
+    campaign_id: u64,
+    merkle_root: [u8; 32],
+    funding: u64,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    if funding > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                system_program::Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: ctx.accounts.campaign.to_account_info(),
+                },
+            ),
+            funding,
+        )?;
+    }
+    
+    let campaign = &mut ctx.accounts.campaign;
+    campaign.campaign_id = campaign_id;
+    campaign.merkle_root = merkle_root;
+    campaign.bump = ctx.bumps.campaign;
+    campaign.funded = campaign.funded
+        .checked_add(funding)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Promo campaign {} root set, funded {}", campaign_id, campaign.funded);
+    
+    emit!(PromoRootSet {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        campaign_id,
+        merkle_root,
+        funding,
+        set_by: signer,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(campaign_id: u64)]
+pub struct SetPromoMerkleRoot<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        init_if_needed,
+        payer = signer,
+        space = 8 + PromoCampaign::INIT_SPACE,
+        seeds = [b"promo", campaign_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub campaign: Account<'info, PromoCampaign>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct PromoRootSet {
+    pub sequence: u64,
+    pub campaign_id: u64,
+    pub merkle_root: [u8; 32],
+    pub funding: u64,
+    pub set_by: Pubkey,
+}
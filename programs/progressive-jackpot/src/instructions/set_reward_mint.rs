@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, Token};
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Start emitting a project token to stakers alongside SOL yield (authority only)
+/// The mint's authority must already be the reward vault PDA; this can only be done once
+pub fn set_reward_mint(
+    ctx: Context<SetRewardMint>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    require!(
+        reward_vault.reward_mint == Pubkey::default(),
+        CasinoError::RewardMintAlreadySet
+    );
+    
+    reward_vault.accrue(Clock::get()?.unix_timestamp)?;
+    reward_vault.reward_mint = ctx.accounts.reward_mint.key();
+    
+    msg!("Reward mint set: {}", reward_vault.reward_mint);
+    
+    emit!(RewardMintSet {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        mint: reward_vault.reward_mint,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRewardMint<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(
+        mint::authority = reward_vault,
+        mint::token_program = token_program
+    )]
+    pub reward_mint: Account<'info, Mint>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+}
+
+#[event]
+pub struct RewardMintSet {
+    pub sequence: u64,
+    pub mint: Pubkey,
+}
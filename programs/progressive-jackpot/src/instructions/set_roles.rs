@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Reassign operational roles (authority only)
+pub fn set_roles(
+    ctx: Context<SetRoles>,
+    treasurer: Option<Pubkey>,
+    operator: Option<Pubkey>,
+    risk_manager: Option<Pubkey>,
+    payout_approver: Option<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    if let Some(t) = treasurer {
+        config.treasurer = t;
+    }
+    
+    if let Some(o) = operator {
+        config.operator = o;
+    }
+    
+    if let Some(r) = risk_manager {
+        config.risk_manager = r;
+    }
+    
+    if let Some(a) = payout_approver {
+        config.payout_approver = a;
+    }
+    
+    msg!(
+        "Roles updated: treasurer={}, operator={}, risk_manager={}, payout_approver={}",
+        config.treasurer, config.operator, config.risk_manager, config.payout_approver
+    );
+    
+    emit!(RolesUpdated {
+        sequence: config.next_event_sequence(),
+        authority: ctx.accounts.authority.key(),
+        treasurer: config.treasurer,
+        operator: config.operator,
+        risk_manager: config.risk_manager,
+        payout_approver: config.payout_approver,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetRoles<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct RolesUpdated {
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub treasurer: Pubkey,
+    pub operator: Pubkey,
+    pub risk_manager: Pubkey,
+    pub payout_approver: Pubkey,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Switch sunset mode on or off (authority only)
+/// While on, new bets are rejected; settlement, refunds, claims and account closing keep working
+/// Switching it on queues a pause notification
+pub fn set_shutdown(
+    ctx: Context<SetShutdown>,
+    shutdown: bool,
+    beneficiary: Pubkey,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        !shutdown || beneficiary != Pubkey::default(),
+        CasinoError::InvalidConfig
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    
+    config.shutdown = shutdown;
+    config.shutdown_at = if shutdown { now } else { 0 };
+    config.sunset_beneficiary = if shutdown { beneficiary } else { Pubkey::default() };
+    
+    if shutdown {
+        ctx.accounts.notification_queue
+            .load_mut()?
+            .push(NOTIFICATION_PAUSE, ctx.accounts.authority.key(), 0, now);
+    }
+    
+    msg!("Shutdown {}: beneficiary {}", shutdown, config.sunset_beneficiary);
+    
+    emit!(ShutdownSet {
+        sequence: config.next_event_sequence(),
+        authority: ctx.accounts.authority.key(),
+        shutdown,
+        beneficiary: config.sunset_beneficiary,
+        timestamp: now,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetShutdown<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"notification_queue"], bump = notification_queue.load()?.bump)]
+    pub notification_queue: AccountLoader<'info, NotificationQueue>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct ShutdownSet {
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub shutdown: bool,
+    pub beneficiary: Pubkey,
+    pub timestamp: i64,
+}
@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Replace the treasury allowlist `withdraw_house` may pay; empty means treasurer only (authority only)
+pub fn set_treasury_destinations(
+    ctx: Context<SetTreasuryDestinations>,
+    destinations: Vec<Pubkey>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        destinations.len() <= MAX_TREASURY_DESTINATIONS
+            && destinations.iter().all(|destination| *destination != Pubkey::default()),
+        CasinoError::InvalidTreasuryDestination
+    );
+    
+    config.treasury_count = destinations.len() as u8;
+    config.treasury_destinations = [Pubkey::default(); MAX_TREASURY_DESTINATIONS];
+    config.treasury_destinations[..destinations.len()].copy_from_slice(&destinations);
+    
+    msg!("Treasury destinations updated: {}", destinations.len());
+    
+    emit!(TreasuryDestinationsUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        destinations,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTreasuryDestinations<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct TreasuryDestinationsUpdated {
+    pub sequence: u64,
+    pub destinations: Vec<Pubkey>,
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Replace the VIP tier table; an empty list disables VIP perks (authority only)
+pub fn set_vip_tiers(
+    ctx: Context<SetVipTiers>,
+    tiers: Vec<VipTier>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        ctx.accounts.authority.key() == config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    require!(
+        tiers.len() <= MAX_VIP_TIERS,
+        CasinoError::InvalidVipTiers
+    );
+    
+    for (index, tier) in tiers.iter().enumerate() {
+        require!(
+            tier.rakeback_bps <= 10000,
+            CasinoError::InvalidVipTiers
+        );
+        
+        require!(
+            tier.max_bet == 0 || tier.max_bet >= config.min_bet,
+            CasinoError::InvalidVipTiers
+        );
+        
+        // Tiers must be strictly ascending by volume
+        if index > 0 {
+            require!(
+                tier.min_wagered > tiers[index - 1].min_wagered,
+                CasinoError::InvalidVipTiers
+            );
+        }
+    }
+    
+    config.vip_tier_count = tiers.len() as u8;
+    config.vip_tiers = [VipTier::default(); MAX_VIP_TIERS];
+    config.vip_tiers[..tiers.len()].copy_from_slice(&tiers);
+    
+    msg!("VIP tiers updated: {} tiers", tiers.len());
+    
+    emit!(VipTiersUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        tier_count: tiers.len() as u8,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetVipTiers<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct VipTiersUpdated {
+    pub sequence: u64,
+    pub tier_count: u8,
+}
@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Pick where idle reward vault lamports are deployed (authority only)
+/// Only possible while nothing is deployed to the current strategy
+pub fn set_yield_strategy(
+    ctx: Context<SetYieldStrategy>,
+    strategy: YieldStrategy,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    require!(
+        reward_vault.strategy_deployed == 0,
+        CasinoError::StrategyPositionOpen
+    );
+    
+    reward_vault.strategy_lst_mint = match (strategy, &ctx.accounts.lst_mint) {
+        (YieldStrategy::None, _) => Pubkey::default(),
+        (_, Some(lst_mint)) => lst_mint.key(),
+        (_, None) => return err!(CasinoError::InvalidStrategyAccounts),
+    };
+    reward_vault.strategy = strategy;
+    
+    msg!("Yield strategy set: {:?}", strategy);
+    
+    emit!(YieldStrategySet {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        strategy,
+        lst_mint: reward_vault.strategy_lst_mint,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetYieldStrategy<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    /// The strategy's liquid staking token (mSOL or JitoSOL); omit for `None`
+    pub lst_mint: Option<Account<'info, Mint>>,
+    
+    pub authority: Signer<'info>,
+}
+
+#[event]
+pub struct YieldStrategySet {
+    pub sequence: u64,
+    pub strategy: YieldStrategy,
+    pub lst_mint: Pubkey,
+}
@@ -0,0 +1,137 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::TokenAccount;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::register_keeper::pay_keeper;
+
+/// Pick a draw winner with probability proportional to wagered amount and pay the prize
+/// Callable by the authority or operator once `draw_end_ts` has passed
+pub fn settle_draw(
+    ctx: Context<SettleDraw>,
+    vrf_result: [u8; 32],
+) -> Result<()> {
+    // The VRF result comes from the caller, so only the house may supply it
+    let settler = ctx.accounts.settler.key();
+    require!(
+        settler == ctx.accounts.config.authority || settler == ctx.accounts.config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let draw_info = ctx.accounts.draw_round.to_account_info();
+    let mut draw_round = ctx.accounts.draw_round.load_mut()?;
+    
+    require!(
+        draw_round.settled == 0,
+        CasinoError::DrawAlreadySettled
+    );
+    
+    require!(
+        Clock::get()?.unix_timestamp >= draw_round.draw_end_ts,
+        CasinoError::DrawNotEnded
+    );
+    
+    draw_round.settled = 1;
+    let prize = draw_round.prize;
+    
+    pay_keeper(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.house_vault,
+        &mut ctx.accounts.keeper,
+        &ctx.accounts.keeper_authority,
+        1,
+    )?;
+    
+    // Nobody entered: nothing was collected, so nothing to pay
+    if draw_round.total_weight == 0 {
+        msg!("Draw round {} settled with no entries", draw_round.round_id);
+        return Ok(());
+    }
+    
+    // Full 64-bit VRF value so large rounds aren't limited to 10000 buckets
+    let vrf_value = u64::from_le_bytes(vrf_result[..8].try_into().unwrap());
+    let entry = draw_round
+        .pick(vrf_value % draw_round.total_weight)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // A ticketed entry belongs to the current ticket holder
+    let winner = if entry.ticket_mint == Pubkey::default() {
+        entry.player
+    } else {
+        let ticket = ctx.accounts.winner_ticket.as_ref().ok_or(CasinoError::InvalidDrawTicket)?;
+        require!(
+            ticket.mint == entry.ticket_mint && ticket.amount == 1,
+            CasinoError::InvalidDrawTicket
+        );
+        ticket.owner
+    };
+    
+    require_keys_eq!(
+        ctx.accounts.winner.key(),
+        winner,
+        CasinoError::InvalidDrawWinner
+    );
+    
+    draw_round.winner = winner;
+    
+    **draw_info.try_borrow_mut_lamports()? -= prize;
+    **ctx.accounts.winner.to_account_info().try_borrow_mut_lamports()? += prize;
+    
+    msg!("Draw round {} won by {}: {} lamports", draw_round.round_id, winner, prize);
+    
+    emit_event!(ctx, DrawSettled {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        round_id: draw_round.round_id,
+        winner,
+        prize,
+        total_weight: draw_round.total_weight,
+        vrf_result,
+    });
+    
+    Ok(())
+}
+
+#[cfg_attr(feature = "event-cpi", event_cpi)]
+#[derive(Accounts)]
+pub struct SettleDraw<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    /// Pays keeper fees
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    #[account(
+        mut,
+        seeds = [b"draw", draw_round.load()?.round_id.to_le_bytes().as_ref()],
+        bump = draw_round.load()?.bump
+    )]
+    pub draw_round: AccountLoader<'info, DrawRound>,
+    
+    /// CHECK: must match the entry selected by the VRF result
+    #[account(mut)]
+    pub winner: UncheckedAccount<'info>,
+    
+    /// Winner's token account holding the ticket, if the winning entry was ticketed
+    pub winner_ticket: Option<Account<'info, TokenAccount>>,
+    
+    /// Registered keeper credited for the crank, if any
+    #[account(mut, seeds = [b"keeper", keeper.authority.as_ref()], bump = keeper.bump)]
+    pub keeper: Option<Account<'info, Keeper>>,
+    
+    /// Keeper wallet receiving the crank fee; required with `keeper`
+    #[account(mut)]
+    pub keeper_authority: Option<Signer<'info>>,
+    
+    /// Authority or operator supplying the VRF result
+    pub settler: Signer<'info>,
+}
+
+#[event]
+pub struct DrawSettled {
+    pub sequence: u64,
+    pub round_id: u64,
+    pub winner: Pubkey,
+    pub prize: u64,
+    pub total_weight: u64,
+    pub vrf_result: [u8; 32],
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Spend an earned bonus wheel spin, requesting VRF for `fulfill_bonus_wheel`
+pub fn spin_bonus_wheel(
+    ctx: Context<SpinBonusWheel>,
+) -> Result<()> {
+    let player_profile = &mut ctx.accounts.player_profile;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        ctx.accounts.bonus_wheel.prize_count > 0,
+        CasinoError::BonusWheelDisabled
+    );
+    
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    require!(
+        player_profile.bonus_wheel_spins > 0,
+        CasinoError::NoBonusWheelSpins
+    );
+    
+    player_profile.bonus_wheel_spins -= 1;
+    
+    let wheel_spin = &mut ctx.accounts.wheel_spin;
+    wheel_spin.player = ctx.accounts.player.key();
+    wheel_spin.placed_slot = Clock::get()?.slot;
+    wheel_spin.bump = ctx.bumps.wheel_spin;
+    
+    let mut request_id = [0u8; 32];
+    request_id[..8].copy_from_slice(&now.to_le_bytes());
+    
+    let vrf_request = &mut ctx.accounts.vrf_request;
+    vrf_request.bet = wheel_spin.key();
+    vrf_request.player = wheel_spin.player;
+    vrf_request.timestamp = now;
+    vrf_request.request_id = request_id;
+    vrf_request.status = 0; // pending
+    vrf_request.result = None;
+    vrf_request.bump = ctx.bumps.vrf_request;
+    
+    msg!("Bonus wheel spun by {}", wheel_spin.player);
+    
+    emit!(BonusWheelSpinRequested {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: wheel_spin.player,
+        wheel_spin: wheel_spin.key(),
+        vrf_request: vrf_request.key(),
+        spins_left: player_profile.bonus_wheel_spins,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SpinBonusWheel<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(seeds = [b"bonus_wheel"], bump = bonus_wheel.bump)]
+    pub bonus_wheel: Account<'info, BonusWheel>,
+    
+    #[account(
+        mut,
+        seeds = [b"player", player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    /// One open spin per player
+    #[account(
+        init,
+        payer = player,
+        space = 8 + WheelSpin::INIT_SPACE,
+        seeds = [b"wheel_spin", player.key().as_ref()],
+        bump
+    )]
+    pub wheel_spin: Account<'info, WheelSpin>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + VrfRequest::INIT_SPACE,
+        seeds = [b"vrf_request", wheel_spin.key().as_ref()],
+        bump
+    )]
+    pub vrf_request: Account<'info, VrfRequest>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct BonusWheelSpinRequested {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub wheel_spin: Pubkey,
+    pub vrf_request: Pubkey,
+    pub spins_left: u8,
+}
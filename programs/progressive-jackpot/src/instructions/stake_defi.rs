@@ -0,0 +1,161 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Stake lamports in the reward vault without betting
+/// Mints vault shares to the caller's `StakePosition`; the deposit earns yield like a bettor's DeFi cut
+pub fn stake_defi(
+    ctx: Context<StakeDefi>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        amount > 0,
+        CasinoError::InvalidAmount
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    
+    let shares = reward_vault.shares_for(amount)?;
+    require!(
+        shares > 0,
+        CasinoError::InvalidAmount
+    );
+    
+    system_program::transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.user.to_account_info(),
+                to: reward_vault.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+    
+    // Yield realized so far belongs to the stake that was in place when it was earned
+    reward_vault.accrue(now)?;
+    reward_vault.distribute_yield()?;
+    
+    let reward_claim = &mut ctx.accounts.reward_claim;
+    if reward_claim.user == Pubkey::default() {
+        reward_claim.user = ctx.accounts.user.key();
+        reward_claim.last_claim = now;
+        reward_claim.bump = ctx.bumps.reward_claim;
+    }
+    reward_claim.deposit(amount, reward_vault)?;
+    
+    let position = &mut ctx.accounts.stake_position;
+    position.owner = ctx.accounts.user.key();
+    position.bump = ctx.bumps.stake_position;
+    position.shares = position.shares
+        .checked_add(shares)
+        .ok_or(CasinoError::MathOverflow)?;
+    position.principal = position.principal
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Every deposit restarts the lockup for the whole position
+    let lockup_secs = ctx.accounts.config.stake_lockup_secs;
+    position.lockup_until = if lockup_secs > 0 { now.saturating_add(lockup_secs) } else { 0 };
+    
+    // Mirror the shares as LP tokens so the position can move across DeFi
+    if reward_vault.lp_mint != Pubkey::default() {
+        let (Some(lp_mint), Some(lp_token_account), Some(token_program)) = (
+            &ctx.accounts.lp_mint,
+            &ctx.accounts.lp_token_account,
+            &ctx.accounts.token_program,
+        ) else {
+            return err!(CasinoError::MissingLpAccounts);
+        };
+        
+        let signer_seeds: &[&[&[u8]]] = &[&[b"reward_vault", &[reward_vault.bump]]];
+        token::mint_to(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                MintTo {
+                    mint: lp_mint.to_account_info(),
+                    to: lp_token_account.to_account_info(),
+                    authority: reward_vault.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            shares,
+        )?;
+    }
+    
+    reward_vault.staked_amount = reward_vault.staked_amount
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    reward_vault.total_shares = reward_vault.total_shares
+        .checked_add(shares)
+        .ok_or(CasinoError::MathOverflow)?;
+    reward_vault.staker_assets = reward_vault.staker_assets
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Staked {} lamports for {} shares", amount, shares);
+    
+    emit!(DefiStaked {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        user: ctx.accounts.user.key(),
+        amount,
+        shares,
+        lockup_until: position.lockup_until,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct StakeDefi<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + StakePosition::INIT_SPACE,
+        seeds = [b"stake", user.key().as_ref()],
+        bump
+    )]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(
+        init_if_needed,
+        payer = user,
+        space = 8 + RewardClaim::INIT_SPACE,
+        seeds = [b"reward_claim", user.key().as_ref()],
+        bump
+    )]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    /// Vault LP mint; required once `initialize_lp_mint` has run
+    #[account(mut, address = reward_vault.lp_mint)]
+    pub lp_mint: Option<Account<'info, Mint>>,
+    
+    /// User's token account for the LP mint
+    #[account(mut, constraint = lp_token_account.mint == reward_vault.lp_mint @ CasinoError::MissingLpAccounts)]
+    pub lp_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct DefiStaked {
+    pub sequence: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub lockup_until: i64,
+}
@@ -0,0 +1,188 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::error::CasinoError;
+use super::register_keeper::pay_keeper;
+
+/// Maximum number of expired bets swept in a single transaction
+pub const MAX_EXPIRED_SWEEP: usize = 10;
+
+/// Accounts supplied per bet in `remaining_accounts`: bet, vrf_request, refund recipient
+const ACCOUNTS_PER_SWEEP: usize = 3;
+
+/// Refund bets whose VRF request timed out and close their accounts (permissionless)
+/// The escrowed leg of the wager comes back from the game vault; the caller keeps `expired_bet_bounty_bps` of the reclaimed rent
+pub fn sweep_expired_bets<'info>(
+    ctx: Context<'_, '_, 'info, 'info, SweepExpiredBets<'info>>,
+) -> Result<()> {
+    let remaining = ctx.remaining_accounts;
+    
+    require!(
+        !remaining.is_empty() && remaining.len() % ACCOUNTS_PER_SWEEP == 0,
+        CasinoError::InvalidBatchAccounts
+    );
+    
+    let swept = remaining.len() / ACCOUNTS_PER_SWEEP;
+    require!(
+        swept <= MAX_EXPIRED_SWEEP,
+        CasinoError::BatchTooLarge
+    );
+    
+    let now = Clock::get()?.unix_timestamp;
+    let game_vault_info = ctx.accounts.game_vault.to_account_info();
+    let game_vault_rent = Rent::get()?.minimum_balance(game_vault_info.data_len());
+    let mut total_refunded: u64 = 0;
+    let mut total_bounty: u64 = 0;
+    
+    for accounts in remaining.chunks(ACCOUNTS_PER_SWEEP) {
+        let bet: Account<'info, Bet> = Account::try_from(&accounts[0])?;
+        let vrf_request: Account<'info, VrfRequest> = Account::try_from(&accounts[1])?;
+        let recipient = &accounts[2];
+        
+        require!(
+            vrf_request.bet == bet.key(),
+            CasinoError::InvalidBatchAccounts
+        );
+        
+        require!(
+            bet.status == 0 && vrf_request.status == 0,
+            CasinoError::BetNotExpired
+        );
+        
+        require!(
+            now - vrf_request.timestamp >= VRF_TIMEOUT,
+            CasinoError::BetNotExpired
+        );
+        
+        // Bonus-funded wagers (and their rent) return to the player's bonus balance
+        let mut bonus: Option<Account<'info, BonusCredit>> = if bet.bonus_funded {
+            let bonus: Account<'info, BonusCredit> = Account::try_from(recipient)?;
+            require_keys_eq!(bonus.player, bet.player, CasinoError::MissingBonusCredit);
+            Some(bonus)
+        } else {
+            require_keys_eq!(recipient.key(), bet.player, CasinoError::InvalidBatchAccounts);
+            None
+        };
+        
+        // Rent goes back with the wager; the caller takes its bounty out of it
+        let rent = close_account(&accounts[0])?
+            .checked_add(close_account(&accounts[1])?)
+            .ok_or(CasinoError::MathOverflow)?;
+        let bounty = rent
+            .checked_mul(ctx.accounts.config.expired_bet_bounty_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?;
+        let refund = bet.escrowed;
+        let returned = refund + rent - bounty;
+        
+        require!(
+            game_vault_info.lamports() >= refund.checked_add(game_vault_rent).ok_or(CasinoError::MathOverflow)?,
+            CasinoError::InsufficientFunds
+        );
+        
+        **game_vault_info.try_borrow_mut_lamports()? -= refund;
+        **recipient.try_borrow_mut_lamports()? += returned;
+        **ctx.accounts.caller.to_account_info().try_borrow_mut_lamports()? += bounty;
+        
+        if let Some(bonus) = bonus.as_mut() {
+            bonus.balance = bonus.balance
+                .checked_add(returned)
+                .ok_or(CasinoError::MathOverflow)?;
+            
+            // Remaining accounts are not persisted by Anchor
+            bonus.exit(&crate::ID)?;
+        }
+        
+        total_refunded = total_refunded
+            .checked_add(refund)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        total_bounty = total_bounty
+            .checked_add(bounty)
+            .ok_or(CasinoError::MathOverflow)?;
+        
+        emit!(BetRefunded {
+            sequence: ctx.accounts.config.next_event_sequence(),
+            player: bet.player,
+            bet: bet.key(),
+            amount: refund,
+        });
+    }
+    
+    ctx.accounts.game_vault.total_withdrawn = ctx.accounts.game_vault.total_withdrawn
+        .checked_add(total_refunded)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    ctx.accounts.config.open_bets = ctx.accounts.config.open_bets.saturating_sub(swept as u64);
+    
+    pay_keeper(
+        &mut ctx.accounts.config,
+        &mut ctx.accounts.house_vault,
+        &mut ctx.accounts.keeper,
+        &ctx.accounts.keeper_authority,
+        swept as u64,
+    )?;
+    
+    msg!("Swept {} expired bets: {} refunded, {} bounty", swept, total_refunded, total_bounty);
+    
+    emit!(ExpiredBetsSwept {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        caller: ctx.accounts.caller.key(),
+        swept: swept as u64,
+        refunded: total_refunded,
+        bounty: total_bounty,
+    });
+    
+    Ok(())
+}
+
+/// Drain and close a program-owned account, returning its lamports
+fn close_account(info: &AccountInfo) -> Result<u64> {
+    let lamports = info.lamports();
+    **info.try_borrow_mut_lamports()? = 0;
+    info.assign(&system_program::ID);
+    info.realloc(0, false)?;
+    
+    Ok(lamports)
+}
+
+#[derive(Accounts)]
+pub struct SweepExpiredBets<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"game_vault"], bump = game_vault.bump)]
+    pub game_vault: Account<'info, GameVault>,
+    
+    /// Pays keeper fees
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// Registered keeper credited for the sweep, if any
+    #[account(mut, seeds = [b"keeper", keeper.authority.as_ref()], bump = keeper.bump)]
+    pub keeper: Option<Account<'info, Keeper>>,
+    
+    /// Keeper wallet receiving the crank fee; required with `keeper`
+    #[account(mut)]
+    pub keeper_authority: Option<Signer<'info>>,
+    
+    #[account(mut)]
+    pub caller: Signer<'info>,
+}
+
+#[event]
+pub struct BetRefunded {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub bet: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ExpiredBetsSwept {
+    pub sequence: u64,
+    pub caller: Pubkey,
+    pub swept: u64,
+    pub refunded: u64,
+    pub bounty: u64,
+}
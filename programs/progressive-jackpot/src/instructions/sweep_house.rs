@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Sweep accumulated house fees to the configured treasury once above `sweep_threshold` (permissionless)
+/// The cranker earns `sweep_bounty_bps` of the sweep
+pub fn sweep_house(
+    ctx: Context<SweepHouse>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    
+    require!(
+        config.sweep_threshold > 0 && config.sweep_treasury != Pubkey::default(),
+        CasinoError::SweepDisabled
+    );
+    
+    require_keys_eq!(
+        ctx.accounts.treasury.key(),
+        config.sweep_treasury,
+        CasinoError::InvalidTreasuryDestination
+    );
+    
+    let amount = ctx.accounts.house_vault.accumulated_house_fees;
+    require!(
+        amount > config.sweep_threshold,
+        CasinoError::SweepBelowThreshold
+    );
+    
+    // Keep the vault rent-exempt and able to pay out owed rakeback
+    let vault_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(vault_info.data_len())?;
+    require!(
+        vault_info.lamports() >= amount.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    let bounty = amount
+        .checked_mul(config.sweep_bounty_bps as u64)
+        .and_then(|x| x.checked_div(10000))
+        .ok_or(CasinoError::MathOverflow)?;
+    let swept = amount - bounty;
+    
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.treasury.try_borrow_mut_lamports()? += swept;
+    **ctx.accounts.cranker.to_account_info().try_borrow_mut_lamports()? += bounty;
+    
+    ctx.accounts.house_vault.accumulated_house_fees = 0;
+    
+    msg!("House swept {} lamports to {}, bounty {}", swept, config.sweep_treasury, bounty);
+    
+    emit!(HouseSwept {
+        sequence: config.next_event_sequence(),
+        treasury: config.sweep_treasury,
+        cranker: ctx.accounts.cranker.key(),
+        amount: swept,
+        bounty,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SweepHouse<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
+    
+    /// CHECK: must be `Config::sweep_treasury`, checked in the handler
+    #[account(mut)]
+    pub treasury: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+#[event]
+pub struct HouseSwept {
+    pub sequence: u64,
+    pub treasury: Pubkey,
+    pub cranker: Pubkey,
+    pub amount: u64,
+    pub bounty: u64,
+}
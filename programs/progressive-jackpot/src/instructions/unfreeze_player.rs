@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Lift a player freeze and return the marker's rent (authority or operator)
+pub fn unfreeze_player(
+    ctx: Context<UnfreezePlayer>,
+) -> Result<()> {
+    let config = &ctx.accounts.config;
+    let signer = ctx.accounts.signer.key();
+    
+    require!(
+        signer == config.authority || signer == config.operator,
+        CasinoError::Unauthorized
+    );
+    
+    let player = ctx.accounts.frozen_player.player;
+    
+    msg!("Player unfrozen: {}", player);
+    
+    emit!(PlayerUnfrozen {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player,
+        unfrozen_by: signer,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfreezePlayer<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(
+        mut,
+        close = signer,
+        seeds = [b"frozen", frozen_player.player.as_ref()],
+        bump = frozen_player.bump
+    )]
+    pub frozen_player: Account<'info, FrozenPlayer>,
+    
+    #[account(mut)]
+    pub signer: Signer<'info>,
+}
+
+#[event]
+pub struct PlayerUnfrozen {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub unfrozen_by: Pubkey,
+}
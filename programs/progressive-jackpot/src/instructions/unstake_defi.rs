@@ -0,0 +1,131 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{self, Burn, Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Burn stake shares and withdraw what they're worth from the reward vault
+/// Leaving before the lockup ends forfeits `early_exit_penalty_bps`, which stays with the remaining stakers
+pub fn unstake_defi(
+    ctx: Context<UnstakeDefi>,
+    shares: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    let position = &mut ctx.accounts.stake_position;
+    let now = Clock::get()?.unix_timestamp;
+    
+    require!(
+        shares > 0 && shares <= position.shares,
+        CasinoError::InsufficientShares
+    );
+    
+    let value = reward_vault.value_of(shares)?;
+    let penalty = if now < position.lockup_until {
+        value
+            .checked_mul(config.early_exit_penalty_bps as u64)
+            .and_then(|x| x.checked_div(10000))
+            .ok_or(CasinoError::MathOverflow)?
+    } else {
+        0
+    };
+    let payout = value - penalty;
+    
+    // The deposit behind these shares stops earning yield
+    let principal = ((position.principal as u128) * (shares as u128) / (position.shares as u128)) as u64;
+    
+    // Yield realized so far belongs to the stake that was in place when it was earned
+    reward_vault.accrue(now)?;
+    reward_vault.distribute_yield()?;
+    ctx.accounts.reward_claim.withdraw(principal, reward_vault)?;
+    
+    let vault_info = reward_vault.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(vault_info.data_len());
+    require!(
+        vault_info.lamports() >= payout.checked_add(rent_reserve).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    // LP tokens for the shares are burned from the user's account
+    if reward_vault.lp_mint != Pubkey::default() {
+        let (Some(lp_mint), Some(lp_token_account), Some(token_program)) = (
+            &ctx.accounts.lp_mint,
+            &ctx.accounts.lp_token_account,
+            &ctx.accounts.token_program,
+        ) else {
+            return err!(CasinoError::MissingLpAccounts);
+        };
+        
+        token::burn(
+            CpiContext::new(
+                token_program.to_account_info(),
+                Burn {
+                    mint: lp_mint.to_account_info(),
+                    from: lp_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            shares,
+        )?;
+    }
+    
+    **vault_info.try_borrow_mut_lamports()? -= payout;
+    **ctx.accounts.user.to_account_info().try_borrow_mut_lamports()? += payout;
+    
+    position.shares -= shares;
+    position.principal -= principal;
+    
+    reward_vault.staked_amount = reward_vault.staked_amount.saturating_sub(principal);
+    reward_vault.total_shares -= shares;
+    reward_vault.staker_assets = reward_vault.staker_assets
+        .checked_sub(payout)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Unstaked {} shares for {} lamports ({} penalty)", shares, payout, penalty);
+    
+    emit!(DefiUnstaked {
+        sequence: config.next_event_sequence(),
+        user: ctx.accounts.user.key(),
+        shares,
+        amount: payout,
+        penalty,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnstakeDefi<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(mut, seeds = [b"stake", user.key().as_ref()], bump = stake_position.bump)]
+    pub stake_position: Account<'info, StakePosition>,
+    
+    #[account(mut, seeds = [b"reward_claim", user.key().as_ref()], bump = reward_claim.bump)]
+    pub reward_claim: Account<'info, RewardClaim>,
+    
+    /// Vault LP mint; required once `initialize_lp_mint` has run
+    #[account(mut, address = reward_vault.lp_mint)]
+    pub lp_mint: Option<Account<'info, Mint>>,
+    
+    /// User's token account for the LP mint
+    #[account(mut, constraint = lp_token_account.mint == reward_vault.lp_mint @ CasinoError::MissingLpAccounts)]
+    pub lp_token_account: Option<Account<'info, TokenAccount>>,
+    
+    pub token_program: Option<Program<'info, Token>>,
+    
+    #[account(mut)]
+    pub user: Signer<'info>,
+}
+
+#[event]
+pub struct DefiUnstaked {
+    pub sequence: u64,
+    pub user: Pubkey,
+    pub shares: u64,
+    pub amount: u64,
+    pub penalty: u64,
+}
@@ -2,28 +2,81 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::CasinoError;
 
-/// Update configuration parameters (authority only)
+/// Update configuration parameters
+/// Authority can change everything; the risk manager can only change bet limits
 pub fn update_config(
     ctx: Context<UpdateConfig>,
-    jackpot_percentage: Option<u16>,
-    house_percentage: Option<u16>,
-    defi_percentage: Option<u16>,
-    min_bet: Option<u64>,
-    max_bet: Option<u64>,
-    win_probability_bps: Option<u16>,
-    reset_threshold: Option<u64>,
-    milestone_bets: Option<u64>,
-    apy_bps: Option<u16>,
+    update: ConfigUpdate,
 ) -> Result<()> {
     let config = &mut ctx.accounts.config;
     let pool = &mut ctx.accounts.pool;
-    let reward_vault = &mut ctx.accounts.reward_vault;
     
+    let signer = ctx.accounts.authority.key();
+    let is_admin = signer == config.authority;
+    
+    // Risk manager is limited to min/max bet
     require!(
-        ctx.accounts.authority.key() == config.authority,
+        is_admin || (signer == config.risk_manager && update.only_bet_limits()),
         CasinoError::Unauthorized
     );
     
+    let ConfigUpdate {
+        jackpot_percentage,
+        house_percentage,
+        defi_percentage,
+        min_bet,
+        max_bet,
+        win_probability_bps,
+        reset_threshold,
+        milestone_bets,
+        big_win_threshold,
+        min_pool_seed,
+        vesting_threshold,
+        max_payout,
+        must_hit_by,
+        odds_mode,
+        odds_ramp_bps,
+        odds_ramp_max_bps,
+        consolation_bps,
+        consolation_amount,
+        referral_fee_bps,
+        comp_points_per_sol,
+        comp_point_value,
+        min_comp_redemption,
+        allowlist_enabled,
+        draw_bps,
+        community_share_bps,
+        streak_days,
+        streak_odds_bonus_bps,
+        streak_rakeback_bps,
+        cashback_bps,
+        max_exposure_bps,
+        max_bet_bps,
+        sweep_threshold,
+        sweep_bounty_bps,
+        sweep_treasury,
+        keeper_fee,
+        expired_bet_bounty_bps,
+        min_settle_delay_slots,
+        max_bets_per_slot,
+        bet_cooldown_secs,
+        manual_review_threshold,
+        payout_dispute_window_secs,
+        emergency_delay_secs,
+        stake_lockup_secs,
+        early_exit_penalty_bps,
+        lending_program,
+        min_claim_amount,
+        claim_cooldown_secs,
+        double_or_nothing_window_secs,
+        max_parlay_payout,
+        dice_house_edge_bps,
+        bonus_wheel_losses,
+    } = update;
+    
+    // Snapshot for the audit trail in `ConfigUpdated`
+    let before: Config = (**config).clone();
+    
     // Update config fields if provided
     if let Some(jp) = jackpot_percentage {
         config.jackpot_percentage = jp;
@@ -52,10 +105,19 @@ pub fn update_config(
         config.win_probability_bps = wp;
     }
     
+    if let Some(bwt) = big_win_threshold {
+        config.big_win_threshold = bwt;
+    }
+    
+    if let Some(v) = draw_bps {
+        config.draw_bps = v;
+    }
+    
     // Validate total percentage
     let total_percentage = config.jackpot_percentage
         .checked_add(config.house_percentage)
         .and_then(|x| x.checked_add(config.defi_percentage))
+        .and_then(|x| x.checked_add(config.draw_bps))
         .ok_or(CasinoError::MathOverflow)?;
     
     require!(
@@ -63,6 +125,12 @@ pub fn update_config(
         CasinoError::InvalidConfig
     );
     
+    // Running experiments may not return more than the base jackpot share
+    require!(
+        config.experiment_rtp_bps() <= config.jackpot_percentage as u64,
+        CasinoError::ExperimentRtpExceeded
+    );
+    
     // Update pool
     if let Some(rt) = reset_threshold {
         pool.reset_threshold = rt;
@@ -72,15 +140,203 @@ pub fn update_config(
         pool.milestone_bets = mb;
     }
     
-    // Update reward vault
-    if let Some(apy) = apy_bps {
-        reward_vault.apy_bps = apy;
+    if let Some(mps) = min_pool_seed {
+        config.min_pool_seed = mps;
+    }
+    
+    if let Some(vt) = vesting_threshold {
+        config.vesting_threshold = vt;
+    }
+    
+    if let Some(mp) = max_payout {
+        config.max_payout = mp;
+    }
+    
+    if let Some(mhb) = must_hit_by {
+        pool.must_hit_by = mhb;
+        pool.must_hit_armed = mhb > 0 && pool.balance >= mhb;
+    }
+    
+    if let Some(om) = odds_mode {
+        require!(om <= ODDS_MODE_SCALED, CasinoError::InvalidConfig);
+        config.odds_mode = om;
+    }
+    
+    if let Some(ramp) = odds_ramp_bps {
+        require!(ramp <= 10000, CasinoError::InvalidConfig);
+        config.odds_ramp_bps = ramp;
+    }
+    
+    if let Some(cap) = odds_ramp_max_bps {
+        require!(cap <= 10000, CasinoError::InvalidConfig);
+        config.odds_ramp_max_bps = cap;
+    }
+    
+    if let Some(band) = consolation_bps {
+        require!(band <= 10000, CasinoError::InvalidConfig);
+        config.consolation_bps = band;
+    }
+    
+    if let Some(amount) = consolation_amount {
+        config.consolation_amount = amount;
+    }
+    
+    if let Some(bps) = referral_fee_bps {
+        require!(bps <= 10000, CasinoError::InvalidConfig);
+        config.referral_fee_bps = bps;
+    }
+    
+    if let Some(v) = comp_points_per_sol {
+        config.comp_points_per_sol = v;
+    }
+    
+    if let Some(v) = comp_point_value {
+        config.comp_point_value = v;
+    }
+    
+    if let Some(v) = min_comp_redemption {
+        config.min_comp_redemption = v;
+    }
+    
+    if let Some(v) = allowlist_enabled {
+        config.allowlist_enabled = v;
+    }
+    
+    if let Some(v) = community_share_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.community_share_bps = v;
+    }
+    
+    if let Some(v) = streak_days {
+        config.streak_days = v;
+    }
+    
+    if let Some(v) = streak_odds_bonus_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.streak_odds_bonus_bps = v;
+    }
+    
+    if let Some(v) = streak_rakeback_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.streak_rakeback_bps = v;
+    }
+    
+    if let Some(v) = cashback_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.cashback_bps = v;
+    }
+    
+    if let Some(v) = max_exposure_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.max_exposure_bps = v;
+    }
+    
+    if let Some(v) = max_bet_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.max_bet_bps = v;
+    }
+    
+    if let Some(v) = sweep_threshold {
+        config.sweep_threshold = v;
+    }
+    
+    if let Some(v) = sweep_bounty_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.sweep_bounty_bps = v;
+    }
+    
+    if let Some(v) = sweep_treasury {
+        config.sweep_treasury = v;
+    }
+    
+    if let Some(v) = keeper_fee {
+        config.keeper_fee = v;
+    }
+    
+    if let Some(v) = expired_bet_bounty_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.expired_bet_bounty_bps = v;
+    }
+    
+    if let Some(v) = min_settle_delay_slots {
+        config.min_settle_delay_slots = v;
+    }
+    
+    if let Some(v) = max_bets_per_slot {
+        config.max_bets_per_slot = v;
+    }
+    
+    if let Some(v) = bet_cooldown_secs {
+        require!(v >= 0, CasinoError::InvalidConfig);
+        config.bet_cooldown_secs = v;
+    }
+    
+    if let Some(v) = manual_review_threshold {
+        config.manual_review_threshold = v;
+    }
+    
+    if let Some(v) = payout_dispute_window_secs {
+        require!(v >= 0, CasinoError::InvalidConfig);
+        config.payout_dispute_window_secs = v;
+    }
+    
+    if let Some(v) = emergency_delay_secs {
+        require!(v >= MIN_EMERGENCY_DELAY_SECS, CasinoError::InvalidConfig);
+        config.emergency_delay_secs = v;
+    }
+    
+    if let Some(v) = stake_lockup_secs {
+        require!(v >= 0, CasinoError::InvalidConfig);
+        config.stake_lockup_secs = v;
+    }
+    
+    if let Some(v) = early_exit_penalty_bps {
+        require!(v <= 10000, CasinoError::InvalidConfig);
+        config.early_exit_penalty_bps = v;
+    }
+    
+    if let Some(v) = lending_program {
+        config.lending_program = v;
+    }
+    
+    if let Some(v) = min_claim_amount {
+        config.min_claim_amount = v;
+    }
+    
+    if let Some(v) = claim_cooldown_secs {
+        require!(v >= 0, CasinoError::InvalidConfig);
+        config.claim_cooldown_secs = v;
+    }
+    
+    if let Some(v) = double_or_nothing_window_secs {
+        require!(v >= 0, CasinoError::InvalidConfig);
+        config.double_or_nothing_window_secs = v;
+    }
+    
+    if let Some(v) = max_parlay_payout {
+        config.max_parlay_payout = v;
+    }
+    
+    if let Some(v) = dice_house_edge_bps {
+        require!(v < 10000, CasinoError::InvalidConfig);
+        config.dice_house_edge_bps = v;
+    }
+    
+    if let Some(v) = bonus_wheel_losses {
+        config.bonus_wheel_losses = v;
     }
     
     msg!("Config updated by {}", ctx.accounts.authority.key());
     
+    let sequence = ctx.accounts.config.next_event_sequence();
     emit!(ConfigUpdated {
+        sequence,
         authority: ctx.accounts.authority.key(),
+        timestamp: Clock::get()?.unix_timestamp,
+        before_params_hash: before.params_hash(),
+        after_params_hash: ctx.accounts.config.params_hash(),
+        before,
+        after: (*ctx.accounts.config).clone(),
     });
     
     Ok(())
@@ -91,16 +347,21 @@ pub struct UpdateConfig<'info> {
     #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
     
-    #[account(mut, seeds = [b"pool"], bump = pool.bump)]
+    #[account(mut, seeds = [b"pool", pool.pool_id.to_le_bytes().as_ref()], bump = pool.bump)]
     pub pool: Account<'info, JackpotPool>,
     
-    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
-    pub reward_vault: Account<'info, RewardVault>,
-    
     pub authority: Signer<'info>,
 }
 
 #[event]
 pub struct ConfigUpdated {
+    pub sequence: u64,
     pub authority: Pubkey,
+    pub timestamp: i64,
+    /// `Config::params_hash` before and after; bets store the hash active at placement
+    pub before_params_hash: [u8; 32],
+    pub after_params_hash: [u8; 32],
+    /// Full config snapshots around the update
+    pub before: Config,
+    pub after: Config,
 }
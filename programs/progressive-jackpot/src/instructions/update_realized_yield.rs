@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Share strategy yield realized since the last update across current stakers (permissionless)
+/// Stake changes and `withdraw_from_strategy` distribute as they go; this picks up yield realized while nothing was staked
+pub fn update_realized_yield(
+    ctx: Context<UpdateRealizedYield>,
+) -> Result<()> {
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    
+    reward_vault.accrue(Clock::get()?.unix_timestamp)?;
+    let distributed = reward_vault.distribute_yield()?;
+    
+    require!(
+        distributed > 0,
+        CasinoError::NoRewardsAvailable
+    );
+    
+    msg!("Realized yield distributed: {} lamports over {} staked", distributed, reward_vault.staked_amount);
+    
+    emit!(RealizedYieldUpdated {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        amount: distributed,
+        staked_amount: reward_vault.staked_amount,
+        acc_reward_per_share: reward_vault.acc_reward_per_share,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateRealizedYield<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    pub keeper: Signer<'info>,
+}
+
+#[event]
+pub struct RealizedYieldUpdated {
+    pub sequence: u64,
+    pub amount: u64,
+    pub staked_amount: u64,
+    pub acc_reward_per_share: u128,
+}
@@ -0,0 +1,59 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Withdraw risk capital from the house bankroll (treasurer only)
+pub fn withdraw_bankroll(
+    ctx: Context<WithdrawBankroll>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.treasurer.key() == ctx.accounts.config.treasurer,
+        CasinoError::Unauthorized
+    );
+    
+    let bankroll = &mut ctx.accounts.bankroll;
+    require!(
+        amount <= bankroll.balance,
+        CasinoError::InsufficientFunds
+    );
+    
+    **bankroll.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.treasurer.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    bankroll.balance -= amount;
+    bankroll.total_withdrawn = bankroll.total_withdrawn
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Bankroll withdrew {} lamports, balance {}", amount, bankroll.balance);
+    
+    emit!(BankrollWithdrawn {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        treasurer: ctx.accounts.treasurer.key(),
+        amount,
+        balance: bankroll.balance,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBankroll<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"bankroll"], bump = bankroll.bump)]
+    pub bankroll: Account<'info, Bankroll>,
+    
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+}
+
+#[event]
+pub struct BankrollWithdrawn {
+    pub sequence: u64,
+    pub treasurer: Pubkey,
+    pub amount: u64,
+    pub balance: u64,
+}
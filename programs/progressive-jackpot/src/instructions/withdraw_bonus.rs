@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Player withdraws their bonus balance once its wagering requirement is met
+pub fn withdraw_bonus(
+    ctx: Context<WithdrawBonus>,
+) -> Result<()> {
+    require!(
+        ctx.accounts.frozen_player.data_is_empty(),
+        CasinoError::PlayerFrozen
+    );
+    
+    let bonus = &mut ctx.accounts.bonus_credit;
+    
+    require!(
+        bonus.wagering_remaining == 0,
+        CasinoError::BonusWageringIncomplete
+    );
+    
+    let amount = bonus.balance;
+    require!(
+        amount > 0,
+        CasinoError::InsufficientBonusCredit
+    );
+    
+    **bonus.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.player.to_account_info().try_borrow_mut_lamports()? += amount;
+    
+    bonus.balance = 0;
+    
+    msg!("Bonus withdrawn: {} lamports by {}", amount, ctx.accounts.player.key());
+    
+    emit!(BonusWithdrawn {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        player: ctx.accounts.player.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawBonus<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"bonus", player.key().as_ref()], bump = bonus_credit.bump)]
+    pub bonus_credit: Account<'info, BonusCredit>,
+    
+    /// CHECK: player's FrozenPlayer PDA; must be uninitialized
+    #[account(seeds = [b"frozen", player.key().as_ref()], bump)]
+    pub frozen_player: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub player: Signer<'info>,
+}
+
+#[event]
+pub struct BonusWithdrawn {
+    pub sequence: u64,
+    pub player: Pubkey,
+    pub amount: u64,
+}
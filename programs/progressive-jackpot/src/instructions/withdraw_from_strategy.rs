@@ -0,0 +1,126 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::system_program;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Unstake LST from the yield strategy back into the reward vault (authority only)
+/// Anything received above the principal those tokens covered is shared right away across the current stake
+pub fn withdraw_from_strategy<'info>(
+    ctx: Context<'_, '_, 'info, 'info, WithdrawFromStrategy<'info>>,
+    lst_amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.authority,
+        CasinoError::Unauthorized
+    );
+    
+    let reward_vault = &ctx.accounts.reward_vault;
+    let call = reward_vault.strategy.withdraw_call().ok_or(CasinoError::StrategyNotSet)?;
+    let protocol_accounts = ctx.remaining_accounts;
+    require!(
+        Some(ctx.accounts.strategy_program.key()) == reward_vault.strategy.program_id()
+            && protocol_accounts.len() == call.accounts
+            && protocol_accounts[call.signer].key() == reward_vault.key()
+            && protocol_accounts[call.escrow].key() == ctx.accounts.strategy_escrow.key()
+            && protocol_accounts[call.lst_account].key() == ctx.accounts.lst_account.key(),
+        CasinoError::InvalidStrategyAccounts
+    );
+    
+    let lst_balance = ctx.accounts.lst_account.amount;
+    require!(
+        lst_amount > 0 && lst_amount <= lst_balance,
+        CasinoError::InvalidAmount
+    );
+    
+    let escrow_before = ctx.accounts.strategy_escrow.lamports();
+    let ix = call.instruction(ctx.accounts.strategy_program.key(), lst_amount, protocol_accounts);
+    invoke_signed(
+        &ix,
+        &[protocol_accounts, &[ctx.accounts.strategy_program.to_account_info()]].concat(),
+        &[&[b"reward_vault", &[reward_vault.bump]]],
+    )?;
+    let received = ctx.accounts.strategy_escrow.lamports()
+        .checked_sub(escrow_before)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    system_program::transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.system_program.to_account_info(),
+            system_program::Transfer {
+                from: ctx.accounts.strategy_escrow.to_account_info(),
+                to: ctx.accounts.reward_vault.to_account_info(),
+            },
+            &[&[b"strategy_escrow", &[ctx.bumps.strategy_escrow]]],
+        ),
+        received,
+    )?;
+    
+    let reward_vault = &mut ctx.accounts.reward_vault;
+    let principal = ((reward_vault.strategy_deployed as u128) * (lst_amount as u128)
+        / (lst_balance as u128)) as u64;
+    let yield_earned = received.saturating_sub(principal);
+    
+    reward_vault.strategy_deployed -= principal;
+    reward_vault.strategy_yield = reward_vault.strategy_yield
+        .checked_add(yield_earned)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    // Distribute before anyone can stake into the new yield; it waits for `update_realized_yield` while nothing is staked
+    reward_vault.accrue(Clock::get()?.unix_timestamp)?;
+    reward_vault.distribute_yield()?;
+    
+    msg!("Withdrew {} lamports from {:?} ({} yield)", received, reward_vault.strategy, yield_earned);
+    
+    emit!(StrategyWithdrawn {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        strategy: reward_vault.strategy,
+        lst_amount,
+        amount: received,
+        yield_earned,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawFromStrategy<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"reward_vault"], bump = reward_vault.bump)]
+    pub reward_vault: Account<'info, RewardVault>,
+    
+    #[account(mut, seeds = [b"strategy_escrow"], bump)]
+    pub strategy_escrow: SystemAccount<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"strategy_lst", lst_mint.key().as_ref()],
+        bump
+    )]
+    pub lst_account: Account<'info, TokenAccount>,
+    
+    #[account(address = reward_vault.strategy_lst_mint)]
+    pub lst_mint: Account<'info, Mint>,
+    
+    /// CHECK: must be the strategy's program, checked in the handler
+    #[account(executable)]
+    pub strategy_program: UncheckedAccount<'info>,
+    
+    pub authority: Signer<'info>,
+    
+    pub token_program: Program<'info, Token>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct StrategyWithdrawn {
+    pub sequence: u64,
+    pub strategy: YieldStrategy,
+    pub lst_amount: u64,
+    pub amount: u64,
+    pub yield_earned: u64,
+}
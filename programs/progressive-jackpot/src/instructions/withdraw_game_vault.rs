@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::error::CasinoError;
+
+/// Treasurer withdraws escrowed wagers from the game vault
+pub fn withdraw_game_vault(
+    ctx: Context<WithdrawGameVault>,
+    amount: u64,
+) -> Result<()> {
+    require!(
+        ctx.accounts.authority.key() == ctx.accounts.config.treasurer,
+        CasinoError::Unauthorized
+    );
+    
+    // Keep the vault rent-exempt
+    let vault_info = ctx.accounts.game_vault.to_account_info();
+    let rent_reserve = Rent::get()?.minimum_balance(vault_info.data_len());
+    require!(
+        vault_info.lamports() >= amount.checked_add(rent_reserve).ok_or(CasinoError::MathOverflow)?,
+        CasinoError::InsufficientFunds
+    );
+    
+    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+    **vault_info.try_borrow_mut_lamports()? -= amount;
+    
+    let game_vault = &mut ctx.accounts.game_vault;
+    game_vault.total_withdrawn = game_vault.total_withdrawn
+        .checked_add(amount)
+        .ok_or(CasinoError::MathOverflow)?;
+    
+    msg!("Treasurer withdrew {} lamports from the game vault", amount);
+    
+    emit!(GameVaultWithdrawal {
+        sequence: ctx.accounts.config.next_event_sequence(),
+        authority: ctx.accounts.authority.key(),
+        amount,
+    });
+    
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct WithdrawGameVault<'info> {
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
+    pub config: Account<'info, Config>,
+    
+    #[account(mut, seeds = [b"game_vault"], bump = game_vault.bump)]
+    pub game_vault: Account<'info, GameVault>,
+    
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[event]
+pub struct GameVaultWithdrawal {
+    pub sequence: u64,
+    pub authority: Pubkey,
+    pub amount: u64,
+}
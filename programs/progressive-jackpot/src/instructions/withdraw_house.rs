@@ -2,7 +2,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::error::CasinoError;
 
-/// House authority withdraws accumulated fees
+/// Treasurer withdraws accumulated house fees
 pub fn withdraw_house(
     ctx: Context<WithdrawHouse>,
     amount: u64,
@@ -10,24 +10,41 @@ pub fn withdraw_house(
     let config = &ctx.accounts.config;
     
     require!(
-        ctx.accounts.authority.key() == config.authority,
+        ctx.accounts.authority.key() == config.treasurer,
         CasinoError::Unauthorized
     );
     
-    let vault_balance = ctx.accounts.house_vault.to_account_info().lamports();
     require!(
-        vault_balance >= amount,
+        amount <= ctx.accounts.house_vault.accumulated_house_fees,
+        CasinoError::HouseFeesExceeded
+    );
+    
+    // Keep the vault rent-exempt and able to pay out owed rakeback
+    let vault_info = ctx.accounts.house_vault.to_account_info();
+    let reserved = ctx.accounts.house_vault.reserved_lamports(vault_info.data_len())?;
+    require!(
+        vault_info.lamports() >= amount.checked_add(reserved).ok_or(CasinoError::MathOverflow)?,
         CasinoError::InsufficientFunds
     );
     
-    // Transfer to authority
-    **ctx.accounts.authority.to_account_info().try_borrow_mut_lamports()? += amount;
+    // Pay the treasurer or one of the allowlisted treasury addresses
+    let destination = ctx.accounts.destination.key();
+    require!(
+        destination == config.treasurer || config.is_treasury_destination(&destination),
+        CasinoError::InvalidTreasuryDestination
+    );
+    
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
     **ctx.accounts.house_vault.to_account_info().try_borrow_mut_lamports()? -= amount;
     
-    msg!("House withdrew {} lamports", amount);
+    ctx.accounts.house_vault.accumulated_house_fees -= amount;
+    
+    msg!("House withdrew {} lamports to {}", amount, destination);
     
     emit!(HouseWithdrawal {
+        sequence: ctx.accounts.config.next_event_sequence(),
         authority: ctx.accounts.authority.key(),
+        destination,
         amount,
     });
     
@@ -36,20 +53,25 @@ pub fn withdraw_house(
 
 #[derive(Accounts)]
 pub struct WithdrawHouse<'info> {
-    #[account(seeds = [b"config"], bump = config.bump)]
+    #[account(mut, seeds = [b"config"], bump = config.bump)]
     pub config: Account<'info, Config>,
     
-    /// CHECK: House vault for fees
-    #[account(mut)]
-    pub house_vault: AccountInfo<'info>,
+    #[account(mut, seeds = [b"house_vault"], bump = house_vault.bump)]
+    pub house_vault: Account<'info, HouseVault>,
     
     pub authority: Signer<'info>,
     
+    /// CHECK: the treasurer or an address in `Config::treasury_destinations`, checked in the handler
+    #[account(mut)]
+    pub destination: UncheckedAccount<'info>,
+    
     pub system_program: Program<'info, System>,
 }
 
 #[event]
 pub struct HouseWithdrawal {
+    pub sequence: u64,
     pub authority: Pubkey,
+    pub destination: Pubkey,
     pub amount: u64,
 }
@@ -1,10 +1,28 @@
 use anchor_lang::prelude::*;
 
+/// Emit an event through a self-CPI when built with `event-cpi`, otherwise to the program log
+/// Self-CPI events survive log truncation; the handler's accounts need `#[event_cpi]` under the feature
+macro_rules! emit_event {
+    ($ctx:expr, $event:expr) => {{
+        #[cfg(feature = "event-cpi")]
+        {
+            let event = $event;
+            let ctx = &$ctx;
+            emit_cpi!(event);
+        }
+        #[cfg(not(feature = "event-cpi"))]
+        {
+            emit!($event);
+        }
+    }};
+}
+
 pub mod error;
 pub mod state;
 pub mod instructions;
 
 use instructions::*;
+use state::{ConfigUpdate, ExperimentVariant, HappyHour, LottoRoundParams, PaytableEntry, PoolParams, ScratchOutcome, SplitShare, VipTier, WheelPrize, YieldStrategy};
 
 declare_id!("JACKPOT1111111111111111111111111111111");
 
@@ -26,7 +44,6 @@ pub mod progressive_jackpot {
         switchboard_queue: Option<Pubkey>,
         reset_threshold: u64,
         milestone_bets: u64,
-        apy_bps: u16,
     ) -> Result<()> {
         instructions::initialize::initialize(
             ctx,
@@ -41,26 +58,357 @@ pub mod progressive_jackpot {
             switchboard_queue,
             reset_threshold,
             milestone_bets,
-            apy_bps,
         )
     }
 
+    /// Create the win history ring buffer (authority only)
+    pub fn initialize_win_history(
+        ctx: Context<InitializeWinHistory>,
+    ) -> Result<()> {
+        instructions::initialize_win_history::initialize_win_history(ctx)
+    }
+
+    /// Create the per-epoch RTP stats account (authority only)
+    pub fn initialize_stats(
+        ctx: Context<InitializeStats>,
+    ) -> Result<()> {
+        instructions::initialize_stats::initialize_stats(ctx)
+    }
+
+    /// Create the keeper notification queue (authority only)
+    pub fn initialize_notification_queue(
+        ctx: Context<InitializeNotificationQueue>,
+    ) -> Result<()> {
+        instructions::initialize_notification_queue::initialize_notification_queue(ctx)
+    }
+
+    /// Create the overflow reserve pool (authority only)
+    pub fn initialize_reserve_pool(
+        ctx: Context<InitializeReservePool>,
+    ) -> Result<()> {
+        instructions::initialize_reserve_pool::initialize_reserve_pool(ctx)
+    }
+
+    /// Create the game vault that escrows bet remainders (authority only)
+    pub fn initialize_game_vault(
+        ctx: Context<InitializeGameVault>,
+    ) -> Result<()> {
+        instructions::initialize_game_vault::initialize_game_vault(ctx)
+    }
+
+    /// Create the vault that collects charity round-ups (authority only)
+    pub fn initialize_charity_vault(
+        ctx: Context<InitializeCharityVault>,
+        charity: Pubkey,
+    ) -> Result<()> {
+        instructions::initialize_charity_vault::initialize_charity_vault(ctx, charity)
+    }
+
+    /// Open an additional jackpot pool with its own limits and odds (authority only)
+    pub fn create_pool(
+        ctx: Context<CreatePool>,
+        pool_id: u64,
+        params: PoolParams,
+    ) -> Result<()> {
+        instructions::create_pool::create_pool(ctx, pool_id, params)
+    }
+
     /// Player contributes a bet to the jackpot pool
     pub fn contribute_bet(
         ctx: Context<ContributeBet>,
         amount: u64,
+        charity_bps: Option<u16>,
     ) -> Result<()> {
-        instructions::contribute_bet::contribute_bet(ctx, amount)
+        instructions::contribute_bet::contribute_bet(ctx, amount, charity_bps)
     }
 
     /// Fulfill jackpot win based on VRF result
     pub fn fulfill_jackpot(
         ctx: Context<FulfillJackpot>,
         vrf_result: [u8; 32],
-    ) -> Result<()> {
+    ) -> Result<SettlementResult> {
         instructions::fulfill_jackpot::fulfill_jackpot(ctx, vrf_result)
     }
 
+    /// Settle multiple pending bets in one transaction (crank helper)
+    pub fn fulfill_jackpots_batch<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FulfillJackpotsBatch<'info>>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::fulfill_jackpots_batch::fulfill_jackpots_batch(ctx, vrf_result)
+    }
+
+    /// Re-wager a just-lost bet at 50/50 against the house
+    pub fn double_or_nothing(ctx: Context<DoubleOrNothing>) -> Result<()> {
+        instructions::double_or_nothing::double_or_nothing(ctx)
+    }
+
+    /// Settle a double-or-nothing re-wager with VRF randomness
+    pub fn fulfill_double_or_nothing(
+        ctx: Context<FulfillDoubleOrNothing>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::fulfill_double_or_nothing::fulfill_double_or_nothing(ctx, vrf_result)
+    }
+
+    /// Place a parlay of 2 to 5 sequential legs, each riding on the last one's payout
+    pub fn create_parlay(
+        ctx: Context<CreateParlay>,
+        stake: u64,
+        legs: Vec<u16>,
+    ) -> Result<()> {
+        instructions::create_parlay::create_parlay(ctx, stake, legs)
+    }
+
+    /// Settle a parlay's current leg with VRF randomness
+    pub fn fulfill_parlay_leg(
+        ctx: Context<FulfillParlayLeg>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::fulfill_parlay_leg::fulfill_parlay_leg(ctx, vrf_result)
+    }
+
+    /// Collect a won parlay, or cash out one whose leg timed out
+    pub fn claim_parlay(ctx: Context<ClaimParlay>) -> Result<()> {
+        instructions::claim_parlay::claim_parlay(ctx)
+    }
+
+    /// Open a side-bet round on when the pool's next jackpot hits (authority or operator)
+    pub fn open_side_bet_round(
+        ctx: Context<OpenSideBetRound>,
+        round_id: u64,
+        bucket_bounds: Vec<u64>,
+        rake_bps: u16,
+        betting_closes_at: i64,
+    ) -> Result<()> {
+        instructions::open_side_bet_round::open_side_bet_round(ctx, round_id, bucket_bounds, rake_bps, betting_closes_at)
+    }
+
+    /// Stake on the bets-since-win bucket the next jackpot hit lands in
+    pub fn place_side_bet(
+        ctx: Context<PlaceSideBet>,
+        bucket: u8,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::place_side_bet::place_side_bet(ctx, bucket, amount)
+    }
+
+    /// Collect a share of a settled side-bet round
+    pub fn claim_side_bet(ctx: Context<ClaimSideBet>) -> Result<()> {
+        instructions::claim_side_bet::claim_side_bet(ctx)
+    }
+
+    /// Roll dice at a player-chosen win chance between 1% and 95%
+    pub fn play_dice(
+        ctx: Context<PlayDice>,
+        amount: u64,
+        target: u16,
+        over_under: bool,
+    ) -> Result<()> {
+        instructions::play_dice::play_dice(ctx, amount, target, over_under)
+    }
+
+    /// Settle a dice roll with VRF randomness
+    pub fn fulfill_dice(
+        ctx: Context<FulfillDice>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::fulfill_dice::fulfill_dice(ctx, vrf_result)
+    }
+
+    /// Replace the slots paytable (authority only)
+    pub fn set_paytable(
+        ctx: Context<SetPaytable>,
+        entries: Vec<PaytableEntry>,
+    ) -> Result<()> {
+        instructions::set_paytable::set_paytable(ctx, entries)
+    }
+
+    /// Spin the slots against the paytable
+    pub fn play_slots(
+        ctx: Context<PlaySlots>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::play_slots::play_slots(ctx, amount)
+    }
+
+    /// Settle a slots spin with VRF randomness
+    pub fn fulfill_slots(
+        ctx: Context<FulfillSlots>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::fulfill_slots::fulfill_slots(ctx, vrf_result)
+    }
+
+    /// Commit a batch of pre-generated scratch cards (authority or operator)
+    pub fn open_scratch_batch(
+        ctx: Context<OpenScratchBatch>,
+        batch_id: u64,
+        merkle_root: [u8; 32],
+        card_count: u32,
+        card_price: u64,
+        total_prizes: u64,
+    ) -> Result<()> {
+        instructions::open_scratch_batch::open_scratch_batch(ctx, batch_id, merkle_root, card_count, card_price, total_prizes)
+    }
+
+    /// Buy the next card in a scratch batch
+    pub fn buy_scratch_card(
+        ctx: Context<BuyScratchCard>,
+    ) -> Result<()> {
+        instructions::buy_scratch_card::buy_scratch_card(ctx)
+    }
+
+    /// Reveal a scratch card's committed outcome and pay any prize
+    pub fn reveal_card(
+        ctx: Context<RevealCard>,
+        index: u32,
+        outcome: ScratchOutcome,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::reveal_card::reveal_card(ctx, index, outcome, proof)
+    }
+
+    /// Retire a fully revealed scratch batch (authority or operator)
+    pub fn close_scratch_batch(
+        ctx: Context<CloseScratchBatch>,
+    ) -> Result<()> {
+        instructions::close_scratch_batch::close_scratch_batch(ctx)
+    }
+
+    /// Open a pick-N lottery round (authority or operator)
+    pub fn open_lotto_round(
+        ctx: Context<OpenLottoRound>,
+        round_id: u64,
+        params: LottoRoundParams,
+    ) -> Result<()> {
+        instructions::open_lotto_round::open_lotto_round(ctx, round_id, params)
+    }
+
+    /// Buy a lottery ticket with picked numbers
+    pub fn buy_lotto_ticket(
+        ctx: Context<BuyLottoTicket>,
+        numbers: Vec<u8>,
+    ) -> Result<()> {
+        instructions::buy_lotto_ticket::buy_lotto_ticket(ctx, numbers)
+    }
+
+    /// Draw a lottery round's winning numbers with VRF randomness
+    pub fn draw_lotto(
+        ctx: Context<DrawLotto>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::draw_lotto::draw_lotto(ctx, vrf_result)
+    }
+
+    /// Tally or pay a batch of lottery tickets passed in `remaining_accounts`
+    pub fn crank_lotto<'info>(
+        ctx: Context<'_, '_, 'info, 'info, CrankLotto<'info>>,
+    ) -> Result<()> {
+        instructions::crank_lotto::crank_lotto(ctx)
+    }
+
+    /// Replace the bonus wheel's prize table (authority only)
+    pub fn set_bonus_wheel(
+        ctx: Context<SetBonusWheel>,
+        prizes: Vec<WheelPrize>,
+    ) -> Result<()> {
+        instructions::set_bonus_wheel::set_bonus_wheel(ctx, prizes)
+    }
+
+    /// Spend an earned bonus wheel spin
+    pub fn spin_bonus_wheel(
+        ctx: Context<SpinBonusWheel>,
+    ) -> Result<()> {
+        instructions::spin_bonus_wheel::spin_bonus_wheel(ctx)
+    }
+
+    /// Settle a bonus wheel spin with VRF randomness
+    pub fn fulfill_bonus_wheel(
+        ctx: Context<FulfillBonusWheel>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::fulfill_bonus_wheel::fulfill_bonus_wheel(ctx, vrf_result)
+    }
+
+    /// Commit a mystery jackpot's secret threshold on a pool (authority or operator)
+    pub fn commit_mystery_jackpot(
+        ctx: Context<CommitMysteryJackpot>,
+        round_id: u64,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::commit_mystery_jackpot::commit_mystery_jackpot(ctx, round_id, commitment)
+    }
+
+    /// Reveal a mystery jackpot's threshold and pay the triggering bet
+    pub fn reveal_mystery(
+        ctx: Context<RevealMystery>,
+        threshold: u64,
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::reveal_mystery::reveal_mystery(ctx, threshold, salt)
+    }
+
+    /// Replace the happy-hour windows (authority only; empty clears them)
+    pub fn set_happy_hours(
+        ctx: Context<SetHappyHours>,
+        windows: Vec<HappyHour>,
+    ) -> Result<()> {
+        instructions::set_happy_hours::set_happy_hours(ctx, windows)
+    }
+
+    /// Winner claims a settled jackpot payout
+    pub fn claim_win<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimWin<'info>>,
+    ) -> Result<()> {
+        instructions::claim_win::claim_win(ctx)
+    }
+
+    /// Winner claims the vested portion of a large jackpot win
+    pub fn claim_vested_win<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimVestedWin<'info>>,
+    ) -> Result<()> {
+        instructions::claim_vested_win::claim_vested_win(ctx)
+    }
+
+    /// Winner holds, then claims, a win above the manual review threshold
+    pub fn claim_reviewed_win<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimReviewedWin<'info>>,
+    ) -> Result<()> {
+        instructions::claim_reviewed_win::claim_reviewed_win(ctx)
+    }
+
+    /// Approve a held payout before its dispute window ends (payout approver only)
+    pub fn approve_payout(
+        ctx: Context<ApprovePayout>,
+    ) -> Result<()> {
+        instructions::approve_payout::approve_payout(ctx)
+    }
+
+    /// Player contests a bet, freezing its payout until resolved
+    pub fn open_dispute(
+        ctx: Context<OpenDispute>,
+        reason: u8,
+    ) -> Result<()> {
+        instructions::open_dispute::open_dispute(ctx, reason)
+    }
+
+    /// Resolve a bet dispute and unfreeze its payout (authority only)
+    pub fn resolve_dispute(
+        ctx: Context<ResolveDispute>,
+        upheld: bool,
+        resolution_hash: [u8; 32],
+    ) -> Result<()> {
+        instructions::resolve_dispute::resolve_dispute(ctx, upheld, resolution_hash)
+    }
+
+    /// Pay out community shares from the pool's last split (permissionless crank)
+    pub fn claim_community_share<'info>(
+        ctx: Context<'_, '_, 'info, 'info, ClaimCommunityShare<'info>>,
+    ) -> Result<()> {
+        instructions::claim_community_share::claim_community_share(ctx)
+    }
+
     /// Claim DeFi rewards from staked pool
     pub fn claim_rewards(
         ctx: Context<ClaimRewards>,
@@ -68,7 +416,100 @@ pub mod progressive_jackpot {
         instructions::claim_rewards::claim_rewards(ctx)
     }
 
-    /// House authority withdraws accumulated fees
+    /// Opt in or out of letting anyone compound the caller's DeFi rewards
+    pub fn set_auto_compound(
+        ctx: Context<SetAutoCompound>,
+        enabled: bool,
+    ) -> Result<()> {
+        instructions::set_auto_compound::set_auto_compound(ctx, enabled)
+    }
+
+    /// Let a delegate trigger `claim_rewards` for the caller; payouts still go to the caller
+    pub fn set_claim_delegate(
+        ctx: Context<SetClaimDelegate>,
+        delegate: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_claim_delegate::set_claim_delegate(ctx, delegate)
+    }
+
+    /// Roll accrued DeFi rewards into the user's stake
+    pub fn compound_rewards(
+        ctx: Context<CompoundRewards>,
+    ) -> Result<()> {
+        instructions::compound_rewards::compound_rewards(ctx)
+    }
+
+    /// Pay DeFi rewards in a project token instead of SOL (authority only)
+    pub fn set_reward_mint(
+        ctx: Context<SetRewardMint>,
+    ) -> Result<()> {
+        instructions::set_reward_mint::set_reward_mint(ctx)
+    }
+
+    /// Set the reward token emission rate and end time (authority only)
+    pub fn set_emission_rate(
+        ctx: Context<SetEmissionRate>,
+        rate_per_second: u64,
+        ends_at: i64,
+    ) -> Result<()> {
+        instructions::set_emission_rate::set_emission_rate(ctx, rate_per_second, ends_at)
+    }
+
+    /// Pick where idle reward vault lamports are deployed (authority only)
+    pub fn set_yield_strategy(
+        ctx: Context<SetYieldStrategy>,
+        strategy: YieldStrategy,
+    ) -> Result<()> {
+        instructions::set_yield_strategy::set_yield_strategy(ctx, strategy)
+    }
+
+    /// Deploy idle reward vault lamports to the yield strategy (authority only)
+    pub fn deploy_to_strategy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeployToStrategy<'info>>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deploy_to_strategy::deploy_to_strategy(ctx, amount)
+    }
+
+    /// Withdraw from the yield strategy back into the reward vault (authority only)
+    pub fn withdraw_from_strategy<'info>(
+        ctx: Context<'_, '_, 'info, 'info, WithdrawFromStrategy<'info>>,
+        lst_amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_from_strategy::withdraw_from_strategy(ctx, lst_amount)
+    }
+
+    /// Distribute realized strategy yield to current stakers (permissionless)
+    pub fn update_realized_yield(
+        ctx: Context<UpdateRealizedYield>,
+    ) -> Result<()> {
+        instructions::update_realized_yield::update_realized_yield(ctx)
+    }
+
+    /// Stake lamports in the reward vault for shares, without betting
+    pub fn stake_defi(
+        ctx: Context<StakeDefi>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::stake_defi::stake_defi(ctx, amount)
+    }
+
+    /// Burn reward vault shares and withdraw their value, less any early-exit penalty
+    pub fn unstake_defi(
+        ctx: Context<UnstakeDefi>,
+        shares: u64,
+    ) -> Result<()> {
+        instructions::unstake_defi::unstake_defi(ctx, shares)
+    }
+
+    /// Create the reward vault's LP share mint before any shares exist (authority only)
+    pub fn initialize_lp_mint(
+        ctx: Context<InitializeLpMint>,
+    ) -> Result<()> {
+        instructions::initialize_lp_mint::initialize_lp_mint(ctx)
+    }
+
+    /// Treasurer withdraws accumulated house fees to themselves or an allowlisted treasury
     pub fn withdraw_house(
         ctx: Context<WithdrawHouse>,
         amount: u64,
@@ -76,30 +517,435 @@ pub mod progressive_jackpot {
         instructions::withdraw_house::withdraw_house(ctx, amount)
     }
 
-    /// Update configuration parameters (authority only)
+    /// Supply idle house fees to the configured lending market (authority only)
+    pub fn deploy_to_lending(
+        ctx: Context<DeployToLending>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::deploy_to_lending::deploy_to_lending(ctx, amount)
+    }
+
+    /// Redeem lending collateral back into house fees (authority only)
+    pub fn recall_from_lending(
+        ctx: Context<RecallFromLending>,
+        collateral_amount: u64,
+    ) -> Result<()> {
+        instructions::recall_from_lending::recall_from_lending(ctx, collateral_amount)
+    }
+
+    /// Replace the treasury addresses house fees may be withdrawn to (authority only)
+    pub fn set_treasury_destinations(
+        ctx: Context<SetTreasuryDestinations>,
+        destinations: Vec<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_treasury_destinations::set_treasury_destinations(ctx, destinations)
+    }
+
+    /// Sweep house fees above `sweep_threshold` to the treasury for a bounty (permissionless)
+    pub fn sweep_house(
+        ctx: Context<SweepHouse>,
+    ) -> Result<()> {
+        instructions::sweep_house::sweep_house(ctx)
+    }
+
+    /// Register as a keeper earning `keeper_fee` per settlement crank
+    pub fn register_keeper(
+        ctx: Context<RegisterKeeper>,
+    ) -> Result<()> {
+        instructions::register_keeper::register_keeper(ctx)
+    }
+
+    /// Refund and close bets whose VRF request timed out, for a rent bounty (permissionless)
+    pub fn sweep_expired_bets<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepExpiredBets<'info>>,
+    ) -> Result<()> {
+        instructions::sweep_expired_bets::sweep_expired_bets(ctx)
+    }
+
+    /// Deposit risk capital into the house bankroll (authority or treasurer)
+    pub fn fund_bankroll(
+        ctx: Context<FundBankroll>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::fund_bankroll::fund_bankroll(ctx, amount)
+    }
+
+    /// Withdraw risk capital from the house bankroll (treasurer only)
+    pub fn withdraw_bankroll(
+        ctx: Context<WithdrawBankroll>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_bankroll::withdraw_bankroll(ctx, amount)
+    }
+
+    /// Withdraw escrowed wagers from the game vault (treasurer only)
+    pub fn withdraw_game_vault(
+        ctx: Context<WithdrawGameVault>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::withdraw_game_vault::withdraw_game_vault(ctx, amount)
+    }
+
+    /// Pay collected charity round-ups to the charity (permissionless)
+    pub fn disburse_charity(
+        ctx: Context<DisburseCharity>,
+    ) -> Result<()> {
+        instructions::disburse_charity::disburse_charity(ctx)
+    }
+
+    /// Update configuration parameters (authority, or risk manager for bet limits)
     pub fn update_config(
         ctx: Context<UpdateConfig>,
-        jackpot_percentage: Option<u16>,
-        house_percentage: Option<u16>,
-        defi_percentage: Option<u16>,
-        min_bet: Option<u64>,
-        max_bet: Option<u64>,
-        win_probability_bps: Option<u16>,
-        reset_threshold: Option<u64>,
-        milestone_bets: Option<u64>,
-        apy_bps: Option<u16>,
-    ) -> Result<()> {
-        instructions::update_config::update_config(
-            ctx,
-            jackpot_percentage,
-            house_percentage,
-            defi_percentage,
-            min_bet,
-            max_bet,
-            win_probability_bps,
-            reset_threshold,
-            milestone_bets,
-            apy_bps,
-        )
+        update: ConfigUpdate,
+    ) -> Result<()> {
+        instructions::update_config::update_config(ctx, update)
+    }
+
+    /// Reassign treasurer / operator / risk manager roles (authority only)
+    pub fn set_roles(
+        ctx: Context<SetRoles>,
+        treasurer: Option<Pubkey>,
+        operator: Option<Pubkey>,
+        risk_manager: Option<Pubkey>,
+        payout_approver: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_roles::set_roles(ctx, treasurer, operator, risk_manager, payout_approver)
+    }
+
+    /// Keeper acknowledges delivered notifications (operator only)
+    pub fn ack_notifications(
+        ctx: Context<AckNotifications>,
+        up_to_seq: u64,
+    ) -> Result<()> {
+        instructions::ack_notifications::ack_notifications(ctx, up_to_seq)
+    }
+
+    /// Export a verifiable proof bundle for a bet via return data
+    pub fn export_bet_proof(
+        ctx: Context<ExportBetProof>,
+    ) -> Result<BetProof> {
+        instructions::export_bet_proof::export_bet_proof(ctx)
+    }
+
+    /// Read a pool's balance and effective odds via return data
+    pub fn get_pool_state(
+        ctx: Context<GetPoolState>,
+    ) -> Result<PoolState> {
+        instructions::get_pool_state::get_pool_state(ctx)
+    }
+
+    /// Grow config, reward vault, and pools to the current account layout (authority only)
+    pub fn migrate<'info>(
+        ctx: Context<'_, '_, 'info, 'info, Migrate<'info>>,
+    ) -> Result<()> {
+        instructions::migrate::migrate(ctx)
+    }
+
+    /// Report drift between a pool's recorded balance and its lamports, optionally correcting it (authority only)
+    pub fn reconcile_pool(
+        ctx: Context<ReconcilePool>,
+        correct: bool,
+    ) -> Result<()> {
+        instructions::reconcile_pool::reconcile_pool(ctx, correct)
+    }
+
+    /// Queue a timelocked emergency withdrawal of pool funds (authority only)
+    pub fn initiate_emergency_withdraw(
+        ctx: Context<InitiateEmergencyWithdraw>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::initiate_emergency_withdraw::initiate_emergency_withdraw(ctx, amount)
+    }
+
+    /// Execute a queued emergency withdrawal after its timelock (authority only)
+    pub fn execute_emergency_withdraw(
+        ctx: Context<ExecuteEmergencyWithdraw>,
+    ) -> Result<()> {
+        instructions::execute_emergency_withdraw::execute_emergency_withdraw(ctx)
+    }
+
+    /// Switch sunset mode on or off and name the beneficiary of residual funds (authority only)
+    pub fn set_shutdown(
+        ctx: Context<SetShutdown>,
+        shutdown: bool,
+        beneficiary: Pubkey,
+    ) -> Result<()> {
+        instructions::set_shutdown::set_shutdown(ctx, shutdown, beneficiary)
+    }
+
+    /// Pay a shut-down pool's residual balance to the sunset beneficiary (permissionless)
+    pub fn decommission(
+        ctx: Context<Decommission>,
+    ) -> Result<()> {
+        instructions::decommission::decommission(ctx)
+    }
+
+    /// Check cross-account accounting invariants; fails with a specific error on a violation
+    pub fn assert_invariants(
+        ctx: Context<AssertInvariants>,
+    ) -> Result<()> {
+        instructions::assert_invariants::assert_invariants(ctx)
+    }
+
+    /// Publish the pool's proof-of-reserves snapshot (permissionless, once per epoch)
+    pub fn publish_reserves(
+        ctx: Context<PublishReserves>,
+    ) -> Result<()> {
+        instructions::publish_reserves::publish_reserves(ctx)
+    }
+
+    /// Open the dashboard volume bucket for a UTC day (permissionless)
+    pub fn open_volume_bucket(
+        ctx: Context<OpenVolumeBucket>,
+        day: i64,
+    ) -> Result<()> {
+        instructions::open_volume_bucket::open_volume_bucket(ctx, day)
+    }
+
+    /// Seed or donate SOL to the jackpot pool (permissionless)
+    pub fn seed_jackpot(
+        ctx: Context<SeedJackpot>,
+        amount: u64,
+    ) -> Result<()> {
+        instructions::seed_jackpot::seed_jackpot(ctx, amount)
+    }
+
+    /// Route a cut of an external game's wager into the jackpot (CPI-friendly)
+    pub fn deposit_contribution(
+        ctx: Context<DepositContribution>,
+        amount: u64,
+        player: Pubkey,
+    ) -> Result<()> {
+        instructions::deposit_contribution::deposit_contribution(ctx, amount, player)
+    }
+
+    /// Register a white-label partner, its house-fee share and optional CPI caller program (authority only)
+    pub fn register_integrator(
+        ctx: Context<RegisterIntegrator>,
+        partner: Pubkey,
+        fee_share_bps: u16,
+        caller_program: Pubkey,
+    ) -> Result<()> {
+        instructions::register_integrator::register_integrator(ctx, partner, fee_share_bps, caller_program)
+    }
+
+    /// Claim house-fee share accrued by a partner
+    pub fn claim_integrator_fees(
+        ctx: Context<ClaimIntegratorFees>,
+    ) -> Result<()> {
+        instructions::claim_integrator_fees::claim_integrator_fees(ctx)
+    }
+
+    /// Record the referrer credited on a player's bets (once per player)
+    pub fn register_referral(
+        ctx: Context<RegisterReferral>,
+        referrer: Pubkey,
+    ) -> Result<()> {
+        instructions::register_referral::register_referral(ctx, referrer)
+    }
+
+    /// Claim referral commission
+    pub fn claim_referral_earnings(
+        ctx: Context<ClaimReferralEarnings>,
+    ) -> Result<()> {
+        instructions::claim_referral_earnings::claim_referral_earnings(ctx)
+    }
+
+    /// Convert comp points into bonus-bet credit
+    pub fn redeem_comp_points(
+        ctx: Context<RedeemCompPoints>,
+        points: u64,
+    ) -> Result<()> {
+        instructions::redeem_comp_points::redeem_comp_points(ctx, points)
+    }
+
+    /// Replace the VIP tier table (authority only)
+    pub fn set_vip_tiers(
+        ctx: Context<SetVipTiers>,
+        tiers: Vec<VipTier>,
+    ) -> Result<()> {
+        instructions::set_vip_tiers::set_vip_tiers(ctx, tiers)
+    }
+
+    /// Claim accrued VIP rakeback
+    pub fn claim_rakeback(
+        ctx: Context<ClaimRakeback>,
+    ) -> Result<()> {
+        instructions::claim_rakeback::claim_rakeback(ctx)
+    }
+
+    /// Claim cashback on net losses from closed weeks
+    pub fn claim_cashback(
+        ctx: Context<ClaimCashback>,
+    ) -> Result<()> {
+        instructions::claim_cashback::claim_cashback(ctx)
+    }
+
+    /// Top up a player's promotional bonus balance (authority or operator)
+    pub fn grant_bonus(
+        ctx: Context<GrantBonus>,
+        player: Pubkey,
+        amount: u64,
+        wagering_requirement: u64,
+    ) -> Result<()> {
+        instructions::grant_bonus::grant_bonus(ctx, player, amount, wagering_requirement)
+    }
+
+    /// Withdraw the bonus balance once its wagering requirement is met
+    pub fn withdraw_bonus(
+        ctx: Context<WithdrawBonus>,
+    ) -> Result<()> {
+        instructions::withdraw_bonus::withdraw_bonus(ctx)
+    }
+
+    /// Publish a promo campaign's Merkle root and fund it (authority or operator)
+    pub fn set_promo_merkle_root(
+        ctx: Context<SetPromoMerkleRoot>,
+        campaign_id: u64,
+        merkle_root: [u8; 32],
+        funding: u64,
+    ) -> Result<()> {
+        instructions::set_promo_merkle_root::set_promo_merkle_root(ctx, campaign_id, merkle_root, funding)
+    }
+
+    /// Claim a promo reward with a Merkle proof
+    pub fn claim_promo(
+        ctx: Context<ClaimPromo>,
+        amount: u64,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        instructions::claim_promo::claim_promo(ctx, amount, proof)
+    }
+
+    /// Set responsible-gaming limits; relaxations wait out a 24h cooldown
+    pub fn set_gaming_limits(
+        ctx: Context<SetGamingLimits>,
+        daily_wager_cap: u64,
+        daily_loss_cap: u64,
+        self_excluded_until: i64,
+    ) -> Result<()> {
+        instructions::set_gaming_limits::set_gaming_limits(ctx, daily_wager_cap, daily_loss_cap, self_excluded_until)
+    }
+
+    /// Block a player from betting and claiming pending review (authority or operator)
+    pub fn freeze_player(
+        ctx: Context<FreezePlayer>,
+        player: Pubkey,
+        reason: u8,
+    ) -> Result<()> {
+        instructions::freeze_player::freeze_player(ctx, player, reason)
+    }
+
+    /// Lift a player freeze (authority or operator)
+    pub fn unfreeze_player(
+        ctx: Context<UnfreezePlayer>,
+    ) -> Result<()> {
+        instructions::unfreeze_player::unfreeze_player(ctx)
+    }
+
+    /// Approve a wallet to bet while the allowlist is enabled (authority only)
+    pub fn add_member(
+        ctx: Context<AddMember>,
+        player: Pubkey,
+    ) -> Result<()> {
+        instructions::add_member::add_member(ctx, player)
+    }
+
+    /// Open a raffle round accepting entries until `draw_end_ts` (authority or operator)
+    pub fn open_draw_round(
+        ctx: Context<OpenDrawRound>,
+        round_id: u64,
+        draw_end_ts: i64,
+    ) -> Result<()> {
+        instructions::open_draw_round::open_draw_round(ctx, round_id, draw_end_ts)
+    }
+
+    /// Mint a transferable ticket for the player's draw entry
+    pub fn mint_draw_ticket(
+        ctx: Context<MintDrawTicket>,
+        entry_index: u32,
+    ) -> Result<()> {
+        instructions::mint_draw_ticket::mint_draw_ticket(ctx, entry_index)
+    }
+
+    /// Pick a winner for an ended draw round, weighted by amount wagered
+    pub fn settle_draw(
+        ctx: Context<SettleDraw>,
+        vrf_result: [u8; 32],
+    ) -> Result<()> {
+        instructions::settle_draw::settle_draw(ctx, vrf_result)
+    }
+
+    /// Schedule a leaderboard season funded by a share of house fees (authority only)
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u64,
+        start_ts: i64,
+        end_ts: i64,
+        fee_bps: u16,
+        payout_bps: Vec<u16>,
+    ) -> Result<()> {
+        instructions::create_season::create_season(ctx, season_id, start_ts, end_ts, fee_bps, payout_bps)
+    }
+
+    /// Pay an ended season's prize to the top of its leaderboard
+    pub fn finalize_season<'info>(
+        ctx: Context<'_, '_, 'info, 'info, FinalizeSeason<'info>>,
+    ) -> Result<()> {
+        instructions::finalize_season::finalize_season(ctx)
+    }
+
+    /// Configure the UTC betting window (operator only)
+    pub fn set_operating_hours(
+        ctx: Context<SetOperatingHours>,
+        enabled: bool,
+        days: u8,
+        start_minute: u16,
+        end_minute: u16,
+    ) -> Result<()> {
+        instructions::set_operating_hours::set_operating_hours(ctx, enabled, days, start_minute, end_minute)
+    }
+
+    /// Start, replace, or stop an odds A/B experiment (authority only)
+    pub fn set_experiment(
+        ctx: Context<SetExperiment>,
+        experiment_id: u64,
+        variants: Vec<ExperimentVariant>,
+    ) -> Result<()> {
+        instructions::set_experiment::set_experiment(ctx, experiment_id, variants)
+    }
+
+    /// Player opts in or out of experiments and win-history attribution
+    pub fn set_privacy(
+        ctx: Context<SetPrivacy>,
+        experiment_opt_out: bool,
+        hide_from_history: bool,
+    ) -> Result<()> {
+        instructions::set_privacy::set_privacy(ctx, experiment_opt_out, hide_from_history)
+    }
+
+    /// Route the caller's wins and DeFi reward claims to a beneficiary wallet
+    pub fn set_payout_beneficiary(
+        ctx: Context<SetPayoutBeneficiary>,
+        beneficiary: Option<Pubkey>,
+    ) -> Result<()> {
+        instructions::set_payout_beneficiary::set_payout_beneficiary(ctx, beneficiary)
+    }
+
+    /// Divide the caller's future win claims among up to 5 recipients
+    pub fn set_payout_split(
+        ctx: Context<SetPayoutSplit>,
+        shares: Vec<SplitShare>,
+    ) -> Result<()> {
+        instructions::set_payout_split::set_payout_split(ctx, shares)
+    }
+
+    /// Configure cold-start bootstrap mode (authority only)
+    pub fn set_bootstrap(
+        ctx: Context<SetBootstrap>,
+        threshold: u64,
+        payout_multiplier: u16,
+    ) -> Result<()> {
+        instructions::set_bootstrap::set_bootstrap(ctx, threshold, payout_multiplier)
     }
 }
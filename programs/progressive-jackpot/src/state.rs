@@ -1,13 +1,25 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::solana_program::instruction::Instruction;
 use anchor_spl::token::{self, Token, TokenAccount};
+use crate::error::CasinoError;
 
 /// Global configuration for the casino jackpot system
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct Config {
-    /// Authority that can update config and withdraw house fees
+    /// Super-admin: full config control and the only key that can reassign roles
     pub authority: Pubkey,
     
+    /// Treasurer role: can withdraw house fees
+    pub treasurer: Pubkey,
+    
+    /// Operator role: day-to-day operational controls
+    pub operator: Pubkey,
+    
+    /// Risk manager role: can change bet limits
+    pub risk_manager: Pubkey,
+    
     /// Percentage of each bet that goes to jackpot (basis points, e.g., 500 = 5%)
     pub jackpot_percentage: u16,
     
@@ -44,14 +56,442 @@ pub struct Config {
     /// Total jackpot wins
     pub total_wins: u64,
     
+    /// Wins at or above this amount are queued as big-win notifications (0 = disabled)
+    pub big_win_threshold: u64,
+    
+    /// Minimum pool balance before bets are accepted (0 = disabled)
+    pub min_pool_seed: u64,
+    
+    /// Wins above this amount are paid out in tranches via `claim_vested_win` (0 = disabled)
+    pub vesting_threshold: u64,
+    
+    /// Largest single payout; the excess goes to the reserve pool (0 = uncapped)
+    pub max_payout: u64,
+    
+    /// Bootstrap mode: while the pool is below this, wins are paid by the house (0 = disabled)
+    pub bootstrap_threshold: u64,
+    
+    /// Bootstrap wins pay this multiple of the bet amount from the house vault
+    pub bootstrap_payout_multiplier: u16,
+    
+    /// Odds mode: 0 = flat, 1 = scaled by bet size (amount / max_bet)
+    pub odds_mode: u8,
+    
+    /// Win threshold boost per bet since the last win, in basis points (0 = disabled)
+    pub odds_ramp_bps: u16,
+    
+    /// Cap on the ramped win threshold, in basis points (0 = 10000)
+    pub odds_ramp_max_bps: u16,
+    
+    /// Share of the house fee credited to the referrer of a referred player (basis points)
+    pub referral_fee_bps: u16,
+    
+    /// Comp points earned per SOL wagered (0 = disabled)
+    pub comp_points_per_sol: u64,
+    
+    /// Bonus-bet credit granted per redeemed comp point, in lamports
+    pub comp_point_value: u64,
+    
+    /// Minimum number of comp points per redemption
+    pub min_comp_redemption: u64,
+    
+    /// Width of the near-miss band above the win threshold, in basis points
+    pub consolation_bps: u16,
+    
+    /// Fixed consolation paid from the house vault for a near miss (0 = disabled)
+    pub consolation_amount: u64,
+    
+    /// Whether bets are restricted to the operating hours window
+    pub operating_hours_enabled: bool,
+    
+    /// Days bets are accepted (UTC), bit 0 = Monday .. bit 6 = Sunday
+    pub operating_days: u8,
+    
+    /// Window start in minutes after UTC midnight (inclusive)
+    pub operating_start_minute: u16,
+    
+    /// Window end in minutes after UTC midnight (exclusive); wraps past midnight if < start, all day if == start
+    pub operating_end_minute: u16,
+    
+    /// Running odds experiment (0 = none)
+    pub experiment_id: u64,
+    
+    /// Number of populated entries in `experiment_variants`
+    pub experiment_variant_count: u8,
+    
+    /// Parameter variants for the running experiment
+    pub experiment_variants: [ExperimentVariant; MAX_EXPERIMENT_VARIANTS],
+    
+    /// Number of populated entries in `vip_tiers`
+    pub vip_tier_count: u8,
+    
+    /// VIP tiers in ascending order of lifetime wager volume
+    pub vip_tiers: [VipTier; MAX_VIP_TIERS],
+    
+    /// Only wallets holding a Membership PDA may bet (private beta)
+    pub allowlist_enabled: bool,
+    
+    /// Share of each bet paid into the open draw round's prize (basis points, 0 = draws off)
+    pub draw_bps: u16,
+    
+    /// Share of a jackpot win split evenly among the pool's recent bettors (basis points, 0 = off)
+    pub community_share_bps: u16,
+    
+    /// Consecutive UTC days of betting that unlock the streak bonus (0 = off)
+    pub streak_days: u16,
+    
+    /// Win probability added to bets placed on a qualifying streak (basis points)
+    pub streak_odds_bonus_bps: u16,
+    
+    /// Extra rakeback on the house fee for bets placed on a qualifying streak (basis points)
+    pub streak_rakeback_bps: u16,
+    
+    /// Share of a player's weekly net loss paid back by `claim_cashback` (basis points, 0 = off)
+    pub cashback_bps: u16,
+    
+    /// Largest potential payout per bet as a share of bankroll + pool (basis points, 0 = unchecked)
+    pub max_exposure_bps: u16,
+    
+    /// Max bet as a share of the pool balance, replacing `max_bet` (basis points, 0 = static)
+    pub max_bet_bps: u16,
+    
+    /// Number of populated entries in `treasury_destinations`
+    pub treasury_count: u8,
+    
+    /// Addresses `withdraw_house` may pay besides the treasurer, e.g. a cold wallet or multisig vault
+    pub treasury_destinations: [Pubkey; MAX_TREASURY_DESTINATIONS],
+    
+    /// `sweep_house` runs once accumulated house fees exceed this (0 = sweeping off)
+    pub sweep_threshold: u64,
+    
+    /// Share of each sweep paid to the cranker (basis points)
+    pub sweep_bounty_bps: u16,
+    
+    /// Address `sweep_house` pays fees to
+    pub sweep_treasury: Pubkey,
+    
+    /// Paid to a registered keeper per settled bet or draw, out of house fees (0 = off)
+    pub keeper_fee: u64,
+    
+    /// Share of the rent reclaimed from an expired bet paid to whoever sweeps it (basis points)
+    pub expired_bet_bounty_bps: u16,
+    
+    /// Sequence number of the last emitted event, so indexers can spot gaps and duplicates
+    pub event_sequence: u64,
+    
     /// Bump seed for config PDA
     pub bump: u8,
+    
+    /// Layout version (see `STATE_VERSION`); fields added after this one are zero until `migrate` runs
+    pub version: u8,
+    
+    /// Minimum slots between a bet and its settlement; same-slot settlement is always rejected
+    pub min_settle_delay_slots: u64,
+    
+    /// Bets each pool accepts per slot (0 = unlimited)
+    pub max_bets_per_slot: u64,
+    
+    /// Seconds a player must wait between bets (0 = no cooldown)
+    pub bet_cooldown_secs: i64,
+    
+    /// Payout approver role: releases wins held for manual review (a multisig vault works)
+    pub payout_approver: Pubkey,
+    
+    /// Wins above this are held in a `PendingPayout` until approved (0 = off)
+    pub manual_review_threshold: u64,
+    
+    /// Seconds after which an unapproved held payout releases on its own
+    pub payout_dispute_window_secs: i64,
+    
+    /// Delay between initiating and executing an emergency pool withdrawal (at least `MIN_EMERGENCY_DELAY_SECS`)
+    pub emergency_delay_secs: i64,
+    
+    /// Sunset mode: new bets are rejected while settlement, refunds and claims keep working
+    pub shutdown: bool,
+    
+    /// When sunset mode was switched on (0 while off)
+    pub shutdown_at: i64,
+    
+    /// Receives residual pool funds on `decommission`; set publicly with `set_shutdown`
+    pub sunset_beneficiary: Pubkey,
+    
+    /// Bets placed but not yet settled or refunded
+    pub open_bets: u64,
+    
+    /// Lockup applied to each `stake_defi` deposit (0 = none)
+    pub stake_lockup_secs: i64,
+    
+    /// Share of an unstake kept for the remaining stakers when leaving before the lockup ends (basis points)
+    pub early_exit_penalty_bps: u16,
+    
+    /// SPL token-lending program idle house fees may be supplied to (default = disabled)
+    pub lending_program: Pubkey,
+    
+    /// Smallest SOL reward a `claim_rewards` may pay out (lamports, 0 = no minimum)
+    pub min_claim_amount: u64,
+    
+    /// Minimum seconds between a user's reward claims (0 = no cooldown)
+    pub claim_cooldown_secs: i64,
+    
+    /// How long after a loss settles the player may double or nothing (0 = off)
+    pub double_or_nothing_window_secs: i64,
+    
+    /// Largest payout a parlay may reach if every leg wins (lamports, 0 = parlays off)
+    pub max_parlay_payout: u64,
+    
+    /// House edge taken from dice payouts (basis points, 0 = dice off)
+    pub dice_house_edge_bps: u16,
+    
+    /// Losing bets in a UTC day that earn a free bonus wheel spin (0 = off)
+    pub bonus_wheel_losses: u16,
+    
+    /// Number of active entries in `happy_hours`
+    pub happy_hour_count: u8,
+    
+    /// Weekly UTC windows that boost odds or jackpot contribution
+    pub happy_hours: [HappyHour; MAX_HAPPY_HOURS],
+}
+
+impl Config {
+    /// Claim the next event sequence number; call once per emitted event
+    pub fn next_event_sequence(&mut self) -> u64 {
+        self.event_sequence += 1;
+        self.event_sequence
+    }
+    
+    /// Whether a win must go through `claim_reviewed_win`
+    pub fn requires_review(&self, win_amount: u64) -> bool {
+        self.manual_review_threshold > 0 && win_amount > self.manual_review_threshold
+    }
+    
+    /// Variant a player is bucketed into for the running experiment, if any
+    /// Deterministic: hash(player || experiment_id) mod 10000 against cumulative traffic weights
+    pub fn experiment_variant(&self, player: &Pubkey) -> Option<u8> {
+        if self.experiment_id == 0 {
+            return None;
+        }
+        
+        let digest = hashv(&[player.as_ref(), &self.experiment_id.to_le_bytes()]).to_bytes();
+        let mut point_bytes = [0u8; 8];
+        point_bytes.copy_from_slice(&digest[..8]);
+        let point = u64::from_le_bytes(point_bytes) % 10000;
+        
+        let mut cumulative: u64 = 0;
+        for (index, variant) in self.experiment_variants[..self.experiment_variant_count as usize].iter().enumerate() {
+            cumulative += variant.weight_bps as u64;
+            if point < cumulative {
+                return Some(index as u8);
+            }
+        }
+        
+        None
+    }
+    
+    /// Traffic-weighted jackpot percentage across the running experiment's variants
+    pub fn experiment_rtp_bps(&self) -> u64 {
+        if self.experiment_id == 0 {
+            return 0;
+        }
+        
+        self.experiment_variants[..self.experiment_variant_count as usize]
+            .iter()
+            .map(|v| v.weight_bps as u64 * v.jackpot_percentage as u64)
+            .sum::<u64>()
+            / 10000
+    }
+    
+    /// Highest VIP tier reached with `total_wagered` lamports of lifetime volume, if any
+    pub fn vip_tier(&self, total_wagered: u64) -> Option<u8> {
+        self.vip_tiers[..self.vip_tier_count as usize]
+            .iter()
+            .rposition(|tier| total_wagered >= tier.min_wagered)
+            .map(|index| index as u8)
+    }
+    
+    /// Hash of the parameters that determine a bet's odds and fee split
+    /// Counters and authority keys are excluded so the hash only changes on parameter updates
+    pub fn params_hash(&self) -> [u8; 32] {
+        hashv(&[
+            &self.jackpot_percentage.to_le_bytes(),
+            &self.house_percentage.to_le_bytes(),
+            &self.defi_percentage.to_le_bytes(),
+            &self.min_bet.to_le_bytes(),
+            &self.max_bet.to_le_bytes(),
+            &self.win_probability_bps.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+    
+    /// Whether a bet at `unix_timestamp` falls inside the configured operating hours
+    pub fn is_within_operating_hours(&self, unix_timestamp: i64) -> bool {
+        if !self.operating_hours_enabled {
+            return true;
+        }
+        
+        if self.operating_days & (1 << utc_weekday(unix_timestamp)) == 0 {
+            return false;
+        }
+        
+        let minute = (unix_timestamp.rem_euclid(86400) / 60) as u16;
+        let start = self.operating_start_minute;
+        let end = self.operating_end_minute;
+        
+        if start < end {
+            minute >= start && minute < end
+        } else if start > end {
+            minute >= start || minute < end
+        } else {
+            true
+        }
+    }
+    
+    /// Happy-hour window covering `unix_timestamp`, if any (the first match wins)
+    pub fn active_happy_hour(&self, unix_timestamp: i64) -> Option<HappyHour> {
+        self.happy_hours[..self.happy_hour_count as usize]
+            .iter()
+            .find(|window| window.contains(unix_timestamp))
+            .copied()
+    }
+    
+    /// Whether house fees may be withdrawn to `destination`
+    pub fn is_treasury_destination(&self, destination: &Pubkey) -> bool {
+        self.treasury_destinations[..self.treasury_count as usize].contains(destination)
+    }
+    
+    /// Largest payout a bet of `amount` could trigger against a pool holding `pool_balance`
+    pub fn max_potential_payout(&self, pool_balance: u64, amount: u64) -> u64 {
+        if self.bootstrap_threshold > 0 && pool_balance < self.bootstrap_threshold {
+            return amount.saturating_mul(self.bootstrap_payout_multiplier as u64);
+        }
+        
+        if self.max_payout > 0 {
+            pool_balance.min(self.max_payout)
+        } else {
+            pool_balance
+        }
+    }
+    
+    /// Reject a bet whose potential payout exceeds `max_exposure_bps` of bankroll + pool
+    pub fn check_exposure(&self, pool_balance: u64, bankroll_balance: u64, amount: u64) -> Result<()> {
+        if self.max_exposure_bps == 0 {
+            return Ok(());
+        }
+        
+        let capacity = (pool_balance as u128 + bankroll_balance as u128)
+            * self.max_exposure_bps as u128
+            / 10000;
+        require!(
+            self.max_potential_payout(pool_balance, amount) as u128 <= capacity,
+            CasinoError::ExposureLimitExceeded
+        );
+        Ok(())
+    }
+}
+
+/// Every bet wins with `win_probability_bps`
+pub const ODDS_MODE_FLAT: u8 = 0;
+
+/// Win probability scales linearly with `amount / max_bet`
+pub const ODDS_MODE_SCALED: u8 = 1;
+
+/// Share of the pool paid for rare, medium and common wins (basis points)
+pub const TIER_MULTIPLIERS_BPS: [u16; 3] = [10000, 5000, 2500];
+
+/// Floor on `Config::emergency_delay_secs`, so the timelock can't be set to nothing
+pub const MIN_EMERGENCY_DELAY_SECS: i64 = 24 * 60 * 60;
+
+/// Maximum number of variants in an odds experiment
+pub const MAX_EXPERIMENT_VARIANTS: usize = 4;
+
+/// Maximum number of VIP tiers
+pub const MAX_VIP_TIERS: usize = 4;
+
+/// Maximum number of allowlisted treasury destinations
+pub const MAX_TREASURY_DESTINATIONS: usize = 4;
+
+/// Maximum number of happy-hour windows
+pub const MAX_HAPPY_HOURS: usize = 4;
+
+/// Largest multiplier a happy hour may apply (basis points, 10000 = 1x)
+pub const MAX_HAPPY_HOUR_MULTIPLIER_BPS: u16 = 30000;
+
+/// Current layout version of `Config`, `JackpotPool`, and `RewardVault`
+/// Layouts only grow at the end, so `migrate` can realloc older accounts in place; bump this whenever one of them grows
+/// 1: layouts ending at `version`; 2: fields through `Config::happy_hours`, `JackpotPool::side_bet_pool` and `RewardVault::yield_distributed`
+pub const STATE_VERSION: u8 = 2;
+
+/// Perks unlocked once a player's lifetime wager volume reaches `min_wagered`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct VipTier {
+    /// Lifetime lamports wagered to reach this tier
+    pub min_wagered: u64,
+    
+    /// Max bet override for this tier (0 = use `Config::max_bet`)
+    pub max_bet: u64,
+    
+    /// Share of the house fee credited back to the player (basis points)
+    pub rakeback_bps: u16,
+}
+
+/// Parameter set applied to one experiment bucket
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct ExperimentVariant {
+    /// Share of players assigned to this variant (basis points; all variants sum to 10000)
+    pub weight_bps: u16,
+    
+    /// Jackpot contribution for bets in this variant (basis points)
+    pub jackpot_percentage: u16,
+    
+    /// Win probability for bets in this variant (basis points)
+    pub win_probability_bps: u16,
+}
+
+/// Weekly UTC window during which bets get boosted odds or jackpot contribution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct HappyHour {
+    /// Bitmask of UTC weekdays the window opens on (bit 0 = Monday)
+    pub days: u8,
+    
+    /// First UTC hour of the window (0-23)
+    pub start_hour: u8,
+    
+    /// UTC hour the window closes (exclusive, 1-24)
+    pub end_hour: u8,
+    
+    /// Multiplier on win probability (basis points, 10000 = no boost)
+    pub win_probability_multiplier_bps: u16,
+    
+    /// Multiplier on the jackpot contribution (basis points, 10000 = no boost)
+    pub jackpot_multiplier_bps: u16,
+}
+
+impl HappyHour {
+    /// Whether `unix_timestamp` falls inside this window
+    pub fn contains(&self, unix_timestamp: i64) -> bool {
+        let hour = (unix_timestamp.rem_euclid(86400) / 3600) as u8;
+        self.days & (1 << utc_weekday(unix_timestamp)) != 0
+            && hour >= self.start_hour
+            && hour < self.end_hour
+    }
+    
+    /// Scale `bps` by `multiplier_bps`, capped at `cap`
+    pub fn boost(bps: u16, multiplier_bps: u16, cap: u16) -> u16 {
+        (bps as u64 * multiplier_bps as u64 / 10000).min(cap as u64) as u16
+    }
+}
+
+/// UTC weekday of `unix_timestamp` (Monday = 0; 1970-01-01 was a Thursday)
+pub fn utc_weekday(unix_timestamp: i64) -> u8 {
+    (unix_timestamp.div_euclid(86400) + 3).rem_euclid(7) as u8
 }
 
 /// Progressive jackpot pool account
 #[account]
-#[derive(Default)]
+#[derive(Default, InitSpace)]
 pub struct JackpotPool {
+    /// Pool identifier, part of the PDA seeds (0 = the pool created by `initialize`)
+    pub pool_id: u64,
+    
     /// Current balance of the jackpot pool
     pub balance: u64,
     
@@ -70,101 +510,2375 @@ pub struct JackpotPool {
     /// Milestone trigger: win every N bets (0 = disabled)
     pub milestone_bets: u64,
     
+    /// Settled winnings held in the pool until claimed (not part of balance)
+    pub pending_payouts: u64,
+    
+    /// Must-hit-by ceiling: once balance reaches it the next settled bet wins (0 = disabled)
+    pub must_hit_by: u64,
+    
+    /// Set when balance crosses `must_hit_by`; cleared by the forced win
+    pub must_hit_armed: bool,
+    
+    /// Minimum bet for this pool (0 = use `Config::min_bet`)
+    pub min_bet: u64,
+    
+    /// Maximum bet for this pool (0 = use `Config::max_bet`)
+    pub max_bet: u64,
+    
+    /// Win probability for this pool in basis points (0 = use `Config::win_probability_bps`)
+    pub win_probability_bps: u16,
+    
+    /// Most recent distinct bettors (circular buffer, default pubkey = empty slot)
+    pub recent_bettors: [Pubkey; RECENT_BETTORS_LEN],
+    
+    /// Slot the next new bettor is written to
+    pub recent_bettors_head: u8,
+    
+    /// Recipients of the last community split
+    pub community_recipients: [Pubkey; RECENT_BETTORS_LEN],
+    
+    /// Lamports owed to each community recipient
+    pub community_share: u64,
+    
+    /// Bit i set while `community_recipients[i]` has not been paid
+    pub community_unpaid: u16,
+    
     /// Bump seed for pool PDA
     pub bump: u8,
+    
+    /// Layout version (see `STATE_VERSION`); fields added after this one are zero until `migrate` runs
+    pub version: u8,
+    
+    /// Slot `bets_this_slot` counts
+    pub current_slot: u64,
+    
+    /// Bets placed in `current_slot`
+    pub bets_this_slot: u64,
+    
+    /// Vesting wins not yet released (part of `pending_payouts`)
+    pub vesting_outstanding: u64,
+    
+    /// Lamports queued by `initiate_emergency_withdraw`
+    pub emergency_amount: u64,
+    
+    /// Where the queued emergency withdrawal pays out
+    pub emergency_destination: Pubkey,
+    
+    /// When the queued emergency withdrawal can execute (0 = none queued; betting is paused while set)
+    pub emergency_unlocks_at: i64,
+    
+    /// Open side-bet round the next jackpot hit settles (see `SideBetPool`)
+    pub side_bet_pool: Option<Pubkey>,
 }
 
-/// Individual bet record (optional, for large bets or tracking)
+impl JackpotPool {
+    /// Arm the must-hit-by jackpot once the balance crosses the ceiling; true if newly armed
+    pub fn arm_must_hit(&mut self) -> bool {
+        if self.must_hit_by > 0 && !self.must_hit_armed && self.balance >= self.must_hit_by {
+            self.must_hit_armed = true;
+            return true;
+        }
+        
+        false
+    }
+    
+    /// Count a bet toward this slot; false once `max_bets_per_slot` is reached (0 = unlimited)
+    pub fn take_slot_capacity(&mut self, slot: u64, max_bets_per_slot: u64) -> bool {
+        if self.current_slot != slot {
+            self.current_slot = slot;
+            self.bets_this_slot = 0;
+        }
+        
+        if max_bets_per_slot > 0 && self.bets_this_slot >= max_bets_per_slot {
+            return false;
+        }
+        
+        self.bets_this_slot += 1;
+        true
+    }
+    
+    /// Remember a bettor for the community split; players already in the buffer keep their slot
+    pub fn record_bettor(&mut self, player: Pubkey) {
+        if self.recent_bettors.contains(&player) {
+            return;
+        }
+        
+        let head = self.recent_bettors_head as usize % RECENT_BETTORS_LEN;
+        self.recent_bettors[head] = player;
+        self.recent_bettors_head = ((head + 1) % RECENT_BETTORS_LEN) as u8;
+    }
+    
+    /// Pool's min and max bet, falling back to the config-wide (or pool-size derived) limits
+    pub fn bet_limits(&self, config: &Config) -> (u64, u64) {
+        let min_bet = if self.min_bet > 0 { self.min_bet } else { config.min_bet };
+        let max_bet = if self.max_bet > 0 {
+            self.max_bet
+        } else if config.max_bet_bps > 0 {
+            // Dynamic limit tracks the jackpot instead of the static config value
+            (self.balance as u128 * config.max_bet_bps as u128 / 10000) as u64
+        } else {
+            config.max_bet
+        };
+        (min_bet, max_bet)
+    }
+    
+    /// Pool's win probability, falling back to the config-wide odds
+    pub fn effective_win_probability_bps(&self, config: &Config) -> u16 {
+        if self.win_probability_bps > 0 {
+            self.win_probability_bps
+        } else {
+            config.win_probability_bps
+        }
+    }
+}
+
+/// Number of recent distinct bettors a community split is shared among
+pub const RECENT_BETTORS_LEN: usize = 10;
+
+/// Id of the pool created by `initialize`
+pub const DEFAULT_POOL_ID: u64 = 0;
+
+/// Per-pool parameters supplied to `create_pool`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct PoolParams {
+    /// Minimum bet (0 = use `Config::min_bet`)
+    pub min_bet: u64,
+    
+    /// Maximum bet (0 = use `Config::max_bet`)
+    pub max_bet: u64,
+    
+    /// Win probability in basis points (0 = use `Config::win_probability_bps`)
+    pub win_probability_bps: u16,
+    
+    /// Reset threshold (0 = disabled)
+    pub reset_threshold: u64,
+    
+    /// Milestone trigger: win every N bets (0 = disabled)
+    pub milestone_bets: u64,
+    
+    /// Must-hit-by ceiling (0 = disabled)
+    pub must_hit_by: u64,
+}
+
+/// Arguments to `update_config`; each set field replaces the same-named `Config` field
+/// (`reset_threshold`, `milestone_bets` and `must_hit_by` update the passed pool), unset fields are left unchanged
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug, PartialEq)]
+pub struct ConfigUpdate {
+    pub jackpot_percentage: Option<u16>,
+    pub house_percentage: Option<u16>,
+    pub defi_percentage: Option<u16>,
+    pub min_bet: Option<u64>,
+    pub max_bet: Option<u64>,
+    pub win_probability_bps: Option<u16>,
+    pub reset_threshold: Option<u64>,
+    pub milestone_bets: Option<u64>,
+    pub big_win_threshold: Option<u64>,
+    pub min_pool_seed: Option<u64>,
+    pub vesting_threshold: Option<u64>,
+    pub max_payout: Option<u64>,
+    pub must_hit_by: Option<u64>,
+    pub odds_mode: Option<u8>,
+    pub odds_ramp_bps: Option<u16>,
+    pub odds_ramp_max_bps: Option<u16>,
+    pub consolation_bps: Option<u16>,
+    pub consolation_amount: Option<u64>,
+    pub referral_fee_bps: Option<u16>,
+    pub comp_points_per_sol: Option<u64>,
+    pub comp_point_value: Option<u64>,
+    pub min_comp_redemption: Option<u64>,
+    pub allowlist_enabled: Option<bool>,
+    pub draw_bps: Option<u16>,
+    pub community_share_bps: Option<u16>,
+    pub streak_days: Option<u16>,
+    pub streak_odds_bonus_bps: Option<u16>,
+    pub streak_rakeback_bps: Option<u16>,
+    pub cashback_bps: Option<u16>,
+    pub max_exposure_bps: Option<u16>,
+    pub max_bet_bps: Option<u16>,
+    pub sweep_threshold: Option<u64>,
+    pub sweep_bounty_bps: Option<u16>,
+    pub sweep_treasury: Option<Pubkey>,
+    pub keeper_fee: Option<u64>,
+    pub expired_bet_bounty_bps: Option<u16>,
+    pub min_settle_delay_slots: Option<u64>,
+    pub max_bets_per_slot: Option<u64>,
+    pub bet_cooldown_secs: Option<i64>,
+    pub manual_review_threshold: Option<u64>,
+    pub payout_dispute_window_secs: Option<i64>,
+    pub emergency_delay_secs: Option<i64>,
+    pub stake_lockup_secs: Option<i64>,
+    pub early_exit_penalty_bps: Option<u16>,
+    pub lending_program: Option<Pubkey>,
+    pub min_claim_amount: Option<u64>,
+    pub claim_cooldown_secs: Option<i64>,
+    pub double_or_nothing_window_secs: Option<i64>,
+    pub max_parlay_payout: Option<u64>,
+    pub dice_house_edge_bps: Option<u16>,
+    pub bonus_wheel_losses: Option<u16>,
+}
+
+impl ConfigUpdate {
+    /// Whether the update only touches bet limits, the one change the risk manager may make
+    /// New fields are authority-only unless they are cleared here as well
+    pub fn only_bet_limits(&self) -> bool {
+        let other = ConfigUpdate {
+            min_bet: None,
+            max_bet: None,
+            ..self.clone()
+        };
+        other == ConfigUpdate::default()
+    }
+}
+
+/// Program-owned vault collecting house fees
 #[account]
-#[derive(Default)]
-pub struct Bet {
-    /// Player who placed the bet
-    pub player: Pubkey,
+#[derive(Default, InitSpace)]
+pub struct HouseVault {
+    /// Total house fees collected
+    pub total_fees: u64,
     
-    /// Bet amount in lamports
-    pub amount: u64,
+    /// Total paid out for fixed-odds wins during bootstrap mode
+    pub total_bootstrap_payouts: u64,
     
-    /// Timestamp when bet was placed
-    pub timestamp: i64,
+    /// Total paid out as near-miss consolations
+    pub total_consolation_payouts: u64,
     
-    /// VRF request ID (if VRF was triggered)
-    pub vrf_request_id: Option<[u8; 32]>,
+    /// Rakeback credited to players but not yet claimed (kept back from withdrawals)
+    pub rakeback_owed: u64,
     
-    /// Status: 0 = pending, 1 = won, 2 = lost, 3 = refunded
-    pub status: u8,
+    /// House fees collected and not yet withdrawn, net of rakeback; caps `withdraw_house`
+    pub accumulated_house_fees: u64,
     
-    /// Win amount if won (0 if lost)
-    pub win_amount: u64,
+    /// Bump seed for house vault PDA; fields added after it are zero until `migrate` grows the vault
+    pub bump: u8,
     
-    /// Bump seed for bet PDA
+    /// Most the house may still owe on open parlays, dice rolls, slots spins and scratch-card batches (kept back from withdrawals)
+    pub game_liability: u64,
+}
+
+impl HouseVault {
+    /// Lamports that must stay in the vault: rent, rakeback owed to players and open game liability
+    pub fn reserved_lamports(&self, data_len: usize) -> Result<u64> {
+        Ok(Rent::get()?
+            .minimum_balance(data_len)
+            .saturating_add(self.rakeback_owed)
+            .saturating_add(self.game_liability))
+    }
+}
+
+/// House risk capital backing exposure limits (see `Config::max_exposure_bps`)
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Bankroll {
+    /// Lamports of risk capital held in the PDA (excluding rent)
+    pub balance: u64,
+    
+    /// Lifetime lamports deposited
+    pub total_deposited: u64,
+    
+    /// Lifetime lamports withdrawn
+    pub total_withdrawn: u64,
+    
+    /// Bump seed for bankroll PDA
     pub bump: u8,
 }
 
-/// DeFi reward vault for staking yields
+/// Overflow reserve fed by payouts clipped at `max_payout`
 #[account]
-#[derive(Default)]
-pub struct RewardVault {
-    /// Total staked amount
-    pub staked_amount: u64,
+#[derive(Default, InitSpace)]
+pub struct ReservePool {
+    /// Lamports held in reserve
+    pub balance: u64,
     
-    /// Total rewards distributed
-    pub total_rewards_distributed: u64,
+    /// Total lamports moved back into the jackpot pool
+    pub total_reseeded: u64,
     
-    /// Last reward distribution timestamp
-    pub last_distribution: i64,
+    /// Bump seed for reserve PDA
+    pub bump: u8,
+}
+
+/// Escrow for the part of each wager not split into the jackpot, house and DeFi legs
+#[account]
+#[derive(Default, InitSpace)]
+pub struct GameVault {
+    /// Total lamports escrowed from bets
+    pub total_escrowed: u64,
     
-    /// Reward distribution period (seconds)
-    pub distribution_period: i64,
+    /// Total lamports withdrawn by the treasurer
+    pub total_withdrawn: u64,
     
-    /// Annual percentage yield (basis points, e.g., 500 = 5% APY)
-    pub apy_bps: u16,
+    /// Bump seed for game vault PDA
+    pub bump: u8,
+}
+
+/// Holds players' charity round-ups until they are disbursed to the configured charity
+#[account]
+#[derive(Default, InitSpace)]
+pub struct CharityVault {
+    /// Charity wallet `disburse_charity` pays
+    pub charity: Pubkey,
     
-    /// Bump seed for vault PDA
+    /// Total lamports donated by players
+    pub total_received: u64,
+    
+    /// Total lamports paid out to the charity
+    pub total_disbursed: u64,
+    
+    /// Bump seed for charity vault PDA
     pub bump: u8,
 }
 
-/// User reward claim account
+/// White-label partner earning a share of the house fee on bets it routes
 #[account]
-#[derive(Default)]
-pub struct RewardClaim {
-    /// User who can claim rewards
-    pub user: Pubkey,
+#[derive(Default, InitSpace)]
+pub struct Integrator {
+    /// Partner wallet that claims accrued fees
+    pub partner: Pubkey,
     
-    /// Total rewards earned
-    pub total_earned: u64,
+    /// Share of the house fee paid to the partner (basis points)
+    pub fee_share_bps: u16,
     
-    /// Total rewards claimed
-    pub total_claimed: u64,
+    /// Number of bets routed through this partner
+    pub total_bets: u64,
     
-    /// Last claim timestamp
-    pub last_claim: i64,
+    /// Total wagered through this partner
+    pub total_volume: u64,
     
-    /// Bump seed for claim PDA
+    /// Lifetime fees accrued to the partner
+    pub total_fees_accrued: u64,
+    
+    /// Fees accrued but not yet claimed
+    pub pending_fees: u64,
+    
+    /// Program allowed to place bets through CPI when routing via this partner (default = none)
+    pub caller_program: Pubkey,
+    
+    /// Bump seed for integrator PDA
     pub bump: u8,
 }
 
-/// VRF request tracking account
+/// Registered crank operator paid `Config::keeper_fee` per settlement out of house fees
 #[account]
-#[derive(Default)]
-pub struct VrfRequest {
-    /// Bet account associated with this request
-    pub bet: Pubkey,
+#[derive(Default, InitSpace)]
+pub struct Keeper {
+    /// Wallet that signs cranks and receives the fees
+    pub authority: Pubkey,
     
-    /// Player who placed the bet
+    /// Bets and draws settled by this keeper
+    pub cranks: u64,
+    
+    /// Lifetime fees paid to the keeper
+    pub total_earned: u64,
+    
+    /// Registration timestamp
+    pub registered_at: i64,
+    
+    /// Bump seed for keeper PDA
+    pub bump: u8,
+}
+
+/// Link from a player to the referrer who brought them in (set once)
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Referral {
+    /// Referred player
     pub player: Pubkey,
     
-    /// VRF request timestamp
-    pub timestamp: i64,
+    /// Referrer credited on the player's bets
+    pub referrer: Pubkey,
     
-    /// VRF request ID/seed
-    pub request_id: [u8; 32],
+    /// Bump seed for referral PDA
+    pub bump: u8,
+}
+
+/// Commission accrued by a referrer across all referred players
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ReferralEarnings {
+    /// Referrer that claims the commission
+    pub referrer: Pubkey,
     
-    /// Status: 0 = pending, 1 = fulfilled, 2 = timeout
-    pub status: u8,
+    /// Number of players registered under this referrer
+    pub referred_players: u64,
     
-    /// VRF result (if fulfilled)
-    pub result: Option<[u8; 32]>,
+    /// Lifetime commission earned
+    pub total_earned: u64,
     
-    /// Bump seed for request PDA
+    /// Commission accrued but not yet claimed
+    pub pending: u64,
+    
+    /// Bump seed for referral earnings PDA
     pub bump: u8,
 }
+
+/// Individual bet record (optional, for large bets or tracking)
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Bet {
+    /// Player who placed the bet
+    pub player: Pubkey,
+    
+    /// Jackpot pool the bet was placed in
+    pub pool: Pubkey,
+    
+    /// Bet amount in lamports
+    pub amount: u64,
+    
+    /// Timestamp when bet was placed
+    pub timestamp: i64,
+    
+    /// VRF request ID (if VRF was triggered)
+    pub vrf_request_id: Option<[u8; 32]>,
+    
+    /// Status: 0 = pending, 1 = won (claimable), 2 = lost, 3 = refunded, 4 = won and claimed, 5 = vesting, 6 = held for review
+    pub status: u8,
+    
+    /// Win amount if won (0 if lost)
+    pub win_amount: u64,
+    
+    /// Hash of the odds and fee parameters in effect when the bet was placed
+    pub config_hash: [u8; 32],
+    
+    /// Experiment the bet was placed under (0 = none)
+    pub experiment_id: u64,
+    
+    /// Experiment bucket the player was assigned to
+    pub experiment_variant: Option<u8>,
+    
+    /// Win probability applied at placement (basis points)
+    pub win_probability_bps: u16,
+    
+    /// Streak bonus added to the win probability at settlement (basis points)
+    pub streak_bonus_bps: u16,
+    
+    /// Jackpot, house and DeFi shares of the bet applied at placement (basis points)
+    pub fee_splits_bps: [u16; 3],
+    
+    /// Odds mode in effect at placement (see `Config::odds_mode`)
+    pub odds_mode: u8,
+    
+    /// Max bet the bet was placed under, after pool, dynamic and VIP limits; the divisor for scaled odds
+    pub odds_max_bet: u64,
+    
+    /// Pool share paid per win tier at placement (see `TIER_MULTIPLIERS_BPS`)
+    pub tier_multipliers_bps: [u16; 3],
+    
+    /// Paid from the player's BonusCredit; winnings return there instead of the wallet
+    pub bonus_funded: bool,
+    
+    /// Slot the bet was placed in (see `Config::min_settle_delay_slots`)
+    pub placed_slot: u64,
+    
+    /// When the bet was settled (0 while pending)
+    pub settled_at: i64,
+    
+    /// Set while a `Dispute` is open; claims are blocked until it's resolved
+    pub disputed: bool,
+    
+    /// Re-wagered at 50/50 by `double_or_nothing` after losing; settles via `fulfill_double_or_nothing`
+    pub double_or_nothing: bool,
+    
+    /// Pool balance just before and after the bet's jackpot contribution (see `MysteryJackpot`)
+    pub pool_balance_before: u64,
+    pub pool_balance_after: u64,
+    
+    /// Share of the wager held in the game vault, refunded if the VRF request times out
+    pub escrowed: u64,
+    
+    /// Bump seed for bet PDA
+    pub bump: u8,
+}
+
+/// Lowest win chance a dice roll may be set to (basis points)
+pub const MIN_DICE_CHANCE_BPS: u16 = 100;
+
+/// Highest win chance a dice roll may be set to (basis points)
+pub const MAX_DICE_CHANCE_BPS: u16 = 9500;
+
+/// A dice roll awaiting VRF; holds the wager's staked part until it settles
+#[account]
+#[derive(Default, InitSpace)]
+pub struct DiceRoll {
+    /// Player who rolled
+    pub player: Pubkey,
+    
+    /// Full wager in lamports
+    pub amount: u64,
+    
+    /// Part of the wager held against the house, after the jackpot and DeFi legs
+    pub stake: u64,
+    
+    /// Roll threshold (0..10000)
+    pub target: u16,
+    
+    /// Wins when the roll is at or above `target`; otherwise when it's below
+    pub roll_over: bool,
+    
+    /// Lamports paid on a win
+    pub payout: u64,
+    
+    /// Slot the roll was placed in (see `Config::min_settle_delay_slots`)
+    pub placed_slot: u64,
+    
+    /// Timestamp when the roll was placed
+    pub created_at: i64,
+    
+    /// Bump seed for dice roll PDA
+    pub bump: u8,
+}
+
+/// Maximum number of outcomes in the slots paytable
+pub const MAX_PAYTABLE_ENTRIES: usize = 16;
+
+/// Number of reels a slots outcome shows
+pub const SLOT_REELS: usize = 3;
+
+/// One weighted slots outcome
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct PaytableEntry {
+    /// Symbol shown on each reel, for frontends
+    pub symbols: [u8; SLOT_REELS],
+    
+    /// Relative chance of this outcome
+    pub weight: u16,
+    
+    /// Payout as a multiple of the wager (basis points); for a jackpot outcome, the share of the pool won
+    pub multiplier_bps: u32,
+    
+    /// Wins the progressive pool instead of a fixed multiple
+    pub jackpot: bool,
+}
+
+/// Weighted outcome table `play_slots` spins against
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Paytable {
+    /// Number of entries in use
+    pub entry_count: u8,
+    
+    /// Outcomes, picked with probability `weight / total_weight`
+    pub entries: [PaytableEntry; MAX_PAYTABLE_ENTRIES],
+    
+    /// Sum of the entries' weights
+    pub total_weight: u32,
+    
+    /// Spins still awaiting settlement; the table can't change while any are open
+    pub open_spins: u64,
+    
+    /// Bump seed for paytable PDA
+    pub bump: u8,
+}
+
+impl Paytable {
+    /// Outcome a VRF result lands on: its first 8 bytes modulo the total weight, walked through the entries
+    pub fn outcome(&self, vrf_result: &[u8; 32]) -> usize {
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&vrf_result[..8]);
+        let mut point = u64::from_le_bytes(bytes) % self.total_weight as u64;
+        
+        for (index, entry) in self.entries[..self.entry_count as usize].iter().enumerate() {
+            if point < entry.weight as u64 {
+                return index;
+            }
+            point -= entry.weight as u64;
+        }
+        
+        self.entry_count as usize - 1
+    }
+}
+
+/// A slots spin awaiting VRF; holds the wager's staked part until it settles
+#[account]
+#[derive(Default, InitSpace)]
+pub struct SlotSpin {
+    /// Player who spun
+    pub player: Pubkey,
+    
+    /// Jackpot pool a jackpot outcome pays from
+    pub pool: Pubkey,
+    
+    /// Full wager in lamports
+    pub amount: u64,
+    
+    /// Part of the wager held against the house, after the jackpot and DeFi legs
+    pub stake: u64,
+    
+    /// Most the house would add to the stake on the best fixed outcome
+    pub liability: u64,
+    
+    /// Slot the spin was placed in (see `Config::min_settle_delay_slots`)
+    pub placed_slot: u64,
+    
+    /// Timestamp when the spin was placed
+    pub created_at: i64,
+    
+    /// Bump seed for slot spin PDA
+    pub bump: u8,
+}
+
+/// Batch of pre-generated scratch cards committed to by a Merkle root over their outcomes
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ScratchBatch {
+    /// Batch identifier (PDA seed)
+    pub batch_id: u64,
+    
+    /// Root over leaves `hashv([batch, index (u32 LE), prize (u64 LE), salt])`, pairs hashed in sorted order
+    pub merkle_root: [u8; 32],
+    
+    /// Number of cards in the batch
+    pub card_count: u32,
+    
+    /// Lamports per card
+    pub card_price: u64,
+    
+    /// Sum of every card's prize, locked against the house while the batch is open
+    pub total_prizes: u64,
+    
+    /// Cards sold so far; the next card sold gets this index
+    pub cards_sold: u32,
+    
+    /// Sold cards revealed so far
+    pub cards_revealed: u32,
+    
+    /// Prize lamports paid so far
+    pub prizes_paid: u64,
+    
+    /// Authority or operator that opened the batch; receives its rent when closed
+    pub opened_by: Pubkey,
+    
+    /// Bump seed for scratch batch PDA
+    pub bump: u8,
+}
+
+impl ScratchBatch {
+    /// Whether `proof` links card `index`'s outcome to `merkle_root`
+    pub fn verify(&self, batch: &Pubkey, index: u32, outcome: &ScratchOutcome, proof: &[[u8; 32]]) -> bool {
+        let leaf = hashv(&[
+            batch.as_ref(),
+            &index.to_le_bytes(),
+            &outcome.prize.to_le_bytes(),
+            &outcome.salt,
+        ]).to_bytes();
+        merkle_root_from(leaf, proof) == self.merkle_root
+    }
+    
+    /// Prize liability still locked: the committed total less what's been paid
+    pub fn remaining_liability(&self) -> u64 {
+        self.total_prizes.saturating_sub(self.prizes_paid)
+    }
+}
+
+/// A pre-generated card outcome, revealed with its Merkle proof
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug)]
+pub struct ScratchOutcome {
+    /// Lamports the card wins (0 for a losing card)
+    pub prize: u64,
+    
+    /// Per-card salt so unrevealed outcomes can't be guessed from the root
+    pub salt: [u8; 32],
+}
+
+/// A bought scratch card awaiting its reveal
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ScratchCard {
+    /// Batch the card belongs to
+    pub batch: Pubkey,
+    
+    /// Card's index within the batch
+    pub index: u32,
+    
+    /// Player who bought the card
+    pub player: Pubkey,
+    
+    /// Timestamp when the card was bought
+    pub bought_at: i64,
+    
+    /// Bump seed for scratch card PDA
+    pub bump: u8,
+}
+
+/// Most numbers a lottery ticket picks
+pub const MAX_LOTTO_PICKS: usize = 6;
+
+/// Highest number a lottery can draw (numbers run from 1)
+pub const MAX_LOTTO_NUMBER: u8 = 64;
+
+/// Round parameters supplied to `open_lotto_round`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default, Debug)]
+pub struct LottoRoundParams {
+    /// Numbers each ticket picks and the draw selects
+    pub picks: u8,
+    
+    /// Highest number a ticket may pick
+    pub max_number: u8,
+    
+    /// Price of one ticket in lamports
+    pub ticket_price: u64,
+    
+    /// Scheduled draw time; ticket sales stop here
+    pub draw_ts: i64,
+    
+    /// Rake taken from the pot before the tier split, in basis points
+    pub rake_bps: u16,
+    
+    /// Pot share per match tier, indexed by matches minus one
+    pub tier_shares_bps: Vec<u16>,
+}
+
+/// Pick-N lottery round; holds ticket sales until the crank pays each match tier's share
+#[account]
+#[derive(Default, InitSpace)]
+pub struct LottoRound {
+    /// Round identifier (PDA seed)
+    pub round_id: u64,
+    
+    /// Numbers each ticket picks and the draw selects
+    pub picks: u8,
+    
+    /// Numbers run from 1 to this
+    pub max_number: u8,
+    
+    /// Lamports per ticket
+    pub ticket_price: u64,
+    
+    /// Ticket sales close and `draw_lotto` opens at this timestamp
+    pub draw_ts: i64,
+    
+    /// Share of ticket sales kept by the house (basis points)
+    pub rake_bps: u16,
+    
+    /// Share of the pot after rake for each tier, indexed by matches - 1 (basis points)
+    pub tier_shares_bps: [u16; MAX_LOTTO_PICKS],
+    
+    /// Tickets sold; the next ticket gets this index
+    pub ticket_count: u32,
+    
+    /// Drawn numbers, ascending (zero before the draw)
+    pub winning_numbers: [u8; MAX_LOTTO_PICKS],
+    
+    /// Whether `draw_lotto` has run
+    pub drawn: bool,
+    
+    /// Tickets the crank has matched against the draw
+    pub tallied_count: u32,
+    
+    /// Tickets in each tier, indexed by matches - 1
+    pub tier_winners: [u32; MAX_LOTTO_PICKS],
+    
+    /// Lamports each ticket in a tier wins, set once every ticket is tallied
+    pub tier_payouts: [u64; MAX_LOTTO_PICKS],
+    
+    /// Tickets paid out and closed by the crank
+    pub paid_count: u32,
+    
+    /// Bump seed for lotto round PDA
+    pub bump: u8,
+}
+
+impl LottoRound {
+    /// Bitmask with bit `n - 1` set for each number
+    pub fn mask(numbers: &[u8]) -> u64 {
+        numbers.iter().fold(0, |mask, &n| mask | 1u64 << (n - 1))
+    }
+    
+    /// Matches between a ticket's numbers and the draw
+    pub fn matches(&self, numbers: &[u8]) -> u8 {
+        let picks = self.picks as usize;
+        (Self::mask(&numbers[..picks]) & Self::mask(&self.winning_numbers[..picks])).count_ones() as u8
+    }
+    
+    /// Whether every sold ticket has been matched against the draw
+    pub fn is_tallied(&self) -> bool {
+        self.drawn && self.tallied_count == self.ticket_count
+    }
+}
+
+/// A lottery ticket's picked numbers
+#[account]
+#[derive(Default, InitSpace)]
+pub struct LottoEntry {
+    /// Round the ticket was bought in
+    pub round: Pubkey,
+    
+    /// Ticket index within the round
+    pub index: u32,
+    
+    /// Player who bought the ticket; paid when the crank settles it
+    pub player: Pubkey,
+    
+    /// Picked numbers, ascending (only the round's `picks` are used)
+    pub numbers: [u8; MAX_LOTTO_PICKS],
+    
+    /// Numbers matched, once tallied
+    pub matches: u8,
+    
+    /// Whether the crank has matched the ticket against the draw
+    pub tallied: bool,
+    
+    /// Bump seed for lotto entry PDA
+    pub bump: u8,
+}
+
+/// Maximum number of slices on the bonus wheel
+pub const MAX_WHEEL_PRIZES: usize = 8;
+
+/// One weighted bonus wheel slice
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct WheelPrize {
+    /// Relative chance of landing on this slice
+    pub weight: u16,
+    
+    /// Lamports paid from house fees (0 for an empty slice)
+    pub amount: u64,
+}
+
+/// Weighted prize table for the bonus wheel earned by losing streaks
+#[account]
+#[derive(Default, InitSpace)]
+pub struct BonusWheel {
+    /// Number of slices in use
+    pub prize_count: u8,
+    
+    /// Slices, landed on with probability `weight / total_weight`
+    pub prizes: [WheelPrize; MAX_WHEEL_PRIZES],
+    
+    /// Sum of the slices' weights
+    pub total_weight: u32,
+    
+    /// Bump seed for bonus wheel PDA
+    pub bump: u8,
+}
+
+impl BonusWheel {
+    /// Slice a VRF result lands on: its first 8 bytes modulo the total weight, walked through the slices
+    pub fn landed(&self, vrf_result: &[u8; 32]) -> usize {
+        let mut point = u64::from_le_bytes(vrf_result[..8].try_into().unwrap()) % self.total_weight as u64;
+        
+        for (index, prize) in self.prizes[..self.prize_count as usize].iter().enumerate() {
+            if point < prize.weight as u64 {
+                return index;
+            }
+            point -= prize.weight as u64;
+        }
+        
+        self.prize_count as usize - 1
+    }
+}
+
+/// A bonus wheel spin awaiting VRF
+#[account]
+#[derive(Default, InitSpace)]
+pub struct WheelSpin {
+    /// Player spinning
+    pub player: Pubkey,
+    
+    /// Slot the spin was made in (see `Config::min_settle_delay_slots`)
+    pub placed_slot: u64,
+    
+    /// Bump seed for wheel spin PDA
+    pub bump: u8,
+}
+
+/// Mystery jackpot round: pays the bet that lifts the pool past a secret threshold committed up front
+#[account]
+#[derive(Default, InitSpace)]
+pub struct MysteryJackpot {
+    /// Jackpot pool the round watches
+    pub pool: Pubkey,
+    
+    /// Round identifier (PDA seed)
+    pub round_id: u64,
+    
+    /// `hashv([threshold (u64 LE), salt])`
+    pub commitment: [u8; 32],
+    
+    /// Only bets placed from this timestamp can trigger the round
+    pub started_at: i64,
+    
+    /// Authority or operator that committed the round; receives its rent on reveal
+    pub committed_by: Pubkey,
+    
+    /// Bump seed for mystery jackpot PDA
+    pub bump: u8,
+}
+
+impl MysteryJackpot {
+    /// Whether `threshold` and `salt` open the commitment
+    pub fn verify(&self, threshold: u64, salt: &[u8; 32]) -> bool {
+        hashv(&[&threshold.to_le_bytes(), salt]).to_bytes() == self.commitment
+    }
+}
+
+/// Maximum number of `bets_since_win` buckets in a side-bet round
+pub const MAX_SIDE_BET_BUCKETS: usize = 8;
+
+/// Pari-mutuel round on how many bets the pool's next jackpot hit takes; holds the stakes until claimed
+#[account]
+#[derive(Default, InitSpace)]
+pub struct SideBetPool {
+    /// Jackpot pool whose next hit settles the round
+    pub pool: Pubkey,
+    
+    /// Operator-chosen round ID
+    pub round_id: u64,
+    
+    /// Number of buckets in use
+    pub bucket_count: u8,
+    
+    /// Exclusive upper `bets_since_win` bound of each bucket but the last, which is open-ended
+    pub bucket_bounds: [u64; MAX_SIDE_BET_BUCKETS],
+    
+    /// Lamports staked on each bucket
+    pub bucket_totals: [u64; MAX_SIDE_BET_BUCKETS],
+    
+    /// Lamports staked across all buckets
+    pub total_staked: u64,
+    
+    /// House cut of the staked total at settlement (basis points)
+    pub rake_bps: u16,
+    
+    /// No side bets are accepted after this time
+    pub betting_closes_at: i64,
+    
+    /// Set once the jackpot hits
+    pub settled: bool,
+    
+    /// `bets_since_win` when the jackpot hit
+    pub hit_at_bets: u64,
+    
+    /// Bucket `hit_at_bets` fell in
+    pub winning_bucket: u8,
+    
+    /// Lamports shared among the winning bucket's backers, after the rake
+    pub distributable: u64,
+    
+    /// Bump seed for side-bet pool PDA
+    pub bump: u8,
+}
+
+impl SideBetPool {
+    /// Bucket a `bets_since_win` count falls in
+    pub fn bucket_for(&self, bets_since_win: u64) -> u8 {
+        let bounds = &self.bucket_bounds[..self.bucket_count as usize - 1];
+        bounds.iter().take_while(|&&bound| bets_since_win >= bound).count() as u8
+    }
+}
+
+/// A player's stakes in one side-bet round
+#[account]
+#[derive(Default, InitSpace)]
+pub struct SideBetEntry {
+    /// Round the stakes are in
+    pub side_bet_pool: Pubkey,
+    
+    /// Player who placed them
+    pub player: Pubkey,
+    
+    /// Lamports staked on each bucket
+    pub stakes: [u64; MAX_SIDE_BET_BUCKETS],
+    
+    /// Bump seed for side-bet entry PDA
+    pub bump: u8,
+}
+
+/// Maximum number of legs in a parlay
+pub const MAX_PARLAY_LEGS: usize = 5;
+
+/// Sequential wagers where each won leg's payout rides on the next; holds the stake until settled
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Parlay {
+    /// Player who placed the parlay
+    pub player: Pubkey,
+    
+    /// Lamports staked on the first leg
+    pub stake: u64,
+    
+    /// Number of legs in use
+    pub leg_count: u8,
+    
+    /// Win chance of each leg (basis points)
+    pub leg_chances_bps: [u16; MAX_PARLAY_LEGS],
+    
+    /// Roll each settled leg drew (0..10000)
+    pub leg_rolls: [u64; MAX_PARLAY_LEGS],
+    
+    /// Index of the leg awaiting VRF
+    pub current_leg: u8,
+    
+    /// Lamports riding on the current leg; the payout once every leg has won
+    pub current_stake: u64,
+    
+    /// Payout if every leg wins
+    pub potential_payout: u64,
+    
+    /// `Config::house_percentage` at placement, taken from each leg's fair payout
+    pub house_edge_bps: u16,
+    
+    /// Status: 0 = pending, 1 = won (claimable)
+    pub status: u8,
+    
+    /// Slot the current leg was requested in (see `Config::min_settle_delay_slots`)
+    pub placed_slot: u64,
+    
+    /// Timestamp when the parlay was placed
+    pub created_at: i64,
+    
+    /// Bump seed for parlay PDA
+    pub bump: u8,
+}
+
+/// House fees supplied to a lending market, held as the reserve's collateral tokens
+#[account]
+#[derive(Default, InitSpace)]
+pub struct YieldPosition {
+    /// Lending program the collateral was minted by
+    pub lending_program: Pubkey,
+    
+    /// Lending reserve the SOL is supplied to
+    pub reserve: Pubkey,
+    
+    /// Reserve collateral (cToken/kToken) mint
+    pub collateral_mint: Pubkey,
+    
+    /// Collateral tokens held in the position's collateral account
+    pub collateral_amount: u64,
+    
+    /// House fees supplied and not yet recalled
+    pub deployed_lamports: u64,
+    
+    /// Lamports recalled above the principal they redeemed
+    pub total_yield: u64,
+    
+    /// Last deploy timestamp
+    pub last_deployed_at: i64,
+    
+    /// Bump seed for yield position PDA
+    pub bump: u8,
+}
+
+/// DeFi reward vault for staking yields
+#[account]
+#[derive(Default, InitSpace)]
+pub struct RewardVault {
+    /// Total staked amount
+    pub staked_amount: u64,
+    
+    /// Total rewards distributed
+    pub total_rewards_distributed: u64,
+    
+    /// Last reward distribution timestamp
+    pub last_distribution: i64,
+    
+    /// Reward distribution period (seconds)
+    pub distribution_period: i64,
+    
+    /// Unused since rewards come from realized yield; kept for the account layout
+    pub apy_bps: u16,
+    
+    /// Bump seed for vault PDA
+    pub bump: u8,
+    
+    /// Layout version (see `STATE_VERSION`); fields added after this one are zero until `migrate` runs
+    pub version: u8,
+    
+    /// Realized yield per staked lamport since launch, scaled by `REWARD_PRECISION`
+    pub acc_reward_per_share: u128,
+    
+    /// When `acc_reward_per_share` was last brought up to date
+    pub last_accrual: i64,
+    
+    /// Share units minted to `stake_defi` positions
+    pub total_shares: u64,
+    
+    /// Lamports backing those shares, including early-exit penalties left behind
+    pub staker_assets: u64,
+    
+    /// SPL mint for vault shares, minted on stake and burned on unstake (default = not created)
+    pub lp_mint: Pubkey,
+    
+    /// Project token emitted alongside SOL yield; the vault is its mint authority (default = none)
+    pub reward_mint: Pubkey,
+    
+    /// Reward tokens emitted per second, shared pro rata across `staked_amount`
+    pub emission_rate: u64,
+    
+    /// Emissions stop after this time (0 = no end)
+    pub emission_ends_at: i64,
+    
+    /// Reward tokens emitted per staked lamport since launch, scaled by `REWARD_PRECISION`
+    pub acc_emission_per_share: u128,
+    
+    /// Total reward tokens minted to claimers
+    pub total_emissions_distributed: u64,
+    
+    /// Where idle DeFi funds are deployed
+    pub strategy: YieldStrategy,
+    
+    /// Liquid staking token the strategy pays out in
+    pub strategy_lst_mint: Pubkey,
+    
+    /// Lamports deployed to the strategy and not yet withdrawn
+    pub strategy_deployed: u64,
+    
+    /// Lamports withdrawn from the strategy above the principal they redeemed
+    pub strategy_yield: u64,
+    
+    /// Portion of `strategy_yield` already added to `acc_reward_per_share`
+    pub yield_distributed: u64,
+}
+
+/// Liquid staking protocol the reward vault can deploy idle lamports into
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, PartialEq, Eq, InitSpace)]
+pub enum YieldStrategy {
+    /// Funds stay in the vault
+    #[default]
+    None,
+    /// Marinade liquid staking (mSOL)
+    Marinade,
+    /// Jito's SPL stake pool (JitoSOL)
+    JitoSol,
+}
+
+/// Where a strategy's deposit or withdrawal expects the reward vault's accounts
+/// Callers pass the protocol's full account list; these slots are checked before the CPI
+pub struct StrategyCall {
+    /// Anchor discriminator or SPL instruction tag, followed by the u64 amount
+    pub prefix: &'static [u8],
+    /// Accounts the protocol instruction takes
+    pub accounts: usize,
+    /// Slot the program signs for: the SOL escrow on deposit, the reward vault on withdrawal
+    pub signer: usize,
+    /// Slot of the SOL escrow, which pays deposits and receives withdrawals
+    pub escrow: usize,
+    /// Slot of the vault's LST token account
+    pub lst_account: usize,
+}
+
+impl StrategyCall {
+    /// Build the CPI from the protocol's accounts, marking the program's PDA slot as a signer
+    pub fn instruction(&self, program_id: Pubkey, amount: u64, accounts: &[AccountInfo]) -> Instruction {
+        let mut data = self.prefix.to_vec();
+        data.extend_from_slice(&amount.to_le_bytes());
+        
+        Instruction {
+            program_id,
+            accounts: accounts
+                .iter()
+                .enumerate()
+                .map(|(i, account)| AccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer || i == self.signer,
+                    is_writable: account.is_writable,
+                })
+                .collect(),
+            data,
+        }
+    }
+}
+
+pub const MARINADE_PROGRAM_ID: Pubkey = pubkey!("MarBmsSgKXdrN1egZf5sqe1TMai9K1rChYNDJgjq7aD");
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+
+impl YieldStrategy {
+    /// Program the strategy's CPIs go to
+    pub fn program_id(&self) -> Option<Pubkey> {
+        match self {
+            YieldStrategy::None => None,
+            YieldStrategy::Marinade => Some(MARINADE_PROGRAM_ID),
+            YieldStrategy::JitoSol => Some(SPL_STAKE_POOL_PROGRAM_ID),
+        }
+    }
+    
+    /// Marinade `deposit` / stake pool `DepositSol`
+    pub fn deposit_call(&self) -> Option<StrategyCall> {
+        match self {
+            YieldStrategy::None => None,
+            YieldStrategy::Marinade => Some(StrategyCall {
+                prefix: &[242, 35, 198, 137, 82, 225, 242, 182],
+                accounts: 11,
+                signer: 6,
+                escrow: 6,
+                lst_account: 7,
+            }),
+            YieldStrategy::JitoSol => Some(StrategyCall {
+                prefix: &[14],
+                accounts: 10,
+                signer: 3,
+                escrow: 3,
+                lst_account: 4,
+            }),
+        }
+    }
+    
+    /// Marinade `liquid_unstake` / stake pool `WithdrawSol`
+    pub fn withdraw_call(&self) -> Option<StrategyCall> {
+        match self {
+            YieldStrategy::None => None,
+            YieldStrategy::Marinade => Some(StrategyCall {
+                prefix: &[30, 30, 119, 240, 191, 227, 12, 16],
+                accounts: 10,
+                signer: 6,
+                escrow: 7,
+                lst_account: 5,
+            }),
+            YieldStrategy::JitoSol => Some(StrategyCall {
+                prefix: &[16],
+                accounts: 12,
+                signer: 2,
+                escrow: 5,
+                lst_account: 3,
+            }),
+        }
+    }
+}
+
+/// Fixed-point scale for `RewardVault::acc_reward_per_share`
+pub const REWARD_PRECISION: u128 = 1_000_000_000_000;
+
+/// Seconds in a 365-day year
+pub const SECONDS_PER_YEAR: i64 = 31_536_000;
+
+impl RewardVault {
+    /// Grow the token emission accumulator for the time since the last accrual
+    /// Call before any stake changes so earlier stakers aren't diluted
+    pub fn accrue(&mut self, now: i64) -> Result<()> {
+        self.accrue_emissions(now)?;
+        self.last_accrual = now;
+        Ok(())
+    }
+    
+    /// Share strategy yield not yet distributed across the current stake
+    /// Returns the lamports distributed; yield waits while nothing is staked
+    pub fn distribute_yield(&mut self) -> Result<u64> {
+        let undistributed = self.strategy_yield.saturating_sub(self.yield_distributed);
+        if undistributed == 0 || self.staked_amount == 0 {
+            return Ok(0);
+        }
+        
+        let increment = (undistributed as u128)
+            .checked_mul(REWARD_PRECISION)
+            .and_then(|x| x.checked_div(self.staked_amount as u128))
+            .ok_or(CasinoError::MathOverflow)?;
+        self.acc_reward_per_share = self.acc_reward_per_share
+            .checked_add(increment)
+            .ok_or(CasinoError::MathOverflow)?;
+        self.yield_distributed = self.strategy_yield;
+        Ok(undistributed)
+    }
+    
+    /// Grow the emission accumulator by `emission_rate` for the time since the last accrual, up to `emission_ends_at`
+    fn accrue_emissions(&mut self, now: i64) -> Result<()> {
+        let until = if self.emission_ends_at > 0 { now.min(self.emission_ends_at) } else { now };
+        let elapsed = until.saturating_sub(self.last_accrual);
+        
+        if self.last_accrual > 0 && elapsed > 0 && self.staked_amount > 0 {
+            let increment = (self.emission_rate as u128)
+                .checked_mul(elapsed as u128)
+                .and_then(|x| x.checked_mul(REWARD_PRECISION))
+                .and_then(|x| x.checked_div(self.staked_amount as u128))
+                .ok_or(CasinoError::MathOverflow)?;
+            self.acc_emission_per_share = self.acc_emission_per_share
+                .checked_add(increment)
+                .ok_or(CasinoError::MathOverflow)?;
+        }
+        
+        Ok(())
+    }
+    
+    /// Shares minted for a `stake_defi` deposit at the current share price
+    pub fn shares_for(&self, amount: u64) -> Result<u64> {
+        if self.total_shares == 0 || self.staker_assets == 0 {
+            return Ok(amount);
+        }
+        
+        let shares = (amount as u128)
+            .checked_mul(self.total_shares as u128)
+            .and_then(|x| x.checked_div(self.staker_assets as u128))
+            .ok_or(CasinoError::MathOverflow)?;
+        Ok(shares as u64)
+    }
+    
+    /// Lamports `shares` are currently worth
+    pub fn value_of(&self, shares: u64) -> Result<u64> {
+        if self.total_shares == 0 {
+            return Ok(0);
+        }
+        
+        let value = (shares as u128)
+            .checked_mul(self.staker_assets as u128)
+            .and_then(|x| x.checked_div(self.total_shares as u128))
+            .ok_or(CasinoError::MathOverflow)?;
+        Ok(value as u64)
+    }
+}
+
+/// User reward claim account
+#[account]
+#[derive(Default, InitSpace)]
+pub struct RewardClaim {
+    /// User who can claim rewards
+    pub user: Pubkey,
+    
+    /// Total rewards earned
+    pub total_earned: u64,
+    
+    /// Total rewards claimed
+    pub total_claimed: u64,
+    
+    /// Last claim timestamp
+    pub last_claim: i64,
+    
+    /// Lamports this user has contributed to the reward vault
+    pub staked: u64,
+    
+    /// `staked * acc_reward_per_share` at the last update; rewards before that are already counted
+    pub reward_debt: u128,
+    
+    /// Rewards accrued but not yet claimed
+    pub pending_rewards: u64,
+    
+    /// Let anyone roll this user's rewards into their stake with `compound_rewards`
+    pub compound: bool,
+    
+    /// `staked * acc_emission_per_share` at the last update
+    pub emission_debt: u128,
+    
+    /// Reward tokens accrued but not yet claimed
+    pub pending_emissions: u64,
+    
+    /// Reward tokens minted to this user
+    pub total_emissions_claimed: u64,
+    
+    /// Bot or custodian allowed to trigger `claim_rewards`; payouts still go to `user`
+    pub claim_delegate: Option<Pubkey>,
+    
+    /// Bump seed for claim PDA
+    pub bump: u8,
+}
+
+impl RewardClaim {
+    /// Move rewards and emissions accrued since the last update into the pending balances
+    pub fn harvest(&mut self, vault: &RewardVault) -> Result<()> {
+        let accrued = (self.staked as u128)
+            .checked_mul(vault.acc_reward_per_share)
+            .ok_or(CasinoError::MathOverflow)?
+            .saturating_sub(self.reward_debt)
+            / REWARD_PRECISION;
+        let emitted = (self.staked as u128)
+            .checked_mul(vault.acc_emission_per_share)
+            .ok_or(CasinoError::MathOverflow)?
+            .saturating_sub(self.emission_debt)
+            / REWARD_PRECISION;
+        
+        self.pending_rewards = self.pending_rewards
+            .checked_add(accrued as u64)
+            .ok_or(CasinoError::MathOverflow)?;
+        self.pending_emissions = self.pending_emissions
+            .checked_add(emitted as u64)
+            .ok_or(CasinoError::MathOverflow)?;
+        self.reset_debt(vault);
+        Ok(())
+    }
+    
+    /// Add to the stake, settling rewards on the old stake first
+    pub fn deposit(&mut self, amount: u64, vault: &RewardVault) -> Result<()> {
+        self.harvest(vault)?;
+        self.staked = self.staked
+            .checked_add(amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        self.reset_debt(vault);
+        Ok(())
+    }
+    
+    /// Remove from the stake, settling rewards on the old stake first
+    pub fn withdraw(&mut self, amount: u64, vault: &RewardVault) -> Result<()> {
+        self.harvest(vault)?;
+        self.staked = self.staked
+            .checked_sub(amount)
+            .ok_or(CasinoError::MathOverflow)?;
+        self.reset_debt(vault);
+        Ok(())
+    }
+    
+    fn reset_debt(&mut self, vault: &RewardVault) {
+        self.reward_debt = (self.staked as u128) * vault.acc_reward_per_share;
+        self.emission_debt = (self.staked as u128) * vault.acc_emission_per_share;
+    }
+}
+
+/// Liquidity a user staked directly with `stake_defi`, held as reward vault shares
+#[account]
+#[derive(Default, InitSpace)]
+pub struct StakePosition {
+    /// Staker
+    pub owner: Pubkey,
+    
+    /// Share units held
+    pub shares: u64,
+    
+    /// Lamports deposited for the shares still held; this is what earns yield
+    pub principal: u64,
+    
+    /// Unstaking before this pays the early-exit penalty (0 = no lockup)
+    pub lockup_until: i64,
+    
+    /// Bump seed for stake position PDA
+    pub bump: u8,
+}
+
+/// Seconds a VRF request may stay pending before the bet can only be refunded
+pub const VRF_TIMEOUT: i64 = 60 * 60;
+
+/// VRF request tracking account
+#[account]
+#[derive(Default, InitSpace)]
+pub struct VrfRequest {
+    /// Bet account associated with this request
+    pub bet: Pubkey,
+    
+    /// Player who placed the bet
+    pub player: Pubkey,
+    
+    /// VRF request timestamp
+    pub timestamp: i64,
+    
+    /// VRF request ID/seed
+    pub request_id: [u8; 32],
+    
+    /// Status: 0 = pending, 1 = fulfilled, 2 = timeout
+    pub status: u8,
+    
+    /// VRF result (if fulfilled)
+    pub result: Option<[u8; 32]>,
+    
+    /// Bump seed for request PDA
+    pub bump: u8,
+}
+
+/// Per-player lifetime statistics
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PlayerProfile {
+    /// Player this profile belongs to
+    pub player: Pubkey,
+    
+    /// Total lamports wagered across all bets
+    pub total_wagered: u64,
+    
+    /// Total lamports won across all bets
+    pub total_won: u64,
+    
+    /// Number of bets placed
+    pub bets_placed: u64,
+    
+    /// Largest single win in lamports
+    pub biggest_win: u64,
+    
+    /// Timestamp of the most recent bet
+    pub last_bet_timestamp: i64,
+    
+    /// Player opted out of odds experiment bucketing
+    pub experiment_opt_out: bool,
+    
+    /// Player's pubkey is withheld from win history and notifications
+    pub hide_from_history: bool,
+    
+    /// Unredeemed comp points earned by wagering
+    pub comp_points: u64,
+    
+    /// Bonus-bet credit from redeemed comp points, in lamports (spent on the next bets)
+    pub bonus_credit: u64,
+    
+    /// Rakeback accrued but not yet claimed, in lamports
+    pub rakeback_pending: u64,
+    
+    /// Lifetime rakeback credited
+    pub total_rakeback: u64,
+    
+    /// Season `season_wagered` refers to (0 = none yet)
+    pub season_id: u64,
+    
+    /// Lamports wagered during `season_id`
+    pub season_wagered: u64,
+    
+    /// UTC day (unix_timestamp / 86400) of the most recent bet
+    pub streak_day: i64,
+    
+    /// Consecutive UTC days with at least one bet, ending on `streak_day`
+    pub streak_len: u16,
+    
+    /// Cashback week currently being accumulated (see `cashback_epoch`)
+    pub cashback_epoch: i64,
+    
+    /// Player's lifetime wagered and won at the start of `cashback_epoch`
+    pub epoch_start_wagered: u64,
+    pub epoch_start_won: u64,
+    
+    /// Cashback credited for closed weeks but not yet claimed, in lamports
+    pub cashback_pending: u64,
+    
+    /// Bump seed for profile PDA; fields added after it are zero until `migrate` grows the profile
+    pub bump: u8,
+    
+    /// Cold wallet that receives the player's wins and DeFi reward claims (None = the player)
+    pub payout_beneficiary: Option<Pubkey>,
+    
+    /// Lifetime lamports donated through charity round-ups
+    pub total_charity: u64,
+    
+    /// UTC day of the most recent losing bet
+    pub loss_day: i64,
+    
+    /// Losing bets settled on `loss_day`
+    pub day_losses: u16,
+    
+    /// Unspent bonus wheel spins
+    pub bonus_wheel_spins: u8,
+}
+
+impl PlayerProfile {
+    /// Extend or restart the daily betting streak for a bet on UTC `day`; returns the streak length
+    pub fn record_bet_day(&mut self, day: i64) -> u16 {
+        if self.streak_len == 0 || day > self.streak_day + 1 {
+            self.streak_len = 1;
+        } else if day == self.streak_day + 1 {
+            self.streak_len = self.streak_len.saturating_add(1);
+        }
+        self.streak_day = day;
+        self.streak_len
+    }
+    
+    /// Count a losing bet on UTC `day`; the day's `losses_required`th loss earns a bonus wheel spin
+    pub fn record_loss_day(&mut self, day: i64, losses_required: u16) -> bool {
+        if day != self.loss_day {
+            self.loss_day = day;
+            self.day_losses = 0;
+        }
+        self.day_losses = self.day_losses.saturating_add(1);
+        
+        let earned = losses_required > 0 && self.day_losses == losses_required;
+        if earned {
+            self.bonus_wheel_spins = self.bonus_wheel_spins.saturating_add(1);
+        }
+        earned
+    }
+    
+    /// Close the open cashback week if `epoch` is later, crediting `cashback_bps` of its net loss
+    /// Must run before the profile's totals change in a new week; returns the lamports credited
+    pub fn roll_cashback_epoch(&mut self, epoch: i64, cashback_bps: u16) -> u64 {
+        if epoch <= self.cashback_epoch {
+            return 0;
+        }
+        
+        let wagered = self.total_wagered.saturating_sub(self.epoch_start_wagered);
+        let won = self.total_won.saturating_sub(self.epoch_start_won);
+        let credit = (wagered.saturating_sub(won) as u128 * cashback_bps as u128 / 10000) as u64;
+        
+        self.cashback_pending = self.cashback_pending.saturating_add(credit);
+        self.cashback_epoch = epoch;
+        self.epoch_start_wagered = self.total_wagered;
+        self.epoch_start_won = self.total_won;
+        credit
+    }
+}
+
+/// Cashback week containing `unix_timestamp`, counted in UTC weeks starting Monday
+pub fn cashback_epoch(unix_timestamp: i64) -> i64 {
+    // 1970-01-01 was a Thursday
+    (unix_timestamp.div_euclid(86400) + 3).div_euclid(7)
+}
+
+/// Maximum number of recipients in a player's payout split
+pub const MAX_SPLIT_RECIPIENTS: usize = 5;
+
+/// One recipient's cut of a split win
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, Debug, InitSpace)]
+pub struct SplitShare {
+    /// Wallet paid this share
+    pub recipient: Pubkey,
+    
+    /// Share of each claimed win (basis points)
+    pub bps: u16,
+}
+
+/// How a player's claimed wins are divided among a syndicate; the remainder goes to the player
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PayoutSplit {
+    /// Player whose wins are split
+    pub player: Pubkey,
+    
+    /// Number of populated entries in `shares` (0 = no split)
+    pub share_count: u8,
+    
+    /// Recipients and their cuts, in the order they are passed when claiming
+    pub shares: [SplitShare; MAX_SPLIT_RECIPIENTS],
+    
+    /// Bump seed for split PDA
+    pub bump: u8,
+}
+
+impl PayoutSplit {
+    /// Populated shares
+    pub fn active_shares(&self) -> &[SplitShare] {
+        &self.shares[..self.share_count as usize]
+    }
+}
+
+/// Delay before a request that relaxes gaming limits takes effect (24 hours)
+pub const LIMIT_RELAX_COOLDOWN: i64 = 24 * 60 * 60;
+
+/// Player-set responsible-gaming limits, enforced on every bet
+#[account]
+#[derive(Default, InitSpace)]
+pub struct GamingLimits {
+    /// Player these limits belong to
+    pub player: Pubkey,
+    
+    /// Max lamports wagered per UTC day (0 = no cap)
+    pub daily_wager_cap: u64,
+    
+    /// Max net lamports lost per UTC day, counting the bet being placed as lost (0 = no cap)
+    pub daily_loss_cap: u64,
+    
+    /// No bets accepted before this timestamp
+    pub self_excluded_until: i64,
+    
+    /// Requested relaxation, applied once `pending_effective_at` passes (0 = none queued)
+    pub pending_wager_cap: u64,
+    pub pending_loss_cap: u64,
+    pub pending_self_excluded_until: i64,
+    pub pending_effective_at: i64,
+    
+    /// UTC day (unix_timestamp / 86400) the daily counters refer to
+    pub day: i64,
+    
+    /// Player's lifetime wagered and won at the start of `day`
+    pub day_start_wagered: u64,
+    pub day_start_won: u64,
+    
+    /// Bump seed for limits PDA
+    pub bump: u8,
+}
+
+impl GamingLimits {
+    /// Apply a queued relaxation whose cooldown has elapsed
+    pub fn apply_pending(&mut self, now: i64) {
+        if self.pending_effective_at != 0 && now >= self.pending_effective_at {
+            self.daily_wager_cap = self.pending_wager_cap;
+            self.daily_loss_cap = self.pending_loss_cap;
+            self.self_excluded_until = self.pending_self_excluded_until;
+            self.pending_effective_at = 0;
+        }
+    }
+}
+
+/// Promotional bonus balance granted by the operator, held in the PDA itself
+#[account]
+#[derive(Default, InitSpace)]
+pub struct BonusCredit {
+    /// Player the bonus belongs to
+    pub player: Pubkey,
+    
+    /// Lamports available for bonus bets, including winnings from them
+    pub balance: u64,
+    
+    /// Lamports still to be wagered from bonus before `balance` can be withdrawn
+    pub wagering_remaining: u64,
+    
+    /// Lifetime lamports granted
+    pub total_granted: u64,
+    
+    /// Bump seed for bonus credit PDA
+    pub bump: u8,
+}
+
+/// Retroactive reward campaign: players claim their Merkle leaf from lamports held in the PDA
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PromoCampaign {
+    /// Campaign identifier (PDA seed)
+    pub campaign_id: u64,
+    
+    /// Root over leaves `hashv([player, amount (u64 LE)])`, pairs hashed in sorted order
+    pub merkle_root: [u8; 32],
+    
+    /// Lifetime lamports deposited for the campaign
+    pub funded: u64,
+    
+    /// Lamports claimed so far
+    pub claimed: u64,
+    
+    /// Bump seed for promo campaign PDA
+    pub bump: u8,
+}
+
+impl PromoCampaign {
+    /// Whether `proof` links the player's leaf to `merkle_root`
+    pub fn verify(&self, player: &Pubkey, amount: u64, proof: &[[u8; 32]]) -> bool {
+        let leaf = hashv(&[player.as_ref(), &amount.to_le_bytes()]).to_bytes();
+        merkle_root_from(leaf, proof) == self.merkle_root
+    }
+}
+
+/// Root reached by folding `proof` into `leaf`, hashing each pair in sorted order
+pub fn merkle_root_from(leaf: [u8; 32], proof: &[[u8; 32]]) -> [u8; 32] {
+    proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&node, sibling]).to_bytes()
+        } else {
+            hashv(&[sibling, &node]).to_bytes()
+        }
+    })
+}
+
+/// Receipt marking a player's promo claim, preventing a second claim
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PromoClaim {
+    /// Campaign the claim was made against
+    pub campaign: Pubkey,
+    
+    /// Player who claimed
+    pub player: Pubkey,
+    
+    /// Lamports paid
+    pub amount: u64,
+    
+    /// When the claim was made
+    pub claimed_at: i64,
+    
+    /// Bump seed for promo claim PDA
+    pub bump: u8,
+}
+
+/// Allowlist entry for a wallet approved to bet while `allowlist_enabled` is set
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Membership {
+    /// Approved wallet
+    pub player: Pubkey,
+    
+    /// Authority that issued the membership
+    pub added_by: Pubkey,
+    
+    /// When the membership was issued
+    pub added_at: i64,
+    
+    /// Bump seed for membership PDA
+    pub bump: u8,
+}
+
+/// Freeze marker for a player blocked from betting and claiming pending review
+#[account]
+#[derive(Default, InitSpace)]
+pub struct FrozenPlayer {
+    /// Frozen player
+    pub player: Pubkey,
+    
+    /// Authority or operator that froze the player
+    pub frozen_by: Pubkey,
+    
+    /// When the freeze was applied
+    pub frozen_at: i64,
+    
+    /// Operator-defined reason code (e.g. sanctions match, abuse report)
+    pub reason: u8,
+    
+    /// Bump seed for frozen-player PDA
+    pub bump: u8,
+}
+
+/// Number of tranches a vested win is released in
+pub const VESTING_TRANCHES: u64 = 12;
+
+/// Seconds between vesting tranches (30 days)
+pub const VESTING_TRANCHE_INTERVAL: i64 = 30 * 24 * 60 * 60;
+
+/// Linear release schedule for a win above the vesting threshold
+#[account]
+#[derive(Default, InitSpace)]
+pub struct WinVesting {
+    /// Winning player
+    pub player: Pubkey,
+    
+    /// Bet the win came from
+    pub bet: Pubkey,
+    
+    /// Total amount being vested
+    pub total_amount: u64,
+    
+    /// Amount released so far
+    pub claimed_amount: u64,
+    
+    /// When vesting started; the first tranche is available immediately
+    pub start_timestamp: i64,
+    
+    /// Bump seed for vesting PDA
+    pub bump: u8,
+}
+
+impl WinVesting {
+    /// Amount released by `now`, one tranche per elapsed interval
+    pub fn vested_amount(&self, now: i64) -> u64 {
+        let elapsed = now.saturating_sub(self.start_timestamp).max(0);
+        let tranches = (elapsed / VESTING_TRANCHE_INTERVAL) as u64 + 1;
+        
+        if tranches >= VESTING_TRANCHES {
+            return self.total_amount;
+        }
+        
+        ((self.total_amount as u128) * (tranches as u128) / (VESTING_TRANCHES as u128)) as u64
+    }
+}
+
+/// Win above the manual review threshold, held until approved or the dispute window passes
+#[account]
+#[derive(Default, InitSpace)]
+pub struct PendingPayout {
+    /// Winning player
+    pub player: Pubkey,
+    
+    /// Bet the win came from
+    pub bet: Pubkey,
+    
+    /// Amount held
+    pub amount: u64,
+    
+    /// When the payout was held
+    pub held_at: i64,
+    
+    /// Set by the payout approver; releases the payout before the window ends
+    pub approved: bool,
+    
+    /// Bump seed for pending payout PDA
+    pub bump: u8,
+}
+
+impl PendingPayout {
+    /// Whether the player can withdraw the payout at `now`
+    pub fn is_releasable(&self, now: i64, dispute_window_secs: i64) -> bool {
+        self.approved || now >= self.held_at.saturating_add(dispute_window_secs)
+    }
+}
+
+/// Dispute status: awaiting the authority
+pub const DISPUTE_OPEN: u8 = 0;
+
+/// Dispute status: resolved in the player's favour
+pub const DISPUTE_UPHELD: u8 = 1;
+
+/// Dispute status: resolved against the player
+pub const DISPUTE_REJECTED: u8 = 2;
+
+/// Player's appeal against a bet, kept after resolution as the support paper trail
+#[account]
+#[derive(Default, InitSpace)]
+pub struct Dispute {
+    /// Disputed bet
+    pub bet: Pubkey,
+    
+    /// Player who opened the dispute
+    pub player: Pubkey,
+    
+    /// Player-chosen reason code (e.g. wrong outcome, missing payout)
+    pub reason: u8,
+    
+    /// When the dispute was opened
+    pub opened_at: i64,
+    
+    /// `DISPUTE_OPEN`, `DISPUTE_UPHELD` or `DISPUTE_REJECTED`
+    pub status: u8,
+    
+    /// Authority that resolved the dispute
+    pub resolved_by: Pubkey,
+    
+    /// When the dispute was resolved (0 while open)
+    pub resolved_at: i64,
+    
+    /// Hash of the support team's resolution notes
+    pub resolution_hash: [u8; 32],
+    
+    /// Bump seed for dispute PDA
+    pub bump: u8,
+}
+
+/// Per-pool proof-of-reserves snapshot, republished at most once per epoch
+#[account]
+#[derive(Default, InitSpace)]
+pub struct ReserveSnapshot {
+    /// Pool the snapshot describes
+    pub pool: Pubkey,
+    
+    /// Open jackpot balance
+    pub pool_balance: u64,
+    
+    /// Settled winnings not yet claimed, including vesting wins
+    pub pending_payouts: u64,
+    
+    /// Vesting wins not yet released
+    pub vesting_outstanding: u64,
+    
+    /// Pool lamports, excluding rent
+    pub pool_lamports: u64,
+    
+    /// Reserve pool balance
+    pub reserve_balance: u64,
+    
+    /// House vault lamports, excluding rent
+    pub house_vault_lamports: u64,
+    
+    /// House fees collected and not yet withdrawn
+    pub house_fees: u64,
+    
+    /// Epoch the snapshot was taken in
+    pub epoch: u64,
+    
+    /// When the snapshot was taken
+    pub timestamp: i64,
+    
+    /// Bump seed for snapshot PDA
+    pub bump: u8,
+}
+
+/// Number of wins kept in the win history ring buffer
+pub const WIN_HISTORY_LEN: usize = 64;
+
+/// Single entry in the win history ring buffer
+#[zero_copy]
+#[derive(Default)]
+pub struct WinRecord {
+    /// Winning player
+    pub player: Pubkey,
+    
+    /// Win amount in lamports
+    pub amount: u64,
+    
+    /// Timestamp of the win
+    pub timestamp: i64,
+    
+    /// Payout tier: 0 = rare (100%), 1 = medium (50%), 2 = common (25%), 3 = bootstrap (house-paid)
+    pub tier: u8,
+    
+    pub padding: [u8; 7],
+}
+
+/// Ring buffer of the most recent jackpot wins (zero-copy)
+#[account(zero_copy)]
+pub struct WinHistory {
+    /// Index the next win will be written to
+    pub head: u64,
+    
+    /// Total wins recorded since creation
+    pub total_recorded: u64,
+    
+    /// Bump seed for history PDA
+    pub bump: u8,
+    
+    pub padding: [u8; 7],
+    
+    /// Most recent wins, oldest overwritten first
+    pub entries: [WinRecord; WIN_HISTORY_LEN],
+}
+
+impl WinHistory {
+    /// Append a win, overwriting the oldest entry once the buffer is full
+    pub fn record(&mut self, player: Pubkey, amount: u64, timestamp: i64, tier: u8) {
+        let index = self.head as usize % WIN_HISTORY_LEN;
+        self.entries[index] = WinRecord {
+            player,
+            amount,
+            timestamp,
+            tier,
+            padding: [0; 7],
+        };
+        self.head = ((index + 1) % WIN_HISTORY_LEN) as u64;
+        self.total_recorded = self.total_recorded.saturating_add(1);
+    }
+}
+
+/// Distinct players that can enter a single draw round
+pub const MAX_DRAW_ENTRIES: usize = 128;
+
+/// A player's weighted entry in a draw round
+#[zero_copy]
+#[derive(Default)]
+pub struct DrawEntry {
+    /// Entered player
+    pub player: Pubkey,
+    
+    /// Lamports wagered during the round (entry weight)
+    pub weight: u64,
+    
+    /// Ticket NFT minted for this entry; whoever holds it owns the entry (default = no ticket)
+    pub ticket_mint: Pubkey,
+}
+
+/// Time-boxed raffle: bets buy entries weighted by amount, one winner takes the prize (zero-copy)
+#[account(zero_copy)]
+pub struct DrawRound {
+    /// Round identifier, part of the PDA seeds
+    pub round_id: u64,
+    
+    /// Bets are accepted from this timestamp
+    pub draw_start_ts: i64,
+    
+    /// Bets close and `settle_draw` opens at this timestamp
+    pub draw_end_ts: i64,
+    
+    /// Lamports collected for the winner
+    pub prize: u64,
+    
+    /// Sum of all entry weights
+    pub total_weight: u64,
+    
+    /// Winner once settled (default pubkey before, or if nobody entered)
+    pub winner: Pubkey,
+    
+    /// Number of populated entries
+    pub entry_count: u32,
+    
+    /// Whether `settle_draw` has run
+    pub settled: u8,
+    
+    /// Bump seed for draw round PDA
+    pub bump: u8,
+    
+    pub padding: [u8; 2],
+    
+    /// Entries, one per player
+    pub entries: [DrawEntry; MAX_DRAW_ENTRIES],
+}
+
+impl DrawRound {
+    /// Whether bets can enter the round at `now`
+    pub fn is_open(&self, now: i64) -> bool {
+        self.settled == 0 && now >= self.draw_start_ts && now < self.draw_end_ts
+    }
+    
+    /// Add weight to the player's entry, creating it if needed; false if the round is full
+    /// Ticketed entries are fixed, so further bets open a new entry
+    pub fn enter(&mut self, player: Pubkey, weight: u64) -> bool {
+        let count = self.entry_count as usize;
+        
+        if let Some(entry) = self.entries[..count]
+            .iter_mut()
+            .find(|entry| entry.player == player && entry.ticket_mint == Pubkey::default())
+        {
+            entry.weight = entry.weight.saturating_add(weight);
+        } else if count < MAX_DRAW_ENTRIES {
+            self.entries[count] = DrawEntry { player, weight, ticket_mint: Pubkey::default() };
+            self.entry_count += 1;
+        } else {
+            return false;
+        }
+        
+        self.total_weight = self.total_weight.saturating_add(weight);
+        true
+    }
+    
+    /// Entry whose cumulative weight range contains `target` (0 <= target < total_weight)
+    pub fn pick(&self, target: u64) -> Option<DrawEntry> {
+        let mut cumulative: u64 = 0;
+        
+        for entry in &self.entries[..self.entry_count as usize] {
+            cumulative = cumulative.saturating_add(entry.weight);
+            if target < cumulative {
+                return Some(*entry);
+            }
+        }
+        
+        None
+    }
+}
+
+/// Number of leaderboard places tracked (and payable) per season
+pub const SEASON_LEADERBOARD_LEN: usize = 10;
+
+/// A player's place on a season leaderboard
+#[zero_copy]
+#[derive(Default)]
+pub struct LeaderboardEntry {
+    /// Ranked player
+    pub player: Pubkey,
+    
+    /// Lamports wagered during the season
+    pub wagered: u64,
+}
+
+/// Competitive season: top wagerers split a prize funded from house fees (zero-copy)
+#[account(zero_copy)]
+pub struct Season {
+    /// Season identifier, part of the PDA seeds
+    pub season_id: u64,
+    
+    /// Bets count toward the season from this timestamp
+    pub start_ts: i64,
+    
+    /// Season ends (and can be finalized) at this timestamp
+    pub end_ts: i64,
+    
+    /// Lamports collected for the prize
+    pub prize_pool: u64,
+    
+    /// Share of each bet's house fee added to the prize (basis points)
+    pub fee_bps: u16,
+    
+    /// Prize share per rank (basis points, first `payout_count` entries used)
+    pub payout_bps: [u16; SEASON_LEADERBOARD_LEN],
+    
+    /// Number of paid ranks
+    pub payout_count: u8,
+    
+    /// Number of populated leaderboard entries
+    pub entry_count: u8,
+    
+    /// Whether `finalize_season` has run
+    pub finalized: u8,
+    
+    /// Bump seed for season PDA
+    pub bump: u8,
+    
+    pub padding: [u8; 6],
+    
+    /// Top wagerers, highest first
+    pub leaderboard: [LeaderboardEntry; SEASON_LEADERBOARD_LEN],
+}
+
+impl Season {
+    /// Whether bets count toward the season at `now`
+    pub fn is_active(&self, now: i64) -> bool {
+        self.finalized == 0 && now >= self.start_ts && now < self.end_ts
+    }
+    
+    /// Update a player's season total on the leaderboard, keeping it sorted
+    pub fn record_wager(&mut self, player: Pubkey, wagered: u64) {
+        let count = self.entry_count as usize;
+        
+        let index = match self.leaderboard[..count].iter().position(|entry| entry.player == player) {
+            Some(index) => index,
+            None if count < SEASON_LEADERBOARD_LEN => {
+                self.entry_count += 1;
+                count
+            }
+            // Full: only displaces the last place if it beats it
+            None if wagered > self.leaderboard[count - 1].wagered => count - 1,
+            None => return,
+        };
+        
+        self.leaderboard[index] = LeaderboardEntry { player, wagered };
+        
+        // Bubble the updated entry up to its rank
+        let mut i = index;
+        while i > 0 && self.leaderboard[i].wagered > self.leaderboard[i - 1].wagered {
+            self.leaderboard.swap(i, i - 1);
+            i -= 1;
+        }
+    }
+}
+
+/// Number of notifications kept in the notification queue
+pub const NOTIFICATION_QUEUE_LEN: usize = 32;
+
+/// Notification kind: a win at or above `Config::big_win_threshold`
+pub const NOTIFICATION_BIG_WIN: u8 = 0;
+
+/// Notification kind: betting stopped, by sunset mode or a pool's emergency withdrawal
+pub const NOTIFICATION_PAUSE: u8 = 1;
+
+/// Notification kind: the house vault couldn't cover a house-paid amount during settlement
+pub const NOTIFICATION_SOLVENCY_WARNING: u8 = 2;
+
+/// Single typed record in the notification queue
+#[zero_copy]
+#[derive(Default)]
+pub struct Notification {
+    /// Sequence number (starts at 1, strictly increasing)
+    pub seq: u64,
+    
+    /// Timestamp the notification was written
+    pub timestamp: i64,
+    
+    /// Amount involved, in lamports (0 if not applicable)
+    pub amount: u64,
+    
+    /// Account the notification is about (player, authority, pool, vault)
+    pub subject: Pubkey,
+    
+    /// Kind: 0 = big win, 1 = pause, 2 = solvency warning
+    pub kind: u8,
+    
+    pub padding: [u8; 7],
+}
+
+/// Ring buffer of notifications for the off-chain keeper (zero-copy)
+/// Keeper reads entries with seq > acked_seq, delivers them, then acknowledges
+#[account(zero_copy)]
+pub struct NotificationQueue {
+    /// Sequence number assigned to the next notification
+    pub next_seq: u64,
+    
+    /// Highest sequence number acknowledged by the keeper
+    pub acked_seq: u64,
+    
+    /// Unacknowledged notifications overwritten because the queue was full
+    pub dropped: u64,
+    
+    /// Bump seed for queue PDA
+    pub bump: u8,
+    
+    pub padding: [u8; 7],
+    
+    /// Notifications indexed by seq % NOTIFICATION_QUEUE_LEN
+    pub entries: [Notification; NOTIFICATION_QUEUE_LEN],
+}
+
+impl NotificationQueue {
+    /// Append a notification, overwriting the oldest entry once the queue is full
+    pub fn push(&mut self, kind: u8, subject: Pubkey, amount: u64, timestamp: i64) {
+        let seq = self.next_seq;
+        let index = seq as usize % NOTIFICATION_QUEUE_LEN;
+        
+        let overwritten = self.entries[index].seq;
+        if overwritten != 0 && overwritten > self.acked_seq {
+            self.dropped = self.dropped.saturating_add(1);
+        }
+        
+        self.entries[index] = Notification {
+            seq,
+            timestamp,
+            amount,
+            subject,
+            kind,
+            padding: [0; 7],
+        };
+        self.next_seq = seq.saturating_add(1);
+    }
+}
+
+/// Betting activity for one UTC day, for dashboards
+#[account]
+#[derive(Default, InitSpace)]
+pub struct VolumeBucket {
+    /// UTC day (unix_timestamp / 86400) the bucket covers
+    pub day: i64,
+    
+    /// Bets placed
+    pub bets: u64,
+    
+    /// Lamports wagered
+    pub volume: u64,
+    
+    /// Players whose first bet of the day landed in this bucket
+    pub unique_bettors: u64,
+    
+    /// Winning settlements
+    pub wins: u64,
+    
+    /// Lamports awarded by winning settlements
+    pub win_amount: u64,
+    
+    /// Bump seed for bucket PDA
+    pub bump: u8,
+}
+
+/// Number of epochs kept in the stats ring buffer
+pub const STATS_EPOCHS: usize = 16;
+
+/// Totals for one epoch of play
+#[zero_copy]
+#[derive(Default)]
+pub struct EpochStats {
+    /// Epoch these totals belong to
+    pub epoch: u64,
+    
+    /// Bets placed
+    pub bets: u64,
+    
+    /// Lamports wagered
+    pub wagered: u64,
+    
+    /// Lamports awarded at settlement, including consolations and reset payouts
+    pub paid_out: u64,
+    
+    /// House fees collected
+    pub house_fees: u64,
+    
+    /// Wins by payout tier: 0 = rare, 1 = medium, 2 = common, 3 = bootstrap
+    pub wins_by_tier: [u64; 4],
+}
+
+/// Per-epoch wagering and payout totals for on-chain RTP reporting (zero-copy)
+#[account(zero_copy)]
+pub struct Stats {
+    /// Bump seed for stats PDA
+    pub bump: u8,
+    
+    pub padding: [u8; 7],
+    
+    /// Totals indexed by epoch % STATS_EPOCHS
+    pub entries: [EpochStats; STATS_EPOCHS],
+}
+
+impl Stats {
+    /// Totals for `epoch`, clearing the entry if it still holds an older epoch
+    pub fn epoch_mut(&mut self, epoch: u64) -> &mut EpochStats {
+        let entry = &mut self.entries[epoch as usize % STATS_EPOCHS];
+        if entry.epoch != epoch {
+            *entry = EpochStats { epoch, ..EpochStats::default() };
+        }
+        entry
+    }
+}
+
+/// Compile-time check that every Borsh account fits a single `init` allocation
+/// (accounts created through CPI may not exceed 10 KiB).
+const _: () = {
+    use anchor_lang::solana_program::entrypoint::MAX_PERMITTED_DATA_INCREASE as MAX;
+    assert!(8 + Config::INIT_SPACE <= MAX);
+    assert!(8 + JackpotPool::INIT_SPACE <= MAX);
+    assert!(8 + HouseVault::INIT_SPACE <= MAX);
+    assert!(8 + YieldPosition::INIT_SPACE <= MAX);
+    assert!(8 + Bankroll::INIT_SPACE <= MAX);
+    assert!(8 + ReservePool::INIT_SPACE <= MAX);
+    assert!(8 + GameVault::INIT_SPACE <= MAX);
+    assert!(8 + CharityVault::INIT_SPACE <= MAX);
+    assert!(8 + Integrator::INIT_SPACE <= MAX);
+    assert!(8 + Keeper::INIT_SPACE <= MAX);
+    assert!(8 + Referral::INIT_SPACE <= MAX);
+    assert!(8 + ReferralEarnings::INIT_SPACE <= MAX);
+    assert!(8 + Bet::INIT_SPACE <= MAX);
+    assert!(8 + Parlay::INIT_SPACE <= MAX);
+    assert!(8 + DiceRoll::INIT_SPACE <= MAX);
+    assert!(8 + Paytable::INIT_SPACE <= MAX);
+    assert!(8 + SlotSpin::INIT_SPACE <= MAX);
+    assert!(8 + ScratchBatch::INIT_SPACE <= MAX);
+    assert!(8 + ScratchCard::INIT_SPACE <= MAX);
+    assert!(8 + LottoRound::INIT_SPACE <= MAX);
+    assert!(8 + LottoEntry::INIT_SPACE <= MAX);
+    assert!(8 + BonusWheel::INIT_SPACE <= MAX);
+    assert!(8 + WheelSpin::INIT_SPACE <= MAX);
+    assert!(8 + MysteryJackpot::INIT_SPACE <= MAX);
+    assert!(8 + SideBetPool::INIT_SPACE <= MAX);
+    assert!(8 + SideBetEntry::INIT_SPACE <= MAX);
+    assert!(8 + RewardVault::INIT_SPACE <= MAX);
+    assert!(8 + RewardClaim::INIT_SPACE <= MAX);
+    assert!(8 + StakePosition::INIT_SPACE <= MAX);
+    assert!(8 + VrfRequest::INIT_SPACE <= MAX);
+    assert!(8 + PlayerProfile::INIT_SPACE <= MAX);
+    assert!(8 + PayoutSplit::INIT_SPACE <= MAX);
+    assert!(8 + GamingLimits::INIT_SPACE <= MAX);
+    assert!(8 + BonusCredit::INIT_SPACE <= MAX);
+    assert!(8 + PromoCampaign::INIT_SPACE <= MAX);
+    assert!(8 + PromoClaim::INIT_SPACE <= MAX);
+    assert!(8 + Membership::INIT_SPACE <= MAX);
+    assert!(8 + FrozenPlayer::INIT_SPACE <= MAX);
+    assert!(8 + WinVesting::INIT_SPACE <= MAX);
+    assert!(8 + PendingPayout::INIT_SPACE <= MAX);
+    assert!(8 + Dispute::INIT_SPACE <= MAX);
+    assert!(8 + ReserveSnapshot::INIT_SPACE <= MAX);
+    assert!(8 + VolumeBucket::INIT_SPACE <= MAX);
+};
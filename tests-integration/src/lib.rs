@@ -0,0 +1,1235 @@
+//! solana-program-test harness for the progressive jackpot program
+//!
+//! Loads the compiled program from `target/deploy` (run `anchor build` first) and
+//! exposes helpers for building instructions, fetching accounts and asserting errors.
+//! Settlement takes the VRF result as an argument, so tests act as a deterministic
+//! oracle stub via [`vrf_with_roll`].
+
+use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorDeserialize, Discriminator, InstructionData, ToAccountMetas};
+use base64::{prelude::BASE64_STANDARD, Engine};
+use progressive_jackpot::error::CasinoError;
+use progressive_jackpot::state::*;
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    clock::Clock,
+    instruction::{AccountMeta, Instruction, InstructionError},
+    program_pack::Pack,
+    pubkey::Pubkey,
+    rent::Rent,
+    signature::{Keypair, Signer},
+    system_instruction, system_program, sysvar,
+    transaction::{Transaction, TransactionError},
+};
+
+pub use anchor_spl::token::spl_token;
+pub use progressive_jackpot::{accounts, instruction};
+
+pub const LAMPORTS_PER_SOL: u64 = 1_000_000_000;
+
+/// Derive a program PDA
+pub fn pda(seeds: &[&[u8]]) -> Pubkey {
+    Pubkey::find_program_address(seeds, &progressive_jackpot::ID).0
+}
+
+pub fn config_pda() -> Pubkey {
+    pda(&[b"config"])
+}
+
+/// The pool created by `initialize`
+pub fn pool_pda() -> Pubkey {
+    pool_pda_with_id(DEFAULT_POOL_ID)
+}
+
+pub fn pool_pda_with_id(pool_id: u64) -> Pubkey {
+    pda(&[b"pool", &pool_id.to_le_bytes()])
+}
+
+pub fn reward_vault_pda() -> Pubkey {
+    pda(&[b"reward_vault"])
+}
+
+pub fn house_vault_pda() -> Pubkey {
+    pda(&[b"house_vault"])
+}
+
+pub fn reserve_pool_pda() -> Pubkey {
+    pda(&[b"reserve_pool"])
+}
+
+pub fn game_vault_pda() -> Pubkey {
+    pda(&[b"game_vault"])
+}
+
+pub fn win_history_pda() -> Pubkey {
+    pda(&[b"win_history"])
+}
+
+pub fn notification_queue_pda() -> Pubkey {
+    pda(&[b"notification_queue"])
+}
+
+pub fn bet_pda(player: &Pubkey, amount: u64) -> Pubkey {
+    pda(&[b"bet", player.as_ref(), &amount.to_le_bytes()])
+}
+
+pub fn vrf_request_pda(bet: &Pubkey) -> Pubkey {
+    pda(&[b"vrf_request", bet.as_ref()])
+}
+
+pub fn player_profile_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"player", player.as_ref()])
+}
+
+pub fn dice_roll_pda(player: &Pubkey, amount: u64) -> Pubkey {
+    pda(&[b"dice", player.as_ref(), &amount.to_le_bytes()])
+}
+
+pub fn paytable_pda() -> Pubkey {
+    pda(&[b"paytable"])
+}
+
+pub fn slot_spin_pda(player: &Pubkey, amount: u64) -> Pubkey {
+    pda(&[b"slots", player.as_ref(), &amount.to_le_bytes()])
+}
+
+pub fn slots_win_pda(slot_spin: &Pubkey, created_at: i64) -> Pubkey {
+    pda(&[b"slots_win", slot_spin.as_ref(), &created_at.to_le_bytes()])
+}
+
+pub fn scratch_batch_pda(batch_id: u64) -> Pubkey {
+    pda(&[b"scratch_batch", &batch_id.to_le_bytes()])
+}
+
+pub fn scratch_card_pda(batch: &Pubkey, index: u32) -> Pubkey {
+    pda(&[b"scratch_card", batch.as_ref(), &index.to_le_bytes()])
+}
+
+pub fn lotto_round_pda(round_id: u64) -> Pubkey {
+    pda(&[b"lotto", &round_id.to_le_bytes()])
+}
+
+pub fn lotto_entry_pda(round: &Pubkey, index: u32) -> Pubkey {
+    pda(&[b"lotto_entry", round.as_ref(), &index.to_le_bytes()])
+}
+
+pub fn bonus_wheel_pda() -> Pubkey {
+    pda(&[b"bonus_wheel"])
+}
+
+pub fn wheel_spin_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"wheel_spin", player.as_ref()])
+}
+
+pub fn mystery_jackpot_pda(pool: &Pubkey, round_id: u64) -> Pubkey {
+    pda(&[b"mystery", pool.as_ref(), &round_id.to_le_bytes()])
+}
+
+pub fn mystery_win_pda(round: &Pubkey, started_at: i64) -> Pubkey {
+    pda(&[b"mystery_win", round.as_ref(), &started_at.to_le_bytes()])
+}
+
+pub fn side_bet_pool_pda(pool: &Pubkey, round_id: u64) -> Pubkey {
+    pda(&[b"side_bet_pool", pool.as_ref(), &round_id.to_le_bytes()])
+}
+
+pub fn side_bet_entry_pda(side_bet_pool: &Pubkey, player: &Pubkey) -> Pubkey {
+    pda(&[b"side_bet", side_bet_pool.as_ref(), player.as_ref()])
+}
+
+pub fn parlay_pda(player: &Pubkey, stake: u64) -> Pubkey {
+    pda(&[b"parlay", player.as_ref(), &stake.to_le_bytes()])
+}
+
+pub fn payout_split_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"payout_split", player.as_ref()])
+}
+
+pub fn reward_claim_pda(user: &Pubkey) -> Pubkey {
+    pda(&[b"reward_claim", user.as_ref()])
+}
+
+pub fn lp_mint_pda() -> Pubkey {
+    pda(&[b"lp_mint"])
+}
+
+pub fn stake_position_pda(user: &Pubkey) -> Pubkey {
+    pda(&[b"stake", user.as_ref()])
+}
+
+pub fn vesting_pda(bet: &Pubkey) -> Pubkey {
+    pda(&[b"vesting", bet.as_ref()])
+}
+
+pub fn pending_payout_pda(bet: &Pubkey) -> Pubkey {
+    pda(&[b"payout", bet.as_ref()])
+}
+
+pub fn dispute_pda(bet: &Pubkey) -> Pubkey {
+    pda(&[b"dispute", bet.as_ref()])
+}
+
+pub fn volume_bucket_pda(day: i64) -> Pubkey {
+    pda(&[b"volume", &day.to_le_bytes()])
+}
+
+pub fn stats_pda() -> Pubkey {
+    pda(&[b"stats"])
+}
+
+pub fn reserve_snapshot_pda(pool: &Pubkey) -> Pubkey {
+    pda(&[b"reserves", pool.as_ref()])
+}
+
+pub fn charity_vault_pda() -> Pubkey {
+    pda(&[b"charity_vault"])
+}
+
+pub fn integrator_pda(partner: &Pubkey) -> Pubkey {
+    pda(&[b"integrator", partner.as_ref()])
+}
+
+pub fn gaming_limits_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"limits", player.as_ref()])
+}
+
+pub fn frozen_player_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"frozen", player.as_ref()])
+}
+
+pub fn membership_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"member", player.as_ref()])
+}
+
+pub fn draw_round_pda(round_id: u64) -> Pubkey {
+    pda(&[b"draw", &round_id.to_le_bytes()])
+}
+
+pub fn draw_ticket_mint_pda(draw_round: &Pubkey, entry_index: u32) -> Pubkey {
+    pda(&[b"ticket", draw_round.as_ref(), &entry_index.to_le_bytes()])
+}
+
+pub fn draw_ticket_account_pda(mint: &Pubkey) -> Pubkey {
+    pda(&[b"ticket_account", mint.as_ref()])
+}
+
+pub fn season_pda(season_id: u64) -> Pubkey {
+    pda(&[b"season", &season_id.to_le_bytes()])
+}
+
+pub fn bonus_credit_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"bonus", player.as_ref()])
+}
+
+pub fn promo_campaign_pda(campaign_id: u64) -> Pubkey {
+    pda(&[b"promo", &campaign_id.to_le_bytes()])
+}
+
+pub fn promo_claim_pda(campaign: &Pubkey, player: &Pubkey) -> Pubkey {
+    pda(&[b"promo_claim", campaign.as_ref(), player.as_ref()])
+}
+
+pub fn bankroll_pda() -> Pubkey {
+    pda(&[b"bankroll"])
+}
+
+pub fn keeper_pda(authority: &Pubkey) -> Pubkey {
+    pda(&[b"keeper", authority.as_ref()])
+}
+
+pub fn referral_pda(player: &Pubkey) -> Pubkey {
+    pda(&[b"referral", player.as_ref()])
+}
+
+pub fn referral_earnings_pda(referrer: &Pubkey) -> Pubkey {
+    pda(&[b"referral_earnings", referrer.as_ref()])
+}
+
+/// VRF result whose derived roll (first 8 bytes LE, mod 10000) equals `roll`
+pub fn vrf_with_roll(roll: u64) -> [u8; 32] {
+    let mut result = [0u8; 32];
+    result[..8].copy_from_slice(&roll.to_le_bytes());
+    result
+}
+
+/// Build a program instruction from an Anchor accounts struct and instruction data
+pub fn ix(accounts: impl ToAccountMetas, data: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: progressive_jackpot::ID,
+        accounts: accounts.to_account_metas(None),
+        data: data.data(),
+    }
+}
+
+/// Custom error code carried by a failed transaction, if any
+pub fn error_code(err: BanksClientError) -> Option<u32> {
+    let tx_err = match err {
+        BanksClientError::TransactionError(e) => e,
+        BanksClientError::SimulationError { err, .. } => err,
+        _ => return None,
+    };
+
+    match tx_err {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(code),
+        _ => None,
+    }
+}
+
+/// Assert that a transaction failed with the given program error
+pub fn assert_casino_error<T: std::fmt::Debug>(result: Result<T, BanksClientError>, expected: CasinoError) {
+    let err = result.expect_err("transaction should have failed");
+    assert_eq!(error_code(err), Some(u32::from(expected)));
+}
+
+/// Arguments for `initialize`
+pub struct InitParams {
+    pub jackpot_percentage: u16,
+    pub house_percentage: u16,
+    pub defi_percentage: u16,
+    pub min_bet: u64,
+    pub max_bet: u64,
+    pub win_probability_bps: u16,
+    pub reset_threshold: u64,
+    pub milestone_bets: u64,
+}
+
+impl Default for InitParams {
+    fn default() -> Self {
+        Self {
+            jackpot_percentage: 500,
+            house_percentage: 200,
+            defi_percentage: 100,
+            min_bet: LAMPORTS_PER_SOL / 10,
+            max_bet: 10 * LAMPORTS_PER_SOL,
+            win_probability_bps: 100,
+            reset_threshold: 100 * LAMPORTS_PER_SOL,
+            milestone_bets: 0,
+        }
+    }
+}
+
+/// Pool and optional accounts a bet is routed through
+pub struct BetRouting {
+    pub pool: Pubkey,
+    pub integrator: Option<Pubkey>,
+    /// Referrer whose ReferralEarnings PDA is credited
+    pub referrer: Option<Pubkey>,
+    pub draw_round: Option<Pubkey>,
+    pub season: Option<Pubkey>,
+    /// Pay with the player's BonusCredit instead of their wallet
+    pub use_bonus: bool,
+    pub volume_bucket: Option<Pubkey>,
+    /// Charity round-up in basis points, paid to the CharityVault PDA
+    pub charity_bps: Option<u16>,
+}
+
+impl Default for BetRouting {
+    fn default() -> Self {
+        Self {
+            pool: pool_pda(),
+            integrator: None,
+            referrer: None,
+            draw_round: None,
+            season: None,
+            use_bonus: false,
+            volume_bucket: None,
+            charity_bps: None,
+        }
+    }
+}
+
+pub struct Harness {
+    pub ctx: ProgramTestContext,
+}
+
+impl Harness {
+    /// Start a validator with the program loaded but nothing initialized
+    pub async fn start() -> Self {
+        // Workspace builds land in <root>/target/deploy
+        if std::env::var("SBF_OUT_DIR").is_err() {
+            std::env::set_var(
+                "SBF_OUT_DIR",
+                concat!(env!("CARGO_MANIFEST_DIR"), "/../target/deploy"),
+            );
+        }
+
+        let mut program_test = ProgramTest::new("progressive_jackpot", progressive_jackpot::ID, None);
+        program_test.prefer_bpf(true);
+
+        Self {
+            ctx: program_test.start_with_context().await,
+        }
+    }
+
+    /// Start with the default config and every account settlement depends on
+    pub async fn new() -> Self {
+        Self::with_params(InitParams::default()).await
+    }
+
+    /// Start with custom `initialize` arguments
+    pub async fn with_params(params: InitParams) -> Self {
+        let mut harness = Self::start().await;
+        harness.initialize(params).await.expect("initialize");
+        harness.initialize_settlement_accounts().await;
+        harness
+    }
+
+    /// Config authority (the test payer)
+    pub fn authority(&self) -> Keypair {
+        Keypair::from_bytes(&self.ctx.payer.to_bytes()).unwrap()
+    }
+
+    /// Sign with the payer plus `signers` and process
+    pub async fn send(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Result<(), BanksClientError> {
+        let blockhash = self.ctx.get_new_latest_blockhash().await?;
+        let payer = &self.ctx.payer;
+
+        let mut all_signers: Vec<&Keypair> = vec![payer];
+        all_signers.extend(signers.iter().filter(|k| k.pubkey() != payer.pubkey()));
+
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all_signers, blockhash);
+        self.ctx.banks_client.process_transaction(tx).await
+    }
+
+    /// Like `send`, but returns the program's return data (empty if none was set)
+    pub async fn send_for_return_data(&mut self, ixs: &[Instruction], signers: &[&Keypair]) -> Result<Vec<u8>, BanksClientError> {
+        let blockhash = self.ctx.get_new_latest_blockhash().await?;
+        let payer = &self.ctx.payer;
+
+        let mut all_signers: Vec<&Keypair> = vec![payer];
+        all_signers.extend(signers.iter().filter(|k| k.pubkey() != payer.pubkey()));
+
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all_signers, blockhash);
+        let outcome = self.ctx.banks_client.process_transaction_with_metadata(tx).await?;
+        outcome.result.map_err(BanksClientError::TransactionError)?;
+        Ok(outcome
+            .metadata
+            .and_then(|metadata| metadata.return_data)
+            .map(|return_data| return_data.data)
+            .unwrap_or_default())
+    }
+
+    /// Like `send`, but returns the first `E` event logged by the transaction
+    pub async fn send_for_event<E: Discriminator + AnchorDeserialize>(
+        &mut self,
+        ixs: &[Instruction],
+        signers: &[&Keypair],
+    ) -> Result<Option<E>, BanksClientError> {
+        let blockhash = self.ctx.get_new_latest_blockhash().await?;
+        let payer = &self.ctx.payer;
+
+        let mut all_signers: Vec<&Keypair> = vec![payer];
+        all_signers.extend(signers.iter().filter(|k| k.pubkey() != payer.pubkey()));
+
+        let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &all_signers, blockhash);
+        let outcome = self.ctx.banks_client.process_transaction_with_metadata(tx).await?;
+        outcome.result.map_err(BanksClientError::TransactionError)?;
+        let logs = outcome.metadata.map(|metadata| metadata.log_messages).unwrap_or_default();
+        Ok(logs.iter().find_map(|log| {
+            let data = BASE64_STANDARD.decode(log.strip_prefix("Program data: ")?).ok()?;
+            let body = data.strip_prefix(E::DISCRIMINATOR.as_slice())?;
+            E::deserialize(&mut &body[..]).ok()
+        }))
+    }
+
+    pub async fn initialize(&mut self, params: InitParams) -> Result<(), BanksClientError> {
+        let authority = self.ctx.payer.pubkey();
+        let ix = ix(
+            accounts::Initialize {
+                config: config_pda(),
+                pool: pool_pda(),
+                reward_vault: reward_vault_pda(),
+                house_vault: house_vault_pda(),
+                authority,
+                system_program: system_program::ID,
+            },
+            instruction::Initialize {
+                jackpot_percentage: params.jackpot_percentage,
+                house_percentage: params.house_percentage,
+                defi_percentage: params.defi_percentage,
+                min_bet: params.min_bet,
+                max_bet: params.max_bet,
+                win_probability_bps: params.win_probability_bps,
+                vrf_provider: 0,
+                orao_network: None,
+                switchboard_queue: None,
+                reset_threshold: params.reset_threshold,
+                milestone_bets: params.milestone_bets,
+            },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Create the win history, notification queue, reserve pool and game vault
+    pub async fn initialize_settlement_accounts(&mut self) {
+        let authority = self.ctx.payer.pubkey();
+        let ixs = [
+            ix(
+                accounts::InitializeWinHistory {
+                    config: config_pda(),
+                    win_history: win_history_pda(),
+                    authority,
+                    system_program: system_program::ID,
+                },
+                instruction::InitializeWinHistory {},
+            ),
+            ix(
+                accounts::InitializeNotificationQueue {
+                    config: config_pda(),
+                    notification_queue: notification_queue_pda(),
+                    authority,
+                    system_program: system_program::ID,
+                },
+                instruction::InitializeNotificationQueue {},
+            ),
+            ix(
+                accounts::InitializeReservePool {
+                    config: config_pda(),
+                    reserve_pool: reserve_pool_pda(),
+                    authority,
+                    system_program: system_program::ID,
+                },
+                instruction::InitializeReservePool {},
+            ),
+            ix(
+                accounts::InitializeGameVault {
+                    config: config_pda(),
+                    game_vault: game_vault_pda(),
+                    authority,
+                    system_program: system_program::ID,
+                },
+                instruction::InitializeGameVault {},
+            ),
+        ];
+        self.send(&ixs, &[]).await.expect("initialize settlement accounts");
+    }
+
+    /// Transfer lamports from the payer
+    pub async fn fund(&mut self, to: &Pubkey, lamports: u64) {
+        let ix = system_instruction::transfer(&self.ctx.payer.pubkey(), to, lamports);
+        self.send(&[ix], &[]).await.expect("fund");
+    }
+
+    /// New keypair funded with `lamports`
+    pub async fn player(&mut self, lamports: u64) -> Keypair {
+        let player = Keypair::new();
+        self.fund(&player.pubkey(), lamports).await;
+        player
+    }
+
+    /// New SPL mint with 9 decimals and the given mint authority
+    pub async fn create_mint(&mut self, mint_authority: &Pubkey) -> Pubkey {
+        let mint = Keypair::new();
+        let ixs = [
+            system_instruction::create_account(
+                &self.ctx.payer.pubkey(),
+                &mint.pubkey(),
+                Rent::default().minimum_balance(spl_token::state::Mint::LEN),
+                spl_token::state::Mint::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_mint2(&spl_token::ID, &mint.pubkey(), mint_authority, None, 9)
+                .expect("initialize_mint2"),
+        ];
+        self.send(&ixs, &[&mint]).await.expect("create mint");
+        mint.pubkey()
+    }
+
+    /// Credit the reward vault as if a strategy withdrawal had earned `amount`, without distributing it
+    pub async fn credit_strategy_yield(&mut self, amount: u64) {
+        let mut account = self.ctx.banks_client.get_account(reward_vault_pda()).await.unwrap().expect("reward vault");
+        let mut vault = RewardVault::try_deserialize(&mut &account.data[..]).expect("reward vault");
+        vault.strategy_yield += amount;
+        vault.try_serialize(&mut &mut account.data[..]).expect("serialize reward vault");
+        account.lamports += amount;
+        self.ctx.set_account(&reward_vault_pda(), &AccountSharedData::from(account));
+    }
+
+    /// Credit the reward vault as if a strategy withdrawal had earned `amount`, then distribute it
+    pub async fn realize_strategy_yield(&mut self, amount: u64) -> Result<(), BanksClientError> {
+        self.credit_strategy_yield(amount).await;
+
+        let keeper = self.ctx.payer.pubkey();
+        let ix = ix(
+            accounts::UpdateRealizedYield {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                keeper,
+            },
+            instruction::UpdateRealizedYield {},
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Install the wrapped SOL mint, which program-test genesis doesn't always include
+    pub fn add_native_mint(&mut self) {
+        let mut data = vec![0; spl_token::state::Mint::LEN];
+        let mint = spl_token::state::Mint { decimals: 9, is_initialized: true, ..Default::default() };
+        mint.pack_into_slice(&mut data);
+        let account = Account {
+            lamports: Rent::default().minimum_balance(data.len()),
+            data,
+            owner: spl_token::ID,
+            executable: false,
+            rent_epoch: 0,
+        };
+        self.ctx.set_account(&spl_token::native_mint::ID, &AccountSharedData::from(account));
+    }
+
+    /// New token account for `mint` owned by `owner`
+    pub async fn create_token_account(&mut self, mint: &Pubkey, owner: &Pubkey) -> Pubkey {
+        let account = Keypair::new();
+        let ixs = [
+            system_instruction::create_account(
+                &self.ctx.payer.pubkey(),
+                &account.pubkey(),
+                Rent::default().minimum_balance(spl_token::state::Account::LEN),
+                spl_token::state::Account::LEN as u64,
+                &spl_token::ID,
+            ),
+            spl_token::instruction::initialize_account3(&spl_token::ID, &account.pubkey(), mint, owner)
+                .expect("initialize_account3"),
+        ];
+        self.send(&ixs, &[&account]).await.expect("create token account");
+        account.pubkey()
+    }
+
+    pub async fn seed_jackpot(&mut self, amount: u64) -> Result<(), BanksClientError> {
+        let donor = self.ctx.payer.pubkey();
+        let ix = ix(
+            accounts::SeedJackpot {
+                config: config_pda(),
+                pool: pool_pda(),
+                donor,
+                system_program: system_program::ID,
+            },
+            instruction::SeedJackpot { amount },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Place a bet and return its PDA
+    pub async fn contribute_bet(&mut self, player: &Keypair, amount: u64) -> Result<Pubkey, BanksClientError> {
+        self.contribute_bet_via(player, amount, None, None).await
+    }
+
+    /// Place a bet routed through an integrator and/or crediting the player's referrer
+    pub async fn contribute_bet_via(
+        &mut self,
+        player: &Keypair,
+        amount: u64,
+        integrator: Option<Pubkey>,
+        referrer: Option<Pubkey>,
+    ) -> Result<Pubkey, BanksClientError> {
+        self.contribute_bet_with(player, amount, BetRouting { integrator, referrer, ..BetRouting::default() }).await
+    }
+
+    /// Place a bet in a pool opened with `create_pool`
+    pub async fn contribute_bet_in_pool(
+        &mut self,
+        player: &Keypair,
+        pool_id: u64,
+        amount: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        let pool = pool_pda_with_id(pool_id);
+        self.contribute_bet_with(player, amount, BetRouting { pool, ..BetRouting::default() }).await
+    }
+
+    /// Place a bet that enters the given draw round
+    pub async fn contribute_bet_in_draw(
+        &mut self,
+        player: &Keypair,
+        round_id: u64,
+        amount: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        let draw_round = Some(draw_round_pda(round_id));
+        self.contribute_bet_with(player, amount, BetRouting { draw_round, ..BetRouting::default() }).await
+    }
+
+    /// Place a bet with explicit pool and optional accounts
+    pub async fn contribute_bet_with(
+        &mut self,
+        player: &Keypair,
+        amount: u64,
+        routing: BetRouting,
+    ) -> Result<Pubkey, BanksClientError> {
+        let ix = self.contribute_bet_ix(&player.pubkey(), amount, routing).await;
+        self.send(&[ix], &[player]).await?;
+        // Bets can't settle in the slot they were placed in
+        self.warp_slots(1).await;
+        Ok(bet_pda(&player.pubkey(), amount))
+    }
+
+    /// `contribute_bet` instruction with explicit pool and optional accounts
+    pub async fn contribute_bet_ix(&mut self, player: &Pubkey, amount: u64, routing: BetRouting) -> Instruction {
+        let bet = bet_pda(player, amount);
+        let bankroll = self.funded_bankroll().await;
+        let stats = self.tracked_stats().await;
+        ix(
+            accounts::ContributeBet {
+                config: config_pda(),
+                pool: routing.pool,
+                reward_vault: reward_vault_pda(),
+                bet,
+                vrf_request: vrf_request_pda(&bet),
+                player_profile: player_profile_pda(player),
+                reward_claim: reward_claim_pda(player),
+                house_vault: house_vault_pda(),
+                game_vault: game_vault_pda(),
+                gaming_limits: gaming_limits_pda(player),
+                frozen_player: frozen_player_pda(player),
+                membership: membership_pda(player),
+                integrator: routing.integrator,
+                referral: referral_pda(player),
+                referral_earnings: routing.referrer.map(|r| referral_earnings_pda(&r)),
+                draw_round: routing.draw_round,
+                season: routing.season,
+                bonus_credit: routing.use_bonus.then(|| bonus_credit_pda(player)),
+                bankroll,
+                stats,
+                charity_vault: routing.charity_bps.map(|_| charity_vault_pda()),
+                volume_bucket: routing.volume_bucket,
+                instructions_sysvar: sysvar::instructions::ID,
+                player: *player,
+                system_program: system_program::ID,
+            },
+            instruction::ContributeBet { amount, charity_bps: routing.charity_bps },
+        )
+    }
+
+    /// The Bankroll PDA, once `fund_bankroll` has created it
+    pub async fn funded_bankroll(&mut self) -> Option<Pubkey> {
+        let account = self.ctx.banks_client.get_account(bankroll_pda()).await.expect("get_account");
+        account.map(|_| bankroll_pda())
+    }
+
+    /// The Stats PDA, once `initialize_stats` has created it
+    pub async fn tracked_stats(&mut self) -> Option<Pubkey> {
+        let account = self.ctx.banks_client.get_account(stats_pda()).await.expect("get_account");
+        account.map(|_| stats_pda())
+    }
+
+    /// Writable split recipients a player's win claims must pass, in the split's order
+    pub async fn split_recipients(&mut self, player: &Pubkey) -> Vec<AccountMeta> {
+        let account = self.ctx.banks_client.get_account(payout_split_pda(player)).await.expect("get_account");
+        let Some(account) = account else {
+            return Vec::new();
+        };
+        let split = PayoutSplit::try_deserialize(&mut account.data.as_slice()).expect("deserialize");
+        split.active_shares().iter().map(|share| AccountMeta::new(share.recipient, false)).collect()
+    }
+
+    /// Settle a bet with the given VRF result
+    pub async fn fulfill(&mut self, bet: Pubkey, vrf_result: [u8; 32]) -> Result<(), BanksClientError> {
+        let ix = self.fulfill_ix(bet, vrf_result).await;
+        self.send(&[ix], &[]).await
+    }
+
+    /// `fulfill_jackpot` instruction for a bet, without a keeper
+    pub async fn fulfill_ix(&mut self, bet: Pubkey, vrf_result: [u8; 32]) -> Instruction {
+        let bet_account: Bet = self.account(bet).await;
+        let player = bet_account.player;
+        let bankroll = self.funded_bankroll().await;
+        let stats = self.tracked_stats().await;
+        let JackpotPool { side_bet_pool, .. } = self.account(bet_account.pool).await;
+        ix(
+            accounts::FulfillJackpot {
+                config: config_pda(),
+                pool: bet_account.pool,
+                reserve_pool: reserve_pool_pda(),
+                house_vault: house_vault_pda(),
+                bet,
+                vrf_request: vrf_request_pda(&bet),
+                player_profile: player_profile_pda(&player),
+                win_history: win_history_pda(),
+                notification_queue: notification_queue_pda(),
+                bankroll,
+                stats,
+                volume_bucket: None,
+                side_bet_pool,
+                keeper: None,
+                keeper_authority: None,
+                settler: self.authority().pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::FulfillJackpot { vrf_result },
+        )
+    }
+
+    /// Roll dice in the default pool
+    pub async fn play_dice(&mut self, player: &Keypair, amount: u64, target: u16, over_under: bool) -> Result<Pubkey, BanksClientError> {
+        let dice_roll = dice_roll_pda(&player.pubkey(), amount);
+        let ix = ix(
+            accounts::PlayDice {
+                config: config_pda(),
+                pool: pool_pda(),
+                reward_vault: reward_vault_pda(),
+                reward_claim: reward_claim_pda(&player.pubkey()),
+                dice_roll,
+                vrf_request: vrf_request_pda(&dice_roll),
+                house_vault: house_vault_pda(),
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                membership: membership_pda(&player.pubkey()),
+                gaming_limits: gaming_limits_pda(&player.pubkey()),
+                player_profile: player_profile_pda(&player.pubkey()),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::PlayDice { amount, target, over_under },
+        );
+        self.send(&[ix], &[player]).await?;
+        // Rolls can't settle in the slot they were placed in
+        self.warp_slots(1).await;
+        Ok(dice_roll)
+    }
+
+    /// Settle a dice roll as the authority
+    pub async fn fulfill_dice(&mut self, dice_roll: Pubkey, vrf_result: [u8; 32]) -> Result<(), BanksClientError> {
+        let DiceRoll { player, .. } = self.account(dice_roll).await;
+        let ix = ix(
+            accounts::FulfillDice {
+                config: config_pda(),
+                house_vault: house_vault_pda(),
+                dice_roll,
+                vrf_request: vrf_request_pda(&dice_roll),
+                player,
+                settler: self.authority().pubkey(),
+            },
+            instruction::FulfillDice { vrf_result },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Replace the slots paytable
+    pub async fn set_paytable(&mut self, entries: Vec<PaytableEntry>) -> Result<(), BanksClientError> {
+        let authority = self.authority();
+        let ix = ix(
+            accounts::SetPaytable {
+                config: config_pda(),
+                paytable: paytable_pda(),
+                authority: authority.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::SetPaytable { entries },
+        );
+        self.send(&[ix], &[&authority]).await
+    }
+
+    /// Spin the slots in the default pool
+    pub async fn play_slots(&mut self, player: &Keypair, amount: u64) -> Result<Pubkey, BanksClientError> {
+        let slot_spin = slot_spin_pda(&player.pubkey(), amount);
+        let ix = ix(
+            accounts::PlaySlots {
+                config: config_pda(),
+                pool: pool_pda(),
+                reward_vault: reward_vault_pda(),
+                reward_claim: reward_claim_pda(&player.pubkey()),
+                paytable: paytable_pda(),
+                slot_spin,
+                vrf_request: vrf_request_pda(&slot_spin),
+                house_vault: house_vault_pda(),
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                membership: membership_pda(&player.pubkey()),
+                gaming_limits: gaming_limits_pda(&player.pubkey()),
+                player_profile: player_profile_pda(&player.pubkey()),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::PlaySlots { amount },
+        );
+        self.send(&[ix], &[player]).await?;
+        // Spins can't settle in the slot they were placed in
+        self.warp_slots(1).await;
+        Ok(slot_spin)
+    }
+
+    /// Settle a slots spin as the authority
+    pub async fn fulfill_slots(&mut self, slot_spin: Pubkey, vrf_result: [u8; 32]) -> Result<(), BanksClientError> {
+        let SlotSpin { player, pool, created_at, .. } = self.account(slot_spin).await;
+        let JackpotPool { side_bet_pool, .. } = self.account(pool).await;
+        let paytable: Paytable = self.account(paytable_pda()).await;
+        let jackpot = paytable.entries[paytable.outcome(&vrf_result)].jackpot;
+        let ix = ix(
+            accounts::FulfillSlots {
+                config: config_pda(),
+                pool,
+                paytable: paytable_pda(),
+                house_vault: house_vault_pda(),
+                slot_spin,
+                vrf_request: vrf_request_pda(&slot_spin),
+                side_bet_pool,
+                player,
+                jackpot_bet: jackpot.then(|| slots_win_pda(&slot_spin, created_at)),
+                settler: self.authority().pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::FulfillSlots { vrf_result },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Open a scratch-card batch as the authority
+    pub async fn open_scratch_batch(
+        &mut self,
+        batch_id: u64,
+        merkle_root: [u8; 32],
+        card_count: u32,
+        card_price: u64,
+        total_prizes: u64,
+    ) -> Result<Pubkey, BanksClientError> {
+        let authority = self.authority();
+        let batch = scratch_batch_pda(batch_id);
+        let ix = ix(
+            accounts::OpenScratchBatch {
+                config: config_pda(),
+                batch,
+                house_vault: house_vault_pda(),
+                signer: authority.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::OpenScratchBatch { batch_id, merkle_root, card_count, card_price, total_prizes },
+        );
+        self.send(&[ix], &[&authority]).await?;
+        Ok(batch)
+    }
+
+    /// Buy the next card in a scratch batch
+    pub async fn buy_scratch_card(&mut self, player: &Keypair, batch: Pubkey) -> Result<Pubkey, BanksClientError> {
+        let ScratchBatch { cards_sold, .. } = self.account(batch).await;
+        let card = scratch_card_pda(&batch, cards_sold);
+        let ix = ix(
+            accounts::BuyScratchCard {
+                config: config_pda(),
+                batch,
+                card,
+                house_vault: house_vault_pda(),
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                membership: membership_pda(&player.pubkey()),
+                gaming_limits: gaming_limits_pda(&player.pubkey()),
+                player_profile: player_profile_pda(&player.pubkey()),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::BuyScratchCard {},
+        );
+        self.send(&[ix], &[player]).await?;
+        Ok(card)
+    }
+
+    /// Reveal a scratch card with its outcome and Merkle proof
+    pub async fn reveal_card(
+        &mut self,
+        batch: Pubkey,
+        index: u32,
+        outcome: ScratchOutcome,
+        proof: Vec<[u8; 32]>,
+    ) -> Result<(), BanksClientError> {
+        let card = scratch_card_pda(&batch, index);
+        let ScratchCard { player, .. } = self.account(card).await;
+        let ix = ix(
+            accounts::RevealCard {
+                config: config_pda(),
+                batch,
+                card,
+                house_vault: house_vault_pda(),
+                player,
+            },
+            instruction::RevealCard { index, outcome, proof },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Close a scratch batch as the authority
+    pub async fn close_scratch_batch(&mut self, batch: Pubkey) -> Result<(), BanksClientError> {
+        let authority = self.authority();
+        let ScratchBatch { opened_by, .. } = self.account(batch).await;
+        let ix = ix(
+            accounts::CloseScratchBatch {
+                config: config_pda(),
+                batch,
+                house_vault: house_vault_pda(),
+                opened_by,
+                signer: authority.pubkey(),
+            },
+            instruction::CloseScratchBatch {},
+        );
+        self.send(&[ix], &[&authority]).await
+    }
+
+    /// Buy a lottery ticket with the given numbers
+    pub async fn buy_lotto_ticket(&mut self, player: &Keypair, round: Pubkey, numbers: Vec<u8>) -> Result<Pubkey, BanksClientError> {
+        let LottoRound { ticket_count, .. } = self.account(round).await;
+        let entry = lotto_entry_pda(&round, ticket_count);
+        let ix = ix(
+            accounts::BuyLottoTicket {
+                config: config_pda(),
+                round,
+                entry,
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                membership: membership_pda(&player.pubkey()),
+                gaming_limits: gaming_limits_pda(&player.pubkey()),
+                player_profile: player_profile_pda(&player.pubkey()),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::BuyLottoTicket { numbers },
+        );
+        self.send(&[ix], &[player]).await?;
+        Ok(entry)
+    }
+
+    /// Draw a lottery round as the authority
+    pub async fn draw_lotto(&mut self, round: Pubkey, vrf_result: [u8; 32]) -> Result<(), BanksClientError> {
+        let authority = self.authority();
+        let ix = ix(
+            accounts::DrawLotto { config: config_pda(), round, settler: authority.pubkey() },
+            instruction::DrawLotto { vrf_result },
+        );
+        self.send(&[ix], &[&authority]).await
+    }
+
+    /// Crank a batch of lottery tickets, without a keeper
+    pub async fn crank_lotto(&mut self, round: Pubkey, entries: &[Pubkey]) -> Result<(), BanksClientError> {
+        let mut ix = ix(
+            accounts::CrankLotto {
+                config: config_pda(),
+                round,
+                house_vault: house_vault_pda(),
+                keeper: None,
+                keeper_authority: None,
+            },
+            instruction::CrankLotto {},
+        );
+        for &entry in entries {
+            let LottoEntry { player, .. } = self.account(entry).await;
+            ix.accounts.push(AccountMeta::new(entry, false));
+            ix.accounts.push(AccountMeta::new(player, false));
+        }
+        self.send(&[ix], &[]).await
+    }
+
+    /// Replace the bonus wheel's prize table
+    pub async fn set_bonus_wheel(&mut self, prizes: Vec<WheelPrize>) -> Result<(), BanksClientError> {
+        let authority = self.authority();
+        let ix = ix(
+            accounts::SetBonusWheel {
+                config: config_pda(),
+                bonus_wheel: bonus_wheel_pda(),
+                authority: authority.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::SetBonusWheel { prizes },
+        );
+        self.send(&[ix], &[&authority]).await
+    }
+
+    /// Spend one of the player's bonus wheel spins
+    pub async fn spin_bonus_wheel(&mut self, player: &Keypair) -> Result<Pubkey, BanksClientError> {
+        let wheel_spin = wheel_spin_pda(&player.pubkey());
+        let ix = ix(
+            accounts::SpinBonusWheel {
+                config: config_pda(),
+                bonus_wheel: bonus_wheel_pda(),
+                player_profile: player_profile_pda(&player.pubkey()),
+                wheel_spin,
+                vrf_request: vrf_request_pda(&wheel_spin),
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::SpinBonusWheel {},
+        );
+        self.send(&[ix], &[player]).await?;
+        // Spins can't settle in the slot they were made in
+        self.warp_slots(1).await;
+        Ok(wheel_spin)
+    }
+
+    /// Settle a bonus wheel spin as the authority
+    pub async fn fulfill_bonus_wheel(&mut self, wheel_spin: Pubkey, vrf_result: [u8; 32]) -> Result<(), BanksClientError> {
+        let WheelSpin { player, .. } = self.account(wheel_spin).await;
+        let ix = ix(
+            accounts::FulfillBonusWheel {
+                config: config_pda(),
+                bonus_wheel: bonus_wheel_pda(),
+                house_vault: house_vault_pda(),
+                player_profile: player_profile_pda(&player),
+                wheel_spin,
+                vrf_request: vrf_request_pda(&wheel_spin),
+                player,
+                settler: self.authority().pubkey(),
+            },
+            instruction::FulfillBonusWheel { vrf_result },
+        );
+        self.send(&[ix], &[]).await
+    }
+
+    /// Commit a mystery jackpot on the default pool as the authority
+    pub async fn commit_mystery_jackpot(&mut self, round_id: u64, commitment: [u8; 32]) -> Result<Pubkey, BanksClientError> {
+        let authority = self.authority();
+        let round = mystery_jackpot_pda(&pool_pda(), round_id);
+        let ix = ix(
+            accounts::CommitMysteryJackpot {
+                config: config_pda(),
+                pool: pool_pda(),
+                round,
+                signer: authority.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::CommitMysteryJackpot { round_id, commitment },
+        );
+        self.send(&[ix], &[&authority]).await?;
+        Ok(round)
+    }
+
+    /// Reveal a mystery jackpot against the bet claimed to have triggered it
+    pub async fn reveal_mystery(&mut self, round: Pubkey, bet: Pubkey, threshold: u64, salt: [u8; 32]) -> Result<(), BanksClientError> {
+        let MysteryJackpot { pool, committed_by, started_at, .. } = self.account(round).await;
+        let JackpotPool { side_bet_pool, .. } = self.account(pool).await;
+        let revealer = self.authority();
+        let ix = ix(
+            accounts::RevealMystery {
+                config: config_pda(),
+                pool,
+                round,
+                bet,
+                house_vault: house_vault_pda(),
+                side_bet_pool,
+                mystery_win: mystery_win_pda(&round, started_at),
+                committed_by,
+                revealer: revealer.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::RevealMystery { threshold, salt },
+        );
+        self.send(&[ix], &[&revealer]).await
+    }
+
+    /// Replace the happy-hour windows as the authority
+    pub async fn set_happy_hours(&mut self, windows: Vec<HappyHour>) -> Result<(), BanksClientError> {
+        let authority = self.authority();
+        let ix = ix(
+            accounts::SetHappyHours {
+                config: config_pda(),
+                authority: authority.pubkey(),
+            },
+            instruction::SetHappyHours { windows },
+        );
+        self.send(&[ix], &[&authority]).await
+    }
+
+    /// Place a parlay with the given leg win chances (basis points)
+    pub async fn create_parlay(&mut self, player: &Keypair, stake: u64, legs: Vec<u16>) -> Result<Pubkey, BanksClientError> {
+        let parlay = parlay_pda(&player.pubkey(), stake);
+        let ix = ix(
+            accounts::CreateParlay {
+                config: config_pda(),
+                parlay,
+                vrf_request: vrf_request_pda(&parlay),
+                house_vault: house_vault_pda(),
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                membership: membership_pda(&player.pubkey()),
+                gaming_limits: gaming_limits_pda(&player.pubkey()),
+                player_profile: player_profile_pda(&player.pubkey()),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::CreateParlay { stake, legs },
+        );
+        self.send(&[ix], &[player]).await?;
+        // Legs can't settle in the slot they were requested in
+        self.warp_slots(1).await;
+        Ok(parlay)
+    }
+
+    /// Settle a parlay's current leg as the authority
+    pub async fn fulfill_parlay_leg(&mut self, parlay: Pubkey, vrf_result: [u8; 32]) -> Result<(), BanksClientError> {
+        let Parlay { player, .. } = self.account(parlay).await;
+        let ix = ix(
+            accounts::FulfillParlayLeg {
+                config: config_pda(),
+                house_vault: house_vault_pda(),
+                parlay,
+                vrf_request: vrf_request_pda(&parlay),
+                player,
+                settler: self.authority().pubkey(),
+            },
+            instruction::FulfillParlayLeg { vrf_result },
+        );
+        self.send(&[ix], &[]).await?;
+        self.warp_slots(1).await;
+        Ok(())
+    }
+
+    pub async fn claim_win(&mut self, player: &Keypair, bet: Pubkey) -> Result<(), BanksClientError> {
+        let Bet { pool, bonus_funded, player: bettor, .. } = self.account::<Bet>(bet).await;
+        let PlayerProfile { payout_beneficiary, .. } = self.account(player_profile_pda(&bettor)).await;
+        let split_recipients = if bonus_funded { Vec::new() } else { self.split_recipients(&bettor).await };
+        let mut ix = ix(
+            accounts::ClaimWin {
+                config: config_pda(),
+                pool,
+                bet,
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                bonus_credit: bonus_funded.then(|| bonus_credit_pda(&player.pubkey())),
+                player_profile: player_profile_pda(&bettor),
+                beneficiary: payout_beneficiary,
+                payout_split: payout_split_pda(&bettor),
+                player: player.pubkey(),
+            },
+            instruction::ClaimWin {},
+        );
+        ix.accounts.extend(split_recipients);
+        self.send(&[ix], &[player]).await
+    }
+
+    pub async fn update_config(&mut self, signer: &Keypair, update: ConfigUpdate) -> Result<(), BanksClientError> {
+        let ix = ix(
+            accounts::UpdateConfig {
+                config: config_pda(),
+                pool: pool_pda(),
+                authority: signer.pubkey(),
+            },
+            instruction::UpdateConfig { update },
+        );
+        self.send(&[ix], &[signer]).await
+    }
+
+    /// Fetch and deserialize an Anchor account
+    pub async fn account<T: AccountDeserialize>(&mut self, address: Pubkey) -> T {
+        let account = self.ctx.banks_client
+            .get_account(address)
+            .await
+            .expect("get_account")
+            .expect("account exists");
+        T::try_deserialize(&mut account.data.as_slice()).expect("deserialize")
+    }
+
+    /// Fetch a zero-copy account
+    pub async fn zero_copy<T: bytemuck::Pod>(&mut self, address: Pubkey) -> T {
+        let account = self.ctx.banks_client
+            .get_account(address)
+            .await
+            .expect("get_account")
+            .expect("account exists");
+        bytemuck::pod_read_unaligned(&account.data[8..8 + std::mem::size_of::<T>()])
+    }
+
+    pub async fn balance(&mut self, address: Pubkey) -> u64 {
+        self.ctx.banks_client.get_balance(address).await.expect("get_balance")
+    }
+
+    /// Move the on-chain clock forward
+    pub async fn warp_forward(&mut self, seconds: i64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.expect("clock");
+        clock.unix_timestamp += seconds;
+        self.ctx.set_sysvar(&clock);
+    }
+
+    /// Move the on-chain slot forward
+    pub async fn warp_slots(&mut self, slots: u64) {
+        let mut clock: Clock = self.ctx.banks_client.get_sysvar().await.expect("clock");
+        clock.slot += slots;
+        self.ctx.set_sysvar(&clock);
+    }
+}
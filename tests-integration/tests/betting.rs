@@ -0,0 +1,1283 @@
+use anchor_spl::token::TokenAccount;
+use progressive_jackpot::error::CasinoError;
+use progressive_jackpot::state::*;
+use solana_sdk::{
+    account::AccountSharedData, clock::Clock, instruction::Instruction, pubkey::Pubkey,
+    signature::{Keypair, Signer}, system_program, sysvar,
+};
+use tests_integration::*;
+
+#[tokio::test]
+async fn records_bet_profile_and_fee_split() {
+    let mut h = Harness::new().await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let amount = LAMPORTS_PER_SOL;
+    let balance_before = h.balance(player.pubkey()).await;
+
+    let bet_address = h.contribute_bet(&player, amount).await.unwrap();
+
+    // The full wager leaves the player, plus rent for the new accounts
+    assert!(h.balance(player.pubkey()).await <= balance_before - amount);
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.player, player.pubkey());
+    assert_eq!(bet.amount, amount);
+    assert_eq!(bet.status, 0);
+
+    let config: Config = h.account(config_pda()).await;
+    assert_eq!(bet.config_hash, config.params_hash());
+    assert_eq!(config.total_bets, 1);
+
+    // 5% jackpot leg
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, amount * 500 / 10000);
+    assert_eq!(pool.bets_since_win, 1);
+
+    // Everything outside the 8% of fee legs is escrowed
+    let game_vault: GameVault = h.account(game_vault_pda()).await;
+    assert_eq!(game_vault.total_escrowed, amount * 9200 / 10000);
+
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!(profile.total_wagered, amount);
+    assert_eq!(profile.bets_placed, 1);
+
+    let vrf_request: VrfRequest = h.account(vrf_request_pda(&bet_address)).await;
+    assert_eq!(vrf_request.bet, bet_address);
+    assert_eq!(vrf_request.status, 0);
+}
+
+#[tokio::test]
+async fn enforces_bet_limits() {
+    let mut h = Harness::new().await;
+    let player = h.player(20 * LAMPORTS_PER_SOL).await;
+
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL / 100).await,
+        CasinoError::BetTooSmall,
+    );
+    assert_casino_error(
+        h.contribute_bet(&player, 11 * LAMPORTS_PER_SOL).await,
+        CasinoError::BetTooLarge,
+    );
+}
+
+#[tokio::test]
+async fn dynamic_max_bet_tracks_pool_balance() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(20 * LAMPORTS_PER_SOL).await;
+    h.seed_jackpot(10 * LAMPORTS_PER_SOL).await.unwrap();
+    h.update_config(&authority, ConfigUpdate {
+        max_bet_bps: Some(2000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    // 20% of a 10 SOL pool
+    assert_casino_error(
+        h.contribute_bet(&player, 5 * LAMPORTS_PER_SOL / 2).await,
+        CasinoError::BetTooLarge,
+    );
+
+    h.seed_jackpot(5 * LAMPORTS_PER_SOL).await.unwrap();
+    let bet = h.contribute_bet(&player, 5 * LAMPORTS_PER_SOL / 2).await.unwrap();
+
+    // Scaled odds divide by the limit the bet was placed under, not the static config value
+    let bet: Bet = h.account(bet).await;
+    assert_eq!(bet.odds_max_bet, 3 * LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn rejects_bets_until_pool_is_seeded() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        min_pool_seed: Some(LAMPORTS_PER_SOL),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL).await,
+        CasinoError::PoolNotSeeded,
+    );
+
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+}
+
+#[tokio::test]
+async fn rejects_bets_outside_operating_hours() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    // No days enabled
+    let ix = ix(
+        accounts::SetOperatingHours {
+            config: config_pda(),
+            operator: authority.pubkey(),
+        },
+        instruction::SetOperatingHours {
+            enabled: true,
+            days: 0,
+            start_minute: 0,
+            end_minute: 0,
+        },
+    );
+    h.send(&[ix], &[]).await.unwrap();
+
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL).await,
+        CasinoError::OutsideOperatingHours,
+    );
+}
+
+#[tokio::test]
+async fn happy_hours_boost_odds_and_jackpot_share() {
+    let mut h = Harness::new().await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    let window = HappyHour {
+        days: 0,
+        start_hour: 0,
+        end_hour: 25,
+        win_probability_multiplier_bps: 20000,
+        jackpot_multiplier_bps: 15000,
+    };
+    assert_casino_error(
+        h.set_happy_hours(vec![window]).await,
+        CasinoError::InvalidHappyHour,
+    );
+
+    // Open all day, but only on today's weekday
+    let now = h.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let today = HappyHour { days: 1 << utc_weekday(now), end_hour: 24, ..window };
+    h.set_happy_hours(vec![today]).await.unwrap();
+
+    let bet_address = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.win_probability_bps, 200);
+    assert_eq!(bet.fee_splits_bps[0], 750);
+
+    h.warp_forward(86400).await;
+    let bet_address = h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.win_probability_bps, 100);
+    assert_eq!(bet.fee_splits_bps[0], 500);
+}
+
+#[tokio::test]
+async fn buckets_players_into_experiment_variants() {
+    let mut h = Harness::new().await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    let variants = vec![
+        ExperimentVariant { weight_bps: 5000, jackpot_percentage: 500, win_probability_bps: 50 },
+        ExperimentVariant { weight_bps: 5000, jackpot_percentage: 500, win_probability_bps: 200 },
+    ];
+    let ix = ix(
+        accounts::SetExperiment {
+            config: config_pda(),
+            authority: h.authority().pubkey(),
+        },
+        instruction::SetExperiment { experiment_id: 7, variants },
+    );
+    h.send(&[ix], &[]).await.unwrap();
+
+    let bet_address = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+
+    let config: Config = h.account(config_pda()).await;
+    let expected = config.experiment_variant(&player.pubkey());
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.experiment_id, 7);
+    assert_eq!(bet.experiment_variant, expected);
+    assert!(bet.win_probability_bps == 50 || bet.win_probability_bps == 200);
+}
+
+#[tokio::test]
+async fn experiment_rtp_guard_rejects_richer_variants() {
+    let mut h = Harness::new().await;
+
+    let variants = vec![
+        ExperimentVariant { weight_bps: 5000, jackpot_percentage: 900, win_probability_bps: 100 },
+        ExperimentVariant { weight_bps: 5000, jackpot_percentage: 500, win_probability_bps: 100 },
+    ];
+    let ix = ix(
+        accounts::SetExperiment {
+            config: config_pda(),
+            authority: h.authority().pubkey(),
+        },
+        instruction::SetExperiment { experiment_id: 1, variants },
+    );
+
+    assert_casino_error(h.send(&[ix], &[]).await, CasinoError::ExperimentRtpExceeded);
+}
+
+#[tokio::test]
+async fn deposit_contribution_only_credits_the_pool() {
+    let mut h = Harness::new().await;
+    let depositor = h.player(2 * LAMPORTS_PER_SOL).await;
+    let player = Pubkey::new_unique();
+
+    let deposit = ix(
+        accounts::DepositContribution {
+            config: config_pda(),
+            pool: pool_pda(),
+            depositor: depositor.pubkey(),
+            instructions_sysvar: sysvar::instructions::ID,
+            system_program: system_program::ID,
+        },
+        instruction::DepositContribution {
+            amount: LAMPORTS_PER_SOL / 2,
+            player,
+        },
+    );
+    h.send(&[deposit], &[&depositor]).await.unwrap();
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, LAMPORTS_PER_SOL / 2);
+    assert_eq!(pool.bets_since_win, 0);
+
+    let config: Config = h.account(config_pda()).await;
+    assert_eq!(config.total_bets, 0);
+}
+
+#[tokio::test]
+async fn integrators_earn_a_share_of_the_house_fee() {
+    let mut h = Harness::new().await;
+    let partner = h.player(LAMPORTS_PER_SOL).await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let integrator = integrator_pda(&partner.pubkey());
+
+    let register = ix(
+        accounts::RegisterIntegrator {
+            config: config_pda(),
+            integrator,
+            authority: h.authority().pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::RegisterIntegrator {
+            partner: partner.pubkey(),
+            fee_share_bps: 5000,
+            caller_program: Pubkey::default(),
+        },
+    );
+    h.send(&[register], &[]).await.unwrap();
+
+    h.contribute_bet_via(&player, LAMPORTS_PER_SOL, Some(integrator), None).await.unwrap();
+
+    // Half of the 2% house fee
+    let fee = LAMPORTS_PER_SOL * 200 / 10000 / 2;
+    let state: Integrator = h.account(integrator).await;
+    assert_eq!(state.total_bets, 1);
+    assert_eq!(state.total_volume, LAMPORTS_PER_SOL);
+    assert_eq!(state.pending_fees, fee);
+
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.total_fees, fee);
+
+    let claim = ix(
+        accounts::ClaimIntegratorFees {
+            config: config_pda(),
+            integrator,
+            partner: partner.pubkey(),
+        },
+        instruction::ClaimIntegratorFees {},
+    );
+    let before = h.balance(partner.pubkey()).await;
+    h.send(&[claim.clone()], &[&partner]).await.unwrap();
+    assert_eq!(h.balance(partner.pubkey()).await, before + fee);
+
+    assert_casino_error(h.send(&[claim], &[&partner]).await, CasinoError::NoFeesToClaim);
+}
+
+#[tokio::test]
+async fn referrers_earn_commission_on_referred_bets() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let referrer = h.player(LAMPORTS_PER_SOL).await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let earnings = referral_earnings_pda(&referrer.pubkey());
+
+    h.update_config(&authority, ConfigUpdate {
+        referral_fee_bps: Some(1000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let register = |referrer: Pubkey| {
+        ix(
+            accounts::RegisterReferral {
+                config: config_pda(),
+                referral: referral_pda(&player.pubkey()),
+                referral_earnings: referral_earnings_pda(&referrer),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::RegisterReferral { referrer },
+        )
+    };
+
+    assert_casino_error(
+        h.send(&[register(player.pubkey())], &[&player]).await,
+        CasinoError::SelfReferral,
+    );
+    h.send(&[register(referrer.pubkey())], &[&player]).await.unwrap();
+
+    h.contribute_bet_via(&player, LAMPORTS_PER_SOL, None, Some(referrer.pubkey())).await.unwrap();
+
+    // Once referred, a bet can't drop the referrer to keep the commission with the house
+    assert_casino_error(h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await, CasinoError::InvalidReferral);
+
+    // 10% of the 2% house fee
+    let commission = LAMPORTS_PER_SOL * 200 / 10000 / 10;
+    let state: ReferralEarnings = h.account(earnings).await;
+    assert_eq!(state.referred_players, 1);
+    assert_eq!(state.pending, commission);
+
+    let claim = ix(
+        accounts::ClaimReferralEarnings {
+            config: config_pda(),
+            referral_earnings: earnings,
+            referrer: referrer.pubkey(),
+        },
+        instruction::ClaimReferralEarnings {},
+    );
+    let before = h.balance(referrer.pubkey()).await;
+    h.send(&[claim], &[&referrer]).await.unwrap();
+    assert_eq!(h.balance(referrer.pubkey()).await, before + commission);
+}
+
+#[tokio::test]
+async fn comp_points_redeem_into_bonus_credit() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.fund(&house_vault_pda(), LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        comp_points_per_sol: Some(100),
+        comp_point_value: Some(LAMPORTS_PER_SOL / 1000),
+        min_comp_redemption: Some(50),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+
+    let profile_address = player_profile_pda(&player.pubkey());
+    let profile: PlayerProfile = h.account(profile_address).await;
+    assert_eq!(profile.comp_points, 100);
+
+    let redeem = |points: u64| {
+        ix(
+            accounts::RedeemCompPoints {
+                config: config_pda(),
+                player_profile: profile_address,
+                player: player.pubkey(),
+            },
+            instruction::RedeemCompPoints { points },
+        )
+    };
+
+    assert_casino_error(h.send(&[redeem(40)], &[&player]).await, CasinoError::CompRedemptionTooSmall);
+    assert_casino_error(h.send(&[redeem(200)], &[&player]).await, CasinoError::InsufficientCompPoints);
+    h.send(&[redeem(100)], &[&player]).await.unwrap();
+
+    let profile: PlayerProfile = h.account(profile_address).await;
+    assert_eq!(profile.comp_points, 0);
+    assert_eq!(profile.bonus_credit, LAMPORTS_PER_SOL / 10);
+
+    // The next bet spends the credit
+    let house_before = h.balance(house_vault_pda()).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+
+    let profile: PlayerProfile = h.account(profile_address).await;
+    assert_eq!(profile.bonus_credit, 0);
+    assert_eq!(profile.comp_points, 50);
+
+    // House fronted the credit and received its 2% fee
+    let house_fee = LAMPORTS_PER_SOL / 2 * 200 / 10000;
+    assert_eq!(h.balance(house_vault_pda()).await, house_before - LAMPORTS_PER_SOL / 10 + house_fee);
+}
+
+#[tokio::test]
+async fn vip_tiers_raise_limits_and_accrue_rakeback() {
+    let mut h = Harness::new().await;
+    let player = h.player(20 * LAMPORTS_PER_SOL).await;
+
+    let set_tiers = ix(
+        accounts::SetVipTiers {
+            config: config_pda(),
+            authority: h.authority().pubkey(),
+        },
+        instruction::SetVipTiers {
+            tiers: vec![VipTier {
+                min_wagered: LAMPORTS_PER_SOL,
+                max_bet: 15 * LAMPORTS_PER_SOL,
+                rakeback_bps: 5000,
+            }],
+        },
+    );
+    h.send(&[set_tiers], &[]).await.unwrap();
+
+    // Not VIP yet: the base 10 SOL limit applies
+    assert_casino_error(
+        h.contribute_bet(&player, 15 * LAMPORTS_PER_SOL).await,
+        CasinoError::BetTooLarge,
+    );
+
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.contribute_bet(&player, 15 * LAMPORTS_PER_SOL).await.unwrap();
+
+    // Half of the 2% house fee on the VIP bet
+    let rakeback = 15 * LAMPORTS_PER_SOL * 200 / 10000 / 2;
+    let profile_address = player_profile_pda(&player.pubkey());
+    let profile: PlayerProfile = h.account(profile_address).await;
+    assert_eq!(profile.rakeback_pending, rakeback);
+
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.rakeback_owed, rakeback);
+
+    let claim = ix(
+        accounts::ClaimRakeback {
+            config: config_pda(),
+            player_profile: profile_address,
+            house_vault: house_vault_pda(),
+            player: player.pubkey(),
+        },
+        instruction::ClaimRakeback {},
+    );
+    let before = h.balance(player.pubkey()).await;
+    h.send(&[claim.clone()], &[&player]).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, before + rakeback);
+
+    assert_casino_error(h.send(&[claim], &[&player]).await, CasinoError::NoRakeback);
+}
+
+fn set_gaming_limits(player: &Pubkey, daily_wager_cap: u64, daily_loss_cap: u64, self_excluded_until: i64) -> Instruction {
+    ix(
+        accounts::SetGamingLimits {
+            config: config_pda(),
+            gaming_limits: gaming_limits_pda(player),
+            player: *player,
+            system_program: system_program::ID,
+        },
+        instruction::SetGamingLimits {
+            daily_wager_cap,
+            daily_loss_cap,
+            self_excluded_until,
+        },
+    )
+}
+
+#[tokio::test]
+async fn daily_wager_cap_blocks_bets_and_relaxes_after_cooldown() {
+    let mut h = Harness::new().await;
+    let player = h.player(10 * LAMPORTS_PER_SOL).await;
+
+    h.send(&[set_gaming_limits(&player.pubkey(), LAMPORTS_PER_SOL, 0, 0)], &[&player]).await.unwrap();
+
+    h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL).await,
+        CasinoError::DailyLimitExceeded,
+    );
+
+    // Lifting the cap is queued for 24 hours
+    h.send(&[set_gaming_limits(&player.pubkey(), 0, 0, 0)], &[&player]).await.unwrap();
+    let limits: GamingLimits = h.account(gaming_limits_pda(&player.pubkey())).await;
+    assert_eq!(limits.daily_wager_cap, LAMPORTS_PER_SOL);
+    assert!(limits.pending_effective_at > 0);
+
+    h.warp_forward(LIMIT_RELAX_COOLDOWN).await;
+    h.contribute_bet(&player, 3 * LAMPORTS_PER_SOL).await.unwrap();
+
+    let limits: GamingLimits = h.account(gaming_limits_pda(&player.pubkey())).await;
+    assert_eq!(limits.daily_wager_cap, 0);
+}
+
+#[tokio::test]
+async fn self_excluded_players_cannot_bet() {
+    let mut h = Harness::new().await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let now = h.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+
+    h.send(&[set_gaming_limits(&player.pubkey(), 0, 0, now + 7 * 86400)], &[&player]).await.unwrap();
+
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL).await,
+        CasinoError::SelfExcluded,
+    );
+
+    // Other games are covered too
+    let authority = h.authority();
+    h.update_config(&authority, ConfigUpdate { dice_house_edge_bps: Some(100), ..ConfigUpdate::default() })
+        .await
+        .unwrap();
+    assert_casino_error(
+        h.play_dice(&player, LAMPORTS_PER_SOL, 7500, true).await,
+        CasinoError::SelfExcluded,
+    );
+
+    // Ending the exclusion early only takes effect after the cooldown
+    h.send(&[set_gaming_limits(&player.pubkey(), 0, 0, 0)], &[&player]).await.unwrap();
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL).await,
+        CasinoError::SelfExcluded,
+    );
+}
+
+#[tokio::test]
+async fn allowlist_mode_requires_membership() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let member = h.player(5 * LAMPORTS_PER_SOL).await;
+    let outsider = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        allowlist_enabled: Some(true),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let add_member = ix(
+        accounts::AddMember {
+            config: config_pda(),
+            membership: membership_pda(&member.pubkey()),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::AddMember { player: member.pubkey() },
+    );
+    h.send(&[add_member], &[]).await.unwrap();
+
+    h.contribute_bet(&member, LAMPORTS_PER_SOL).await.unwrap();
+    assert_casino_error(
+        h.contribute_bet(&outsider, LAMPORTS_PER_SOL).await,
+        CasinoError::NotAMember,
+    );
+
+    // Opening the doors lets everyone bet
+    h.update_config(&authority, ConfigUpdate {
+        allowlist_enabled: Some(false),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+    h.contribute_bet(&outsider, LAMPORTS_PER_SOL).await.unwrap();
+}
+
+#[tokio::test]
+async fn daily_streaks_earn_odds_and_rakeback_bonus() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(10 * LAMPORTS_PER_SOL).await;
+    h.update_config(&authority, ConfigUpdate {
+        streak_days: Some(3),
+        streak_odds_bonus_bps: Some(500),
+        streak_rakeback_bps: Some(1000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let first = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.warp_forward(86400).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL + 1).await.unwrap();
+    let bet: Bet = h.account(first).await;
+    assert_eq!(bet.streak_bonus_bps, 0);
+
+    h.warp_forward(86400).await;
+    let third = h.contribute_bet(&player, 2 * LAMPORTS_PER_SOL).await.unwrap();
+    let bet: Bet = h.account(third).await;
+    assert_eq!(bet.streak_bonus_bps, 500);
+
+    // 10% of the 2% house fee on the third-day bet only
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!(profile.streak_len, 3);
+    assert_eq!(profile.rakeback_pending, 2 * LAMPORTS_PER_SOL * 200 / 10000 / 10);
+
+    // Skipping a day restarts the streak
+    h.warp_forward(2 * 86400).await;
+    let fourth = h.contribute_bet(&player, 3 * LAMPORTS_PER_SOL).await.unwrap();
+    let bet: Bet = h.account(fourth).await;
+    assert_eq!(bet.streak_bonus_bps, 0);
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!(profile.streak_len, 1);
+}
+
+#[tokio::test]
+async fn weekly_cashback_returns_part_of_net_losses() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(10 * LAMPORTS_PER_SOL).await;
+    h.fund(&house_vault_pda(), LAMPORTS_PER_SOL).await;
+    h.update_config(&authority, ConfigUpdate {
+        cashback_bps: Some(1000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.contribute_bet(&player, 2 * LAMPORTS_PER_SOL).await.unwrap();
+
+    let claim = ix(
+        accounts::ClaimCashback {
+            config: config_pda(),
+            player_profile: player_profile_pda(&player.pubkey()),
+            house_vault: house_vault_pda(),
+            player: player.pubkey(),
+        },
+        instruction::ClaimCashback {},
+    );
+
+    // The week is still open
+    assert_casino_error(h.send(&[claim.clone()], &[&player]).await, CasinoError::NoCashback);
+
+    h.warp_forward(7 * 86400).await;
+    let before = h.balance(player.pubkey()).await;
+    h.send(&[claim.clone()], &[&player]).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, before + 3 * LAMPORTS_PER_SOL / 10);
+
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!(profile.cashback_pending, 0);
+    assert_casino_error(h.send(&[claim], &[&player]).await, CasinoError::NoCashback);
+}
+
+#[tokio::test]
+async fn every_event_takes_the_next_sequence_number() {
+    let mut h = Harness::new().await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let before: Config = h.account(config_pda()).await;
+
+    let bet = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    let config: Config = h.account(config_pda()).await;
+    assert_eq!(config.event_sequence, before.event_sequence + 1);
+
+    h.fulfill(bet, vrf_with_roll(9999)).await.unwrap();
+    let config: Config = h.account(config_pda()).await;
+    assert_eq!(config.event_sequence, before.event_sequence + 2);
+}
+
+#[tokio::test]
+async fn program_owned_players_cannot_bet() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    let mut account = h.ctx.banks_client.get_account(player.pubkey()).await.unwrap().unwrap();
+    account.owner = Pubkey::new_unique();
+    h.ctx.set_account(&player.pubkey(), &AccountSharedData::from(account));
+
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL).await,
+        CasinoError::UnauthorizedCaller,
+    );
+}
+
+#[tokio::test]
+async fn pools_cap_bets_per_slot() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        max_bets_per_slot: Some(1),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let first = h.contribute_bet_ix(&player.pubkey(), LAMPORTS_PER_SOL, BetRouting::default()).await;
+    let second = h.contribute_bet_ix(&player.pubkey(), LAMPORTS_PER_SOL / 2, BetRouting::default()).await;
+    assert_casino_error(
+        h.send(&[first, second], &[&player]).await,
+        CasinoError::SlotBetLimitReached,
+    );
+
+    // One bet per slot still goes through; the harness moves to the next slot after each
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.bets_this_slot, 1);
+}
+
+#[tokio::test]
+async fn players_wait_out_the_bet_cooldown() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        bet_cooldown_secs: Some(60),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await,
+        CasinoError::CooldownActive,
+    );
+
+    h.warp_forward(60).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+}
+
+#[tokio::test]
+async fn volume_buckets_count_daily_bets_and_bettors() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let alice = h.player(5 * LAMPORTS_PER_SOL).await;
+    let bob = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    let clock: Clock = h.ctx.banks_client.get_sysvar().await.unwrap();
+    let today = clock.unix_timestamp.div_euclid(86400);
+    let payer = h.authority().pubkey();
+    let open = |day: i64| {
+        ix(
+            accounts::OpenVolumeBucket {
+                volume_bucket: volume_bucket_pda(day),
+                payer,
+                system_program: system_program::ID,
+            },
+            instruction::OpenVolumeBucket { day },
+        )
+    };
+    assert_casino_error(h.send(&[open(today + 2)], &[]).await, CasinoError::InvalidVolumeBucketDay);
+    h.send(&[open(today)], &[]).await.unwrap();
+
+    let routing = || BetRouting { volume_bucket: Some(volume_bucket_pda(today)), ..BetRouting::default() };
+    h.contribute_bet_with(&alice, LAMPORTS_PER_SOL, routing()).await.unwrap();
+    h.contribute_bet_with(&alice, LAMPORTS_PER_SOL / 2, routing()).await.unwrap();
+    h.contribute_bet_with(&bob, LAMPORTS_PER_SOL, routing()).await.unwrap();
+
+    let bucket: VolumeBucket = h.account(volume_bucket_pda(today)).await;
+    assert_eq!(bucket.day, today);
+    assert_eq!(bucket.bets, 3);
+    assert_eq!(bucket.volume, 5 * LAMPORTS_PER_SOL / 2);
+    assert_eq!(bucket.unique_bettors, 2);
+}
+
+#[tokio::test]
+async fn charity_round_ups_reach_the_charity() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let charity = h.player(LAMPORTS_PER_SOL).await.pubkey();
+
+    let init = ix(
+        accounts::InitializeCharityVault {
+            config: config_pda(),
+            charity_vault: charity_vault_pda(),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeCharityVault { charity },
+    );
+    h.send(&[init], &[]).await.unwrap();
+
+    let round_up = |charity_bps| BetRouting { charity_bps: Some(charity_bps), ..BetRouting::default() };
+    assert_casino_error(
+        h.contribute_bet_with(&player, LAMPORTS_PER_SOL, round_up(10001)).await,
+        CasinoError::InvalidCharityBps,
+    );
+
+    // 2% on top of a 1 SOL bet
+    let vault_before = h.balance(charity_vault_pda()).await;
+    h.contribute_bet_with(&player, LAMPORTS_PER_SOL, round_up(200)).await.unwrap();
+    let donation = LAMPORTS_PER_SOL / 50;
+    assert_eq!(h.balance(charity_vault_pda()).await, vault_before + donation);
+
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!(profile.total_charity, donation);
+    assert_eq!(profile.total_wagered, LAMPORTS_PER_SOL);
+
+    let charity_before = h.balance(charity).await;
+    let disburse = ix(
+        accounts::DisburseCharity {
+            config: config_pda(),
+            charity_vault: charity_vault_pda(),
+            charity,
+        },
+        instruction::DisburseCharity {},
+    );
+    h.send(&[disburse], &[]).await.unwrap();
+    assert_eq!(h.balance(charity).await, charity_before + donation);
+
+    let vault: CharityVault = h.account(charity_vault_pda()).await;
+    assert_eq!(vault.total_received, donation);
+    assert_eq!(vault.total_disbursed, donation);
+}
+
+#[tokio::test]
+async fn defi_rewards_come_only_from_realized_yield() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let early = h.player(5 * LAMPORTS_PER_SOL).await;
+    let late = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    let claim = |user: &solana_sdk::signature::Keypair| {
+        ix(
+            accounts::ClaimRewards {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                reward_claim: reward_claim_pda(&user.pubkey()),
+                reward_mint: None,
+                reward_token_account: None,
+                token_program: None,
+                user: user.pubkey(),
+                player_profile: player_profile_pda(&user.pubkey()),
+                beneficiary: None,
+                claimer: user.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::ClaimRewards {},
+        )
+    };
+
+    // 1% DeFi cut: each 1 SOL bet stakes 0.01 SOL
+    h.contribute_bet(&early, LAMPORTS_PER_SOL).await.unwrap();
+    let stake: RewardClaim = h.account(reward_claim_pda(&early.pubkey())).await;
+    assert_eq!(stake.staked, LAMPORTS_PER_SOL / 100);
+
+    // Time alone earns nothing
+    h.warp_forward(SECONDS_PER_YEAR).await;
+    assert_casino_error(h.send(&[claim(&early)], &[&early]).await, CasinoError::NoRewardsAvailable);
+    assert_casino_error(h.realize_strategy_yield(0).await, CasinoError::NoRewardsAvailable);
+
+    // Yield realized while only the early stake exists is all theirs
+    h.realize_strategy_yield(500_000).await.unwrap();
+    h.contribute_bet(&late, LAMPORTS_PER_SOL).await.unwrap();
+
+    let balance_before = h.balance(early.pubkey()).await;
+    h.send(&[claim(&early)], &[&early]).await.unwrap();
+    let claimed: RewardClaim = h.account(reward_claim_pda(&early.pubkey())).await;
+    assert_eq!(claimed.total_claimed, 500_000);
+    assert_eq!(claimed.pending_rewards, 0);
+    assert!(h.balance(early.pubkey()).await > balance_before);
+
+    assert_casino_error(h.send(&[claim(&late)], &[&late]).await, CasinoError::NoRewardsAvailable);
+    assert_casino_error(h.send(&[claim(&early)], &[&early]).await, CasinoError::NoRewardsAvailable);
+
+    // Yield nobody has distributed yet is shared by the stakes in place before the next bet
+    h.credit_strategy_yield(300_000).await;
+    h.contribute_bet(&late, LAMPORTS_PER_SOL / 2).await.unwrap();
+    h.send(&[claim(&early)], &[&early]).await.unwrap();
+    let claimed: RewardClaim = h.account(reward_claim_pda(&early.pubkey())).await;
+    assert_eq!(claimed.total_claimed, 500_000 + 150_000);
+}
+
+#[tokio::test]
+async fn claim_delegates_trigger_claims_paid_to_the_user() {
+    let mut h = Harness::new().await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let bot = h.player(LAMPORTS_PER_SOL).await;
+
+    let claim_by = |claimer: &Keypair| {
+        ix(
+            accounts::ClaimRewards {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                reward_claim: reward_claim_pda(&player.pubkey()),
+                reward_mint: None,
+                reward_token_account: None,
+                token_program: None,
+                user: player.pubkey(),
+                player_profile: player_profile_pda(&player.pubkey()),
+                beneficiary: None,
+                claimer: claimer.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::ClaimRewards {},
+        )
+    };
+    let set_delegate = |delegate: Option<Pubkey>| {
+        ix(
+            accounts::SetClaimDelegate {
+                config: config_pda(),
+                reward_claim: reward_claim_pda(&player.pubkey()),
+                user: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::SetClaimDelegate { delegate },
+        )
+    };
+
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.realize_strategy_yield(500_000).await.unwrap();
+    assert_casino_error(h.send(&[claim_by(&bot)], &[&bot]).await, CasinoError::Unauthorized);
+
+    h.send(&[set_delegate(Some(bot.pubkey()))], &[&player]).await.unwrap();
+    let player_before = h.balance(player.pubkey()).await;
+    let bot_before = h.balance(bot.pubkey()).await;
+    h.send(&[claim_by(&bot)], &[&bot]).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + 500_000);
+    assert_eq!(h.balance(bot.pubkey()).await, bot_before);
+
+    h.send(&[set_delegate(None)], &[&player]).await.unwrap();
+    assert_casino_error(h.send(&[claim_by(&bot)], &[&bot]).await, CasinoError::Unauthorized);
+}
+
+#[tokio::test]
+async fn reward_claims_respect_the_cooldown_and_minimum() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.update_config(&authority, ConfigUpdate {
+        min_claim_amount: Some(400_000),
+        claim_cooldown_secs: Some(3_600),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let claim = ix(
+        accounts::ClaimRewards {
+            config: config_pda(),
+            reward_vault: reward_vault_pda(),
+            reward_claim: reward_claim_pda(&player.pubkey()),
+            reward_mint: None,
+            reward_token_account: None,
+            token_program: None,
+            user: player.pubkey(),
+            player_profile: player_profile_pda(&player.pubkey()),
+            beneficiary: None,
+            claimer: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::ClaimRewards {},
+    );
+
+    // The cooldown also runs from when the stake was opened
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.realize_strategy_yield(300_000).await.unwrap();
+    assert_casino_error(h.send(&[claim.clone()], &[&player]).await, CasinoError::ClaimCooldownActive);
+
+    h.warp_forward(3_600).await;
+    assert_casino_error(h.send(&[claim.clone()], &[&player]).await, CasinoError::ClaimBelowMinimum);
+
+    h.realize_strategy_yield(200_000).await.unwrap();
+    h.send(&[claim.clone()], &[&player]).await.unwrap();
+    let claimed: RewardClaim = h.account(reward_claim_pda(&player.pubkey())).await;
+    assert_eq!(claimed.total_claimed, 500_000);
+
+    h.realize_strategy_yield(500_000).await.unwrap();
+    assert_casino_error(h.send(&[claim], &[&player]).await, CasinoError::ClaimCooldownActive);
+}
+
+#[tokio::test]
+async fn opted_in_rewards_can_be_compounded_by_a_keeper() {
+    let mut h = Harness::new().await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let keeper = h.player(LAMPORTS_PER_SOL).await;
+
+    let compound = |caller: &Keypair| {
+        ix(
+            accounts::CompoundRewards {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                reward_claim: reward_claim_pda(&player.pubkey()),
+                caller: caller.pubkey(),
+            },
+            instruction::CompoundRewards {},
+        )
+    };
+
+    let reward = 500_000;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.realize_strategy_yield(reward).await.unwrap();
+    assert_casino_error(h.send(&[compound(&keeper)], &[&keeper]).await, CasinoError::CompoundingDisabled);
+
+    let opt_in = ix(
+        accounts::SetAutoCompound {
+            config: config_pda(),
+            reward_claim: reward_claim_pda(&player.pubkey()),
+            user: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::SetAutoCompound { enabled: true },
+    );
+    h.send(&[opt_in], &[&player]).await.unwrap();
+
+    let vault_before: RewardVault = h.account(reward_vault_pda()).await;
+    h.send(&[compound(&keeper)], &[&keeper]).await.unwrap();
+
+    // The yield on the 0.01 SOL DeFi cut is now staked alongside it
+    let claim: RewardClaim = h.account(reward_claim_pda(&player.pubkey())).await;
+    assert_eq!(claim.staked, LAMPORTS_PER_SOL / 100 + reward);
+    assert_eq!(claim.pending_rewards, 0);
+    assert_eq!(claim.total_earned, reward);
+    assert_eq!(claim.total_claimed, 0);
+    let vault: RewardVault = h.account(reward_vault_pda()).await;
+    assert_eq!(vault.staked_amount, vault_before.staked_amount + reward);
+
+    assert_casino_error(h.send(&[compound(&keeper)], &[&keeper]).await, CasinoError::NoRewardsAvailable);
+}
+
+#[tokio::test]
+async fn claims_pay_sol_yield_and_token_emissions_together() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+
+    let set_rate = ix(
+        accounts::SetEmissionRate {
+            config: config_pda(),
+            reward_vault: reward_vault_pda(),
+            authority: authority.pubkey(),
+        },
+        instruction::SetEmissionRate { rate_per_second: 1_000, ends_at: 0 },
+    );
+    assert_casino_error(h.send(&[set_rate.clone()], &[]).await, CasinoError::RewardMintNotSet);
+
+    // A project token whose mint authority has been handed to the reward vault
+    let mint = h.create_mint(&reward_vault_pda()).await;
+    let token_account = h.create_token_account(&mint, &player.pubkey()).await;
+
+    let set_mint = ix(
+        accounts::SetRewardMint {
+            config: config_pda(),
+            reward_vault: reward_vault_pda(),
+            reward_mint: mint,
+            authority: authority.pubkey(),
+            token_program: spl_token::ID,
+        },
+        instruction::SetRewardMint {},
+    );
+    h.send(&[set_mint], &[]).await.unwrap();
+    h.send(&[set_rate], &[]).await.unwrap();
+    h.warp_forward(SECONDS_PER_YEAR).await;
+
+    let claim = |with_tokens: bool| {
+        ix(
+            accounts::ClaimRewards {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                reward_claim: reward_claim_pda(&player.pubkey()),
+                reward_mint: with_tokens.then_some(mint),
+                reward_token_account: with_tokens.then_some(token_account),
+                token_program: with_tokens.then_some(spl_token::ID),
+                user: player.pubkey(),
+                player_profile: player_profile_pda(&player.pubkey()),
+                beneficiary: None,
+                claimer: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::ClaimRewards {},
+        )
+    };
+    assert_casino_error(h.send(&[claim(false)], &[&player]).await, CasinoError::MissingRewardTokenAccounts);
+
+    // The player is the only staker: a year of emissions, plus all the realized SOL yield
+    let sol_yield = 500_000;
+    h.realize_strategy_yield(sol_yield).await.unwrap();
+    let vault_lamports = h.balance(reward_vault_pda()).await;
+    h.send(&[claim(true)], &[&player]).await.unwrap();
+    let tokens: TokenAccount = h.account(token_account).await;
+    assert_eq!(tokens.amount, 1_000 * SECONDS_PER_YEAR as u64);
+    assert_eq!(h.balance(reward_vault_pda()).await, vault_lamports - sol_yield);
+
+    let reward_claim: RewardClaim = h.account(reward_claim_pda(&player.pubkey())).await;
+    assert_eq!(reward_claim.total_emissions_claimed, tokens.amount);
+    assert_eq!(reward_claim.total_claimed, sol_yield);
+}
+
+#[tokio::test]
+async fn yield_strategy_deposits_only_reach_the_strategy_program() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    let msol = h.create_mint(&Pubkey::new_unique()).await;
+
+    let set_strategy = |lst_mint: Option<Pubkey>| {
+        ix(
+            accounts::SetYieldStrategy {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                lst_mint,
+                authority: authority.pubkey(),
+            },
+            instruction::SetYieldStrategy { strategy: YieldStrategy::Marinade },
+        )
+    };
+    assert_casino_error(h.send(&[set_strategy(None)], &[]).await, CasinoError::InvalidStrategyAccounts);
+    h.send(&[set_strategy(Some(msol))], &[]).await.unwrap();
+
+    let vault: RewardVault = h.account(reward_vault_pda()).await;
+    assert_eq!(vault.strategy, YieldStrategy::Marinade);
+    assert_eq!(vault.strategy_lst_mint, msol);
+
+    // Any other executable program is refused before the CPI
+    let deploy = ix(
+        accounts::DeployToStrategy {
+            config: config_pda(),
+            reward_vault: reward_vault_pda(),
+            strategy_escrow: pda(&[b"strategy_escrow"]),
+            lst_account: pda(&[b"strategy_lst", msol.as_ref()]),
+            lst_mint: msol,
+            strategy_program: spl_token::ID,
+            authority: authority.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::DeployToStrategy { amount: LAMPORTS_PER_SOL / 200 },
+    );
+    assert_casino_error(h.send(&[deploy], &[]).await, CasinoError::InvalidStrategyAccounts);
+}
+
+#[tokio::test]
+async fn early_unstakers_leave_a_penalty_for_remaining_stakers() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let leaver = h.player(5 * LAMPORTS_PER_SOL).await;
+    let stayer = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        stake_lockup_secs: Some(3600),
+        early_exit_penalty_bps: Some(1000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let stake = |user: &solana_sdk::signature::Keypair, amount: u64| {
+        ix(
+            accounts::StakeDefi {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                stake_position: stake_position_pda(&user.pubkey()),
+                reward_claim: reward_claim_pda(&user.pubkey()),
+                lp_mint: None,
+                lp_token_account: None,
+                token_program: None,
+                user: user.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::StakeDefi { amount },
+        )
+    };
+    let unstake = |user: &solana_sdk::signature::Keypair, shares: u64| {
+        ix(
+            accounts::UnstakeDefi {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                stake_position: stake_position_pda(&user.pubkey()),
+                reward_claim: reward_claim_pda(&user.pubkey()),
+                lp_mint: None,
+                lp_token_account: None,
+                token_program: None,
+                user: user.pubkey(),
+            },
+            instruction::UnstakeDefi { shares },
+        )
+    };
+
+    h.send(&[stake(&leaver, LAMPORTS_PER_SOL)], &[&leaver]).await.unwrap();
+    h.send(&[stake(&stayer, LAMPORTS_PER_SOL)], &[&stayer]).await.unwrap();
+    let position: StakePosition = h.account(stake_position_pda(&stayer.pubkey())).await;
+    assert_eq!(position.shares, LAMPORTS_PER_SOL);
+
+    assert_casino_error(
+        h.send(&[unstake(&leaver, LAMPORTS_PER_SOL + 1)], &[&leaver]).await,
+        CasinoError::InsufficientShares,
+    );
+
+    // Leaving inside the lockup forfeits 10%
+    let balance_before = h.balance(leaver.pubkey()).await;
+    h.send(&[unstake(&leaver, LAMPORTS_PER_SOL)], &[&leaver]).await.unwrap();
+    assert_eq!(h.balance(leaver.pubkey()).await - balance_before, LAMPORTS_PER_SOL * 9 / 10);
+
+    // The penalty raises the value of the remaining shares
+    h.warp_forward(3600).await;
+    let balance_before = h.balance(stayer.pubkey()).await;
+    h.send(&[unstake(&stayer, LAMPORTS_PER_SOL)], &[&stayer]).await.unwrap();
+    assert_eq!(h.balance(stayer.pubkey()).await - balance_before, LAMPORTS_PER_SOL * 11 / 10);
+
+    let vault: RewardVault = h.account(reward_vault_pda()).await;
+    assert_eq!(vault.total_shares, 0);
+    assert_eq!(vault.staker_assets, 0);
+    let claim: RewardClaim = h.account(reward_claim_pda(&stayer.pubkey())).await;
+    assert_eq!(claim.staked, 0);
+}
+
+#[tokio::test]
+async fn lp_tokens_mirror_stake_shares() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let user = h.player(5 * LAMPORTS_PER_SOL).await;
+    let lp_mint = lp_mint_pda();
+
+    let init_mint = ix(
+        accounts::InitializeLpMint {
+            config: config_pda(),
+            reward_vault: reward_vault_pda(),
+            lp_mint,
+            authority: authority.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::InitializeLpMint {},
+    );
+    h.send(&[init_mint], &[]).await.unwrap();
+
+    let lp_account = h.create_token_account(&lp_mint, &user.pubkey()).await;
+
+    let stake = |with_lp: bool| {
+        ix(
+            accounts::StakeDefi {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                stake_position: stake_position_pda(&user.pubkey()),
+                reward_claim: reward_claim_pda(&user.pubkey()),
+                lp_mint: with_lp.then_some(lp_mint),
+                lp_token_account: with_lp.then_some(lp_account),
+                token_program: with_lp.then_some(spl_token::ID),
+                user: user.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::StakeDefi { amount: LAMPORTS_PER_SOL },
+        )
+    };
+    assert_casino_error(h.send(&[stake(false)], &[&user]).await, CasinoError::MissingLpAccounts);
+    h.send(&[stake(true)], &[&user]).await.unwrap();
+
+    let tokens: TokenAccount = h.account(lp_account).await;
+    assert_eq!(tokens.amount, LAMPORTS_PER_SOL);
+
+    let unstake = ix(
+        accounts::UnstakeDefi {
+            config: config_pda(),
+            reward_vault: reward_vault_pda(),
+            stake_position: stake_position_pda(&user.pubkey()),
+            reward_claim: reward_claim_pda(&user.pubkey()),
+            lp_mint: Some(lp_mint),
+            lp_token_account: Some(lp_account),
+            token_program: Some(spl_token::ID),
+            user: user.pubkey(),
+        },
+        instruction::UnstakeDefi { shares: LAMPORTS_PER_SOL / 2 },
+    );
+    h.send(&[unstake], &[&user]).await.unwrap();
+
+    let tokens: TokenAccount = h.account(lp_account).await;
+    assert_eq!(tokens.amount, LAMPORTS_PER_SOL / 2);
+    let position: StakePosition = h.account(stake_position_pda(&user.pubkey())).await;
+    assert_eq!(position.shares, tokens.amount);
+}
@@ -0,0 +1,285 @@
+use anchor_lang::{AccountDeserialize, AccountSerialize, AnchorSerialize};
+use progressive_jackpot::error::CasinoError;
+use progressive_jackpot::instructions::StateMigrated;
+use progressive_jackpot::state::*;
+use solana_sdk::{account::AccountSharedData, instruction::AccountMeta, pubkey::Pubkey, signature::Signer, system_program};
+use tests_integration::*;
+
+#[tokio::test]
+async fn initializes_config_and_pools() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+
+    let config: Config = h.account(config_pda()).await;
+    assert_eq!(config.authority, authority.pubkey());
+    assert_eq!(config.treasurer, authority.pubkey());
+    assert_eq!(config.operator, authority.pubkey());
+    assert_eq!(config.risk_manager, authority.pubkey());
+    assert_eq!(config.jackpot_percentage, 500);
+    assert_eq!(config.win_probability_bps, 100);
+    assert_eq!(config.total_bets, 0);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, 0);
+    assert_eq!(pool.reset_threshold, 100 * LAMPORTS_PER_SOL);
+
+    let reserve: ReservePool = h.account(reserve_pool_pda()).await;
+    assert_eq!(reserve.balance, 0);
+
+    let history: WinHistory = h.zero_copy(win_history_pda()).await;
+    assert_eq!(history.total_recorded, 0);
+
+    let queue: NotificationQueue = h.zero_copy(notification_queue_pda()).await;
+    assert_eq!(queue.next_seq, 1);
+    assert_eq!(queue.acked_seq, 0);
+}
+
+#[tokio::test]
+async fn rejects_percentages_above_100() {
+    let mut h = Harness::start().await;
+
+    let result = h
+        .initialize(InitParams {
+            jackpot_percentage: 6000,
+            house_percentage: 3000,
+            defi_percentage: 2000,
+            ..InitParams::default()
+        })
+        .await;
+
+    assert_casino_error(result, CasinoError::InvalidConfig);
+}
+
+#[tokio::test]
+async fn rejects_invalid_win_probability() {
+    let mut h = Harness::start().await;
+
+    let result = h
+        .initialize(InitParams {
+            win_probability_bps: 0,
+            ..InitParams::default()
+        })
+        .await;
+
+    assert_casino_error(result, CasinoError::InvalidConfig);
+}
+
+#[tokio::test]
+async fn seeding_grows_the_pool() {
+    let mut h = Harness::new().await;
+
+    h.seed_jackpot(2 * LAMPORTS_PER_SOL).await.unwrap();
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, 2 * LAMPORTS_PER_SOL);
+
+    assert_casino_error(h.seed_jackpot(0).await, CasinoError::InvalidAmount);
+}
+
+#[tokio::test]
+async fn migrate_grows_pools_created_before_versioning() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL / 10).await.unwrap();
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.version, STATE_VERSION);
+
+    // An old pool ends right before the `version` byte: three u64 counters and the emergency withdrawal follow it
+    let mut account = h.ctx.banks_client.get_account(pool_pda()).await.unwrap().unwrap();
+    account.data.truncate(8 + pool.try_to_vec().unwrap().len() - 1 - 3 * 8 - (8 + 32 + 8));
+    h.ctx.set_account(&pool_pda(), &AccountSharedData::from(account));
+
+    // An old house vault ends at its bump, before `game_liability`
+    let mut account = h.ctx.banks_client.get_account(house_vault_pda()).await.unwrap().unwrap();
+    account.data.truncate(8 + HouseVault::INIT_SPACE - 8);
+    h.ctx.set_account(&house_vault_pda(), &AccountSharedData::from(account));
+
+    // An old profile ends at its bump, before the beneficiary, charity and bonus wheel fields
+    let profile_address = player_profile_pda(&player.pubkey());
+    let profile: PlayerProfile = h.account(profile_address).await;
+    let mut account = h.ctx.banks_client.get_account(profile_address).await.unwrap().unwrap();
+    account.data.truncate(8 + PlayerProfile::INIT_SPACE - (33 + 8 + 8 + 2 + 1));
+    h.ctx.set_account(&profile_address, &AccountSharedData::from(account));
+
+    // A config last migrated to v1
+    let mut account = h.ctx.banks_client.get_account(config_pda()).await.unwrap().unwrap();
+    let mut config = Config::try_deserialize(&mut &account.data[..]).unwrap();
+    config.version = 1;
+    config.try_serialize(&mut &mut account.data[..]).unwrap();
+    h.ctx.set_account(&config_pda(), &AccountSharedData::from(account));
+
+    let migrate = |authority: Pubkey| {
+        let mut migrate = ix(
+            accounts::Migrate {
+                config: config_pda(),
+                reward_vault: reward_vault_pda(),
+                house_vault: house_vault_pda(),
+                authority,
+                system_program: system_program::ID,
+            },
+            instruction::Migrate {},
+        );
+        migrate.accounts.push(AccountMeta::new(pool_pda(), false));
+        migrate.accounts.push(AccountMeta::new(profile_address, false));
+        migrate
+    };
+
+    let stranger = h.player(LAMPORTS_PER_SOL).await;
+    assert_casino_error(
+        h.send(&[migrate(stranger.pubkey())], &[&stranger]).await,
+        CasinoError::Unauthorized,
+    );
+
+    // The authority pays the extra rent; the pool's own funds are untouched
+    let rent = h.ctx.banks_client.get_rent().await.unwrap();
+    let old_pool = h.ctx.banks_client.get_account(pool_pda()).await.unwrap().unwrap();
+    let authority_before = h.balance(h.authority().pubkey()).await;
+
+    let event: StateMigrated = h.send_for_event(&[migrate(h.authority().pubkey())], &[]).await.unwrap().expect("StateMigrated");
+    assert_eq!((event.from_version, event.version), (1, STATE_VERSION));
+    assert_eq!((event.pools, event.profiles), (1, 1));
+
+    let pool_rent = rent.minimum_balance(8 + JackpotPool::INIT_SPACE) - rent.minimum_balance(old_pool.data.len());
+    assert_eq!(h.balance(pool_pda()).await, old_pool.lamports + pool_rent);
+    assert!(h.balance(h.authority().pubkey()).await < authority_before - pool_rent);
+
+    let migrated: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(migrated.version, STATE_VERSION);
+    assert_eq!(migrated.balance, pool.balance);
+
+    let account = h.ctx.banks_client.get_account(pool_pda()).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + JackpotPool::INIT_SPACE);
+
+    let account = h.ctx.banks_client.get_account(house_vault_pda()).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + HouseVault::INIT_SPACE);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, 0);
+
+    let account = h.ctx.banks_client.get_account(profile_address).await.unwrap().unwrap();
+    assert_eq!(account.data.len(), 8 + PlayerProfile::INIT_SPACE);
+    let migrated: PlayerProfile = h.account(profile_address).await;
+    assert_eq!((migrated.total_wagered, migrated.bump), (profile.total_wagered, profile.bump));
+    assert_eq!((migrated.payout_beneficiary, migrated.total_charity, migrated.bonus_wheel_spins), (None, 0, 0));
+}
+
+#[test]
+fn init_space_covers_populated_optional_fields() {
+    let config = Config {
+        orao_network: Some(Pubkey::new_unique()),
+        switchboard_queue: Some(Pubkey::new_unique()),
+        ..Default::default()
+    };
+    assert_eq!(config.try_to_vec().unwrap().len(), Config::INIT_SPACE);
+
+    let pool = JackpotPool {
+        last_winner: Some(Pubkey::new_unique()),
+        last_win_timestamp: Some(1),
+        side_bet_pool: Some(Pubkey::new_unique()),
+        ..Default::default()
+    };
+    assert_eq!(pool.try_to_vec().unwrap().len(), JackpotPool::INIT_SPACE);
+
+    let bet = Bet {
+        vrf_request_id: Some([1; 32]),
+        experiment_variant: Some(0),
+        ..Default::default()
+    };
+    assert_eq!(bet.try_to_vec().unwrap().len(), Bet::INIT_SPACE);
+
+    let request = VrfRequest { result: Some([1; 32]), ..Default::default() };
+    assert_eq!(request.try_to_vec().unwrap().len(), VrfRequest::INIT_SPACE);
+}
+
+#[tokio::test]
+async fn reconcile_pool_reports_and_corrects_drift() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let pool: JackpotPool = h.account(pool_pda()).await;
+
+    // Lamports sent straight to the pool bypass `balance`
+    let mut account = h.ctx.banks_client.get_account(pool_pda()).await.unwrap().unwrap();
+    account.lamports += 1_000;
+    h.ctx.set_account(&pool_pda(), &AccountSharedData::from(account));
+
+    let reconcile = |authority: Pubkey, correct: bool| {
+        ix(
+            accounts::ReconcilePool { config: config_pda(), pool: pool_pda(), authority },
+            instruction::ReconcilePool { correct },
+        )
+    };
+
+    let stranger = h.player(LAMPORTS_PER_SOL).await;
+    assert_casino_error(
+        h.send(&[reconcile(stranger.pubkey(), true)], &[&stranger]).await,
+        CasinoError::Unauthorized,
+    );
+
+    h.send(&[reconcile(h.authority().pubkey(), false)], &[]).await.unwrap();
+    let reported: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(reported.balance, pool.balance);
+
+    h.send(&[reconcile(h.authority().pubkey(), true)], &[]).await.unwrap();
+    let corrected: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(corrected.balance, pool.balance + 1_000);
+}
+
+#[tokio::test]
+async fn emergency_withdrawals_wait_out_the_timelock() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    h.seed_jackpot(2 * LAMPORTS_PER_SOL).await.unwrap();
+    let pool: JackpotPool = h.account(pool_pda()).await;
+
+    let treasury = Pubkey::new_unique();
+    let set_roles = ix(
+        accounts::SetRoles { config: config_pda(), authority: authority.pubkey() },
+        instruction::SetRoles { treasurer: Some(treasury), operator: None, risk_manager: None, payout_approver: None },
+    );
+    h.send(&[set_roles], &[]).await.unwrap();
+
+    let initiate = ix(
+        accounts::InitiateEmergencyWithdraw {
+            config: config_pda(),
+            pool: pool_pda(),
+            notification_queue: notification_queue_pda(),
+            destination: treasury,
+            authority: authority.pubkey(),
+        },
+        instruction::InitiateEmergencyWithdraw { amount: LAMPORTS_PER_SOL },
+    );
+    h.send(&[initiate], &[]).await.unwrap();
+
+    let queue: NotificationQueue = h.zero_copy(notification_queue_pda()).await;
+    assert_eq!(queue.entries[1].kind, NOTIFICATION_PAUSE);
+    assert_eq!((queue.entries[1].subject, queue.entries[1].amount), (pool_pda(), LAMPORTS_PER_SOL));
+
+    // Betting stops as soon as the withdrawal is queued
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    assert_casino_error(
+        h.contribute_bet(&player, LAMPORTS_PER_SOL).await,
+        CasinoError::EmergencyWithdrawPending,
+    );
+
+    let execute = ix(
+        accounts::ExecuteEmergencyWithdraw {
+            config: config_pda(),
+            pool: pool_pda(),
+            destination: treasury,
+            authority: authority.pubkey(),
+        },
+        instruction::ExecuteEmergencyWithdraw {},
+    );
+    assert_casino_error(h.send(&[execute.clone()], &[]).await, CasinoError::EmergencyTimelockActive);
+
+    h.warp_forward(MIN_EMERGENCY_DELAY_SECS).await;
+    h.send(&[execute], &[]).await.unwrap();
+
+    assert_eq!(h.balance(treasury).await, LAMPORTS_PER_SOL);
+    let drained: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(drained.balance, pool.balance - LAMPORTS_PER_SOL);
+    assert_eq!(drained.emergency_unlocks_at, 0);
+
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+}
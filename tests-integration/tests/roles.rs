@@ -0,0 +1,398 @@
+use progressive_jackpot::error::CasinoError;
+use progressive_jackpot::instructions::ConfigUpdated;
+use progressive_jackpot::state::*;
+use solana_sdk::{pubkey::Pubkey, signature::{Keypair, Signer}, system_program};
+use tests_integration::*;
+
+async fn set_roles(
+    h: &mut Harness,
+    treasurer: Option<Pubkey>,
+    operator: Option<Pubkey>,
+    risk_manager: Option<Pubkey>,
+    payout_approver: Option<Pubkey>,
+) {
+    let ix = ix(
+        accounts::SetRoles {
+            config: config_pda(),
+            authority: h.authority().pubkey(),
+        },
+        instruction::SetRoles { treasurer, operator, risk_manager, payout_approver },
+    );
+    h.send(&[ix], &[]).await.unwrap();
+}
+
+fn withdraw_house(treasurer: &Keypair, amount: u64) -> solana_sdk::instruction::Instruction {
+    withdraw_house_to(treasurer, treasurer.pubkey(), amount)
+}
+
+fn withdraw_house_to(treasurer: &Keypair, destination: Pubkey, amount: u64) -> solana_sdk::instruction::Instruction {
+    ix(
+        accounts::WithdrawHouse {
+            config: config_pda(),
+            house_vault: house_vault_pda(),
+            authority: treasurer.pubkey(),
+            destination,
+            system_program: system_program::ID,
+        },
+        instruction::WithdrawHouse { amount },
+    )
+}
+
+#[tokio::test]
+async fn risk_manager_can_only_change_bet_limits() {
+    let mut h = Harness::new().await;
+    let risk_manager = h.player(LAMPORTS_PER_SOL).await;
+    set_roles(&mut h, None, None, Some(risk_manager.pubkey()), None).await;
+
+    h.update_config(&risk_manager, ConfigUpdate {
+        max_bet: Some(5 * LAMPORTS_PER_SOL),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let config: Config = h.account(config_pda()).await;
+    assert_eq!(config.max_bet, 5 * LAMPORTS_PER_SOL);
+
+    assert_casino_error(
+        h.update_config(&risk_manager, ConfigUpdate {
+            win_probability_bps: Some(5000),
+            ..ConfigUpdate::default()
+        })
+        .await,
+        CasinoError::Unauthorized,
+    );
+
+    // Bet limits don't carry other fields through with them
+    assert_casino_error(
+        h.update_config(&risk_manager, ConfigUpdate {
+            min_bet: Some(LAMPORTS_PER_SOL / 10),
+            bonus_wheel_losses: Some(1),
+            ..ConfigUpdate::default()
+        })
+        .await,
+        CasinoError::Unauthorized,
+    );
+}
+
+#[tokio::test]
+async fn strangers_cannot_update_config() {
+    let mut h = Harness::new().await;
+    let stranger = h.player(LAMPORTS_PER_SOL).await;
+
+    assert_casino_error(
+        h.update_config(&stranger, ConfigUpdate {
+            min_bet: Some(1),
+            ..ConfigUpdate::default()
+        })
+        .await,
+        CasinoError::Unauthorized,
+    );
+}
+
+#[tokio::test]
+async fn only_the_treasurer_withdraws_house_fees() {
+    let mut h = Harness::new().await;
+    let treasurer = h.player(LAMPORTS_PER_SOL).await;
+    set_roles(&mut h, Some(treasurer.pubkey()), None, None, None).await;
+
+    // 2% house fee on a 10 SOL bet
+    let player = h.player(20 * LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&player, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    let fees = 10 * LAMPORTS_PER_SOL * 200 / 10000;
+
+    let authority = h.authority();
+    assert_casino_error(
+        h.send(&[withdraw_house(&authority, fees / 2)], &[]).await,
+        CasinoError::Unauthorized,
+    );
+
+    let before = h.balance(treasurer.pubkey()).await;
+    h.send(&[withdraw_house(&treasurer, fees / 2)], &[&treasurer]).await.unwrap();
+    assert_eq!(h.balance(treasurer.pubkey()).await, before + fees / 2);
+
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, fees / 2);
+
+    // Lamports deposited outside of fees are not withdrawable
+    h.fund(&house_vault_pda(), LAMPORTS_PER_SOL).await;
+    assert_casino_error(
+        h.send(&[withdraw_house(&treasurer, fees)], &[&treasurer]).await,
+        CasinoError::HouseFeesExceeded,
+    );
+}
+
+#[tokio::test]
+async fn house_fees_go_only_to_allowlisted_treasuries() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(20 * LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&player, 10 * LAMPORTS_PER_SOL).await.unwrap();
+
+    let cold_wallet = Keypair::new().pubkey();
+    assert_casino_error(
+        h.send(&[withdraw_house_to(&authority, cold_wallet, LAMPORTS_PER_SOL / 100)], &[]).await,
+        CasinoError::InvalidTreasuryDestination,
+    );
+
+    let allowlist = ix(
+        accounts::SetTreasuryDestinations { config: config_pda(), authority: authority.pubkey() },
+        instruction::SetTreasuryDestinations { destinations: vec![cold_wallet] },
+    );
+    h.send(&[allowlist], &[]).await.unwrap();
+
+    h.send(&[withdraw_house_to(&authority, cold_wallet, LAMPORTS_PER_SOL / 100)], &[]).await.unwrap();
+    assert_eq!(h.balance(cold_wallet).await, LAMPORTS_PER_SOL / 100);
+}
+
+#[tokio::test]
+async fn only_the_authority_deploys_house_fees_to_lending() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let treasurer = h.player(LAMPORTS_PER_SOL).await;
+    set_roles(&mut h, Some(treasurer.pubkey()), None, None, None).await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+
+    h.add_native_mint();
+    let collateral_mint = h.create_mint(&Pubkey::new_unique()).await;
+    let deploy = |signer: &Keypair, lending_program: Pubkey, amount: u64| {
+        ix(
+            accounts::DeployToLending {
+                config: config_pda(),
+                house_vault: house_vault_pda(),
+                yield_position: pda(&[b"yield_position"]),
+                wsol_account: pda(&[b"yield_wsol"]),
+                native_mint: spl_token::native_mint::ID,
+                collateral_account: pda(&[b"yield_collateral", collateral_mint.as_ref()]),
+                reserve: Pubkey::new_unique(),
+                reserve_liquidity_supply: Pubkey::new_unique(),
+                reserve_collateral_mint: collateral_mint,
+                lending_market: Pubkey::new_unique(),
+                lending_market_authority: Pubkey::new_unique(),
+                lending_program,
+                authority: signer.pubkey(),
+                token_program: spl_token::ID,
+                system_program: system_program::ID,
+            },
+            instruction::DeployToLending { amount },
+        )
+    };
+
+    // Any executable account will do to get past the account checks
+    let lending_program = spl_token::ID;
+    assert_casino_error(
+        h.send(&[deploy(&authority, lending_program, 1)], &[]).await,
+        CasinoError::LendingNotConfigured,
+    );
+
+    h.update_config(&authority, ConfigUpdate {
+        lending_program: Some(lending_program),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    assert_casino_error(
+        h.send(&[deploy(&treasurer, lending_program, 1)], &[&treasurer]).await,
+        CasinoError::Unauthorized,
+    );
+    let fees = h.account::<HouseVault>(house_vault_pda()).await.accumulated_house_fees;
+    assert_casino_error(
+        h.send(&[deploy(&authority, lending_program, fees + 1)], &[]).await,
+        CasinoError::HouseFeesExceeded,
+    );
+}
+
+#[tokio::test]
+async fn operator_acknowledges_big_win_notifications() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+
+    h.update_config(&authority, ConfigUpdate {
+        big_win_threshold: Some(1),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let bet = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.fulfill(bet, vrf_with_roll(60)).await.unwrap();
+
+    let queue: NotificationQueue = h.zero_copy(notification_queue_pda()).await;
+    assert_eq!(queue.next_seq, 2);
+    assert_eq!(queue.entries[1].kind, NOTIFICATION_BIG_WIN);
+    assert_eq!(queue.entries[1].subject, player.pubkey());
+
+    let ack = |up_to_seq: u64| {
+        ix(
+            accounts::AckNotifications {
+                config: config_pda(),
+                notification_queue: notification_queue_pda(),
+                operator: authority.pubkey(),
+            },
+            instruction::AckNotifications { up_to_seq },
+        )
+    };
+
+    assert_casino_error(h.send(&[ack(2)], &[]).await, CasinoError::InvalidNotificationAck);
+    h.send(&[ack(1)], &[]).await.unwrap();
+
+    let queue: NotificationQueue = h.zero_copy(notification_queue_pda()).await;
+    assert_eq!(queue.acked_seq, 1);
+}
+
+#[tokio::test]
+async fn hidden_players_are_not_written_to_win_history() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    let ix = ix(
+        accounts::SetPrivacy {
+            config: config_pda(),
+            player_profile: player_profile_pda(&player.pubkey()),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::SetPrivacy {
+            experiment_opt_out: true,
+            hide_from_history: true,
+        },
+    );
+    h.send(&[ix], &[&player]).await.unwrap();
+
+    let bet = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.fulfill(bet, vrf_with_roll(60)).await.unwrap();
+
+    let history: WinHistory = h.zero_copy(win_history_pda()).await;
+    assert_eq!(history.total_recorded, 1);
+    assert_eq!(history.entries[0].player, Pubkey::default());
+}
+
+#[tokio::test]
+async fn frozen_players_cannot_bet_or_claim() {
+    let mut h = Harness::new().await;
+    let operator = h.player(LAMPORTS_PER_SOL).await;
+    set_roles(&mut h, None, Some(operator.pubkey()), None, None).await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let bet = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.fulfill(bet, vrf_with_roll(60)).await.unwrap();
+
+    let freeze = |signer: &Keypair| {
+        ix(
+            accounts::FreezePlayer {
+                config: config_pda(),
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                signer: signer.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::FreezePlayer { player: player.pubkey(), reason: 1 },
+        )
+    };
+
+    let stranger = h.player(LAMPORTS_PER_SOL).await;
+    assert_casino_error(h.send(&[freeze(&stranger)], &[&stranger]).await, CasinoError::Unauthorized);
+    h.send(&[freeze(&operator)], &[&operator]).await.unwrap();
+
+    let frozen: FrozenPlayer = h.account(frozen_player_pda(&player.pubkey())).await;
+    assert_eq!(frozen.frozen_by, operator.pubkey());
+    assert_eq!(frozen.reason, 1);
+
+    assert_casino_error(h.claim_win(&player, bet).await, CasinoError::PlayerFrozen);
+    assert_casino_error(h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await, CasinoError::PlayerFrozen);
+
+    let unfreeze = ix(
+        accounts::UnfreezePlayer {
+            config: config_pda(),
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            signer: h.authority().pubkey(),
+        },
+        instruction::UnfreezePlayer {},
+    );
+    h.send(&[unfreeze], &[]).await.unwrap();
+
+    h.claim_win(&player, bet).await.unwrap();
+    let bet: Bet = h.account(bet).await;
+    assert_eq!(bet.status, 4);
+}
+
+#[tokio::test]
+async fn anyone_sweeps_house_fees_above_the_threshold() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let treasury = h.player(LAMPORTS_PER_SOL).await.pubkey();
+    let cranker = h.player(LAMPORTS_PER_SOL).await;
+
+    let sweep = ix(
+        accounts::SweepHouse {
+            config: config_pda(),
+            house_vault: house_vault_pda(),
+            treasury,
+            cranker: cranker.pubkey(),
+        },
+        instruction::SweepHouse {},
+    );
+    assert_casino_error(h.send(&[sweep.clone()], &[&cranker]).await, CasinoError::SweepDisabled);
+
+    h.update_config(&authority, ConfigUpdate {
+        sweep_threshold: Some(LAMPORTS_PER_SOL / 10),
+        sweep_bounty_bps: Some(100),
+        sweep_treasury: Some(treasury),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    // 2% of 1 SOL stays under the threshold
+    let player = h.player(20 * LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    assert_casino_error(h.send(&[sweep.clone()], &[&cranker]).await, CasinoError::SweepBelowThreshold);
+
+    h.contribute_bet(&player, 10 * LAMPORTS_PER_SOL).await.unwrap();
+    let fees = 11 * LAMPORTS_PER_SOL * 200 / 10000;
+    let bounty = fees / 100;
+
+    let treasury_before = h.balance(treasury).await;
+    let cranker_before = h.balance(cranker.pubkey()).await;
+    h.send(&[sweep], &[&cranker]).await.unwrap();
+
+    assert_eq!(h.balance(treasury).await, treasury_before + fees - bounty);
+    assert_eq!(h.balance(cranker.pubkey()).await, cranker_before + bounty);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, 0);
+}
+
+#[tokio::test]
+async fn config_updates_log_before_and_after_snapshots() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let before: Config = h.account(config_pda()).await;
+
+    let update = ix(
+        accounts::UpdateConfig {
+            config: config_pda(),
+            pool: pool_pda(),
+            authority: authority.pubkey(),
+        },
+        instruction::UpdateConfig {
+            update: ConfigUpdate {
+                win_probability_bps: Some(250),
+                ..ConfigUpdate::default()
+            },
+        },
+    );
+    let event: ConfigUpdated = h.send_for_event(&[update], &[]).await.unwrap().expect("ConfigUpdated");
+
+    let after: Config = h.account(config_pda()).await;
+    assert_eq!(event.authority, authority.pubkey());
+    assert_eq!(event.before.win_probability_bps, before.win_probability_bps);
+    assert_eq!(event.after.win_probability_bps, 250);
+    assert_eq!(event.before_params_hash, before.params_hash());
+    assert_eq!(event.after_params_hash, after.params_hash());
+    assert_ne!(event.before_params_hash, event.after_params_hash);
+}
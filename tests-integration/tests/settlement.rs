@@ -0,0 +1,2187 @@
+use anchor_lang::AnchorDeserialize;
+use progressive_jackpot::error::CasinoError;
+use progressive_jackpot::instructions::{parlay_leg_payout, PoolState, SettlementResult};
+use progressive_jackpot::state::*;
+use solana_sdk::{account::AccountSharedData, clock::Clock, hash::hashv, instruction::AccountMeta, pubkey::Pubkey, signature::Signer, system_program};
+use tests_integration::*;
+
+/// Harness with a seeded pool and one pending bet
+async fn pending_bet(seed: u64) -> (Harness, solana_sdk::signature::Keypair, Pubkey) {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(seed).await.unwrap();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let bet = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    (h, player, bet)
+}
+
+#[tokio::test]
+async fn losing_roll_leaves_pool_untouched() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    h.fulfill(bet_address, vrf_with_roll(500)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 2);
+    assert_eq!(bet.win_amount, 0);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, pool_before.balance);
+
+    let vrf_request: VrfRequest = h.account(vrf_request_pda(&bet_address)).await;
+    assert_eq!(vrf_request.status, 1);
+}
+
+#[tokio::test]
+async fn winning_roll_is_claimable_once() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    // Common tier: 25% of the pool
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 1);
+    assert_eq!(bet.win_amount, pool_before.balance / 4);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.pending_payouts, bet.win_amount);
+    assert_eq!(pool.last_winner, Some(player.pubkey()));
+
+    let history: WinHistory = h.zero_copy(win_history_pda()).await;
+    assert_eq!(history.total_recorded, 1);
+    assert_eq!(history.entries[0].player, player.pubkey());
+    assert_eq!(history.entries[0].amount, bet.win_amount);
+    assert_eq!(history.entries[0].tier, 2);
+
+    let balance_before = h.balance(player.pubkey()).await;
+    h.claim_win(&player, bet_address).await.unwrap();
+    assert!(h.balance(player.pubkey()).await > balance_before);
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 4);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.pending_payouts, 0);
+
+    assert_casino_error(h.claim_win(&player, bet_address).await, CasinoError::NoWinToClaim);
+}
+
+#[tokio::test]
+async fn only_the_winner_can_claim() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let intruder = h.player(LAMPORTS_PER_SOL).await;
+
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    assert_casino_error(h.claim_win(&intruder, bet_address).await, CasinoError::Unauthorized);
+}
+
+#[tokio::test]
+async fn wins_go_to_the_designated_beneficiary() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let cold_wallet = solana_sdk::signature::Keypair::new().pubkey();
+
+    let set_beneficiary = ix(
+        accounts::SetPayoutBeneficiary {
+            config: config_pda(),
+            player_profile: player_profile_pda(&player.pubkey()),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::SetPayoutBeneficiary { beneficiary: Some(cold_wallet) },
+    );
+    h.send(&[set_beneficiary], &[&player]).await.unwrap();
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    // Leaving out the beneficiary can't route the win back to the hot wallet
+    let claim_to_player = ix(
+        accounts::ClaimWin {
+            config: config_pda(),
+            pool: pool_pda(),
+            bet: bet_address,
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            bonus_credit: None,
+            player_profile: player_profile_pda(&player.pubkey()),
+            beneficiary: None,
+            payout_split: payout_split_pda(&player.pubkey()),
+            player: player.pubkey(),
+        },
+        instruction::ClaimWin {},
+    );
+    assert_casino_error(h.send(&[claim_to_player], &[&player]).await, CasinoError::BeneficiaryMismatch);
+
+    let player_before = h.balance(player.pubkey()).await;
+    h.claim_win(&player, bet_address).await.unwrap();
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(h.balance(cold_wallet).await, bet.win_amount);
+    assert_eq!(h.balance(player.pubkey()).await, player_before);
+}
+
+#[tokio::test]
+async fn claimed_wins_are_divided_by_the_payout_split() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let partner = h.player(LAMPORTS_PER_SOL).await.pubkey();
+    let investor = h.player(LAMPORTS_PER_SOL).await.pubkey();
+
+    let set_split = |shares: Vec<SplitShare>| {
+        ix(
+            accounts::SetPayoutSplit {
+                config: config_pda(),
+                payout_split: payout_split_pda(&player.pubkey()),
+                player: player.pubkey(),
+                system_program: system_program::ID,
+            },
+            instruction::SetPayoutSplit { shares },
+        )
+    };
+    let over_allocated = vec![
+        SplitShare { recipient: partner, bps: 6000 },
+        SplitShare { recipient: investor, bps: 5000 },
+    ];
+    assert_casino_error(h.send(&[set_split(over_allocated)], &[&player]).await, CasinoError::InvalidPayoutSplit);
+
+    let shares = vec![
+        SplitShare { recipient: partner, bps: 3000 },
+        SplitShare { recipient: investor, bps: 2000 },
+    ];
+    h.send(&[set_split(shares)], &[&player]).await.unwrap();
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    // Every recipient must be passed, in order
+    let mut claim_out_of_order = ix(
+        accounts::ClaimWin {
+            config: config_pda(),
+            pool: pool_pda(),
+            bet: bet_address,
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            bonus_credit: None,
+            player_profile: player_profile_pda(&player.pubkey()),
+            beneficiary: None,
+            payout_split: payout_split_pda(&player.pubkey()),
+            player: player.pubkey(),
+        },
+        instruction::ClaimWin {},
+    );
+    claim_out_of_order.accounts.extend([AccountMeta::new(investor, false), AccountMeta::new(partner, false)]);
+    assert_casino_error(h.send(&[claim_out_of_order], &[&player]).await, CasinoError::SplitRecipientMismatch);
+
+    let player_before = h.balance(player.pubkey()).await;
+    let partner_before = h.balance(partner).await;
+    let investor_before = h.balance(investor).await;
+    h.claim_win(&player, bet_address).await.unwrap();
+
+    let win = h.account::<Bet>(bet_address).await.win_amount;
+    let partner_cut = win * 3000 / 10000;
+    let investor_cut = win * 2000 / 10000;
+    assert_eq!(h.balance(partner).await, partner_before + partner_cut);
+    assert_eq!(h.balance(investor).await, investor_before + investor_cut);
+    assert_eq!(h.balance(player.pubkey()).await, player_before + win - partner_cut - investor_cut);
+}
+
+#[tokio::test]
+async fn rejects_double_fulfillment() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+
+    h.fulfill(bet_address, vrf_with_roll(500)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 2);
+    assert!(bet.settled_at > 0);
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    assert_casino_error(
+        h.fulfill(bet_address, vrf_with_roll(60)).await,
+        CasinoError::VrfAlreadyFulfilled,
+    );
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, pool_before.balance);
+    assert_eq!(pool.pending_payouts, pool_before.pending_payouts);
+}
+
+#[tokio::test]
+async fn rejects_expired_vrf_request() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+
+    h.warp_forward(3601).await;
+
+    assert_casino_error(h.fulfill(bet_address, vrf_with_roll(60)).await, CasinoError::VrfTimeout);
+}
+
+#[tokio::test]
+async fn odds_ramp_widens_the_threshold_during_droughts() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+
+    h.update_config(&authority, ConfigUpdate {
+        odds_ramp_bps: Some(50),
+        odds_ramp_max_bps: Some(1000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    // Loses at the flat 100 bps, wins once the bet since the last win adds 50
+    h.fulfill(bet_address, vrf_with_roll(140)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 1);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.bets_since_win, 0);
+}
+
+#[tokio::test]
+async fn near_misses_earn_a_house_consolation() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    h.fund(&house_vault_pda(), LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        consolation_bps: Some(100),
+        consolation_amount: Some(LAMPORTS_PER_SOL / 100),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    h.fulfill(bet_address, vrf_with_roll(150)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 1);
+    assert_eq!(bet.win_amount, LAMPORTS_PER_SOL / 100);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, pool_before.balance);
+
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.total_consolation_payouts, bet.win_amount);
+
+    h.claim_win(&player, bet_address).await.unwrap();
+}
+
+#[tokio::test]
+async fn max_payout_clips_to_reserve_and_reseeds() {
+    let (mut h, _player, bet_address) = pending_bet(4 * LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+
+    h.update_config(&authority, ConfigUpdate {
+        max_payout: Some(LAMPORTS_PER_SOL),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    // Rare tier: the whole pool
+    h.fulfill(bet_address, vrf_with_roll(5)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.win_amount, LAMPORTS_PER_SOL);
+
+    // Excess went to the reserve and straight back after the grand win
+    let reserve: ReservePool = h.account(reserve_pool_pda()).await;
+    assert_eq!(reserve.total_reseeded, pool_before.balance - LAMPORTS_PER_SOL);
+    assert_eq!(reserve.balance, 0);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, reserve.total_reseeded);
+}
+
+#[tokio::test]
+async fn must_hit_by_forces_a_win() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+
+    h.update_config(&authority, ConfigUpdate {
+        must_hit_by: Some(LAMPORTS_PER_SOL / 2),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert!(pool.must_hit_armed);
+
+    h.fulfill(bet_address, vrf_with_roll(9999)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 1);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert!(!pool.must_hit_armed);
+}
+
+#[tokio::test]
+async fn large_wins_vest_in_tranches() {
+    let (mut h, player, bet_address) = pending_bet(12 * LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+
+    h.update_config(&authority, ConfigUpdate {
+        vesting_threshold: Some(1),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+    assert_casino_error(h.claim_win(&player, bet_address).await, CasinoError::WinRequiresVesting);
+
+    let claim = ix(
+        accounts::ClaimVestedWin {
+            config: config_pda(),
+            pool: pool_pda(),
+            bet: bet_address,
+            vesting: vesting_pda(&bet_address),
+            pending_payout: None,
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            bonus_credit: None,
+            player_profile: player_profile_pda(&player.pubkey()),
+            beneficiary: None,
+            payout_split: payout_split_pda(&player.pubkey()),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::ClaimVestedWin {},
+    );
+
+    h.send(&[claim.clone()], &[&player]).await.unwrap();
+    let vesting: WinVesting = h.account(vesting_pda(&bet_address)).await;
+    assert_eq!(vesting.claimed_amount, vesting.total_amount / VESTING_TRANCHES);
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.vesting_outstanding, vesting.total_amount - vesting.claimed_amount);
+
+    assert_casino_error(h.send(&[claim.clone()], &[&player]).await, CasinoError::NothingVested);
+
+    // Everything is released after the last tranche
+    h.warp_forward(VESTING_TRANCHE_INTERVAL * VESTING_TRANCHES as i64).await;
+    h.send(&[claim], &[&player]).await.unwrap();
+
+    let vesting: WinVesting = h.account(vesting_pda(&bet_address)).await;
+    assert_eq!(vesting.claimed_amount, vesting.total_amount);
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.vesting_outstanding, 0);
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 4);
+}
+
+fn claim_reviewed_win(player: &solana_sdk::signature::Keypair, bet: Pubkey) -> solana_sdk::instruction::Instruction {
+    ix(
+        accounts::ClaimReviewedWin {
+            config: config_pda(),
+            pool: pool_pda(),
+            bet,
+            pending_payout: pending_payout_pda(&bet),
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            bonus_credit: None,
+            player_profile: player_profile_pda(&player.pubkey()),
+            beneficiary: None,
+            payout_split: payout_split_pda(&player.pubkey()),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::ClaimReviewedWin {},
+    )
+}
+
+#[tokio::test]
+async fn large_wins_wait_for_approval_or_the_dispute_window() {
+    let (mut h, player, approved_bet) = pending_bet(12 * LAMPORTS_PER_SOL).await;
+    let unapproved_bet = h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+    let authority = h.authority();
+
+    h.update_config(&authority, ConfigUpdate {
+        manual_review_threshold: Some(1),
+        payout_dispute_window_secs: Some(3600),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    h.fulfill(approved_bet, vrf_with_roll(60)).await.unwrap();
+    h.fulfill(unapproved_bet, vrf_with_roll(60)).await.unwrap();
+    assert_casino_error(h.claim_win(&player, approved_bet).await, CasinoError::WinRequiresReview);
+
+    // The first claim holds the payout
+    for bet in [approved_bet, unapproved_bet] {
+        h.send(&[claim_reviewed_win(&player, bet)], &[&player]).await.unwrap();
+        let held: Bet = h.account(bet).await;
+        assert_eq!(held.status, 6);
+        let payout: PendingPayout = h.account(pending_payout_pda(&bet)).await;
+        assert_eq!(payout.amount, held.win_amount);
+    }
+    assert_casino_error(
+        h.send(&[claim_reviewed_win(&player, approved_bet)], &[&player]).await,
+        CasinoError::PayoutNotReleasable,
+    );
+
+    let approve = |approver: Pubkey| {
+        ix(
+            accounts::ApprovePayout {
+                config: config_pda(),
+                pending_payout: pending_payout_pda(&approved_bet),
+                approver,
+            },
+            instruction::ApprovePayout {},
+        )
+    };
+    assert_casino_error(h.send(&[approve(player.pubkey())], &[&player]).await, CasinoError::Unauthorized);
+    h.send(&[approve(authority.pubkey())], &[]).await.unwrap();
+
+    let balance_before = h.balance(player.pubkey()).await;
+    h.send(&[claim_reviewed_win(&player, approved_bet)], &[&player]).await.unwrap();
+    assert!(h.balance(player.pubkey()).await > balance_before);
+    let bet: Bet = h.account(approved_bet).await;
+    assert_eq!(bet.status, 4);
+
+    // Unapproved payouts release once the dispute window passes
+    assert_casino_error(
+        h.send(&[claim_reviewed_win(&player, unapproved_bet)], &[&player]).await,
+        CasinoError::PayoutNotReleasable,
+    );
+    h.warp_forward(3600).await;
+    h.send(&[claim_reviewed_win(&player, unapproved_bet)], &[&player]).await.unwrap();
+    let bet: Bet = h.account(unapproved_bet).await;
+    assert_eq!(bet.status, 4);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.pending_payouts, 0);
+}
+
+#[tokio::test]
+async fn reviewed_wins_above_the_vesting_threshold_still_vest() {
+    let (mut h, player, bet_address) = pending_bet(12 * LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+
+    h.update_config(&authority, ConfigUpdate {
+        manual_review_threshold: Some(1),
+        vesting_threshold: Some(1),
+        payout_dispute_window_secs: Some(3600),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+    h.send(&[claim_reviewed_win(&player, bet_address)], &[&player]).await.unwrap();
+
+    let claim_vested = ix(
+        accounts::ClaimVestedWin {
+            config: config_pda(),
+            pool: pool_pda(),
+            bet: bet_address,
+            vesting: vesting_pda(&bet_address),
+            pending_payout: Some(pending_payout_pda(&bet_address)),
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            bonus_credit: None,
+            player_profile: player_profile_pda(&player.pubkey()),
+            beneficiary: None,
+            payout_split: payout_split_pda(&player.pubkey()),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::ClaimVestedWin {},
+    );
+
+    // Vesting can't start before the review releases the win
+    assert_casino_error(h.send(&[claim_vested.clone()], &[&player]).await, CasinoError::PayoutNotReleasable);
+
+    // Once released, the full amount isn't paid at once
+    h.warp_forward(3600).await;
+    assert_casino_error(
+        h.send(&[claim_reviewed_win(&player, bet_address)], &[&player]).await,
+        CasinoError::WinRequiresVesting,
+    );
+
+    h.send(&[claim_vested], &[&player]).await.unwrap();
+    let vesting: WinVesting = h.account(vesting_pda(&bet_address)).await;
+    assert_eq!(vesting.claimed_amount, vesting.total_amount / VESTING_TRANCHES);
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 5);
+}
+
+#[tokio::test]
+async fn disputes_freeze_the_payout_until_resolved() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let open = ix(
+        accounts::OpenDispute {
+            config: config_pda(),
+            bet: bet_address,
+            dispute: dispute_pda(&bet_address),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::OpenDispute { reason: 1 },
+    );
+    h.send(&[open], &[&player]).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert!(bet.disputed);
+    assert_casino_error(h.claim_win(&player, bet_address).await, CasinoError::BetDisputed);
+
+    let resolve = |authority: Pubkey| {
+        ix(
+            accounts::ResolveDispute {
+                config: config_pda(),
+                bet: bet_address,
+                dispute: dispute_pda(&bet_address),
+                authority,
+            },
+            instruction::ResolveDispute { upheld: false, resolution_hash: [7; 32] },
+        )
+    };
+    assert_casino_error(h.send(&[resolve(player.pubkey())], &[&player]).await, CasinoError::Unauthorized);
+    h.send(&[resolve(authority.pubkey())], &[]).await.unwrap();
+    assert_casino_error(h.send(&[resolve(authority.pubkey())], &[]).await, CasinoError::DisputeNotOpen);
+
+    let dispute: Dispute = h.account(dispute_pda(&bet_address)).await;
+    assert_eq!(dispute.status, DISPUTE_REJECTED);
+    assert_eq!(dispute.resolution_hash, [7; 32]);
+
+    h.claim_win(&player, bet_address).await.unwrap();
+}
+
+#[tokio::test]
+async fn batch_settles_every_group() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(10 * LAMPORTS_PER_SOL).await;
+
+    let mut remaining = Vec::new();
+    for amount in [LAMPORTS_PER_SOL, 2 * LAMPORTS_PER_SOL] {
+        let bet = h.contribute_bet(&player, amount).await.unwrap();
+        remaining.push(AccountMeta::new(bet, false));
+        remaining.push(AccountMeta::new(vrf_request_pda(&bet), false));
+        remaining.push(AccountMeta::new(player_profile_pda(&player.pubkey()), false));
+    }
+
+    let batch = |settler: Pubkey, vrf_result: [u8; 32]| {
+        let mut batch = ix(
+            accounts::FulfillJackpotsBatch {
+                config: config_pda(),
+                pool: pool_pda(),
+                reserve_pool: reserve_pool_pda(),
+                house_vault: house_vault_pda(),
+                win_history: win_history_pda(),
+                notification_queue: notification_queue_pda(),
+                bankroll: None,
+                stats: None,
+                volume_bucket: None,
+                side_bet_pool: None,
+                keeper: None,
+                keeper_authority: None,
+                settler,
+                system_program: system_program::ID,
+            },
+            instruction::FulfillJackpotsBatch { vrf_result },
+        );
+        batch.accounts.extend(remaining.iter().cloned());
+        batch
+    };
+
+    // Players can't settle their own bets with a result they chose
+    assert_casino_error(
+        h.send(&[batch(player.pubkey(), [0u8; 32])], &[&player]).await,
+        CasinoError::Unauthorized,
+    );
+
+    h.send(&[batch(h.authority().pubkey(), [7u8; 32])], &[]).await.unwrap();
+
+    for group in remaining.chunks(3) {
+        let bet: Bet = h.account(group[0].pubkey).await;
+        assert!(bet.status == 1 || bet.status == 2);
+    }
+}
+
+#[tokio::test]
+async fn batch_rejects_malformed_groups() {
+    let mut h = Harness::new().await;
+
+    let mut batch = ix(
+        accounts::FulfillJackpotsBatch {
+            config: config_pda(),
+            pool: pool_pda(),
+            reserve_pool: reserve_pool_pda(),
+            house_vault: house_vault_pda(),
+            win_history: win_history_pda(),
+            notification_queue: notification_queue_pda(),
+            bankroll: None,
+            stats: None,
+            volume_bucket: None,
+            side_bet_pool: None,
+            keeper: None,
+            keeper_authority: None,
+            settler: h.authority().pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::FulfillJackpotsBatch { vrf_result: [0u8; 32] },
+    );
+    batch.accounts.push(AccountMeta::new(pool_pda(), false));
+
+    assert_casino_error(h.send(&[batch], &[]).await, CasinoError::InvalidBatchAccounts);
+}
+
+#[tokio::test]
+async fn bootstrap_wins_are_paid_by_the_house() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    h.fund(&house_vault_pda(), 5 * LAMPORTS_PER_SOL).await;
+
+    let ix = ix(
+        accounts::SetBootstrap {
+            config: config_pda(),
+            authority: h.authority().pubkey(),
+        },
+        instruction::SetBootstrap {
+            threshold: 100 * LAMPORTS_PER_SOL,
+            payout_multiplier: 2,
+        },
+    );
+    h.send(&[ix], &[]).await.unwrap();
+
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.win_amount, 2 * LAMPORTS_PER_SOL);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, pool_before.balance);
+    assert_eq!(pool.pending_payouts, bet.win_amount);
+
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.total_bootstrap_payouts, bet.win_amount);
+}
+
+#[tokio::test]
+async fn a_short_house_pays_bootstrap_wins_from_the_pool_and_warns() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+
+    let ix = ix(
+        accounts::SetBootstrap {
+            config: config_pda(),
+            authority: h.authority().pubkey(),
+        },
+        instruction::SetBootstrap {
+            threshold: 100 * LAMPORTS_PER_SOL,
+            payout_multiplier: 2,
+        },
+    );
+    h.send(&[ix], &[]).await.unwrap();
+
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    // The unfunded house can't pay 2x, so the bet wins its normal share of the pool
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.win_amount, pool_before.balance / 4);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.total_bootstrap_payouts, 0);
+
+    let queue: NotificationQueue = h.zero_copy(notification_queue_pda()).await;
+    assert_eq!(queue.entries[1].kind, NOTIFICATION_SOLVENCY_WARNING);
+    assert_eq!((queue.entries[1].subject, queue.entries[1].amount), (house_vault_pda(), 2 * LAMPORTS_PER_SOL));
+}
+
+#[tokio::test]
+async fn pools_settle_with_their_own_limits_and_odds() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+
+    let gold = pool_pda_with_id(1);
+    let create_pool = ix(
+        accounts::CreatePool {
+            config: config_pda(),
+            pool: gold,
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::CreatePool {
+            pool_id: 1,
+            params: PoolParams {
+                min_bet: 2 * LAMPORTS_PER_SOL,
+                win_probability_bps: 6000,
+                ..PoolParams::default()
+            },
+        },
+    );
+    h.send(&[create_pool], &[]).await.unwrap();
+
+    let seed_gold = ix(
+        accounts::SeedJackpot {
+            config: config_pda(),
+            pool: gold,
+            donor: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::SeedJackpot { amount: 4 * LAMPORTS_PER_SOL },
+    );
+    h.send(&[seed_gold], &[]).await.unwrap();
+
+    let player = h.player(10 * LAMPORTS_PER_SOL).await;
+    assert_casino_error(
+        h.contribute_bet_in_pool(&player, 1, LAMPORTS_PER_SOL).await,
+        CasinoError::BetTooSmall,
+    );
+
+    let bet_address = h.contribute_bet_in_pool(&player, 1, 2 * LAMPORTS_PER_SOL).await.unwrap();
+    let main_before: JackpotPool = h.account(pool_pda()).await;
+    let gold_before: JackpotPool = h.account(gold).await;
+
+    // Loses at the config-wide 1% odds, wins at the gold pool's 60%
+    h.fulfill(bet_address, vrf_with_roll(5000)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.pool, gold);
+    assert_eq!(bet.status, 1);
+    assert_eq!(bet.win_amount, gold_before.balance / 4);
+
+    let main: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(main.balance, main_before.balance);
+    assert_eq!(main.pending_payouts, 0);
+
+    // Claims must come out of the bet's own pool
+    let claim_from_main = ix(
+        accounts::ClaimWin {
+            config: config_pda(),
+            pool: pool_pda(),
+            bet: bet_address,
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            bonus_credit: None,
+            player_profile: player_profile_pda(&player.pubkey()),
+            beneficiary: None,
+            payout_split: payout_split_pda(&player.pubkey()),
+            player: player.pubkey(),
+        },
+        instruction::ClaimWin {},
+    );
+    assert_casino_error(h.send(&[claim_from_main], &[&player]).await, CasinoError::PoolMismatch);
+
+    h.claim_win(&player, bet_address).await.unwrap();
+    let gold_pool: JackpotPool = h.account(gold).await;
+    assert_eq!(gold_pool.pending_payouts, 0);
+}
+
+/// Turn draws on at 5% of each bet and open round `round_id` for a day
+async fn open_daily_draw(h: &mut Harness, round_id: u64) {
+    let authority = h.authority();
+    h.update_config(&authority, ConfigUpdate {
+        draw_bps: Some(500),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let now = h.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let open = ix(
+        accounts::OpenDrawRound {
+            config: config_pda(),
+            draw_round: draw_round_pda(round_id),
+            signer: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::OpenDrawRound { round_id, draw_end_ts: now + 86400 },
+    );
+    h.send(&[open], &[]).await.unwrap();
+}
+
+/// VRF result whose first 8 bytes (LE) equal `value`
+fn vrf_with_value(value: u64) -> [u8; 32] {
+    let mut vrf_result = [0u8; 32];
+    vrf_result[..8].copy_from_slice(&value.to_le_bytes());
+    vrf_result
+}
+
+fn settle_draw(settler: Pubkey, round_id: u64, winner: Pubkey, winner_ticket: Option<Pubkey>, vrf_result: [u8; 32]) -> solana_sdk::instruction::Instruction {
+    ix(
+        accounts::SettleDraw {
+            config: config_pda(),
+            house_vault: house_vault_pda(),
+            draw_round: draw_round_pda(round_id),
+            winner,
+            winner_ticket,
+            keeper: None,
+            keeper_authority: None,
+            settler,
+        },
+        instruction::SettleDraw { vrf_result },
+    )
+}
+
+#[tokio::test]
+async fn draw_round_pays_a_winner_weighted_by_wager() {
+    let mut h = Harness::new().await;
+    let small = h.player(5 * LAMPORTS_PER_SOL).await;
+    let large = h.player(5 * LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+
+    // The draw share counts toward the same cap as the jackpot, house and DeFi shares
+    assert_casino_error(
+        h.update_config(&authority, ConfigUpdate {
+            draw_bps: Some(10000 - 800 + 1),
+            ..ConfigUpdate::default()
+        })
+        .await,
+        CasinoError::InvalidConfig,
+    );
+
+    h.update_config(&authority, ConfigUpdate {
+        draw_bps: Some(500),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    // With no round open, bets still go through and keep the draw share in the wager
+    let between_rounds = h.contribute_bet(&small, LAMPORTS_PER_SOL / 2).await.unwrap();
+    let bet: Bet = h.account(between_rounds).await;
+    assert_eq!(bet.escrowed, LAMPORTS_PER_SOL / 2 * 92 / 100);
+
+    open_daily_draw(&mut h, 1).await;
+    h.contribute_bet_in_draw(&small, 1, LAMPORTS_PER_SOL).await.unwrap();
+    h.contribute_bet_in_draw(&large, 1, 3 * LAMPORTS_PER_SOL).await.unwrap();
+
+    let round: DrawRound = h.zero_copy(draw_round_pda(1)).await;
+    assert_eq!(round.entry_count, 2);
+    assert_eq!(round.total_weight, 4 * LAMPORTS_PER_SOL);
+    assert_eq!(round.prize, 4 * LAMPORTS_PER_SOL / 20);
+
+    // Weight ranges: small [0, 1 SOL), large [1 SOL, 4 SOL)
+    let vrf_result = vrf_with_value(2 * LAMPORTS_PER_SOL);
+
+    assert_casino_error(
+        h.send(&[settle_draw(authority.pubkey(), 1, large.pubkey(), None, vrf_result)], &[]).await,
+        CasinoError::DrawNotEnded,
+    );
+    h.warp_forward(86400).await;
+    assert_casino_error(
+        h.send(&[settle_draw(authority.pubkey(), 1, small.pubkey(), None, vrf_result)], &[]).await,
+        CasinoError::InvalidDrawWinner,
+    );
+    // Entrants can't pick the VRF value themselves
+    assert_casino_error(
+        h.send(&[settle_draw(small.pubkey(), 1, small.pubkey(), None, vrf_with_value(0))], &[&small]).await,
+        CasinoError::Unauthorized,
+    );
+
+    let before = h.balance(large.pubkey()).await;
+    h.send(&[settle_draw(authority.pubkey(), 1, large.pubkey(), None, vrf_result)], &[]).await.unwrap();
+    assert_eq!(h.balance(large.pubkey()).await, before + round.prize);
+
+    let round: DrawRound = h.zero_copy(draw_round_pda(1)).await;
+    assert_eq!(round.winner, large.pubkey());
+    assert_casino_error(
+        h.send(&[settle_draw(authority.pubkey(), 1, large.pubkey(), None, vrf_result)], &[]).await,
+        CasinoError::DrawAlreadySettled,
+    );
+}
+
+#[tokio::test]
+async fn ticketed_draw_entry_pays_the_ticket_holder() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    open_daily_draw(&mut h, 1).await;
+
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let buyer = h.player(LAMPORTS_PER_SOL).await;
+    h.contribute_bet_in_draw(&player, 1, LAMPORTS_PER_SOL).await.unwrap();
+
+    let mint = draw_ticket_mint_pda(&draw_round_pda(1), 0);
+    let ticket = draw_ticket_account_pda(&mint);
+    let mint_ticket = ix(
+        accounts::MintDrawTicket {
+            config: config_pda(),
+            draw_round: draw_round_pda(1),
+            ticket_mint: mint,
+            ticket_account: ticket,
+            player: player.pubkey(),
+            token_program: spl_token::ID,
+            system_program: system_program::ID,
+        },
+        instruction::MintDrawTicket { entry_index: 0 },
+    );
+    h.send(&[mint_ticket], &[&player]).await.unwrap();
+
+    let round: DrawRound = h.zero_copy(draw_round_pda(1)).await;
+    assert_eq!(round.entries[0].ticket_mint, mint);
+
+    // Hand the ticket to the buyer
+    let transfer = spl_token::instruction::set_authority(
+        &spl_token::ID,
+        &ticket,
+        Some(&buyer.pubkey()),
+        spl_token::instruction::AuthorityType::AccountOwner,
+        &player.pubkey(),
+        &[],
+    )
+    .unwrap();
+    h.send(&[transfer], &[&player]).await.unwrap();
+
+    // Later bets by the original player open a fresh, untransferred entry
+    h.contribute_bet_in_draw(&player, 1, 2 * LAMPORTS_PER_SOL).await.unwrap();
+    let round: DrawRound = h.zero_copy(draw_round_pda(1)).await;
+    assert_eq!(round.entry_count, 2);
+
+    h.warp_forward(86400).await;
+    let vrf_result = vrf_with_value(0);
+    assert_casino_error(
+        h.send(&[settle_draw(authority.pubkey(), 1, player.pubkey(), None, vrf_result)], &[]).await,
+        CasinoError::InvalidDrawTicket,
+    );
+    assert_casino_error(
+        h.send(&[settle_draw(authority.pubkey(), 1, player.pubkey(), Some(ticket), vrf_result)], &[]).await,
+        CasinoError::InvalidDrawWinner,
+    );
+
+    let before = h.balance(buyer.pubkey()).await;
+    h.send(&[settle_draw(authority.pubkey(), 1, buyer.pubkey(), Some(ticket), vrf_result)], &[]).await.unwrap();
+    assert_eq!(h.balance(buyer.pubkey()).await, before + round.prize);
+}
+
+#[tokio::test]
+async fn community_split_pays_recent_bettors() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    h.seed_jackpot(4 * LAMPORTS_PER_SOL).await.unwrap();
+    h.update_config(&authority, ConfigUpdate {
+        community_share_bps: Some(5000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let first = h.player(5 * LAMPORTS_PER_SOL).await;
+    let second = h.player(5 * LAMPORTS_PER_SOL).await;
+    let winner = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.contribute_bet(&first, LAMPORTS_PER_SOL).await.unwrap();
+    h.contribute_bet(&second, LAMPORTS_PER_SOL).await.unwrap();
+    let bet_address = h.contribute_bet(&winner, LAMPORTS_PER_SOL).await.unwrap();
+
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    // Half of the common-tier win is split between the two other bettors
+    let total_win = pool_before.balance / 4;
+    let share = total_win / 2 / 2;
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.win_amount, total_win - 2 * share);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.community_share, share);
+    assert_eq!(pool.community_unpaid, 0b11);
+    assert_eq!(pool.pending_payouts, total_win);
+
+    let claim = |recipients: &[Pubkey]| {
+        let mut claim = ix(accounts::ClaimCommunityShare { pool: pool_pda() }, instruction::ClaimCommunityShare {});
+        claim.accounts.extend(recipients.iter().map(|r| AccountMeta::new(*r, false)));
+        claim
+    };
+
+    assert_casino_error(h.send(&[claim(&[winner.pubkey()])], &[]).await, CasinoError::NoCommunityShare);
+
+    let before = h.balance(second.pubkey()).await;
+    h.send(&[claim(&[first.pubkey(), second.pubkey()])], &[]).await.unwrap();
+    assert_eq!(h.balance(second.pubkey()).await, before + share);
+    assert_casino_error(h.send(&[claim(&[first.pubkey()])], &[]).await, CasinoError::NoCommunityShare);
+
+    h.claim_win(&winner, bet_address).await.unwrap();
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.community_unpaid, 0);
+    assert_eq!(pool.pending_payouts, 0);
+}
+
+#[tokio::test]
+async fn season_leaderboard_pays_top_wagerers() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let now = h.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let create = ix(
+        accounts::CreateSeason {
+            config: config_pda(),
+            season: season_pda(1),
+            authority: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::CreateSeason {
+            season_id: 1,
+            start_ts: now,
+            end_ts: now + 86400,
+            fee_bps: 5000,
+            payout_bps: vec![6000, 3000],
+        },
+    );
+    h.send(&[create], &[]).await.unwrap();
+
+    let first = h.player(5 * LAMPORTS_PER_SOL).await;
+    let second = h.player(5 * LAMPORTS_PER_SOL).await;
+    let third = h.player(5 * LAMPORTS_PER_SOL).await;
+    let in_season = || BetRouting { season: Some(season_pda(1)), ..BetRouting::default() };
+    h.contribute_bet_with(&first, LAMPORTS_PER_SOL, in_season()).await.unwrap();
+    h.contribute_bet_with(&first, 2 * LAMPORTS_PER_SOL, in_season()).await.unwrap();
+    h.contribute_bet_with(&second, LAMPORTS_PER_SOL, in_season()).await.unwrap();
+    h.contribute_bet_with(&third, LAMPORTS_PER_SOL / 2, in_season()).await.unwrap();
+
+    // Half of the 2% house fee funds the season
+    let season: Season = h.zero_copy(season_pda(1)).await;
+    let prize_pool = 9 * LAMPORTS_PER_SOL / 2 / 100;
+    assert_eq!(season.prize_pool, prize_pool);
+    assert_eq!(season.entry_count, 3);
+    assert_eq!(season.leaderboard[0].player, first.pubkey());
+    assert_eq!(season.leaderboard[0].wagered, 3 * LAMPORTS_PER_SOL);
+    assert_eq!(season.leaderboard[1].player, second.pubkey());
+
+    let finalize = |winners: &[Pubkey]| {
+        let mut finalize = ix(
+            accounts::FinalizeSeason { season: season_pda(1), house_vault: house_vault_pda() },
+            instruction::FinalizeSeason {},
+        );
+        finalize.accounts.extend(winners.iter().map(|w| AccountMeta::new(*w, false)));
+        finalize
+    };
+
+    let winners = [first.pubkey(), second.pubkey()];
+    assert_casino_error(h.send(&[finalize(&winners)], &[]).await, CasinoError::SeasonNotEnded);
+    h.warp_forward(86400).await;
+    assert_casino_error(
+        h.send(&[finalize(&[second.pubkey(), first.pubkey()])], &[]).await,
+        CasinoError::InvalidSeasonWinners,
+    );
+
+    let first_before = h.balance(first.pubkey()).await;
+    let second_before = h.balance(second.pubkey()).await;
+    let house_before = h.balance(house_vault_pda()).await;
+    h.send(&[finalize(&winners)], &[]).await.unwrap();
+    assert_eq!(h.balance(first.pubkey()).await, first_before + prize_pool * 6000 / 10000);
+    assert_eq!(h.balance(second.pubkey()).await, second_before + prize_pool * 3000 / 10000);
+    assert_eq!(h.balance(house_vault_pda()).await, house_before + prize_pool / 10);
+
+    assert_casino_error(h.send(&[finalize(&winners)], &[]).await, CasinoError::SeasonAlreadyFinalized);
+}
+
+#[tokio::test]
+async fn bonus_winnings_unlock_after_wagering() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    h.seed_jackpot(4 * LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(LAMPORTS_PER_SOL).await;
+
+    let grant = ix(
+        accounts::GrantBonus {
+            config: config_pda(),
+            bonus_credit: bonus_credit_pda(&player.pubkey()),
+            signer: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::GrantBonus {
+            player: player.pubkey(),
+            amount: 2 * LAMPORTS_PER_SOL,
+            wagering_requirement: 3 * LAMPORTS_PER_SOL / 2,
+        },
+    );
+    h.send(&[grant], &[]).await.unwrap();
+
+    let with_bonus = BetRouting { use_bonus: true, ..BetRouting::default() };
+    assert_casino_error(
+        h.contribute_bet_with(&player, 3 * LAMPORTS_PER_SOL, with_bonus).await,
+        CasinoError::InsufficientBonusCredit,
+    );
+
+    let with_bonus = BetRouting { use_bonus: true, ..BetRouting::default() };
+    let bet_address = h.contribute_bet_with(&player, LAMPORTS_PER_SOL, with_bonus).await.unwrap();
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    // Winnings from a bonus bet land in the bonus balance, not the wallet
+    let wallet = h.balance(player.pubkey()).await;
+    h.claim_win(&player, bet_address).await.unwrap();
+    let bet: Bet = h.account(bet_address).await;
+    let bonus: BonusCredit = h.account(bonus_credit_pda(&player.pubkey())).await;
+    assert_eq!(h.balance(player.pubkey()).await, wallet);
+    assert_eq!(bonus.balance, LAMPORTS_PER_SOL + bet.win_amount);
+    assert_eq!(bonus.wagering_remaining, LAMPORTS_PER_SOL / 2);
+
+    let withdraw = ix(
+        accounts::WithdrawBonus {
+            config: config_pda(),
+            bonus_credit: bonus_credit_pda(&player.pubkey()),
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            player: player.pubkey(),
+        },
+        instruction::WithdrawBonus {},
+    );
+    assert_casino_error(h.send(&[withdraw.clone()], &[&player]).await, CasinoError::BonusWageringIncomplete);
+
+    let with_bonus = BetRouting { use_bonus: true, ..BetRouting::default() };
+    h.contribute_bet_with(&player, LAMPORTS_PER_SOL / 2, with_bonus).await.unwrap();
+    let bonus: BonusCredit = h.account(bonus_credit_pda(&player.pubkey())).await;
+    assert_eq!(bonus.wagering_remaining, 0);
+
+    let wallet = h.balance(player.pubkey()).await;
+    h.send(&[withdraw], &[&player]).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, wallet + bonus.balance);
+}
+
+#[tokio::test]
+async fn promo_claims_verify_merkle_proofs_once() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let alice = h.player(LAMPORTS_PER_SOL).await;
+    let bob = h.player(LAMPORTS_PER_SOL).await;
+
+    // Two-leaf tree: each leaf's proof is the other leaf
+    let leaf = |player: &Pubkey, amount: u64| hashv(&[player.as_ref(), &amount.to_le_bytes()]).to_bytes();
+    let alice_leaf = leaf(&alice.pubkey(), LAMPORTS_PER_SOL / 2);
+    let bob_leaf = leaf(&bob.pubkey(), LAMPORTS_PER_SOL / 4);
+    let (lo, hi) = if alice_leaf <= bob_leaf { (alice_leaf, bob_leaf) } else { (bob_leaf, alice_leaf) };
+    let merkle_root = hashv(&[&lo, &hi]).to_bytes();
+
+    let campaign = promo_campaign_pda(1);
+    let set_root = ix(
+        accounts::SetPromoMerkleRoot {
+            config: config_pda(),
+            campaign,
+            signer: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::SetPromoMerkleRoot { campaign_id: 1, merkle_root, funding: LAMPORTS_PER_SOL },
+    );
+    h.send(&[set_root], &[]).await.unwrap();
+
+    let claim = |player: &Pubkey, amount: u64, proof: Vec<[u8; 32]>| {
+        ix(
+            accounts::ClaimPromo {
+                config: config_pda(),
+                campaign,
+                promo_claim: promo_claim_pda(&campaign, player),
+                frozen_player: frozen_player_pda(player),
+                player: *player,
+                system_program: system_program::ID,
+            },
+            instruction::ClaimPromo { amount, proof },
+        )
+    };
+
+    assert_casino_error(
+        h.send(&[claim(&alice.pubkey(), LAMPORTS_PER_SOL, vec![bob_leaf])], &[&alice]).await,
+        CasinoError::InvalidPromoProof,
+    );
+
+    let before = h.balance(alice.pubkey()).await;
+    h.send(&[claim(&alice.pubkey(), LAMPORTS_PER_SOL / 2, vec![bob_leaf])], &[&alice]).await.unwrap();
+    let receipt_rent = h.balance(promo_claim_pda(&campaign, &alice.pubkey())).await;
+    assert_eq!(h.balance(alice.pubkey()).await, before + LAMPORTS_PER_SOL / 2 - receipt_rent);
+
+    // The receipt PDA already exists, so a second claim fails
+    assert!(h.send(&[claim(&alice.pubkey(), LAMPORTS_PER_SOL / 2, vec![bob_leaf])], &[&alice]).await.is_err());
+
+    h.send(&[claim(&bob.pubkey(), LAMPORTS_PER_SOL / 4, vec![alice_leaf])], &[&bob]).await.unwrap();
+    let campaign: PromoCampaign = h.account(campaign).await;
+    assert_eq!(campaign.claimed, 3 * LAMPORTS_PER_SOL / 4);
+}
+
+#[tokio::test]
+async fn exposure_limit_counts_the_bankroll() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.seed_jackpot(4 * LAMPORTS_PER_SOL).await.unwrap();
+    h.update_config(&authority, ConfigUpdate {
+        max_exposure_bps: Some(5000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    // A full-jackpot win (4 SOL) exceeds half of the 4 SOL pool
+    assert_casino_error(h.contribute_bet(&player, LAMPORTS_PER_SOL).await, CasinoError::ExposureLimitExceeded);
+
+    let fund = ix(
+        accounts::FundBankroll {
+            config: config_pda(),
+            bankroll: bankroll_pda(),
+            signer: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::FundBankroll { amount: 4 * LAMPORTS_PER_SOL },
+    );
+    h.send(&[fund], &[]).await.unwrap();
+    let bet_address = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+
+    // Pulling capital out leaves the pending bet over the limit at settlement
+    let withdraw = ix(
+        accounts::WithdrawBankroll {
+            config: config_pda(),
+            bankroll: bankroll_pda(),
+            treasurer: authority.pubkey(),
+        },
+        instruction::WithdrawBankroll { amount: LAMPORTS_PER_SOL },
+    );
+    h.send(&[withdraw], &[]).await.unwrap();
+    assert_casino_error(h.fulfill(bet_address, vrf_with_roll(9999)).await, CasinoError::ExposureLimitExceeded);
+
+    let bankroll: Bankroll = h.account(bankroll_pda()).await;
+    assert_eq!(bankroll.balance, 3 * LAMPORTS_PER_SOL);
+    assert_eq!(bankroll.total_withdrawn, LAMPORTS_PER_SOL);
+}
+
+#[tokio::test]
+async fn registered_keepers_earn_fees_for_settling() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    let keeper = h.player(LAMPORTS_PER_SOL).await;
+    let fee = LAMPORTS_PER_SOL / 200;
+
+    h.update_config(&authority, ConfigUpdate {
+        keeper_fee: Some(fee),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let register = ix(
+        accounts::RegisterKeeper {
+            config: config_pda(),
+            keeper: keeper_pda(&keeper.pubkey()),
+            authority: keeper.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::RegisterKeeper {},
+    );
+    h.send(&[register], &[&keeper]).await.unwrap();
+
+    let fulfill = |settler: Pubkey, keeper_authority: Pubkey| ix(
+        accounts::FulfillJackpot {
+            config: config_pda(),
+            pool: pool_pda(),
+            reserve_pool: reserve_pool_pda(),
+            house_vault: house_vault_pda(),
+            bet: bet_address,
+            vrf_request: vrf_request_pda(&bet_address),
+            player_profile: player_profile_pda(&player.pubkey()),
+            win_history: win_history_pda(),
+            notification_queue: notification_queue_pda(),
+            bankroll: None,
+            stats: None,
+            volume_bucket: None,
+            side_bet_pool: None,
+            keeper: Some(keeper_pda(&keeper.pubkey())),
+            keeper_authority: Some(keeper_authority),
+            settler,
+            system_program: system_program::ID,
+        },
+        instruction::FulfillJackpot { vrf_result: vrf_with_roll(500) },
+    );
+
+    // Only the operator-run crank settles, so a registration alone earns nothing
+    assert_casino_error(
+        h.send(&[fulfill(keeper.pubkey(), keeper.pubkey())], &[&keeper]).await,
+        CasinoError::Unauthorized,
+    );
+
+    let roles = ix(
+        accounts::SetRoles {
+            config: config_pda(),
+            authority: authority.pubkey(),
+        },
+        instruction::SetRoles { treasurer: None, operator: Some(keeper.pubkey()), risk_manager: None, payout_approver: None },
+    );
+    h.send(&[roles], &[]).await.unwrap();
+
+    // Another wallet can't collect on the keeper's crank
+    let impostor = h.player(LAMPORTS_PER_SOL).await;
+    assert_casino_error(
+        h.send(&[fulfill(keeper.pubkey(), impostor.pubkey())], &[&keeper, &impostor]).await,
+        CasinoError::InvalidKeeper,
+    );
+
+    let house_before: HouseVault = h.account(house_vault_pda()).await;
+    let keeper_balance = h.balance(keeper.pubkey()).await;
+    h.send(&[fulfill(keeper.pubkey(), keeper.pubkey())], &[&keeper]).await.unwrap();
+
+    assert_eq!(h.balance(keeper.pubkey()).await, keeper_balance + fee);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, house_before.accumulated_house_fees - fee);
+
+    let registration: Keeper = h.account(keeper_pda(&keeper.pubkey())).await;
+    assert_eq!(registration.cranks, 1);
+    assert_eq!(registration.total_earned, fee);
+}
+
+#[tokio::test]
+async fn expired_bets_are_refunded_and_closed() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    let caller = h.player(LAMPORTS_PER_SOL).await;
+    h.fund(&game_vault_pda(), 2 * LAMPORTS_PER_SOL).await;
+
+    h.update_config(&authority, ConfigUpdate {
+        expired_bet_bounty_bps: Some(1000),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    let mut sweep = ix(
+        accounts::SweepExpiredBets {
+            config: config_pda(),
+            game_vault: game_vault_pda(),
+            house_vault: house_vault_pda(),
+            keeper: None,
+            keeper_authority: None,
+            caller: caller.pubkey(),
+        },
+        instruction::SweepExpiredBets {},
+    );
+    sweep.accounts.extend([
+        AccountMeta::new(bet_address, false),
+        AccountMeta::new(vrf_request_pda(&bet_address), false),
+        AccountMeta::new(player.pubkey(), false),
+    ]);
+
+    assert_casino_error(h.send(&[sweep.clone()], &[&caller]).await, CasinoError::BetNotExpired);
+
+    h.warp_forward(3601).await;
+
+    // Only the game leg is escrowed; the jackpot, house and DeFi legs already left
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.escrowed, LAMPORTS_PER_SOL * 92 / 100);
+
+    let rent = h.balance(bet_address).await + h.balance(vrf_request_pda(&bet_address)).await;
+    let bounty = rent / 10;
+    let player_before = h.balance(player.pubkey()).await;
+    let caller_before = h.balance(caller.pubkey()).await;
+    h.send(&[sweep], &[&caller]).await.unwrap();
+
+    assert_eq!(h.balance(player.pubkey()).await, player_before + bet.escrowed + rent - bounty);
+    assert_eq!(h.balance(caller.pubkey()).await, caller_before + bounty);
+    assert_eq!(h.balance(bet_address).await, 0);
+    assert_eq!(h.balance(vrf_request_pda(&bet_address)).await, 0);
+}
+
+#[tokio::test]
+async fn fulfill_returns_the_outcome_as_return_data() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    let fulfill = h.fulfill_ix(bet_address, vrf_with_roll(60)).await;
+    let data = h.send_for_return_data(&[fulfill], &[]).await.unwrap();
+    let result = SettlementResult::try_from_slice(&data).unwrap();
+
+    assert!(result.is_win);
+    assert_eq!(result.win_amount, pool_before.balance / 4);
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(result.new_pool_balance, pool.balance);
+
+    let get_pool_state = ix(
+        accounts::GetPoolState { config: config_pda(), pool: pool_pda() },
+        instruction::GetPoolState {},
+    );
+    let data = h.send_for_return_data(&[get_pool_state], &[]).await.unwrap();
+    let state = PoolState::try_from_slice(&data).unwrap();
+    assert_eq!(state.balance, pool.balance);
+    assert_eq!(state.pending_payouts, result.win_amount);
+}
+
+#[tokio::test]
+async fn fulfill_rejects_a_vrf_request_from_another_bet() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let other_bet = h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+
+    let mut fulfill = h.fulfill_ix(bet_address, vrf_with_roll(9_999)).await;
+    let vrf_request = vrf_request_pda(&bet_address);
+    for meta in fulfill.accounts.iter_mut().filter(|meta| meta.pubkey == vrf_request) {
+        meta.pubkey = vrf_request_pda(&other_bet);
+    }
+    assert_casino_error(h.send(&[fulfill], &[]).await, CasinoError::VrfRequestBetMismatch);
+
+    h.fulfill(bet_address, vrf_with_roll(9_999)).await.unwrap();
+}
+
+#[tokio::test]
+async fn settlement_waits_for_the_configured_slot_delay() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+
+    h.update_config(&authority, ConfigUpdate {
+        min_settle_delay_slots: Some(5),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    assert_casino_error(
+        h.fulfill(bet_address, vrf_with_roll(9_999)).await,
+        CasinoError::SettlementTooEarly,
+    );
+
+    h.warp_slots(4).await;
+    h.fulfill(bet_address, vrf_with_roll(9_999)).await.unwrap();
+}
+
+#[tokio::test]
+async fn bets_settle_against_odds_frozen_at_placement() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.win_probability_bps, 100);
+    assert_eq!(bet.odds_mode, ODDS_MODE_FLAT);
+    assert_eq!(bet.tier_multipliers_bps, TIER_MULTIPLIERS_BPS);
+
+    // Tighter odds after placement don't apply to the pending bet
+    h.update_config(&authority, ConfigUpdate {
+        win_probability_bps: Some(10),
+        odds_mode: Some(ODDS_MODE_SCALED),
+        ..ConfigUpdate::default()
+    })
+    .await
+    .unwrap();
+
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 1);
+    assert_eq!(bet.win_amount, pool_before.balance / 4);
+}
+
+#[tokio::test]
+async fn sunset_mode_settles_open_bets_before_decommissioning() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    let beneficiary = Pubkey::new_unique();
+
+    let set_shutdown = ix(
+        accounts::SetShutdown {
+            config: config_pda(),
+            notification_queue: notification_queue_pda(),
+            authority: authority.pubkey(),
+        },
+        instruction::SetShutdown { shutdown: true, beneficiary },
+    );
+    h.send(&[set_shutdown], &[]).await.unwrap();
+    let queue: NotificationQueue = h.zero_copy(notification_queue_pda()).await;
+    assert_eq!(queue.entries[1].kind, NOTIFICATION_PAUSE);
+    assert_casino_error(h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await, CasinoError::CasinoShutdown);
+
+    let decommission = ix(
+        accounts::Decommission { config: config_pda(), pool: pool_pda(), beneficiary },
+        instruction::Decommission {},
+    );
+    assert_casino_error(h.send(&[decommission.clone()], &[]).await, CasinoError::BetsStillOpen);
+
+    // Settlement keeps working in sunset mode
+    h.fulfill(bet_address, vrf_with_roll(500)).await.unwrap();
+    let config: Config = h.account(config_pda()).await;
+    assert_eq!(config.open_bets, 0);
+
+    assert_casino_error(h.send(&[decommission.clone()], &[]).await, CasinoError::DecommissionTooEarly);
+    h.warp_forward(MIN_EMERGENCY_DELAY_SECS).await;
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    h.send(&[decommission], &[]).await.unwrap();
+    assert_eq!(h.balance(beneficiary).await, pool.balance);
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, 0);
+}
+
+#[tokio::test]
+async fn assert_invariants_flags_an_underfunded_pool() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let assert_invariants = || {
+        ix(
+            accounts::AssertInvariants {
+                config: config_pda(),
+                pool: pool_pda(),
+                reserve_pool: reserve_pool_pda(),
+                house_vault: house_vault_pda(),
+                integrator: None,
+                referral_earnings: None,
+            },
+            instruction::AssertInvariants {},
+        )
+    };
+    h.send(&[assert_invariants()], &[]).await.unwrap();
+
+    // Winnings are still owed, so pulling lamports out of the pool breaks solvency
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    let mut account = h.ctx.banks_client.get_account(pool_pda()).await.unwrap().unwrap();
+    account.lamports -= pool.pending_payouts;
+    h.ctx.set_account(&pool_pda(), &AccountSharedData::from(account));
+
+    assert_casino_error(
+        h.send(&[assert_invariants()], &[]).await,
+        CasinoError::InvariantPoolUnderfunded,
+    );
+}
+
+#[tokio::test]
+async fn reserves_are_published_once_per_epoch() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+    let publisher = h.player(LAMPORTS_PER_SOL).await;
+
+    let publish = ix(
+        accounts::PublishReserves {
+            config: config_pda(),
+            pool: pool_pda(),
+            reserve_pool: reserve_pool_pda(),
+            house_vault: house_vault_pda(),
+            snapshot: reserve_snapshot_pda(&pool_pda()),
+            publisher: publisher.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::PublishReserves {},
+    );
+    h.send(&[publish.clone()], &[&publisher]).await.unwrap();
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    let snapshot: ReserveSnapshot = h.account(reserve_snapshot_pda(&pool_pda())).await;
+    assert_eq!(snapshot.pool, pool_pda());
+    assert_eq!(snapshot.pool_balance, pool.balance);
+    assert_eq!(snapshot.pending_payouts, pool.pending_payouts);
+    assert!(snapshot.pool_lamports >= pool.balance + pool.pending_payouts);
+
+    assert_casino_error(
+        h.send(&[publish.clone()], &[&publisher]).await,
+        CasinoError::ReservesAlreadyPublished,
+    );
+
+    let mut clock: Clock = h.ctx.banks_client.get_sysvar().await.unwrap();
+    clock.epoch += 1;
+    h.ctx.set_sysvar(&clock);
+    h.send(&[publish], &[&publisher]).await.unwrap();
+
+    let republished: ReserveSnapshot = h.account(reserve_snapshot_pda(&pool_pda())).await;
+    assert_eq!(republished.epoch, snapshot.epoch + 1);
+}
+
+#[tokio::test]
+async fn stats_tally_wagers_and_payouts_per_epoch() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let initialize = ix(
+        accounts::InitializeStats {
+            config: config_pda(),
+            stats: stats_pda(),
+            authority: h.authority().pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::InitializeStats {},
+    );
+    h.send(&[initialize], &[]).await.unwrap();
+
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let bet_address = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let clock: Clock = h.ctx.banks_client.get_sysvar().await.unwrap();
+    let stats: Stats = h.zero_copy(stats_pda()).await;
+    let totals = stats.entries[clock.epoch as usize % STATS_EPOCHS];
+    let bet: Bet = h.account(bet_address).await;
+    let house: HouseVault = h.account(house_vault_pda()).await;
+
+    assert_eq!(totals.epoch, clock.epoch);
+    assert_eq!(totals.bets, 1);
+    assert_eq!(totals.wagered, LAMPORTS_PER_SOL);
+    assert_eq!(totals.house_fees, house.total_fees);
+    assert_eq!(totals.paid_out, bet.win_amount);
+    assert_eq!(totals.wins_by_tier, [0, 0, 1, 0]);
+}
+
+#[tokio::test]
+async fn lost_bets_can_be_doubled_once() {
+    let (mut h, player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    h.fund(&house_vault_pda(), 2 * LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    h.update_config(&authority, ConfigUpdate { double_or_nothing_window_secs: Some(600), ..ConfigUpdate::default() })
+        .await
+        .unwrap();
+    h.fulfill(bet_address, vrf_with_roll(500)).await.unwrap();
+
+    let double = ix(
+        accounts::DoubleOrNothing {
+            config: config_pda(),
+            bet: bet_address,
+            vrf_request: vrf_request_pda(&bet_address),
+            house_vault: house_vault_pda(),
+            game_vault: game_vault_pda(),
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            membership: membership_pda(&player.pubkey()),
+            gaming_limits: gaming_limits_pda(&player.pubkey()),
+            player_profile: player_profile_pda(&player.pubkey()),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::DoubleOrNothing {},
+    );
+    let game_vault_before = h.balance(game_vault_pda()).await;
+    h.send(&[double.clone()], &[&player]).await.unwrap();
+    assert_eq!(h.balance(game_vault_pda()).await, game_vault_before + LAMPORTS_PER_SOL);
+
+    let bet: Bet = h.account(bet_address).await;
+    assert!(bet.double_or_nothing);
+    assert_eq!(bet.status, 0);
+
+    // The re-wager settles at 50/50, never against the jackpot
+    h.warp_slots(1).await;
+    assert_casino_error(h.fulfill(bet_address, vrf_with_roll(60)).await, CasinoError::DoubleOrNothingPending);
+
+    let settle = ix(
+        accounts::FulfillDoubleOrNothing {
+            config: config_pda(),
+            pool: pool_pda(),
+            house_vault: house_vault_pda(),
+            game_vault: game_vault_pda(),
+            bet: bet_address,
+            vrf_request: vrf_request_pda(&bet_address),
+            player_profile: player_profile_pda(&player.pubkey()),
+            settler: h.authority().pubkey(),
+        },
+        instruction::FulfillDoubleOrNothing { vrf_result: vrf_with_roll(4999) },
+    );
+    h.send(&[settle], &[]).await.unwrap();
+
+    let bet: Bet = h.account(bet_address).await;
+    assert_eq!(bet.status, 1);
+    assert_eq!(bet.win_amount, 2 * LAMPORTS_PER_SOL);
+
+    // Each loss can only be doubled once
+    assert_casino_error(h.send(&[double], &[&player]).await, CasinoError::DoubleOrNothingUnavailable);
+
+    let player_before = h.balance(player.pubkey()).await;
+    h.claim_win(&player, bet_address).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + 2 * LAMPORTS_PER_SOL);
+}
+
+/// Harness with a funded house vault, parlays capped at 2 SOL, and a player
+async fn parlay_harness() -> (Harness, solana_sdk::signature::Keypair) {
+    let mut h = Harness::new().await;
+    h.fund(&house_vault_pda(), 5 * LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    h.update_config(&authority, ConfigUpdate { max_parlay_payout: Some(2 * LAMPORTS_PER_SOL), ..ConfigUpdate::default() })
+        .await
+        .unwrap();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    (h, player)
+}
+
+#[tokio::test]
+async fn parlays_pay_out_once_every_leg_wins() {
+    let (mut h, player) = parlay_harness().await;
+    let stake = LAMPORTS_PER_SOL / 2;
+
+    // A third even-money leg would take the payout past the cap
+    assert_casino_error(
+        h.create_parlay(&player, stake, vec![5000, 5000, 5000]).await,
+        CasinoError::ParlayPayoutTooLarge,
+    );
+
+    // Each even-money leg pays double, less the 2% house edge
+    let first_leg = parlay_leg_payout(stake, 5000, 200).unwrap();
+    let payout = parlay_leg_payout(first_leg, 5000, 200).unwrap();
+    let parlay_address = h.create_parlay(&player, stake, vec![5000, 5000]).await.unwrap();
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, payout - stake);
+
+    h.fulfill_parlay_leg(parlay_address, vrf_with_roll(100)).await.unwrap();
+    let parlay: Parlay = h.account(parlay_address).await;
+    assert_eq!((parlay.current_leg, parlay.current_stake, parlay.status), (1, first_leg, 0));
+
+    let house_before = h.balance(house_vault_pda()).await;
+    h.fulfill_parlay_leg(parlay_address, vrf_with_roll(4999)).await.unwrap();
+    assert_eq!(h.balance(house_vault_pda()).await, house_before - (payout - stake));
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, 0);
+
+    let claim = ix(
+        accounts::ClaimParlay {
+            config: config_pda(),
+            house_vault: house_vault_pda(),
+            parlay: parlay_address,
+            vrf_request: vrf_request_pda(&parlay_address),
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            player: player.pubkey(),
+        },
+        instruction::ClaimParlay {},
+    );
+    let held = h.balance(parlay_address).await + h.balance(vrf_request_pda(&parlay_address)).await;
+    let player_before = h.balance(player.pubkey()).await;
+    h.send(&[claim], &[&player]).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + held);
+    assert_eq!(h.balance(parlay_address).await, 0);
+}
+
+#[tokio::test]
+async fn a_lost_parlay_leg_pays_the_house_and_closes_the_parlay() {
+    let (mut h, player) = parlay_harness().await;
+    let stake = LAMPORTS_PER_SOL / 2;
+    let parlay_address = h.create_parlay(&player, stake, vec![5000, 5000]).await.unwrap();
+    h.fulfill_parlay_leg(parlay_address, vrf_with_roll(100)).await.unwrap();
+
+    let house_before: HouseVault = h.account(house_vault_pda()).await;
+    let rent = h.balance(parlay_address).await - stake + h.balance(vrf_request_pda(&parlay_address)).await;
+    let player_before = h.balance(player.pubkey()).await;
+    h.fulfill_parlay_leg(parlay_address, vrf_with_roll(5000)).await.unwrap();
+
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, house_before.accumulated_house_fees + stake);
+    assert_eq!(house.game_liability, 0);
+    assert_eq!(h.balance(player.pubkey()).await, player_before + rent);
+    assert_eq!(h.balance(parlay_address).await, 0);
+}
+
+/// Stake on a side-bet bucket in the default pool's round
+async fn place_side_bet(
+    h: &mut Harness,
+    player: &solana_sdk::signature::Keypair,
+    round: Pubkey,
+    bucket: u8,
+    amount: u64,
+) -> Result<(), solana_program_test::BanksClientError> {
+    let place = ix(
+        accounts::PlaceSideBet {
+            config: config_pda(),
+            pool: pool_pda(),
+            side_bet_pool: round,
+            side_bet_entry: side_bet_entry_pda(&round, &player.pubkey()),
+            frozen_player: frozen_player_pda(&player.pubkey()),
+            membership: membership_pda(&player.pubkey()),
+            gaming_limits: gaming_limits_pda(&player.pubkey()),
+            player_profile: player_profile_pda(&player.pubkey()),
+            player: player.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::PlaceSideBet { bucket, amount },
+    );
+    h.send(&[place], &[player]).await
+}
+
+#[tokio::test]
+async fn side_bets_on_the_hit_bucket_split_the_round() {
+    let (mut h, _player, bet_address) = pending_bet(LAMPORTS_PER_SOL).await;
+    let authority = h.authority();
+    let round = side_bet_pool_pda(&pool_pda(), 1);
+    let now = h.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+
+    // Bucket 0 covers a hit within the first 5 bets, bucket 1 everything after
+    let open = ix(
+        accounts::OpenSideBetRound {
+            config: config_pda(),
+            pool: pool_pda(),
+            side_bet_pool: round,
+            signer: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::OpenSideBetRound { round_id: 1, bucket_bounds: vec![5], rake_bps: 500, betting_closes_at: now + 3600 },
+    );
+    h.send(&[open], &[&authority]).await.unwrap();
+
+    let early = h.player(LAMPORTS_PER_SOL).await;
+    let also_early = h.player(LAMPORTS_PER_SOL).await;
+    let late = h.player(LAMPORTS_PER_SOL).await;
+    assert_casino_error(place_side_bet(&mut h, &early, round, 2, LAMPORTS_PER_SOL / 10).await, CasinoError::InvalidSideBetBucket);
+    place_side_bet(&mut h, &early, round, 0, 3 * LAMPORTS_PER_SOL / 10).await.unwrap();
+    place_side_bet(&mut h, &also_early, round, 0, LAMPORTS_PER_SOL / 10).await.unwrap();
+    place_side_bet(&mut h, &late, round, 1, 4 * LAMPORTS_PER_SOL / 10).await.unwrap();
+
+    // A hit can't be settled without the open round
+    let mut settle = h.fulfill_ix(bet_address, vrf_with_roll(60)).await;
+    let side_bet_meta = settle.accounts.iter_mut().find(|meta| meta.pubkey == round).unwrap();
+    *side_bet_meta = AccountMeta::new_readonly(progressive_jackpot::ID, false);
+    assert_casino_error(h.send(&[settle], &[]).await, CasinoError::SideBetPoolRequired);
+
+    let house_before: HouseVault = h.account(house_vault_pda()).await;
+    h.fulfill(bet_address, vrf_with_roll(60)).await.unwrap();
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.side_bet_pool, None);
+    let settled: SideBetPool = h.account(round).await;
+    assert!(settled.settled);
+    assert_eq!((settled.hit_at_bets, settled.winning_bucket), (1, 0));
+
+    // 5% of the 0.8 SOL round goes to the house; bucket 0's backers split the rest 3:1
+    let rake = 8 * LAMPORTS_PER_SOL / 10 * 500 / 10000;
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, house_before.accumulated_house_fees + rake);
+    let distributable = 8 * LAMPORTS_PER_SOL / 10 - rake;
+    assert_eq!(settled.distributable, distributable);
+
+    for (player, expected) in [(&early, distributable * 3 / 4), (&also_early, distributable / 4), (&late, 0)] {
+        let entry = side_bet_entry_pda(&round, &player.pubkey());
+        let claim = ix(
+            accounts::ClaimSideBet {
+                config: config_pda(),
+                side_bet_pool: round,
+                side_bet_entry: entry,
+                frozen_player: frozen_player_pda(&player.pubkey()),
+                player: player.pubkey(),
+            },
+            instruction::ClaimSideBet {},
+        );
+        let rent = h.balance(entry).await;
+        let before = h.balance(player.pubkey()).await;
+        h.send(&[claim], &[player]).await.unwrap();
+        assert_eq!(h.balance(player.pubkey()).await, before + rent + expected);
+    }
+}
+
+#[tokio::test]
+async fn dice_pay_out_at_the_player_chosen_odds() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    h.fund(&house_vault_pda(), 5 * LAMPORTS_PER_SOL).await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    assert_casino_error(h.play_dice(&player, LAMPORTS_PER_SOL, 7500, true).await, CasinoError::DiceDisabled);
+
+    let authority = h.authority();
+    h.update_config(&authority, ConfigUpdate { dice_house_edge_bps: Some(100), ..ConfigUpdate::default() })
+        .await
+        .unwrap();
+    assert_casino_error(h.play_dice(&player, LAMPORTS_PER_SOL, 9900, false).await, CasinoError::InvalidDiceTarget);
+
+    // Rolling over 7500 is a 25% chance, paying 4x less the 1% edge
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+    let dice_address = h.play_dice(&player, LAMPORTS_PER_SOL, 7500, true).await.unwrap();
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, pool_before.balance + LAMPORTS_PER_SOL / 20);
+
+    let dice: DiceRoll = h.account(dice_address).await;
+    assert_eq!(dice.payout, 396 * LAMPORTS_PER_SOL / 100);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, dice.payout - dice.stake);
+
+    // Players can't settle their own rolls with a winning value
+    let self_settle = ix(
+        accounts::FulfillDice {
+            config: config_pda(),
+            house_vault: house_vault_pda(),
+            dice_roll: dice_address,
+            vrf_request: vrf_request_pda(&dice_address),
+            player: player.pubkey(),
+            settler: player.pubkey(),
+        },
+        instruction::FulfillDice { vrf_result: vrf_with_roll(9999) },
+    );
+    assert_casino_error(h.send(&[self_settle], &[&player]).await, CasinoError::Unauthorized);
+
+    let rent = h.balance(dice_address).await - dice.stake + h.balance(vrf_request_pda(&dice_address)).await;
+    let player_before = h.balance(player.pubkey()).await;
+    h.fulfill_dice(dice_address, vrf_with_roll(7500)).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + rent + dice.payout);
+    assert_eq!(h.balance(dice_address).await, 0);
+
+    // A losing roll leaves the held stake with the house
+    let dice_address = h.play_dice(&player, LAMPORTS_PER_SOL, 2000, false).await.unwrap();
+    let dice: DiceRoll = h.account(dice_address).await;
+    let house_before: HouseVault = h.account(house_vault_pda()).await;
+    h.fulfill_dice(dice_address, vrf_with_roll(2000)).await.unwrap();
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, house_before.accumulated_house_fees + dice.stake);
+    assert_eq!(house.game_liability, 0);
+}
+
+#[tokio::test]
+async fn slots_pay_the_landed_paytable_outcome() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    h.fund(&house_vault_pda(), 10 * LAMPORTS_PER_SOL).await;
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    h.set_paytable(vec![]).await.unwrap();
+    assert_casino_error(h.play_slots(&player, LAMPORTS_PER_SOL).await, CasinoError::SlotsDisabled);
+
+    // Rolls below 100 pay 5x, below 950 lose, and the rest win half the pool
+    let entry = |symbol: u8, weight: u16, multiplier_bps: u32, jackpot: bool| PaytableEntry {
+        symbols: [symbol; 3],
+        weight,
+        multiplier_bps,
+        jackpot,
+    };
+    h.set_paytable(vec![entry(7, 100, 50_000, false), entry(0, 850, 0, false), entry(9, 50, 5_000, true)])
+        .await
+        .unwrap();
+
+    let spin_address = h.play_slots(&player, LAMPORTS_PER_SOL).await.unwrap();
+    let spin: SlotSpin = h.account(spin_address).await;
+    assert_eq!(spin.liability, 5 * LAMPORTS_PER_SOL - spin.stake);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, spin.liability);
+    assert_casino_error(h.set_paytable(vec![]).await, CasinoError::PaytableInUse);
+
+    let rent = h.balance(spin_address).await - spin.stake + h.balance(vrf_request_pda(&spin_address)).await;
+    let player_before = h.balance(player.pubkey()).await;
+    h.fulfill_slots(spin_address, vrf_with_roll(42)).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + rent + 5 * LAMPORTS_PER_SOL);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, 0);
+
+    // A losing spin leaves the held stake with the house
+    let spin_address = h.play_slots(&player, LAMPORTS_PER_SOL).await.unwrap();
+    let house_before: HouseVault = h.account(house_vault_pda()).await;
+    h.fulfill_slots(spin_address, vrf_with_roll(500)).await.unwrap();
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, house_before.accumulated_house_fees + spin.stake);
+
+    // A jackpot spin reserves half the pool as a claimable win instead of paying it out
+    let spin_address = h.play_slots(&player, LAMPORTS_PER_SOL).await.unwrap();
+    let spin: SlotSpin = h.account(spin_address).await;
+    let jackpot_bet = slots_win_pda(&spin_address, spin.created_at);
+    let pool_before: JackpotPool = h.account(pool_pda()).await;
+    let rent = h.balance(spin_address).await - spin.stake + h.balance(vrf_request_pda(&spin_address)).await;
+    let player_before = h.balance(player.pubkey()).await;
+    h.fulfill_slots(spin_address, vrf_with_roll(975)).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + rent);
+
+    let win = pool_before.balance / 2;
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, pool_before.balance - win);
+    assert_eq!(pool.pending_payouts, pool_before.pending_payouts + win);
+    assert_eq!(pool.bets_since_win, 0);
+    assert_eq!(pool.last_winner, Some(player.pubkey()));
+
+    let bet: Bet = h.account(jackpot_bet).await;
+    assert_eq!((bet.player, bet.status, bet.win_amount), (player.pubkey(), 1, win));
+
+    h.claim_win(&player, jackpot_bet).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + rent + win);
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.pending_payouts, pool_before.pending_payouts);
+}
+
+#[tokio::test]
+async fn scratch_cards_reveal_committed_outcomes() {
+    let mut h = Harness::new().await;
+    h.fund(&house_vault_pda(), 5 * LAMPORTS_PER_SOL).await;
+    let player = h.player(LAMPORTS_PER_SOL).await;
+    let price = LAMPORTS_PER_SOL / 10;
+
+    // Four-card tree where only card 1 wins
+    let batch = scratch_batch_pda(1);
+    let outcomes: Vec<ScratchOutcome> = [0, 2 * LAMPORTS_PER_SOL, 0, 0]
+        .iter()
+        .enumerate()
+        .map(|(i, &prize)| ScratchOutcome { prize, salt: [i as u8 + 1; 32] })
+        .collect();
+    let leaves: Vec<[u8; 32]> = outcomes
+        .iter()
+        .enumerate()
+        .map(|(i, o)| hashv(&[batch.as_ref(), &(i as u32).to_le_bytes(), &o.prize.to_le_bytes(), &o.salt]).to_bytes())
+        .collect();
+    let pair = |a: [u8; 32], b: [u8; 32]| if a <= b { hashv(&[&a, &b]).to_bytes() } else { hashv(&[&b, &a]).to_bytes() };
+    let (left, right) = (pair(leaves[0], leaves[1]), pair(leaves[2], leaves[3]));
+    let merkle_root = pair(left, right);
+
+    h.open_scratch_batch(1, merkle_root, 4, price, 2 * LAMPORTS_PER_SOL).await.unwrap();
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, 2 * LAMPORTS_PER_SOL);
+
+    let losing_card = h.buy_scratch_card(&player, batch).await.unwrap();
+    let winning_card = h.buy_scratch_card(&player, batch).await.unwrap();
+    assert_eq!(winning_card, scratch_card_pda(&batch, 1));
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, 2 * price);
+
+    // A forged prize doesn't hash to the committed root
+    let forged = ScratchOutcome { prize: 3 * LAMPORTS_PER_SOL, ..outcomes[1] };
+    assert_casino_error(h.reveal_card(batch, 1, forged, vec![leaves[0], right]).await, CasinoError::InvalidScratchProof);
+    assert_casino_error(h.close_scratch_batch(batch).await, CasinoError::ScratchCardsUnrevealed);
+
+    let rent = h.balance(winning_card).await;
+    let player_before = h.balance(player.pubkey()).await;
+    h.reveal_card(batch, 1, outcomes[1], vec![leaves[0], right]).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + rent + 2 * LAMPORTS_PER_SOL);
+    assert_eq!(h.balance(winning_card).await, 0);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.game_liability, 0);
+
+    h.reveal_card(batch, 0, outcomes[0], vec![leaves[1], right]).await.unwrap();
+    assert_eq!(h.balance(losing_card).await, 0);
+
+    // Unsold cards are forfeited when the batch closes
+    h.close_scratch_batch(batch).await.unwrap();
+    assert_eq!(h.balance(batch).await, 0);
+}
+
+#[tokio::test]
+async fn lotto_tiers_split_the_pot_among_matching_tickets() {
+    let mut h = Harness::new().await;
+    let authority = h.authority();
+    let alice = h.player(LAMPORTS_PER_SOL).await;
+    let bob = h.player(LAMPORTS_PER_SOL).await;
+    let price = LAMPORTS_PER_SOL / 10;
+
+    // Pick 2 of 5; a 10% rake, then 30% of the pot to one match and 70% to two
+    let round = lotto_round_pda(1);
+    let now = h.ctx.banks_client.get_sysvar::<Clock>().await.unwrap().unix_timestamp;
+    let open = ix(
+        accounts::OpenLottoRound {
+            config: config_pda(),
+            round,
+            signer: authority.pubkey(),
+            system_program: system_program::ID,
+        },
+        instruction::OpenLottoRound {
+            round_id: 1,
+            params: LottoRoundParams {
+                picks: 2,
+                max_number: 5,
+                ticket_price: price,
+                draw_ts: now + 3600,
+                rake_bps: 1000,
+                tier_shares_bps: vec![3000, 7000],
+            },
+        },
+    );
+    h.send(&[open], &[]).await.unwrap();
+
+    assert_casino_error(h.buy_lotto_ticket(&alice, round, vec![2, 2]).await, CasinoError::InvalidLottoPicks);
+    let tickets = vec![
+        h.buy_lotto_ticket(&alice, round, vec![3, 2]).await.unwrap(),
+        h.buy_lotto_ticket(&alice, round, vec![1, 4]).await.unwrap(),
+        h.buy_lotto_ticket(&bob, round, vec![3, 5]).await.unwrap(),
+        h.buy_lotto_ticket(&bob, round, vec![4, 3]).await.unwrap(),
+    ];
+    assert_casino_error(h.draw_lotto(round, vrf_with_roll(2)).await, CasinoError::LottoNotDrawable);
+    assert_casino_error(h.crank_lotto(round, &tickets).await, CasinoError::LottoNotDrawn);
+
+    h.warp_forward(3600).await;
+    assert_casino_error(h.buy_lotto_ticket(&bob, round, vec![1, 2]).await, CasinoError::LottoSalesClosed);
+
+    // Ticket holders can't draw their own numbers
+    let self_draw = ix(
+        accounts::DrawLotto { config: config_pda(), round, settler: bob.pubkey() },
+        instruction::DrawLotto { vrf_result: vrf_with_roll(2) },
+    );
+    assert_casino_error(h.send(&[self_draw], &[&bob]).await, CasinoError::Unauthorized);
+
+    // The first slice of the result swaps 1 and 3; the second is zero and leaves 2 in place
+    h.draw_lotto(round, vrf_with_roll(2)).await.unwrap();
+    let drawn: LottoRound = h.account(round).await;
+    assert_eq!(drawn.winning_numbers[..2], [2, 3]);
+
+    let house_before: HouseVault = h.account(house_vault_pda()).await;
+    h.crank_lotto(round, &tickets[..2]).await.unwrap();
+    h.crank_lotto(round, &tickets[1..]).await.unwrap();
+    let tallied: LottoRound = h.account(round).await;
+    assert_eq!(tallied.tallied_count, 4);
+    assert_eq!(tallied.tier_winners[..2], [2, 1]);
+
+    let net_pot = 4 * price * 9 / 10;
+    let (one_match, two_matches) = (net_pot * 3 / 10 / 2, net_pot * 7 / 10);
+    assert_eq!(tallied.tier_payouts[..2], [one_match, two_matches]);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, house_before.accumulated_house_fees + 4 * price - 2 * one_match - two_matches);
+
+    let ticket_rent = h.balance(tickets[0]).await;
+    let (alice_before, bob_before) = (h.balance(alice.pubkey()).await, h.balance(bob.pubkey()).await);
+    h.crank_lotto(round, &tickets).await.unwrap();
+    assert_eq!(h.balance(alice.pubkey()).await, alice_before + 2 * ticket_rent + two_matches);
+    assert_eq!(h.balance(bob.pubkey()).await, bob_before + 2 * ticket_rent + 2 * one_match);
+    for ticket in &tickets {
+        assert_eq!(h.balance(*ticket).await, 0);
+    }
+    let settled: LottoRound = h.account(round).await;
+    assert_eq!(settled.paid_count, 4);
+}
+
+#[tokio::test]
+async fn a_days_losses_earn_a_bonus_wheel_spin_paid_from_house_fees() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let authority = h.authority();
+    h.update_config(&authority, ConfigUpdate { bonus_wheel_losses: Some(2), ..ConfigUpdate::default() })
+        .await
+        .unwrap();
+
+    // Half the wheel pays a small prize; the other half more than the house has collected
+    h.set_bonus_wheel(vec![
+        WheelPrize { weight: 1, amount: LAMPORTS_PER_SOL / 100 },
+        WheelPrize { weight: 1, amount: 10 * LAMPORTS_PER_SOL },
+    ])
+    .await
+    .unwrap();
+
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+    let first = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    h.fulfill(first, vrf_with_roll(500)).await.unwrap();
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!((profile.day_losses, profile.bonus_wheel_spins), (1, 0));
+    assert_casino_error(h.spin_bonus_wheel(&player).await, CasinoError::NoBonusWheelSpins);
+
+    let second = h.contribute_bet(&player, LAMPORTS_PER_SOL / 2).await.unwrap();
+    h.fulfill(second, vrf_with_roll(500)).await.unwrap();
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!((profile.day_losses, profile.bonus_wheel_spins), (2, 1));
+
+    let spin = h.spin_bonus_wheel(&player).await.unwrap();
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!(profile.bonus_wheel_spins, 0);
+
+    let rent = h.balance(spin).await + h.balance(vrf_request_pda(&spin)).await;
+    let house_before: HouseVault = h.account(house_vault_pda()).await;
+    let player_before = h.balance(player.pubkey()).await;
+    h.fulfill_bonus_wheel(spin, vrf_with_roll(0)).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + rent + LAMPORTS_PER_SOL / 100);
+    let house: HouseVault = h.account(house_vault_pda()).await;
+    assert_eq!(house.accumulated_house_fees, house_before.accumulated_house_fees - LAMPORTS_PER_SOL / 100);
+
+    // A third loss the same day doesn't earn another spin
+    let third = h.contribute_bet(&player, LAMPORTS_PER_SOL / 4).await.unwrap();
+    h.fulfill(third, vrf_with_roll(500)).await.unwrap();
+    let profile: PlayerProfile = h.account(player_profile_pda(&player.pubkey())).await;
+    assert_eq!((profile.day_losses, profile.bonus_wheel_spins), (3, 0));
+}
+
+#[tokio::test]
+async fn the_bet_crossing_the_mystery_threshold_wins_the_pool() {
+    let mut h = Harness::new().await;
+    h.seed_jackpot(LAMPORTS_PER_SOL).await.unwrap();
+    let player = h.player(5 * LAMPORTS_PER_SOL).await;
+
+    let seeded: JackpotPool = h.account(pool_pda()).await;
+    let threshold = seeded.balance + LAMPORTS_PER_SOL / 10;
+    let salt = [7u8; 32];
+    let round = h
+        .commit_mystery_jackpot(1, hashv(&[&threshold.to_le_bytes(), &salt]).to_bytes())
+        .await
+        .unwrap();
+
+    // 5% of 1 SOL stays under the threshold; 5% of 2 SOL more crosses it
+    let short = h.contribute_bet(&player, LAMPORTS_PER_SOL).await.unwrap();
+    let crossing = h.contribute_bet(&player, 2 * LAMPORTS_PER_SOL).await.unwrap();
+    let bet: Bet = h.account(crossing).await;
+    assert!(bet.pool_balance_before < threshold && bet.pool_balance_after >= threshold);
+
+    assert_casino_error(h.reveal_mystery(round, crossing, threshold, [8u8; 32]).await, CasinoError::InvalidMysteryReveal);
+    assert_casino_error(h.reveal_mystery(round, short, threshold, salt).await, CasinoError::MysteryNotTriggered);
+
+    // The win is reserved in the pool and claimed like any other jackpot win
+    let MysteryJackpot { started_at, .. } = h.account(round).await;
+    let mystery_win = mystery_win_pda(&round, started_at);
+    let player_before = h.balance(player.pubkey()).await;
+    h.reveal_mystery(round, crossing, threshold, salt).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before);
+    assert_eq!(h.balance(round).await, 0);
+
+    let pool: JackpotPool = h.account(pool_pda()).await;
+    assert_eq!(pool.balance, 0);
+    assert_eq!(pool.pending_payouts, bet.pool_balance_after);
+    assert_eq!(pool.bets_since_win, 0);
+    assert_eq!(pool.last_winner, Some(player.pubkey()));
+
+    let win: Bet = h.account(mystery_win).await;
+    assert_eq!((win.player, win.status, win.win_amount), (player.pubkey(), 1, bet.pool_balance_after));
+
+    h.claim_win(&player, mystery_win).await.unwrap();
+    assert_eq!(h.balance(player.pubkey()).await, player_before + bet.pool_balance_after);
+}